@@ -137,6 +137,8 @@ build_exceptions! {
     DropColumnEmptyError(1109),
     // create table or alter table add column with internal column name
     TableWithInternalColumnName(1110),
+    // order by an expression which is not supported in its context
+    UnsupportedOrderBy(1111),
 
     // Data Related Errors
 
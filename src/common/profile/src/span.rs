@@ -22,11 +22,20 @@ pub type ProfSpanSetRef<K = u32> = Arc<Mutex<ProfSpanSet<K>>>;
 pub struct ProfSpan {
     /// The time spent to process in nanoseconds
     pub process_time: u64,
+    /// The number of rows produced
+    pub output_rows: usize,
+    /// The size of the rows produced in bytes
+    pub output_bytes: usize,
+    /// The peak memory usage in bytes, summed up over the processors running in parallel
+    pub peak_memory_usage: usize,
 }
 
 impl ProfSpan {
     pub fn add(&mut self, other: &Self) {
         self.process_time += other.process_time;
+        self.output_rows += other.output_rows;
+        self.output_bytes += other.output_bytes;
+        self.peak_memory_usage += other.peak_memory_usage;
     }
 }
 
@@ -55,6 +64,9 @@ where K: std::hash::Hash + Eq
 #[derive(Clone, Default)]
 pub struct ProfSpanBuilder {
     process_time: u64,
+    output_rows: usize,
+    output_bytes: usize,
+    peak_memory_usage: usize,
 }
 
 impl ProfSpanBuilder {
@@ -62,9 +74,21 @@ impl ProfSpanBuilder {
         self.process_time += nanos;
     }
 
+    pub fn accumulate_output(&mut self, rows: usize, bytes: usize) {
+        self.output_rows += rows;
+        self.output_bytes += bytes;
+    }
+
+    pub fn update_peak_memory_usage(&mut self, bytes: usize) {
+        self.peak_memory_usage = self.peak_memory_usage.max(bytes);
+    }
+
     pub fn finish(self) -> ProfSpan {
         ProfSpan {
             process_time: self.process_time,
+            output_rows: self.output_rows,
+            output_bytes: self.output_bytes,
+            peak_memory_usage: self.peak_memory_usage,
         }
     }
 }
@@ -39,6 +39,16 @@ pub enum StageFileStatus {
     AlreadyCopied,
 }
 
+/// The status of a file loaded into a table by `COPY`.
+#[derive(Clone, Debug, Default)]
+pub struct FileLoadStatus {
+    pub rows_loaded: u64,
+    /// Rows skipped because of errors, under `ON_ERROR = CONTINUE`.
+    pub errors_seen: u64,
+    /// The most frequent error of the skipped rows.
+    pub error: Option<ErrorCode>,
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct StageFileInfo {
     pub path: String,
@@ -62,13 +62,18 @@ pub enum UserPrivilegeType {
     DropRole = 1 << 14,
     // Privilege to Drop user.
     DropUser = 1 << 15,
+    // Privilege to read the files of a stage.
+    Read = 1 << 16,
+    // Privilege to write the files of a stage.
+    Write = 1 << 17,
     // TODO: remove this later
     Set = 1 << 4,
 }
 
 const ALL_PRIVILEGES: BitFlags<UserPrivilegeType> = make_bitflags!(
     UserPrivilegeType::{
-        Create
+        Usage
+        | Create
         | Select
         | Insert
         | Update
@@ -82,6 +87,8 @@ const ALL_PRIVILEGES: BitFlags<UserPrivilegeType> = make_bitflags!(
         | DropRole
         | Grant
         | CreateStage
+        | Read
+        | Write
         | Set
     }
 );
@@ -104,6 +111,8 @@ impl std::fmt::Display for UserPrivilegeType {
             UserPrivilegeType::DropRole => "DROP ROLE",
             UserPrivilegeType::CreateStage => "CREATE STAGE",
             UserPrivilegeType::Grant => "GRANT",
+            UserPrivilegeType::Read => "READ",
+            UserPrivilegeType::Write => "WRITE",
             UserPrivilegeType::Set => "SET",
         })
     }
@@ -126,11 +135,13 @@ impl UserPrivilegeSet {
     }
 
     /// The all privileges which available to the global grant object. It contains ALL the privileges
-    /// on databases and tables, and has some Global only privileges.
+    /// on databases, tables, stages and udfs, and has some Global only privileges.
     pub fn available_privileges_on_global() -> Self {
         let database_privs = Self::available_privileges_on_database();
+        let stage_privs = Self::available_privileges_on_stage();
+        let udf_privs = Self::available_privileges_on_udf();
         let privs = make_bitflags!(UserPrivilegeType::{ Usage | Super | CreateUser | DropUser | CreateRole | DropRole | Grant });
-        (database_privs.privileges | privs).into()
+        (database_privs.privileges | stage_privs.privileges | udf_privs.privileges | privs).into()
     }
 
     /// The available privileges on database object contains ALL the available privileges to a table.
@@ -145,6 +156,16 @@ impl UserPrivilegeSet {
         make_bitflags!(UserPrivilegeType::{ Create | Update | Select | Insert | Delete | Drop | Alter | Grant }).into()
     }
 
+    /// The all privileges which available to the stage object
+    pub fn available_privileges_on_stage() -> Self {
+        make_bitflags!(UserPrivilegeType::{ Read | Write }).into()
+    }
+
+    /// The all privileges which available to the udf object
+    pub fn available_privileges_on_udf() -> Self {
+        make_bitflags!(UserPrivilegeType::{ Usage }).into()
+    }
+
     // TODO: remove this, as ALL has different meanings on different objects
     pub fn all_privileges() -> Self {
         ALL_PRIVILEGES.into()
@@ -190,6 +190,28 @@ impl FromStr for StageFileFormatType {
     }
 }
 
+impl StageFileFormatType {
+    /// Infer the format from the extension of a file, the compression extension
+    /// (e.g. `.gz` of `data.csv.gz`) is skipped.
+    pub fn from_path(path: &str) -> Option<StageFileFormatType> {
+        let name = path.rsplit('/').next()?.to_lowercase();
+        let mut stem = name.as_str();
+        loop {
+            let (rest, ext) = stem.rsplit_once('.')?;
+            match ext {
+                "csv" => return Some(StageFileFormatType::Csv),
+                "tsv" => return Some(StageFileFormatType::Tsv),
+                "ndjson" | "jsonl" => return Some(StageFileFormatType::NdJson),
+                "parquet" => return Some(StageFileFormatType::Parquet),
+                "xml" => return Some(StageFileFormatType::Xml),
+                "gz" | "gzip" | "bz2" | "br" | "zst" | "zstd" | "xz" | "lzma" | "deflate"
+                | "zz" => stem = rest,
+                _ => return None,
+            }
+        }
+    }
+}
+
 impl ToString for StageFileFormatType {
     fn to_string(&self) -> String {
         format!("{:?}", *self)
@@ -396,38 +418,34 @@ impl Display for OnErrorMode {
 impl FromStr for OnErrorMode {
     type Err = String;
     fn from_str(s: &str) -> std::result::Result<Self, String> {
+        let unknown = |v: &str| {
+            format!(
+                "Unknown OnError mode:{:?}, must one of {{ CONTINUE | SKIP_FILE | SKIP_FILE_<num> | ABORT | ABORT_STATEMENT | ABORT_<num> }}",
+                v
+            )
+        };
+
         match s.to_uppercase().as_str() {
-            "" | "ABORT" => Ok(OnErrorMode::AbortNum(1)),
+            "" | "ABORT" | "ABORT_STATEMENT" => Ok(OnErrorMode::AbortNum(1)),
             "CONTINUE" => Ok(OnErrorMode::Continue),
             "SKIP_FILE" => Ok(OnErrorMode::SkipFileNum(1)),
             v => {
-                if v.starts_with("ABORT_") {
-                    let num_str = v.replace("ABORT_", "");
-                    let nums = num_str.parse::<u64>();
-                    match nums {
-                        Ok(n) if n < 1 => {
-                            Err("OnError mode `ABORT_<num>` num must be greater than 0".to_string())
-                        }
-                        Ok(n) => Ok(OnErrorMode::AbortNum(n)),
-                        Err(_) => Err(format!(
-                            "Unknown OnError mode:{:?}, must one of {{ CONTINUE | SKIP_FILE | SKIP_FILE_<num> | ABORT | ABORT_<num> }}",
-                            v
-                        )),
-                    }
-                } else {
-                    let num_str = v.replace("SKIP_FILE_", "");
-                    let nums = num_str.parse::<u64>();
-                    match nums {
-                        Ok(n) if n < 1 => {
-                            Err("OnError mode `SKIP_FILE_<num>` num must be greater than 0"
-                                .to_string())
-                        }
-                        Ok(n) => Ok(OnErrorMode::SkipFileNum(n)),
-                        Err(_) => Err(format!(
-                            "Unknown OnError mode:{:?}, must one of {{ CONTINUE | SKIP_FILE | SKIP_FILE_<num> | ABORT | ABORT_<num> }}",
-                            v
-                        )),
-                    }
+                let (mode, num_str, create): (_, _, fn(u64) -> Self) =
+                    if let Some(num_str) = v.strip_prefix("ABORT_") {
+                        ("ABORT_<num>", num_str, OnErrorMode::AbortNum)
+                    } else if let Some(num_str) = v.strip_prefix("SKIP_FILE_") {
+                        ("SKIP_FILE_<num>", num_str, OnErrorMode::SkipFileNum)
+                    } else {
+                        return Err(unknown(v));
+                    };
+
+                match num_str.parse::<u64>() {
+                    Ok(0) => Err(format!(
+                        "OnError mode `{}` num must be greater than 0",
+                        mode
+                    )),
+                    Ok(n) => Ok(create(n)),
+                    Err(_) => Err(unknown(v)),
                 }
             }
         }
@@ -17,6 +17,7 @@ mod user_grant;
 mod user_info;
 mod user_privilege;
 mod user_quota;
+mod user_stage;
 
 #[test]
 fn test_bin_commit_version() -> anyhow::Result<()> {
@@ -77,6 +77,46 @@ fn test_grant_object_contains() -> Result<()> {
             rhs: GrantObject::Database("default".into(), "db1".into()),
             expect: false,
         },
+        Test {
+            lhs: GrantObject::Global,
+            rhs: GrantObject::Stage("s1".into()),
+            expect: true,
+        },
+        Test {
+            lhs: GrantObject::Stage("s1".into()),
+            rhs: GrantObject::Stage("s1".into()),
+            expect: true,
+        },
+        Test {
+            lhs: GrantObject::Stage("s1".into()),
+            rhs: GrantObject::Stage("s2".into()),
+            expect: false,
+        },
+        Test {
+            lhs: GrantObject::Database("default".into(), "db1".into()),
+            rhs: GrantObject::Stage("s1".into()),
+            expect: false,
+        },
+        Test {
+            lhs: GrantObject::Global,
+            rhs: GrantObject::UDF("f1".into()),
+            expect: true,
+        },
+        Test {
+            lhs: GrantObject::UDF("f1".into()),
+            rhs: GrantObject::UDF("f1".into()),
+            expect: true,
+        },
+        Test {
+            lhs: GrantObject::UDF("f1".into()),
+            rhs: GrantObject::UDF("f2".into()),
+            expect: false,
+        },
+        Test {
+            lhs: GrantObject::UDF("f1".into()),
+            rhs: GrantObject::Stage("f1".into()),
+            expect: false,
+        },
     ];
     for t in tests {
         assert_eq!(
@@ -148,6 +188,51 @@ fn test_user_grant_entry() -> Result<()> {
         vec![UserPrivilegeType::Create]
     ));
 
+    let grant = GrantEntry::new(
+        GrantObject::Stage("s1".into()),
+        make_bitflags!(UserPrivilegeType::{Read}),
+    );
+    assert!(
+        grant.verify_privilege(&GrantObject::Stage("s1".into()), vec![
+            UserPrivilegeType::Read
+        ])
+    );
+    assert!(
+        !grant.verify_privilege(&GrantObject::Stage("s2".into()), vec![
+            UserPrivilegeType::Read
+        ])
+    );
+    assert!(
+        !grant.verify_privilege(&GrantObject::Stage("s1".into()), vec![
+            UserPrivilegeType::Write
+        ])
+    );
+    assert_eq!(grant.to_string(), "GRANT READ ON STAGE s1");
+
+    let grant = GrantEntry::new(
+        GrantObject::Stage("s1".into()),
+        make_bitflags!(UserPrivilegeType::{Read | Write}),
+    );
+    assert!(
+        grant.verify_privilege(&GrantObject::Stage("s1".into()), vec![
+            UserPrivilegeType::Write
+        ])
+    );
+    assert_eq!(grant.to_string(), "GRANT ALL ON STAGE s1");
+
+    let grant = GrantEntry::new(
+        GrantObject::UDF("f1".into()),
+        make_bitflags!(UserPrivilegeType::{Usage}),
+    );
+    assert!(grant.verify_privilege(&GrantObject::UDF("f1".into()), vec![
+        UserPrivilegeType::Usage
+    ]));
+    assert!(
+        !grant.verify_privilege(&GrantObject::UDF("f2".into()), vec![
+            UserPrivilegeType::Usage
+        ])
+    );
+
     Ok(())
 }
 
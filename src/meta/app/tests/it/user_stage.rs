@@ -0,0 +1,78 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::str::FromStr;
+
+use common_meta_app::principal::OnErrorMode;
+use common_meta_app::principal::StageFileFormatType;
+
+#[test]
+fn test_on_error_mode_from_str() {
+    let cases = [
+        ("", OnErrorMode::AbortNum(1)),
+        ("abort", OnErrorMode::AbortNum(1)),
+        ("ABORT_STATEMENT", OnErrorMode::AbortNum(1)),
+        ("abort_statement", OnErrorMode::AbortNum(1)),
+        ("abort_3", OnErrorMode::AbortNum(3)),
+        ("continue", OnErrorMode::Continue),
+        ("skip_file", OnErrorMode::SkipFileNum(1)),
+        ("SKIP_FILE_10", OnErrorMode::SkipFileNum(10)),
+    ];
+    for (s, expected) in cases {
+        assert_eq!(OnErrorMode::from_str(s), Ok(expected), "{}", s);
+    }
+}
+
+#[test]
+fn test_on_error_mode_from_str_invalid() {
+    let err = OnErrorMode::from_str("abort_0").unwrap_err();
+    assert!(err.contains("`ABORT_<num>` num must be greater than 0"));
+
+    let err = OnErrorMode::from_str("skip_file_0").unwrap_err();
+    assert!(err.contains("`SKIP_FILE_<num>` num must be greater than 0"));
+
+    for s in [
+        "abort_",
+        "abort_x",
+        "abort_-1",
+        "skip_file_x",
+        "5",
+        "skip",
+        "abort_statement_2",
+    ] {
+        let err = OnErrorMode::from_str(s).unwrap_err();
+        assert!(err.starts_with("Unknown OnError mode"), "{}: {}", s, err);
+    }
+}
+
+#[test]
+fn test_stage_file_format_type_from_path() {
+    let cases = [
+        ("data.csv", Some(StageFileFormatType::Csv)),
+        ("dir/data.CSV", Some(StageFileFormatType::Csv)),
+        ("dir/data.csv.gz", Some(StageFileFormatType::Csv)),
+        ("data.tsv", Some(StageFileFormatType::Tsv)),
+        ("data.ndjson", Some(StageFileFormatType::NdJson)),
+        ("data.jsonl.zst", Some(StageFileFormatType::NdJson)),
+        ("data.parquet", Some(StageFileFormatType::Parquet)),
+        ("data.xml", Some(StageFileFormatType::Xml)),
+        ("data.txt", None),
+        ("data.gz", None),
+        ("data", None),
+        ("dir.csv/data", None),
+    ];
+    for (path, expected) in cases {
+        assert_eq!(StageFileFormatType::from_path(path), expected, "{}", path);
+    }
+}
@@ -161,6 +161,12 @@ impl FromToProto for mt::principal::GrantObject {
                 db,
                 table,
             })) => Ok(mt::principal::GrantObject::Table(catalog, db, table)),
+            Some(pb::grant_object::Object::Stage(pb::grant_object::GrantStageObject { stage })) => {
+                Ok(mt::principal::GrantObject::Stage(stage))
+            }
+            Some(pb::grant_object::Object::Udf(pb::grant_object::GrantUdfObject { udf })) => {
+                Ok(mt::principal::GrantObject::UDF(udf))
+            }
             _ => Err(Incompatible {
                 reason: "GrantObject cannot be None".to_string(),
             }),
@@ -185,6 +191,14 @@ impl FromToProto for mt::principal::GrantObject {
                     table: table.clone(),
                 }),
             ),
+            mt::principal::GrantObject::Stage(stage) => Some(pb::grant_object::Object::Stage(
+                pb::grant_object::GrantStageObject {
+                    stage: stage.clone(),
+                },
+            )),
+            mt::principal::GrantObject::UDF(udf) => Some(pb::grant_object::Object::Udf(
+                pb::grant_object::GrantUdfObject { udf: udf.clone() },
+            )),
         };
         Ok(pb::GrantObject {
             ver: VER,
@@ -244,7 +258,20 @@ impl FromToProto for mt::principal::UserGrantSet {
         for role in p.roles.iter() {
             roles.insert(role.0.clone());
         }
-        Ok(mt::principal::UserGrantSet::new(entries, roles))
+        let mut grant_set = mt::principal::UserGrantSet::new(entries, roles);
+
+        // Stages and udfs were accessible without any privilege before v31,
+        // keep them accessible to the users and roles created before then.
+        if p.ver < 31 {
+            grant_set.grant_privileges(
+                &mt::principal::GrantObject::Global,
+                (mt::principal::UserPrivilegeType::Usage
+                    | mt::principal::UserPrivilegeType::Read
+                    | mt::principal::UserPrivilegeType::Write)
+                    .into(),
+            );
+        }
+        Ok(grant_set)
     }
 
     fn to_pb(&self) -> Result<pb::UserGrantSet, Incompatible> {
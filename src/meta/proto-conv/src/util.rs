@@ -60,6 +60,7 @@ const META_CHANGE_LOG: &[(u64, &str)] = &[
     (28, "2023-02-13: Add: user.proto/UserDefinedFileFormat"),
     (29, "2023-02-23: Add: metadata.proto/DataType EmptyMap types", ),
     (30, "2023-02-21: Add: config.proto/WebhdfsStorageConfig; Modify: user.proto/UserStageInfo::StageStorage", ),
+    (31, "2023-03-08: Add: user.proto/GrantObject::stage and GrantObject::udf, UserPrivilegeType::Read and UserPrivilegeType::Write, which are granted globally to the older grant sets; Bump MIN_READER_VER to 31", ),
     // Dear developer:
     //      If you're gonna add a new metadata version, you'll have to add a test for it.
     //      You could just copy an existing test file(e.g., `../tests/it/v024_table_meta.rs`)
@@ -74,7 +75,7 @@ pub const VER: u64 = META_CHANGE_LOG.last().unwrap().0;
 /// The minimal reader version that can read message of version `VER`, i.e. `message.ver=VER`.
 ///
 /// This is written to every message that needs to be serialized independently.
-pub const MIN_READER_VER: u64 = 24;
+pub const MIN_READER_VER: u64 = 31;
 
 /// Attribute of a reader:
 /// The minimal message version(`message.ver`) that a reader can read.
@@ -36,4 +36,6 @@ mod v026_schema;
 mod v027_schema;
 mod v028_schema;
 mod v029_schema;
+mod v030_user_grant;
 mod v030_user_stage;
+mod v031_user_grant;
@@ -68,6 +68,20 @@ fn test_user_info() -> mt::principal::UserInfo {
     }
 }
 
+// The global grant created before v31 also has the privileges on stages and udfs.
+fn test_old_user_info() -> mt::principal::UserInfo {
+    mt::principal::UserInfo {
+        grants: mt::principal::UserGrantSet::new(
+            vec![mt::principal::GrantEntry::new(
+                mt::principal::GrantObject::Global,
+                make_bitflags!(UserPrivilegeType::{Create | Usage | Read | Write}),
+            )],
+            HashSet::new(),
+        ),
+        ..test_user_info()
+    }
+}
+
 pub(crate) fn test_fs_stage_info() -> mt::principal::StageInfo {
     mt::principal::StageInfo {
         stage_name: "fs://dir/to/files".to_string(),
@@ -644,7 +658,7 @@ fn test_load_old_user() -> anyhow::Result<()> {
         let p: pb::UserInfo =
             common_protos::prost::Message::decode(user_info_v4.as_slice()).map_err(print_err)?;
         let got = mt::principal::UserInfo::from_pb(p).map_err(print_err)?;
-        let want = test_user_info();
+        let want = test_old_user_info();
 
         assert_eq!(want, got);
     }
@@ -678,7 +692,7 @@ fn test_load_old_user() -> anyhow::Result<()> {
         let p: pb::UserInfo =
             common_protos::prost::Message::decode(user_info_v3.as_slice()).map_err(print_err)?;
         let got = mt::principal::UserInfo::from_pb(p).map_err(print_err)?;
-        let want = test_user_info();
+        let want = test_old_user_info();
         assert_eq!(want, got);
     }
 
@@ -0,0 +1,64 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use common_meta_app as mt;
+use common_meta_app::principal::UserPrivilegeType;
+use enumflags2::make_bitflags;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+#[test]
+fn test_decode_v30_user_grant() -> anyhow::Result<()> {
+    // Encoded data of version 30 of common_meta_app::principal::user_grant::UserGrantSet:
+    let user_grant_set_v30 = vec![
+        10, 36, 10, 26, 26, 18, 10, 7, 100, 101, 102, 97, 117, 108, 116, 18, 3, 100, 98, 49, 26, 2,
+        116, 49, 160, 6, 30, 168, 6, 24, 16, 4, 160, 6, 30, 168, 6, 24, 160, 6, 30, 168, 6, 24,
+    ];
+
+    // Stages and udfs were accessible without any privilege before v31, so a grant set of v30
+    // is granted the privileges on them globally, even without any global grant.
+    let want = || {
+        mt::principal::UserGrantSet::new(
+            vec![
+                mt::principal::GrantEntry::new(
+                    mt::principal::GrantObject::Table(
+                        "default".to_string(),
+                        "db1".to_string(),
+                        "t1".to_string(),
+                    ),
+                    make_bitflags!(UserPrivilegeType::{Select}),
+                ),
+                mt::principal::GrantEntry::new(
+                    mt::principal::GrantObject::Global,
+                    make_bitflags!(UserPrivilegeType::{Usage | Read | Write}),
+                ),
+            ],
+            HashSet::new(),
+        )
+    };
+    common::test_load_old(func_name!(), user_grant_set_v30.as_slice(), 30, want())?;
+    common::test_pb_from_to(func_name!(), want())?;
+
+    Ok(())
+}
@@ -0,0 +1,62 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use common_meta_app as mt;
+use common_meta_app::principal::UserPrivilegeType;
+use enumflags2::make_bitflags;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_pb_from_to()`
+#[test]
+fn test_decode_v31_user_grant() -> anyhow::Result<()> {
+    // Encoded data of version 31 of common_meta_app::principal::user_grant::UserGrantSet:
+    // It is generated with common::test_pb_from_to().
+    let user_grant_set_v31 = vec![
+        10, 24, 10, 12, 34, 4, 10, 2, 115, 49, 160, 6, 31, 168, 6, 31, 16, 128, 128, 4, 160, 6, 31,
+        168, 6, 31, 10, 22, 10, 12, 42, 4, 10, 2, 102, 49, 160, 6, 31, 168, 6, 31, 16, 1, 160, 6,
+        31, 168, 6, 31, 160, 6, 31, 168, 6, 31,
+    ];
+
+    let want = || {
+        mt::principal::UserGrantSet::new(
+            vec![
+                mt::principal::GrantEntry::new(
+                    mt::principal::GrantObject::Stage("s1".to_string()),
+                    make_bitflags!(UserPrivilegeType::{Read}),
+                ),
+                mt::principal::GrantEntry::new(
+                    mt::principal::GrantObject::UDF("f1".to_string()),
+                    make_bitflags!(UserPrivilegeType::{Usage}),
+                ),
+            ],
+            HashSet::new(),
+        )
+    };
+    common::test_load_old(func_name!(), user_grant_set_v31.as_slice(), 31, want())?;
+    common::test_pb_from_to(func_name!(), want())?;
+
+    Ok(())
+}
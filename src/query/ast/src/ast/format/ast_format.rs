@@ -691,7 +691,9 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
             ExplainKind::Fragments => "Fragments",
             ExplainKind::Raw => "Raw",
             ExplainKind::Plan => "Plan",
+            ExplainKind::JsonPlan => "JsonPlan",
             ExplainKind::Memo(_) => "Memo",
+            ExplainKind::Optimize(_) => "Optimize",
             ExplainKind::AnalyzePlan => "Analyze",
         });
         let format_ctx = AstFormatContext::with_children(name, 1);
@@ -770,6 +772,7 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 catalog,
                 database,
                 table,
+                ..
             } => self.visit_table_ref(catalog, database, table),
             CopyUnit::StageLocation(v) => {
                 let location_format_ctx =
@@ -967,6 +970,12 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 self.children.push(values_node);
             }
             InsertSource::Select { query } => self.visit_query(query),
+            InsertSource::Stage { location, .. } => {
+                let stage_name = format!("StageSource @{}", location);
+                let stage_format_ctx = AstFormatContext::new(stage_name);
+                let stage_node = FormatTreeNode::new(stage_format_ctx);
+                self.children.push(stage_node);
+            }
         }
         let child = self.children.pop().unwrap();
         let name = "Source".to_string();
@@ -1005,6 +1014,10 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
             self.visit_expr(&update_expr.expr);
             children.push(self.children.pop().unwrap());
         }
+        for table in update.from.iter() {
+            self.visit_table_reference(table);
+            children.push(self.children.pop().unwrap());
+        }
         if let Some(selection) = &update.selection {
             self.visit_expr(selection);
             children.push(self.children.pop().unwrap());
@@ -15,6 +15,7 @@
 use pretty::RcDoc;
 
 use super::expr::pretty_expr;
+use super::query::pretty_from;
 use super::query::pretty_query;
 use super::query::pretty_table;
 use crate::ast::format::syntax::inline_comma;
@@ -109,6 +110,28 @@ fn pretty_source(source: InsertSource) -> RcDoc<'static> {
                 .append(RcDoc::text(rest_str)),
         ),
         InsertSource::Select { query } => pretty_query(*query),
+        InsertSource::Stage {
+            location,
+            file_format,
+        } => RcDoc::text("FROM")
+            .append(RcDoc::space())
+            .append(RcDoc::text(format!("@{location}")))
+            .append(if !file_format.is_empty() {
+                RcDoc::line()
+                    .append(RcDoc::text("FILE_FORMAT = "))
+                    .append(parenthenized(
+                        interweave_comma(file_format.iter().map(|(k, v)| {
+                            RcDoc::text(k.to_string())
+                                .append(RcDoc::space())
+                                .append(RcDoc::text("="))
+                                .append(RcDoc::space())
+                                .append(RcDoc::text(format!("{:?}", v)))
+                        }))
+                        .group(),
+                    ))
+            } else {
+                RcDoc::nil()
+            }),
     })
 }
 
@@ -135,6 +158,7 @@ pub(crate) fn pretty_update(update_stmt: UpdateStmt) -> RcDoc<'static> {
         )
         .append(RcDoc::line().append(RcDoc::text("SET")))
         .append(pretty_update_list(update_stmt.update_list))
+        .append(pretty_from(update_stmt.from))
         .append(if let Some(selection) = update_stmt.selection {
             RcDoc::line().append(RcDoc::text("WHERE")).append(
                 RcDoc::line()
@@ -232,6 +256,7 @@ fn pretty_copy_unit(copy_unit: CopyUnit) -> RcDoc<'static> {
             catalog,
             database,
             table,
+            columns,
         } => if let Some(catalog) = catalog {
             RcDoc::text(catalog.to_string()).append(RcDoc::text("."))
         } else {
@@ -242,7 +267,19 @@ fn pretty_copy_unit(copy_unit: CopyUnit) -> RcDoc<'static> {
         } else {
             RcDoc::nil()
         })
-        .append(RcDoc::text(table.to_string())),
+        .append(RcDoc::text(table.to_string()))
+        .append(if !columns.is_empty() {
+            RcDoc::space()
+                .append(RcDoc::text("("))
+                .append(inline_comma(
+                    columns
+                        .into_iter()
+                        .map(|ident| RcDoc::text(ident.to_string())),
+                ))
+                .append(RcDoc::text(")"))
+        } else {
+            RcDoc::nil()
+        }),
         CopyUnit::StageLocation(v) => RcDoc::text("@")
             .append(RcDoc::text(v.name))
             .append(RcDoc::text(v.path)),
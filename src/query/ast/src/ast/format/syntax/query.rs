@@ -169,7 +169,7 @@ fn pretty_select_list(select_list: Vec<SelectTarget>) -> RcDoc<'static> {
     )
 }
 
-fn pretty_from(from: Vec<TableReference>) -> RcDoc<'static> {
+pub(crate) fn pretty_from(from: Vec<TableReference>) -> RcDoc<'static> {
     if !from.is_empty() {
         RcDoc::line()
             .append(RcDoc::text("FROM").append(RcDoc::line().nest(NEST_FACTOR)))
@@ -56,6 +56,8 @@ pub struct With {
 pub struct CTE {
     pub span: Span,
     pub alias: TableAlias,
+    /// Whether the CTE is computed once and shared by all its references.
+    pub materialized: bool,
     pub query: Query,
 }
 
@@ -541,7 +543,11 @@ impl Display for SetExpr {
 
 impl Display for CTE {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{} AS ({})", self.alias, self.query)?;
+        write!(f, "{} AS ", self.alias)?;
+        if self.materialized {
+            write!(f, "MATERIALIZED ")?;
+        }
+        write!(f, "({})", self.query)?;
         Ok(())
     }
 }
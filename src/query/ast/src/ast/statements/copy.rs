@@ -22,6 +22,7 @@ use std::io::Result;
 
 use url::Url;
 
+use crate::ast::write_comma_separated_list;
 use crate::ast::write_quoted_comma_separated_list;
 use crate::ast::write_space_separated_map;
 use crate::ast::Identifier;
@@ -125,10 +126,14 @@ pub enum CopyUnit {
     /// Table can be used in `INTO` or `FROM`.
     ///
     /// While table used as `FROM`, it will be rewrite as `(SELECT * FROM table)`
+    ///
+    /// While table used as `INTO`, the columns of the files can be mapped to a
+    /// list of columns of the table, like `mytable (c1, c3)`.
     Table {
         catalog: Option<Identifier>,
         database: Option<Identifier>,
         table: Identifier,
+        columns: Vec<Identifier>,
     },
     /// StageLocation (a.k.a internal and external stage) can be used
     /// in `INTO` or `FROM`.
@@ -166,18 +171,25 @@ impl Display for CopyUnit {
                 catalog,
                 database,
                 table,
+                columns,
             } => {
                 if let Some(catalog) = catalog {
                     write!(
                         f,
                         "{catalog}.{}.{table}",
                         database.as_ref().expect("database must be valid")
-                    )
+                    )?;
                 } else if let Some(database) = database {
-                    write!(f, "{database}.{table}")
+                    write!(f, "{database}.{table}")?;
                 } else {
-                    write!(f, "{table}")
+                    write!(f, "{table}")?;
+                }
+                if !columns.is_empty() {
+                    write!(f, " (")?;
+                    write_comma_separated_list(f, columns)?;
+                    write!(f, ")")?;
                 }
+                Ok(())
             }
             CopyUnit::StageLocation(v) => v.fmt(f),
             CopyUnit::UriLocation(v) => v.fmt(f),
@@ -19,11 +19,15 @@ pub enum ExplainKind {
     // The display string will be filled by optimizer, as we
     // don't want to expose `Memo` to other crates.
     Memo(String),
+    // The plans after each step of the heuristic optimization, filled by optimizer too.
+    Optimize(String),
     Graph,
     Pipeline,
     Fragments,
     Raw,
     Plan,
+    // The plan as a JSON tree, by `EXPLAIN FORMAT JSON`
+    JsonPlan,
 
     // Explain analyze plan
     AnalyzePlan,
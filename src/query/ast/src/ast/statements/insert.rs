@@ -20,6 +20,7 @@ use crate::ast::write_comma_separated_list;
 use crate::ast::write_period_separated_list;
 use crate::ast::Identifier;
 use crate::ast::Query;
+use crate::ast::SelectTarget;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct InsertStmt {
@@ -29,6 +30,7 @@ pub struct InsertStmt {
     pub columns: Vec<Identifier>,
     pub source: InsertSource,
     pub overwrite: bool,
+    pub returning: Vec<SelectTarget>,
 }
 
 impl Display for InsertStmt {
@@ -51,7 +53,12 @@ impl Display for InsertStmt {
             write_comma_separated_list(f, &self.columns)?;
             write!(f, ")")?;
         }
-        write!(f, " {}", self.source)
+        write!(f, " {}", self.source)?;
+        if !self.returning.is_empty() {
+            write!(f, " RETURNING ")?;
+            write_comma_separated_list(f, &self.returning)?;
+        }
+        Ok(())
     }
 }
 
@@ -72,6 +79,11 @@ pub enum InsertSource {
     Select {
         query: Box<Query>,
     },
+    /// `FROM @<stage>[/<path>] [FILE_FORMAT = (...)]`, the file format overrides the stage's.
+    Stage {
+        location: String,
+        file_format: BTreeMap<String, String>,
+    },
 }
 
 impl Display for InsertSource {
@@ -91,6 +103,20 @@ impl Display for InsertSource {
             }
             InsertSource::Values { rest_str } => write!(f, "VALUES {rest_str}"),
             InsertSource::Select { query } => write!(f, "{query}"),
+            InsertSource::Stage {
+                location,
+                file_format,
+            } => {
+                write!(f, "FROM @{location}")?;
+                if !file_format.is_empty() {
+                    write!(f, " FILE_FORMAT = (")?;
+                    for (k, v) in file_format.iter() {
+                        write!(f, " {} = '{}'", k, v)?;
+                    }
+                    write!(f, " )")?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -252,8 +252,10 @@ impl Display for Statement {
                     ExplainKind::Fragments => write!(f, " FRAGMENTS")?,
                     ExplainKind::Raw => write!(f, " RAW")?,
                     ExplainKind::Plan => (),
+                    ExplainKind::JsonPlan => write!(f, " FORMAT JSON")?,
                     ExplainKind::AnalyzePlan => write!(f, " ANALYZE")?,
                     ExplainKind::Memo(_) => write!(f, "MEMO")?,
+                    ExplainKind::Optimize(_) => write!(f, " OPTIMIZE")?,
                 }
                 write!(f, " {query}")?;
             }
@@ -24,6 +24,8 @@ use crate::ast::TableReference;
 pub struct UpdateStmt {
     pub table: TableReference,
     pub update_list: Vec<UpdateExpr>,
+    // `FROM` clause, the tables joined with the updated table.
+    pub from: Vec<TableReference>,
     pub selection: Option<Expr>,
 }
 
@@ -37,6 +39,10 @@ impl Display for UpdateStmt {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         write!(f, "UPDATE {} SET ", self.table)?;
         write_comma_separated_list(f, &self.update_list)?;
+        if !self.from.is_empty() {
+            write!(f, " FROM ")?;
+            write_comma_separated_list(f, &self.from)?;
+        }
         if let Some(conditions) = &self.selection {
             write!(f, " WHERE {conditions}")?;
         }
@@ -152,6 +152,8 @@ pub enum AccountMgrLevel {
     Global,
     Database(Option<String>),
     Table(Option<String>, String),
+    Stage(String),
+    UDF(String),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -195,6 +197,8 @@ impl Display for AccountMgrSource {
                             write!(f, " {table_name}")?;
                         }
                     }
+                    AccountMgrLevel::Stage(stage_name) => write!(f, " STAGE {stage_name}")?,
+                    AccountMgrLevel::UDF(udf_name) => write!(f, " UDF {udf_name}")?,
                 }
             }
             AccountMgrSource::ALL { level, .. } => {
@@ -216,6 +220,8 @@ impl Display for AccountMgrSource {
                             write!(f, " {table_name}")?;
                         }
                     }
+                    AccountMgrLevel::Stage(stage_name) => write!(f, " STAGE {stage_name}")?,
+                    AccountMgrLevel::UDF(udf_name) => write!(f, " UDF {udf_name}")?,
                 }
             }
         }
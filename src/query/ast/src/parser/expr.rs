@@ -853,8 +853,9 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
             rule! { BETWEEN ~ #window_frame_bound ~ AND ~ #window_frame_bound },
             |(_, s, _, e)| (s, e),
         ),
+        // `ROWS <bound>` is a shorthand for `ROWS BETWEEN <bound> AND CURRENT ROW`
         map(rule! {#window_frame_bound}, |s| {
-            (s, WindowFrameBound::Following(None))
+            (s, WindowFrameBound::CurrentRow)
         }),
     ));
 
@@ -1074,12 +1075,18 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
         },
     );
 
-    let is_distinct_from = map(
-        rule! {
-            IS ~ NOT? ~ DISTINCT ~ FROM
-        },
-        |(_, not, _, _)| ExprElement::IsDistinctFrom { not: not.is_some() },
-    );
+    let is_distinct_from = alt((
+        map(
+            rule! {
+                IS ~ NOT? ~ DISTINCT ~ FROM
+            },
+            |(_, not, _, _)| ExprElement::IsDistinctFrom { not: not.is_some() },
+        ),
+        // `<=>` is the null-safe equal operator, i.e. `IS NOT DISTINCT FROM`.
+        map(rule! { "<=>" }, |_| ExprElement::IsDistinctFrom {
+            not: true,
+        }),
+    ));
     let (rest, (span, elem)) = consumed(alt((
         // Note: each `alt` call supports maximum of 21 parsers
         rule!(
@@ -1128,20 +1135,20 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
 pub fn window_frame_bound(i: Input) -> IResult<WindowFrameBound> {
     alt((
         value(WindowFrameBound::CurrentRow, rule! { CURRENT ~ ROW }),
-        map(rule! { #subexpr(0) ~ PRECEDING }, |(expr, _)| {
-            WindowFrameBound::Preceding(Some(Box::new(expr)))
-        }),
         value(
             WindowFrameBound::Preceding(None),
             rule! { UNBOUNDED ~ PRECEDING },
         ),
-        map(rule! { #subexpr(0) ~ FOLLOWING }, |(expr, _)| {
-            WindowFrameBound::Following(Some(Box::new(expr)))
-        }),
         value(
             WindowFrameBound::Following(None),
             rule! { UNBOUNDED ~ FOLLOWING },
         ),
+        map(rule! { #subexpr(0) ~ PRECEDING }, |(expr, _)| {
+            WindowFrameBound::Preceding(Some(Box::new(expr)))
+        }),
+        map(rule! { #subexpr(0) ~ FOLLOWING }, |(expr, _)| {
+            WindowFrameBound::Following(Some(Box::new(expr)))
+        }),
     ))(i)
 }
 
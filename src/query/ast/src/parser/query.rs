@@ -64,11 +64,12 @@ pub fn query(i: Input) -> IResult<Query> {
 pub fn with(i: Input) -> IResult<With> {
     let cte = map(
         consumed(rule! {
-            #table_alias ~ AS ~ "(" ~ #query ~ ")"
+            #table_alias ~ AS ~ MATERIALIZED? ~ "(" ~ #query ~ ")"
         }),
-        |(span, (table_alias, _, _, query, _))| CTE {
+        |(span, (table_alias, _, materialized, _, query, _))| CTE {
             span: transform_span(span.0),
             alias: table_alias,
+            materialized: materialized.is_some(),
             query,
         },
     );
@@ -56,9 +56,15 @@ pub enum CreateDatabaseOption {
 pub fn statement(i: Input) -> IResult<StatementMsg> {
     let explain = map_res(
         rule! {
-            EXPLAIN ~ ( AST | SYNTAX | PIPELINE | GRAPH | FRAGMENTS | RAW | MEMO )? ~ #statement
-        },
-        |(_, opt_kind, statement)| {
+            EXPLAIN ~ ( AST | SYNTAX | PIPELINE | GRAPH | FRAGMENTS | RAW | MEMO )?
+            ~ ( FORMAT ~ JSON )? ~ #statement
+        },
+        |(_, opt_kind, opt_format_json, statement)| {
+            if opt_kind.is_some() && opt_format_json.is_some() {
+                return Err(ErrorKind::Other(
+                    "FORMAT JSON is only supported by EXPLAIN of the plan",
+                ));
+            }
             Ok(Statement::Explain {
                 kind: match opt_kind.map(|token| token.kind) {
                     Some(TokenKind::AST) => {
@@ -76,6 +82,7 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
                     Some(TokenKind::FRAGMENTS) => ExplainKind::Fragments,
                     Some(TokenKind::RAW) => ExplainKind::Raw,
                     Some(TokenKind::MEMO) => ExplainKind::Memo("".to_string()),
+                    None if opt_format_json.is_some() => ExplainKind::JsonPlan,
                     None => ExplainKind::Plan,
                     _ => unreachable!(),
                 },
@@ -83,6 +90,17 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             })
         },
     );
+    // `OPTIMIZE` also starts the `OPTIMIZE TABLE` statement, so it's parsed as an explain
+    // kind in a separate rule, `EXPLAIN OPTIMIZE TABLE t` falls back to `explain` if it fails.
+    let explain_optimize = map(
+        rule! {
+            EXPLAIN ~ OPTIMIZE ~ #statement
+        },
+        |(_, _, statement)| Statement::Explain {
+            kind: ExplainKind::Optimize("".to_string()),
+            query: Box::new(statement.stmt),
+        },
+    );
     let explain_analyze = map(
         rule! {
             EXPLAIN ~ ANALYZE ~ #statement
@@ -98,8 +116,9 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             ~ #period_separated_idents_1_to_3
             ~ ( "(" ~ #comma_separated_list1(ident) ~ ")" )?
             ~ #insert_source
+            ~ ( RETURNING ~ ^#comma_separated_list1(select_target) )?
         },
-        |(_, overwrite, _, (catalog, database, table), opt_columns, source)| {
+        |(_, overwrite, _, (catalog, database, table), opt_columns, source, opt_returning)| {
             Statement::Insert(InsertStmt {
                 catalog,
                 database,
@@ -109,6 +128,9 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
                     .unwrap_or_default(),
                 source,
                 overwrite: overwrite.kind == OVERWRITE,
+                returning: opt_returning
+                    .map(|(_, returning)| returning)
+                    .unwrap_or_default(),
             })
         },
     );
@@ -157,12 +179,14 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         rule! {
             UPDATE ~ #table_reference_only
             ~ SET ~ ^#comma_separated_list1(update_expr)
+            ~ ( FROM ~ ^#comma_separated_list1(table_reference) )?
             ~ ( WHERE ~ ^#expr )?
         },
-        |(_, table, _, update_list, opt_selection)| {
+        |(_, table, _, update_list, opt_from, opt_selection)| {
             Statement::Update(UpdateStmt {
                 table,
                 update_list,
+                from: opt_from.map(|(_, from)| from).unwrap_or_default(),
                 selection: opt_selection.map(|(_, selection)| selection),
             })
         },
@@ -1063,7 +1087,8 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
     let statement_body = alt((
         rule!(
             #map(query, |query| Statement::Query(Box::new(query)))
-            | #explain : "`EXPLAIN [PIPELINE | GRAPH] <statement>`"
+            | #explain_optimize : "`EXPLAIN OPTIMIZE <statement>`"
+            | #explain : "`EXPLAIN [PIPELINE | GRAPH | FORMAT JSON] <statement>`"
             | #explain_analyze : "`EXPLAIN ANALYZE <statement>`"
             | #delete : "`DELETE FROM <table> [WHERE ...]`"
             | #update : "`UPDATE <table> SET <column> = <expr> [, <column> = <expr> , ... ] [WHERE ...]`"
@@ -1217,10 +1242,19 @@ pub fn insert_source(i: Input) -> IResult<InsertSource> {
     );
     let values = map(
         rule! {
-            VALUES ~ #rest_str
+            VALUES ~ #values_rest_str
         },
         |(_, (rest_str, _))| InsertSource::Values { rest_str },
     );
+    let stage = map(
+        rule! {
+            FROM ~ #at_string ~ #file_format_clause?
+        },
+        |(_, location, file_format)| InsertSource::Stage {
+            location,
+            file_format: file_format.unwrap_or_default(),
+        },
+    );
     let query = map(query, |query| InsertSource::Select {
         query: Box::new(query),
     });
@@ -1229,6 +1263,7 @@ pub fn insert_source(i: Input) -> IResult<InsertSource> {
         #streaming
         | #streaming_v2
         | #values
+        | #stage
         | #query
     )(i)
 }
@@ -1267,6 +1302,33 @@ pub fn rest_str(i: Input) -> IResult<(String, usize)> {
     ))
 }
 
+/// Like `rest_str`, but stops before the `RETURNING` clause of `INSERT INTO ... VALUES`.
+pub fn values_rest_str(i: Input) -> IResult<(String, usize)> {
+    let mut depth = 0;
+    let returning = i.0.iter().position(|token| {
+        match token.kind {
+            LParen => depth += 1,
+            RParen => depth -= 1,
+            _ => {}
+        }
+        depth == 0 && token.kind == RETURNING
+    });
+    match returning {
+        Some(pos) if pos > 0 => {
+            let first_token = i.0.first().unwrap();
+            let last_token = &i.0[pos - 1];
+            Ok((
+                i.slice(pos..),
+                (
+                    first_token.source[first_token.span.start..last_token.span.end].to_string(),
+                    first_token.span.start,
+                ),
+            ))
+        }
+        _ => rest_str(i),
+    }
+}
+
 pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
     #[derive(Clone)]
     enum ColumnConstraint {
@@ -1356,6 +1418,8 @@ pub fn priv_type(i: Input) -> IResult<UserPrivilegeType> {
     alt((
         value(UserPrivilegeType::Usage, rule! { USAGE }),
         value(UserPrivilegeType::Select, rule! { SELECT }),
+        value(UserPrivilegeType::Read, rule! { READ }),
+        value(UserPrivilegeType::Write, rule! { WRITE }),
         value(UserPrivilegeType::Insert, rule! { INSERT }),
         value(UserPrivilegeType::Update, rule! { UPDATE }),
         value(UserPrivilegeType::Delete, rule! { DELETE }),
@@ -1434,8 +1498,20 @@ pub fn grant_level(i: Input) -> IResult<AccountMgrLevel> {
         },
     );
 
+    // STAGE my_stage
+    let stage = map(rule! { STAGE ~ #ident }, |(_, stage)| {
+        AccountMgrLevel::Stage(stage.name)
+    });
+
+    // UDF my_udf
+    let udf = map(rule! { UDF ~ #ident }, |(_, udf)| {
+        AccountMgrLevel::UDF(udf.name)
+    });
+
     rule!(
         #global : "*.*"
+        | #stage : "STAGE <stage>"
+        | #udf : "UDF <udf>"
         | #db : "<database>.*"
         | #table : "<database>.<table>"
     )(i)
@@ -1631,14 +1707,20 @@ pub fn copy_unit(i: Input) -> IResult<CopyUnit> {
         )(i)
     };
 
-    // Parse input like `mytable`
+    // Parse input like `mytable` or `mytable (c1, c2)`
     let table = |i| {
         map(
-            period_separated_idents_1_to_3,
-            |(catalog, database, table)| CopyUnit::Table {
+            rule! {
+                #period_separated_idents_1_to_3
+                ~ ( "(" ~ #comma_separated_list1(ident) ~ ")" )?
+            },
+            |((catalog, database, table), opt_columns)| CopyUnit::Table {
                 catalog,
                 database,
                 table,
+                columns: opt_columns
+                    .map(|(_, columns, _)| columns)
+                    .unwrap_or_default(),
             },
         )(i)
     };
@@ -1663,7 +1745,7 @@ pub fn copy_unit(i: Input) -> IResult<CopyUnit> {
     rule!(
        #stage_location: "@<stage_name> { <path> }"
         | #inner_uri_location: "'<protocol>://<name> {<path>} { CONNECTION = ({ AWS_ACCESS_KEY = 'aws_access_key' }) } '"
-        | #table: "{ { <catalog>. } <database>. }<table>"
+        | #table: "{ { <catalog>. } <database>. }<table> { ( <column>, ... ) }"
         | #query: "( <query> )"
     )(i)
 }
@@ -561,6 +561,8 @@ pub enum TokenKind {
     MAX_FILE_SIZE,
     #[token("MASTER_KEY", ignore(ascii_case))]
     MASTER_KEY,
+    #[token("MATERIALIZED", ignore(ascii_case))]
+    MATERIALIZED,
     #[token("MEMO", ignore(ascii_case))]
     MEMO,
     #[token("MEMORY", ignore(ascii_case))]
@@ -643,6 +645,8 @@ pub enum TokenKind {
     RANGE,
     #[token("RAWDEFLATE", ignore(ascii_case))]
     RAWDEFLATE,
+    #[token("READ", ignore(ascii_case))]
+    READ,
     #[token("RECLUSTER", ignore(ascii_case))]
     RECLUSTER,
     #[token("RECORD_DELIMITER", ignore(ascii_case))]
@@ -677,6 +681,8 @@ pub enum TokenKind {
     REGION,
     #[token("REMOVE", ignore(ascii_case))]
     REMOVE,
+    #[token("RETURNING", ignore(ascii_case))]
+    RETURNING,
     #[token("REVOKE", ignore(ascii_case))]
     REVOKE,
     #[token("RECURSIVE", ignore(ascii_case))]
@@ -809,6 +815,8 @@ pub enum TokenKind {
     UNBOUNDED,
     #[token("UNION", ignore(ascii_case))]
     UNION,
+    #[token("UDF", ignore(ascii_case))]
+    UDF,
     #[token("UINT16", ignore(ascii_case))]
     UINT16,
     #[token("UINT32", ignore(ascii_case))]
@@ -849,6 +857,8 @@ pub enum TokenKind {
     WHERE,
     #[token("WITH", ignore(ascii_case))]
     WITH,
+    #[token("WRITE", ignore(ascii_case))]
+    WRITE,
     #[token("XML", ignore(ascii_case))]
     XML,
     #[token("XOR", ignore(ascii_case))]
@@ -1079,7 +1089,7 @@ impl TokenKind {
             | TokenKind::ROWS
             | TokenKind::RANGE
             // | TokenKind::PRECISION
-            // | TokenKind::RETURNING
+            | TokenKind::RETURNING
             | TokenKind::TO
             | TokenKind::UNION
             | TokenKind::WHERE
@@ -1196,7 +1206,7 @@ impl TokenKind {
             | TokenKind::ROWS
             | TokenKind::RANGE
             // | TokenKind::OVERLAPS
-            // | TokenKind::RETURNING
+            | TokenKind::RETURNING
             | TokenKind::STAGE
             | TokenKind::SHARE
             | TokenKind::SHARES
@@ -74,6 +74,9 @@ fn test_statement() {
         r#"show create table a.b;"#,
         r#"show create table a.b format TabSeparatedWithNamesAndTypes;"#,
         r#"explain pipeline select a from b;"#,
+        r#"explain format json select a from b;"#,
+        r#"explain optimize select a from b;"#,
+        r#"explain optimize table t all;"#,
         r#"describe a;"#,
         r#"describe a format TabSeparatedWithNamesAndTypes;"#,
         r#"create table a (c decimal(38, 0))"#,
@@ -144,8 +147,10 @@ fn test_statement() {
         r#"select 1 from numbers(1) where ((1 = 1) or 1)"#,
         r#"select * from read_parquet('p1', 'p2', 'p3', prune_page => true, refresh_meta_cache => true);"#,
         r#"insert into t (c1, c2) values (1, 2), (3, 4);"#,
+        r#"insert into t (c1, c2) values (1, 2), (3, 4) returning c1, c2 + 1 as c;"#,
         r#"insert into table t format json;"#,
         r#"insert into table t select * from t2;"#,
+        r#"insert overwrite t from @my_stage/data file_format = (type = CSV skip_header = 1);"#,
         r#"select parse_json('{"k1": [0, 1, 2]}').k1[0];"#,
         r#"CREATE STAGE ~"#,
         r#"CREATE STAGE IF NOT EXISTS test_stage url='s3://load/files/' credentials=(aws_key_id='1a2b3c' aws_secret_key='4x5y6z') file_format=(type = CSV compression = GZIP record_delimiter=',')"#,
@@ -187,6 +192,9 @@ fn test_statement() {
         r#"GRANT SELECT ON db01.tb1 TO ROLE 'role1';"#,
         r#"GRANT SELECT ON tb1 TO ROLE 'role1';"#,
         r#"GRANT ALL ON tb1 TO 'u1';"#,
+        r#"GRANT READ ON STAGE s1 TO ROLE 'role1';"#,
+        r#"GRANT WRITE ON STAGE s1 TO ROLE 'role1';"#,
+        r#"GRANT USAGE ON UDF f1 TO 'test-grant'@'localhost';"#,
         r#"SHOW GRANTS;"#,
         r#"SHOW GRANTS FOR 'test-grant'@'localhost';"#,
         r#"SHOW GRANTS FOR USER 'test-grant'@'localhost';"#,
@@ -194,6 +202,7 @@ fn test_statement() {
         r#"REVOKE SELECT, CREATE ON * FROM 'test-grant'@'localhost';"#,
         r#"REVOKE SELECT ON tb1 FROM ROLE 'role1';"#,
         r#"REVOKE ALL ON tb1 FROM 'u1';"#,
+        r#"REVOKE READ ON STAGE s1 FROM ROLE 'role1';"#,
         r#"COPY INTO mytable
                 FROM @~/mybucket/data.csv
                 FILE_FORMAT = (
@@ -316,6 +325,7 @@ fn test_statement() {
                     skip_header = 1
                 )
                 size_limit=10;"#,
+        r#"COPY INTO mytable (c1, c3) FROM @my_stage FILE_FORMAT = (type = CSV);"#,
         // We used to support COPY FROM a quoted at string
         // r#"COPY INTO mytable
         //         FROM '@external_stage/path/to/file.csv'
@@ -354,6 +364,7 @@ fn test_statement() {
         r#"SHOW GRANTS ON DATABASE db;"#,
         r#"SHOW GRANTS OF SHARE t;"#,
         r#"UPDATE db1.tb1 set a = a + 1, b = 2 WHERE c > 3;"#,
+        r#"UPDATE t1 SET a = t2.a FROM t2 WHERE t1.id = t2.id;"#,
         r#"SET max_threads = 10;"#,
         r#"SET max_threads = 10*2;"#,
         r#"UNSET max_threads;"#,
@@ -459,6 +470,7 @@ fn test_query() {
         r#"select * from customer natural join orders left outer join detail using (id)"#,
         r#"with t2(tt) as (select a from t) select t2.tt from t2  where t2.tt > 1"#,
         r#"with t2 as (select a from t) select t2.a from t2  where t2.a > 1"#,
+        r#"with t2 as materialized (select a from t) select t2.a from t2  where t2.a > 1"#,
         r#"with t2(tt) as (select a from t), t3 as (select * from t), t4 as (select a from t where a > 1) select t2.tt, t3.a, t4.a from t2, t3, t4 where t2.tt > 1"#,
         r#"with recursive t2(tt) as (select a from t1 union select tt from t2) select t2.tt from t2"#,
         r#"select c_count cc, count(*) as custdist, sum(c_acctbal) as totacctbal
@@ -583,6 +595,7 @@ fn test_expr() {
         r#"1 is distinct from 2"#,
         r#"a is distinct from b"#,
         r#"1 is not distinct from null"#,
+        r#"a <=> b"#,
         r#"{'k1':1,'k2':2}"#,
         // window expr
         r#"ROW_NUMBER() OVER (ORDER BY salary DESC)"#,
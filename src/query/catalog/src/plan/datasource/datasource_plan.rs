@@ -36,6 +36,9 @@ pub struct DataSourcePlan {
     pub tbl_args: Option<TableArgs>,
     pub push_downs: Option<PushDownInfo>,
     pub query_internal_columns: bool,
+    /// The index of the table in the metadata of the query, used to find the runtime
+    /// filters pushed down into the scan.
+    pub table_index: Option<usize>,
 }
 
 impl DataSourcePlan {
@@ -0,0 +1,133 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
+use common_expression::types::UInt64Type;
+use common_expression::types::ValueType;
+use common_expression::DataBlock;
+use common_expression::TableSchemaRef;
+
+/// The rows of a table matched by the `FROM` clause of an `UPDATE`, and their new values.
+///
+/// A row is identified by the internal columns `_block_name` and `_row_id`.
+#[derive(Clone, Debug)]
+pub struct MatchedRows {
+    /// Block location -> row id -> offset of the new values of the row in `values`.
+    rows: HashMap<Vec<u8>, HashMap<u64, u32>>,
+    /// The schema of `values`.
+    schema: TableSchemaRef,
+    values: DataBlock,
+}
+
+impl MatchedRows {
+    /// Creates from the result of `SELECT _block_name, _row_id, <values> ...`.
+    ///
+    /// A row that is matched more than once keeps the values of its first match, so all
+    /// its new values come from the same matched row.
+    pub fn try_create(blocks: &[DataBlock], schema: TableSchemaRef) -> Result<Self> {
+        let block = DataBlock::concat(blocks)?;
+        let num_rows = block.num_rows();
+        if block.num_columns() != schema.num_fields() + 2 {
+            return Err(ErrorCode::Internal(format!(
+                "matched rows expect {} columns, but got {}",
+                schema.num_fields() + 2,
+                block.num_columns()
+            )));
+        }
+
+        let block_names = block
+            .get_by_offset(0)
+            .value
+            .convert_to_full_column(&DataType::String, num_rows);
+        let block_names = StringType::try_downcast_column(&block_names)
+            .ok_or_else(|| ErrorCode::Internal("_block_name must be a string column"))?;
+        let row_ids = block
+            .get_by_offset(1)
+            .value
+            .convert_to_full_column(&DataType::Number(NumberDataType::UInt64), num_rows);
+        let row_ids = UInt64Type::try_downcast_column(&row_ids)
+            .ok_or_else(|| ErrorCode::Internal("_row_id must be a UInt64 column"))?;
+
+        let mut rows: HashMap<Vec<u8>, HashMap<u64, u32>> = HashMap::new();
+        for (offset, (block_name, row_id)) in block_names.iter().zip(row_ids.iter()).enumerate() {
+            rows.entry(block_name.to_vec())
+                .or_default()
+                .entry(*row_id)
+                .or_insert(offset as u32);
+        }
+
+        let values = DataBlock::new(block.columns()[2..].to_vec(), num_rows);
+        Ok(MatchedRows {
+            rows,
+            schema,
+            values,
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    pub fn schema(&self) -> TableSchemaRef {
+        self.schema.clone()
+    }
+
+    pub fn contains_block(&self, block_location: &str) -> bool {
+        self.rows.contains_key(block_location.as_bytes())
+    }
+
+    /// Returns which rows of a block are matched, and the new values of all the rows.
+    ///
+    /// The values of the rows that are not matched are unspecified. Returns `None` if none of
+    /// the rows is matched.
+    pub fn take_block_rows(
+        &self,
+        block_location: &str,
+        row_ids: &[u64],
+    ) -> Result<Option<(Bitmap, DataBlock)>> {
+        let rows = match self.rows.get(block_location.as_bytes()) {
+            Some(rows) => rows,
+            None => return Ok(None),
+        };
+
+        let mut matched = MutableBitmap::with_capacity(row_ids.len());
+        let mut indices = Vec::with_capacity(row_ids.len());
+        for row_id in row_ids {
+            match rows.get(row_id) {
+                Some(offset) => {
+                    matched.push(true);
+                    indices.push(*offset);
+                }
+                None => {
+                    matched.push(false);
+                    indices.push(0);
+                }
+            }
+        }
+
+        let matched: Bitmap = matched.into();
+        if matched.unset_bits() == matched.len() {
+            return Ok(None);
+        }
+        Ok(Some((matched, self.values.take(&indices)?)))
+    }
+}
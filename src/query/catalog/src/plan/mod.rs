@@ -14,6 +14,7 @@
 
 mod datasource;
 mod internal_column;
+mod matched_rows;
 mod partition;
 mod partition_statistics;
 mod projection;
@@ -22,6 +23,7 @@ mod pushdown;
 
 pub use datasource::*;
 pub use internal_column::*;
+pub use matched_rows::MatchedRows;
 pub use partition::*;
 pub use partition_statistics::PartStatistics;
 pub use projection::Projection;
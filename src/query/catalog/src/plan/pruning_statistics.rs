@@ -25,4 +25,8 @@ pub struct PruningStatistics {
     /// Block bloom filter pruning stats.
     pub blocks_bloom_pruning_before: usize,
     pub blocks_bloom_pruning_after: usize,
+    /// Blocks kept by bloom filter with a residual predicate on unindexed columns.
+    pub blocks_bloom_pruning_partial: usize,
+    /// Blocks kept without loading the bloom filter, as the memory budget is exhausted.
+    pub blocks_bloom_pruning_skipped: usize,
 }
@@ -62,6 +62,20 @@ pub struct PushDownInfo {
     pub limit: Option<usize>,
     /// Optional order_by expression plan, asc, null_first
     pub order_by: Vec<(RemoteExpr<String>, bool, bool)>,
+    /// Optional hint to read only the leading or trailing pages of the blocks,
+    /// used for `ORDER BY cluster_key LIMIT n`.
+    pub page_limit: Option<PageLimit>,
+}
+
+/// PageLimit is pushed down if the query is ordered by the prefix of the cluster keys,
+/// in the same order as the blocks are sorted, with a limit.
+///
+/// As the blocks are sorted by the cluster keys, the top `limit` rows of the query must be
+/// in the first (or last if `asc` is false) `limit` rows of the blocks.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PageLimit {
+    pub limit: usize,
+    pub asc: bool,
 }
 
 /// TopK is a wrapper for topk push down items.
@@ -0,0 +1,51 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::tokio::sync::Notify;
+use parking_lot::Mutex;
+use storages_common_index::RuntimeBloomFilter;
+
+/// The runtime filters pushed down into a table scan by a hash join.
+///
+/// The filters are built from the build side of the join, they're set once the build side
+/// is finished, and the scan waits for them before reading the blocks.
+#[derive(Default)]
+pub struct RuntimeFilterInfo {
+    filters: Mutex<Option<Arc<Vec<RuntimeBloomFilter>>>>,
+    ready_notify: Notify,
+}
+
+impl RuntimeFilterInfo {
+    pub fn set_filters(&self, filters: Vec<RuntimeBloomFilter>) {
+        *self.filters.lock() = Some(Arc::new(filters));
+        self.ready_notify.notify_waiters();
+    }
+
+    pub async fn wait_filters(&self) -> Arc<Vec<RuntimeBloomFilter>> {
+        loop {
+            // Register the waiter before checking the filters, so that a notification
+            // between the check and the await is not missed.
+            let notified = self.ready_notify.notified();
+
+            let filters = self.filters.lock().clone();
+            if let Some(filters) = filters {
+                return filters;
+            }
+
+            notified.await;
+        }
+    }
+}
@@ -38,6 +38,7 @@ use common_storage::StorageMetrics;
 
 use crate::plan::DataSourceInfo;
 use crate::plan::DataSourcePlan;
+use crate::plan::MatchedRows;
 use crate::plan::PartStatistics;
 use crate::plan::Partitions;
 use crate::plan::PushDownInfo;
@@ -265,9 +266,17 @@ pub trait Table: Sync + Send {
         filter: Option<RemoteExpr<String>>,
         col_indices: Vec<usize>,
         update_list: Vec<(usize, RemoteExpr<String>)>,
+        matched_rows: Option<MatchedRows>,
         pipeline: &mut Pipeline,
     ) -> Result<()> {
-        let (_, _, _, _, _) = (ctx, filter, col_indices, update_list, pipeline);
+        let (_, _, _, _, _, _) = (
+            ctx,
+            filter,
+            col_indices,
+            update_list,
+            matched_rows,
+            pipeline,
+        );
 
         Err(ErrorCode::Unimplemented(format!(
             "table {},  of engine type {}, does not support UPDATE",
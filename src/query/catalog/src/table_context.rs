@@ -22,17 +22,19 @@ use std::time::SystemTime;
 
 use common_base::base::Progress;
 use common_base::base::ProgressValues;
-use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::DataBlock;
 use common_expression::FunctionContext;
 use common_io::prelude::FormatSettings;
 use common_meta_app::principal::FileFormatOptions;
+use common_meta_app::principal::GrantObject;
 use common_meta_app::principal::RoleInfo;
 use common_meta_app::principal::UserInfo;
+use common_meta_app::principal::UserPrivilegeType;
 use common_meta_app::schema::TableCopiedFileInfo;
 use common_settings::Settings;
 use common_storage::DataOperator;
+use common_storage::FileLoadStatus;
 use common_storage::StageFileInfo;
 use common_storage::StorageMetrics;
 
@@ -41,6 +43,7 @@ use crate::cluster_info::Cluster;
 use crate::plan::DataSourcePlan;
 use crate::plan::PartInfoPtr;
 use crate::plan::Partitions;
+use crate::runtime_filter_info::RuntimeFilterInfo;
 use crate::table::Table;
 
 #[derive(Debug)]
@@ -94,6 +97,9 @@ pub trait TableContext: Send + Sync {
     fn get_partitions_shas(&self) -> Vec<String>;
     fn get_cacheable(&self) -> bool;
     fn set_cacheable(&self, cacheable: bool);
+    /// Push down the runtime filters of a join into the scan of the table.
+    fn add_runtime_filter(&self, table_index: usize, info: Arc<RuntimeFilterInfo>);
+    fn get_runtime_filters(&self, table_index: usize) -> Vec<Arc<RuntimeFilterInfo>>;
 
     fn attach_query_str(&self, kind: String, query: String);
     fn get_query_str(&self) -> String;
@@ -121,7 +127,8 @@ pub trait TableContext: Send + Sync {
     fn get_query_id_history(&self) -> HashSet<String>;
     fn get_result_cache_key(&self, query_id: &str) -> Option<String>;
     fn set_query_id_result_cache(&self, query_id: String, result_cache_key: String);
-    fn set_on_error_map(&self, map: Option<HashMap<String, ErrorCode>>);
+    fn set_file_load_status(&self, status: HashMap<String, FileLoadStatus>);
+    fn set_on_error_rows(&self, rows: u64);
 
     fn apply_changed_settings(&self, changed_settings: Arc<Settings>) -> Result<()>;
     fn get_changed_settings(&self) -> Arc<Settings>;
@@ -133,6 +140,13 @@ pub trait TableContext: Send + Sync {
 
     async fn get_file_format(&self, name: &str) -> Result<FileFormatOptions>;
 
+    /// Check the current user or role has the privileges on the object.
+    async fn validate_privilege(
+        &self,
+        object: &GrantObject,
+        privileges: Vec<UserPrivilegeType>,
+    ) -> Result<()>;
+
     async fn get_table(&self, catalog: &str, database: &str, table: &str)
     -> Result<Arc<dyn Table>>;
 
@@ -14,4 +14,5 @@
 
 #![allow(clippy::uninlined_format_args)]
 
+mod matched_rows;
 mod partitions;
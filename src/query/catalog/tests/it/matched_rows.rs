@@ -0,0 +1,72 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::plan::MatchedRows;
+use common_exception::Result;
+use common_expression::types::Int32Type;
+use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
+use common_expression::types::UInt64Type;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+
+#[test]
+fn test_matched_rows() -> Result<()> {
+    let schema = Arc::new(TableSchema::new(vec![
+        TableField::new("x", TableDataType::Number(NumberDataType::Int32)),
+        TableField::new("y", TableDataType::Number(NumberDataType::Int32)),
+    ]));
+    let blocks = vec![
+        DataBlock::new_from_columns(vec![
+            StringType::from_data(vec!["b1", "b1"]),
+            UInt64Type::from_data(vec![2u64, 0]),
+            Int32Type::from_data(vec![1, 2]),
+            Int32Type::from_data(vec![10, 20]),
+        ]),
+        // The row 2 of b1 is matched again, its first values are kept.
+        DataBlock::new_from_columns(vec![
+            StringType::from_data(vec!["b1", "b2"]),
+            UInt64Type::from_data(vec![2u64, 0]),
+            Int32Type::from_data(vec![3, 4]),
+            Int32Type::from_data(vec![30, 40]),
+        ]),
+    ];
+    let matched_rows = MatchedRows::try_create(&blocks, schema)?;
+    assert!(!matched_rows.is_empty());
+    assert!(matched_rows.contains_block("b1"));
+    assert!(!matched_rows.contains_block("b3"));
+
+    let (matched, values) = matched_rows.take_block_rows("b1", &[0, 1, 2])?.unwrap();
+    assert_eq!(matched.iter().collect::<Vec<_>>(), vec![true, false, true]);
+    assert_eq!(values.num_rows(), 3);
+    // Only the values of the matched rows are checked.
+    let expected = DataBlock::new_from_columns(vec![
+        Int32Type::from_data(vec![2, 0, 1]),
+        Int32Type::from_data(vec![20, 0, 10]),
+    ]);
+    for (value, expected) in values.columns().iter().zip(expected.columns()) {
+        for row in [0, 2] {
+            assert_eq!(value.value.index(row), expected.value.index(row));
+        }
+    }
+
+    assert!(matched_rows.take_block_rows("b2", &[1])?.is_none());
+    assert!(matched_rows.take_block_rows("b3", &[0])?.is_none());
+    Ok(())
+}
@@ -23,6 +23,8 @@ use common_expression::type_check;
 use common_expression::DataBlock;
 use common_expression::Evaluator;
 use common_expression::FunctionContext;
+use common_functions::scalars::compute_grouping;
+use common_functions::scalars::GroupingMapping;
 use common_functions::scalars::BUILTIN_FUNCTIONS;
 use criterion::Criterion;
 
@@ -53,5 +55,32 @@ fn bench(c: &mut Criterion) {
     }
 }
 
-criterion_group!(benches, bench);
+fn bench_grouping(c: &mut Criterion) {
+    let mut group = c.benchmark_group("bench_grouping");
+
+    // A grouping set over 31 columns, with the grouping ids of a large block.
+    let cols = (0..31).rev().collect::<Vec<usize>>();
+    let grouping_ids = (0..65536u32).collect::<Vec<_>>();
+
+    group.bench_function("compute_grouping", |b| {
+        b.iter(|| {
+            grouping_ids
+                .iter()
+                .map(|id| compute_grouping(&cols, *id))
+                .collect::<Vec<_>>()
+        })
+    });
+
+    let mapping = GroupingMapping::new(&cols);
+    group.bench_function("grouping_mapping", |b| {
+        b.iter(|| {
+            grouping_ids
+                .iter()
+                .map(|id| mapping.compute(*id))
+                .collect::<Vec<_>>()
+        })
+    });
+}
+
+criterion_group!(benches, bench, bench_grouping);
 criterion_main!(benches);
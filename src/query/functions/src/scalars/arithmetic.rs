@@ -77,6 +77,7 @@ pub fn register(registry: &mut FunctionRegistry) {
     registry.register_aliases("plus", &["add"]);
     registry.register_aliases("minus", &["subtract", "neg", "negate"]);
     registry.register_aliases("div", &["intdiv"]);
+    registry.register_aliases("div0", &["safe_divide"]);
     registry.register_aliases("modulo", &["mod"]);
 
     register_unary_minus(registry);
@@ -196,6 +197,28 @@ macro_rules! register_divide {
     };
 }
 
+macro_rules! register_div0 {
+    ( $lt:ty, $rt:ty, $registry:expr) => {
+        type L = $lt;
+        type R = $rt;
+        type T = F64;
+        $registry.register_combine_nullable_2_arg::<NumberType<L>, NumberType<R>, NumberType<T>, _, _>(
+            "div0",
+            FunctionProperty::default(),
+            |_, _| FunctionDomain::Full,
+            vectorize_with_builder_2_arg::<NumberType<L>, NumberType<R>, NullableType<NumberType<T>>>(
+                |a, b, output, _| {
+                    let b = (b.as_() : T);
+                    if std::intrinsics::unlikely(b == 0.0) {
+                        output.push_null();
+                    } else {
+                        output.push(((a.as_() : T) / b));
+                    }
+                }),
+        );
+    };
+}
+
 macro_rules! register_div {
     ( $lt:ty, $rt:ty, $registry:expr) => {
         type L = $lt;
@@ -276,6 +299,9 @@ macro_rules! register_basic_arithmetic {
     {
         register_divide!($lt, $rt, $registry);
     }
+    {
+        register_div0!($lt, $rt, $registry);
+    }
     {
         register_div!($lt, $rt, $registry);
     }
@@ -20,6 +20,8 @@ use common_expression::types::boolean::BooleanDomain;
 use common_expression::types::nullable::NullableColumn;
 use common_expression::types::nullable::NullableDomain;
 use common_expression::types::BooleanType;
+use common_expression::types::GenericType;
+use common_expression::types::NullType;
 use common_expression::types::NullableType;
 use common_expression::types::NumberDataType;
 use common_expression::types::NumberType;
@@ -35,6 +37,7 @@ use common_expression::FunctionProperty;
 use common_expression::FunctionRegistry;
 use common_expression::Value;
 use common_expression::ValueRef;
+use num_traits::Zero;
 
 pub fn register(registry: &mut FunctionRegistry) {
     registry.register_passthrough_nullable_1_arg::<BooleanType, BooleanType, _, _>(
@@ -217,6 +220,49 @@ pub fn register(registry: &mut FunctionRegistry) {
         },
     );
 
+    // Null-safe comparison, the NULLs are equal to each other and distinct from any other value.
+    registry.register_2_arg_core::<NullType, NullType, BooleanType, _, _>(
+        "is_distinct_from",
+        FunctionProperty::default(),
+        |_, _| {
+            FunctionDomain::Domain(BooleanDomain {
+                has_false: true,
+                has_true: false,
+            })
+        },
+        |_, _, _| Value::Scalar(false),
+    );
+
+    registry.register_2_arg_core::<NullableType<GenericType<0>>, NullableType<GenericType<0>>, BooleanType, _, _>(
+        "is_distinct_from",
+        FunctionProperty::default(),
+        |_, _| FunctionDomain::Full,
+        vectorize_2_arg::<NullableType<GenericType<0>>, NullableType<GenericType<0>>, BooleanType>(
+            |lhs, rhs, _| lhs != rhs,
+        ),
+    );
+
+    registry.register_2_arg_core::<NullType, NullType, BooleanType, _, _>(
+        "is_not_distinct_from",
+        FunctionProperty::default(),
+        |_, _| {
+            FunctionDomain::Domain(BooleanDomain {
+                has_false: false,
+                has_true: true,
+            })
+        },
+        |_, _, _| Value::Scalar(true),
+    );
+
+    registry.register_2_arg_core::<NullableType<GenericType<0>>, NullableType<GenericType<0>>, BooleanType, _, _>(
+        "is_not_distinct_from",
+        FunctionProperty::default(),
+        |_, _| FunctionDomain::Full,
+        vectorize_2_arg::<NullableType<GenericType<0>>, NullableType<GenericType<0>>, BooleanType>(
+            |lhs, rhs, _| lhs == rhs,
+        ),
+    );
+
     registry.register_passthrough_nullable_1_arg::<BooleanType, StringType, _, _>(
         "to_string",
         FunctionProperty::default(),
@@ -277,12 +323,7 @@ pub fn register(registry: &mut FunctionRegistry) {
                 registry.register_1_arg::<NumberType<NUM_TYPE>, BooleanType, _, _>(
                     "to_boolean",
                     FunctionProperty::default(),
-                    |domain| {
-                        FunctionDomain::Domain(BooleanDomain {
-                            has_false: domain.min <= 0 && domain.max >= 0,
-                            has_true: !(domain.min == 0 && domain.max == 0),
-                        })
-                    },
+                    |domain| FunctionDomain::Domain(integer_domain_to_boolean_domain(domain)),
                     |val, _| val != 0,
                 );
 
@@ -293,10 +334,7 @@ pub fn register(registry: &mut FunctionRegistry) {
                         |domain| {
                             FunctionDomain::Domain(NullableDomain {
                                 has_null: false,
-                                value: Some(Box::new(BooleanDomain {
-                                    has_false: domain.min <= 0 && domain.max >= 0,
-                                    has_true: !(domain.min == 0 && domain.max == 0),
-                                })),
+                                value: Some(Box::new(integer_domain_to_boolean_domain(domain))),
                             })
                         },
                         vectorize_with_builder_1_arg::<
@@ -348,6 +386,19 @@ pub fn register(registry: &mut FunctionRegistry) {
     }
 }
 
+/// Compute the domain of `val != 0` from the domain of an integer `val`.
+///
+/// The domain is exact: a column proven to be all zeros yields only `false`,
+/// and a column whose range excludes zero yields only `true`.
+fn integer_domain_to_boolean_domain<T>(domain: &SimpleDomain<T>) -> BooleanDomain
+where T: PartialOrd + Zero {
+    let zero = T::zero();
+    BooleanDomain {
+        has_false: domain.min <= zero && domain.max >= zero,
+        has_true: domain.min != zero || domain.max != zero,
+    }
+}
+
 fn eval_boolean_to_string(val: ValueRef<BooleanType>, ctx: &mut EvalContext) -> Value<StringType> {
     vectorize_with_builder_1_arg::<BooleanType, StringType>(|val, output, _| {
         output.put_str(if val { "true" } else { "false" });
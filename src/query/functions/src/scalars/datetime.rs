@@ -14,7 +14,13 @@
 
 use std::io::Write;
 
+use chrono::format::parse;
+use chrono::format::ParseResult;
+use chrono::format::Parsed;
+use chrono::format::StrftimeItems;
 use chrono::Datelike;
+use chrono::NaiveTime;
+use chrono::TimeZone;
 use chrono::Utc;
 use common_arrow::arrow::temporal_conversions::EPOCH_DAYS_FROM_CE;
 use common_expression::error_to_null;
@@ -64,12 +70,16 @@ pub fn register(registry: &mut FunctionRegistry) {
     register_string_to_timestamp(registry);
     register_date_to_timestamp(registry);
     register_number_to_timestamp(registry);
+    // to_timestamp(xx, format)
+    register_string_to_timestamp_with_format(registry);
 
     // cast(xx AS date)
     // to_date(xx)
     register_string_to_date(registry);
     register_timestamp_to_date(registry);
     register_number_to_date(registry);
+    // to_date(xx, format)
+    register_string_to_date_with_format(registry);
 
     // cast([date | timestamp] AS string)
     // to_string([date | timestamp])
@@ -152,6 +162,90 @@ fn register_string_to_timestamp(registry: &mut FunctionRegistry) {
     }
 }
 
+/// Parses `val` with a strftime-like `format`, e.g. `%Y-%m-%d %H:%M:%S`.
+fn parse_with_format(val: &str, format: &str) -> ParseResult<Parsed> {
+    let mut parsed = Parsed::new();
+    parse(&mut parsed, val, StrftimeItems::new(format))?;
+    Ok(parsed)
+}
+
+/// The time defaults to midnight if the format has no time fields, and is in the timezone `tz`
+/// if the format has no offset field.
+fn string_to_timestamp_with_format(
+    val: &[u8],
+    format: &[u8],
+    tz: &impl TimeZone,
+) -> Result<i64, String> {
+    let val = std::str::from_utf8(val).map_err(|e| e.to_string())?;
+    let format = std::str::from_utf8(format).map_err(|e| e.to_string())?;
+    let micros = || -> ParseResult<Option<i64>> {
+        let parsed = parse_with_format(val, format)?;
+        if parsed.offset.is_some() {
+            return Ok(Some(parsed.to_datetime()?.timestamp_micros()));
+        }
+
+        let date = parsed.to_naive_date()?;
+        let time = match (parsed.hour_div_12, parsed.hour_mod_12) {
+            (None, None) => NaiveTime::from_hms_opt(0, 0, 0).unwrap(),
+            _ => parsed.to_naive_time()?,
+        };
+        Ok(tz
+            .from_local_datetime(&date.and_time(time))
+            .earliest()
+            .map(|dt| dt.timestamp_micros()))
+    };
+
+    match micros() {
+        Ok(Some(micros)) => check_timestamp(micros),
+        Ok(None) => Err(format!(
+            "cannot parse `{val}` with format `{format}`: no such local time"
+        )),
+        Err(e) => Err(format!("cannot parse `{val}` with format `{format}`: {e}")),
+    }
+}
+
+fn string_to_date_with_format(val: &[u8], format: &[u8]) -> Result<i32, String> {
+    let val = std::str::from_utf8(val).map_err(|e| e.to_string())?;
+    let format = std::str::from_utf8(format).map_err(|e| e.to_string())?;
+    match parse_with_format(val, format).and_then(|parsed| parsed.to_naive_date()) {
+        Ok(date) => check_date((date.num_days_from_ce() - EPOCH_DAYS_FROM_CE) as i64),
+        Err(e) => Err(format!("cannot parse `{val}` with format `{format}`: {e}")),
+    }
+}
+
+fn register_string_to_timestamp_with_format(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_2_arg::<StringType, StringType, TimestampType, _, _>(
+        "to_timestamp",
+        FunctionProperty::default(),
+        |_, _| FunctionDomain::MayThrow,
+        eval_string_to_timestamp_with_format,
+    );
+    registry.register_combine_nullable_2_arg::<StringType, StringType, TimestampType, _, _>(
+        "try_to_timestamp",
+        FunctionProperty::default(),
+        |_, _| FunctionDomain::Full,
+        error_to_null(eval_string_to_timestamp_with_format),
+    );
+
+    fn eval_string_to_timestamp_with_format(
+        val: ValueRef<StringType>,
+        format: ValueRef<StringType>,
+        ctx: &mut EvalContext,
+    ) -> Value<TimestampType> {
+        vectorize_with_builder_2_arg::<StringType, StringType, TimestampType>(
+            |val, format, output, ctx| match string_to_timestamp_with_format(
+                val, format, &ctx.tz.tz,
+            ) {
+                Ok(ts) => output.push(ts),
+                Err(e) => {
+                    ctx.set_error(output.len(), e);
+                    output.push(0);
+                }
+            },
+        )(val, format, ctx)
+    }
+}
+
 fn register_date_to_timestamp(registry: &mut FunctionRegistry) {
     registry.register_passthrough_nullable_1_arg::<DateType, TimestampType, _, _>(
         "to_timestamp",
@@ -260,6 +354,37 @@ fn register_string_to_date(registry: &mut FunctionRegistry) {
     }
 }
 
+fn register_string_to_date_with_format(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_2_arg::<StringType, StringType, DateType, _, _>(
+        "to_date",
+        FunctionProperty::default(),
+        |_, _| FunctionDomain::MayThrow,
+        eval_string_to_date_with_format,
+    );
+    registry.register_combine_nullable_2_arg::<StringType, StringType, DateType, _, _>(
+        "try_to_date",
+        FunctionProperty::default(),
+        |_, _| FunctionDomain::Full,
+        error_to_null(eval_string_to_date_with_format),
+    );
+
+    fn eval_string_to_date_with_format(
+        val: ValueRef<StringType>,
+        format: ValueRef<StringType>,
+        ctx: &mut EvalContext,
+    ) -> Value<DateType> {
+        vectorize_with_builder_2_arg::<StringType, StringType, DateType>(
+            |val, format, output, ctx| match string_to_date_with_format(val, format) {
+                Ok(d) => output.push(d),
+                Err(e) => {
+                    ctx.set_error(output.len(), e);
+                    output.push(0);
+                }
+            },
+        )(val, format, ctx)
+    }
+}
+
 fn register_timestamp_to_date(registry: &mut FunctionRegistry) {
     registry.register_passthrough_nullable_1_arg::<TimestampType, DateType, _, _>(
         "to_date",
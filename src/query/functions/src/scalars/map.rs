@@ -22,6 +22,7 @@ use common_expression::types::GenericType;
 use common_expression::types::MapType;
 use common_expression::types::NullType;
 use common_expression::types::NullableType;
+use common_expression::vectorize_with_builder_1_arg;
 use common_expression::vectorize_with_builder_2_arg;
 use common_expression::FunctionDomain;
 use common_expression::FunctionProperty;
@@ -32,6 +33,8 @@ use siphasher::sip128::Hasher128;
 use siphasher::sip128::SipHasher24;
 
 pub fn register(registry: &mut FunctionRegistry) {
+    registry.register_aliases("get", &["element_at"]);
+
     registry
         .register_passthrough_nullable_2_arg::<EmptyArrayType, EmptyArrayType, EmptyMapType, _, _>(
             "map",
@@ -113,4 +116,36 @@ pub fn register(registry: &mut FunctionRegistry) {
             }
         ),
     );
+
+    registry.register_passthrough_nullable_1_arg::<EmptyMapType, EmptyArrayType, _, _>(
+        "map_keys",
+        FunctionProperty::default(),
+        |_| FunctionDomain::Full,
+        |_, _| Value::Scalar(()),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<MapType<GenericType<0>, GenericType<1>>, ArrayType<GenericType<0>>, _, _>(
+        "map_keys",
+        FunctionProperty::default(),
+        |domain| FunctionDomain::Domain(domain.as_ref().map(|(key_domain, _)| key_domain.clone())),
+        vectorize_with_builder_1_arg::<MapType<GenericType<0>, GenericType<1>>, ArrayType<GenericType<0>>>(
+            |map, output, _| output.push(map.keys)
+        ),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<EmptyMapType, EmptyArrayType, _, _>(
+        "map_values",
+        FunctionProperty::default(),
+        |_| FunctionDomain::Full,
+        |_, _| Value::Scalar(()),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<MapType<GenericType<0>, GenericType<1>>, ArrayType<GenericType<1>>, _, _>(
+        "map_values",
+        FunctionProperty::default(),
+        |domain| FunctionDomain::Domain(domain.as_ref().map(|(_, val_domain)| val_domain.clone())),
+        vectorize_with_builder_1_arg::<MapType<GenericType<0>, GenericType<1>>, ArrayType<GenericType<1>>>(
+            |map, output, _| output.push(map.values)
+        ),
+    );
 }
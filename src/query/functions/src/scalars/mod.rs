@@ -43,6 +43,8 @@ mod string_multi_args;
 pub use comparison::check_pattern_type;
 pub use comparison::is_like_pattern_escape;
 pub use comparison::PatternType;
+pub use other::compute_grouping;
+pub use other::GroupingMapping;
 
 use self::comparison::ALL_COMP_FUNC_NAMES;
 
@@ -13,10 +13,13 @@
 // limitations under the License.
 
 use std::io::Write;
+use std::net::IpAddr;
 use std::net::Ipv4Addr;
+use std::net::Ipv6Addr;
 use std::sync::Arc;
 use std::time::Duration;
 
+use common_arrow::arrow::bitmap::Bitmap;
 use common_base::base::convert_byte_size;
 use common_base::base::convert_number_size;
 use common_base::base::uuid::Uuid;
@@ -27,9 +30,12 @@ use common_expression::types::number::Float64Type;
 use common_expression::types::number::Int64Type;
 use common_expression::types::number::UInt32Type;
 use common_expression::types::number::UInt8Type;
+use common_expression::types::number::F32;
 use common_expression::types::number::F64;
 use common_expression::types::string::StringColumn;
+use common_expression::types::string::StringColumnBuilder;
 use common_expression::types::ArgType;
+use common_expression::types::BooleanType;
 use common_expression::types::DataType;
 use common_expression::types::DateType;
 use common_expression::types::GenericType;
@@ -44,6 +50,7 @@ use common_expression::types::StringType;
 use common_expression::types::TimestampType;
 use common_expression::types::ValueType;
 use common_expression::vectorize_with_builder_1_arg;
+use common_expression::vectorize_with_builder_2_arg;
 use common_expression::Column;
 use common_expression::Domain;
 use common_expression::EvalContext;
@@ -70,8 +77,12 @@ pub fn register(registry: &mut FunctionRegistry) {
 
     register_inet_aton(registry);
     register_inet_ntoa(registry);
+    register_ip_in_cidr(registry);
+    register_cidr_to_range(registry);
+    register_ip_network(registry);
     register_run_diff(registry);
     register_grouping(registry);
+    register_complex_to_string(registry);
 
     registry.register_passthrough_nullable_1_arg::<Float64Type, StringType, _, _>(
         "humanize_size",
@@ -215,7 +226,7 @@ pub fn register(registry: &mut FunctionRegistry) {
 
     registry.register_0_arg_core::<StringType, _, _>(
         "gen_random_uuid",
-        FunctionProperty::default(),
+        FunctionProperty::default().non_deterministic(),
         || FunctionDomain::Full,
         |ctx| {
             let mut values: Vec<u8> = Vec::with_capacity(ctx.num_rows * 36);
@@ -270,6 +281,22 @@ fn register_inet_aton(registry: &mut FunctionRegistry) {
 }
 
 fn register_inet_ntoa(registry: &mut FunctionRegistry) {
+    // The `UInt32` overloads must be registered before the `Int64` ones so that
+    // IPv4 numbers stored as `UInt32` are not widened to `Int64` and re-checked.
+    registry.register_passthrough_nullable_1_arg::<UInt32Type, StringType, _, _>(
+        "inet_ntoa",
+        FunctionProperty::default(),
+        |_| FunctionDomain::Full,
+        eval_u32_inet_ntoa,
+    );
+
+    registry.register_combine_nullable_1_arg::<UInt32Type, StringType, _, _>(
+        "try_inet_ntoa",
+        FunctionProperty::default(),
+        |_| FunctionDomain::Full,
+        error_to_null(eval_u32_inet_ntoa),
+    );
+
     registry.register_passthrough_nullable_1_arg::<Int64Type, StringType, _, _>(
         "inet_ntoa",
         FunctionProperty::default(),
@@ -302,6 +329,222 @@ fn register_inet_ntoa(registry: &mut FunctionRegistry) {
             }
         })(val, ctx)
     }
+
+    fn eval_u32_inet_ntoa(val: ValueRef<UInt32Type>, ctx: &mut EvalContext) -> Value<StringType> {
+        vectorize_with_builder_1_arg::<UInt32Type, StringType>(|val, output, _| {
+            let addr_str = Ipv4Addr::from(val.to_be_bytes()).to_string();
+            output.put_str(&addr_str);
+            output.commit_row();
+        })(val, ctx)
+    }
+}
+
+fn register_ip_in_cidr(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_2_arg::<StringType, StringType, BooleanType, _, _>(
+        "ip_in_cidr",
+        FunctionProperty::default(),
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<StringType, StringType, BooleanType>(
+            |ip, cidr, output, ctx| {
+                let ip = String::from_utf8_lossy(ip);
+                let cidr = String::from_utf8_lossy(cidr);
+                match ip_in_cidr(&ip, &cidr) {
+                    Ok(contained) => output.push(contained),
+                    Err(err) => {
+                        ctx.set_error(output.len(), err);
+                        output.push(false);
+                    }
+                }
+            },
+        ),
+    );
+}
+
+fn register_cidr_to_range(registry: &mut FunctionRegistry) {
+    registry.register_function_factory("cidr_to_range", |_, args_type| {
+        if args_type.len() != 1 {
+            return None;
+        }
+
+        let range_type = DataType::Tuple(vec![DataType::String, DataType::String]);
+        let (arg_type, return_type) = match &args_type[0] {
+            DataType::String => (DataType::String, range_type),
+            DataType::Null | DataType::Nullable(box DataType::String) => {
+                (DataType::String.wrap_nullable(), range_type.wrap_nullable())
+            }
+            _ => return None,
+        };
+
+        Some(Arc::new(Function {
+            signature: FunctionSignature {
+                name: "cidr_to_range".to_string(),
+                args_type: vec![arg_type],
+                return_type,
+                property: FunctionProperty::default(),
+            },
+            calc_domain: Box::new(|_| FunctionDomain::MayThrow),
+            eval: Box::new(|args, ctx| match &args[0] {
+                ValueRef::Scalar(ScalarRef::Null) => Value::Scalar(Scalar::Null),
+                ValueRef::Scalar(ScalarRef::String(cidr)) => {
+                    let (first, last) = match cidr_to_range(&String::from_utf8_lossy(cidr)) {
+                        Ok(range) => range,
+                        Err(err) => {
+                            ctx.set_error(0, err);
+                            (String::new(), String::new())
+                        }
+                    };
+                    Value::Scalar(Scalar::Tuple(vec![
+                        Scalar::String(first.into_bytes()),
+                        Scalar::String(last.into_bytes()),
+                    ]))
+                }
+                ValueRef::Column(Column::Nullable(box NullableColumn { column, validity })) => {
+                    let column =
+                        cidr_column_to_range(column.as_string().unwrap(), Some(validity), ctx);
+                    Value::Column(Column::Nullable(Box::new(NullableColumn {
+                        column,
+                        validity: validity.clone(),
+                    })))
+                }
+                ValueRef::Column(Column::String(column)) => {
+                    Value::Column(cidr_column_to_range(column, None, ctx))
+                }
+                _ => unreachable!(),
+            }),
+        }))
+    });
+}
+
+fn register_ip_network(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_2_arg::<StringType, UInt8Type, StringType, _, _>(
+        "ip_network",
+        FunctionProperty::default(),
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<StringType, UInt8Type, StringType>(
+            |ip, prefix_len, output, ctx| {
+                match ip_network(&String::from_utf8_lossy(ip), prefix_len as u32) {
+                    Ok(network) => output.put_str(&network),
+                    Err(err) => ctx.set_error(output.len(), err),
+                }
+                output.commit_row();
+            },
+        ),
+    );
+}
+
+fn cidr_column_to_range(
+    column: &StringColumn,
+    validity: Option<&Bitmap>,
+    ctx: &mut EvalContext,
+) -> Column {
+    let mut first_builder = StringColumnBuilder::with_capacity(column.len(), 0);
+    let mut last_builder = StringColumnBuilder::with_capacity(column.len(), 0);
+    for (row, cidr) in column.iter().enumerate() {
+        // The NULL rows are left empty, they are not checked.
+        if validity.map(|v| v.get_bit(row)).unwrap_or(true) {
+            match cidr_to_range(&String::from_utf8_lossy(cidr)) {
+                Ok((first, last)) => {
+                    first_builder.put_str(&first);
+                    last_builder.put_str(&last);
+                }
+                Err(err) => ctx.set_error(row, err),
+            }
+        }
+        first_builder.commit_row();
+        last_builder.commit_row();
+    }
+    Column::Tuple(vec![
+        Column::String(first_builder.build()),
+        Column::String(last_builder.build()),
+    ])
+}
+
+/// Parses a CIDR block written as `<address>/<prefix length>`.
+fn parse_cidr(cidr: &str) -> Result<(IpAddr, u32), String> {
+    let invalid_cidr = || format!("Failed to parse '{}' into a CIDR block", cidr);
+    let (network, prefix_len) = cidr.split_once('/').ok_or_else(invalid_cidr)?;
+    let network = network.parse::<IpAddr>().map_err(|_| invalid_cidr())?;
+    let prefix_len = prefix_len.parse::<u32>().map_err(|_| invalid_cidr())?;
+    let max_prefix_len = if network.is_ipv4() { 32 } else { 128 };
+    if prefix_len > max_prefix_len {
+        return Err(format!(
+            "Invalid CIDR block '{}', the prefix length must be at most {}",
+            cidr, max_prefix_len
+        ));
+    }
+    Ok((network, prefix_len))
+}
+
+fn ipv4_mask(prefix_len: u32) -> u32 {
+    u32::MAX.checked_shl(32 - prefix_len).unwrap_or(0)
+}
+
+fn ipv6_mask(prefix_len: u32) -> u128 {
+    u128::MAX.checked_shl(128 - prefix_len).unwrap_or(0)
+}
+
+/// Checks whether `ip` is in the block `cidr`.
+/// An IPv4 address is never in an IPv6 block, and vice versa.
+fn ip_in_cidr(ip: &str, cidr: &str) -> Result<bool, String> {
+    let ip = ip
+        .parse::<IpAddr>()
+        .map_err(|_| format!("Failed to parse '{}' into an IP address", ip))?;
+    let (network, prefix_len) = parse_cidr(cidr)?;
+
+    match (ip, network) {
+        (IpAddr::V4(ip), IpAddr::V4(network)) => {
+            let mask = ipv4_mask(prefix_len);
+            Ok(u32::from(ip) & mask == u32::from(network) & mask)
+        }
+        (IpAddr::V6(ip), IpAddr::V6(network)) => {
+            let mask = ipv6_mask(prefix_len);
+            Ok(u128::from(ip) & mask == u128::from(network) & mask)
+        }
+        _ => Ok(false),
+    }
+}
+
+/// Returns the first and the last addresses of the block `cidr`.
+fn cidr_to_range(cidr: &str) -> Result<(String, String), String> {
+    let (network, prefix_len) = parse_cidr(cidr)?;
+
+    match network {
+        IpAddr::V4(network) => {
+            let mask = ipv4_mask(prefix_len);
+            let first = u32::from(network) & mask;
+            Ok((
+                Ipv4Addr::from(first).to_string(),
+                Ipv4Addr::from(first | !mask).to_string(),
+            ))
+        }
+        IpAddr::V6(network) => {
+            let mask = ipv6_mask(prefix_len);
+            let first = u128::from(network) & mask;
+            Ok((
+                Ipv6Addr::from(first).to_string(),
+                Ipv6Addr::from(first | !mask).to_string(),
+            ))
+        }
+    }
+}
+
+/// Masks `ip` to its first `prefix_len` bits, e.g. to group the addresses by subnet.
+fn ip_network(ip: &str, prefix_len: u32) -> Result<String, String> {
+    let ip = ip
+        .parse::<IpAddr>()
+        .map_err(|_| format!("Failed to parse '{}' into an IP address", ip))?;
+    let max_prefix_len = if ip.is_ipv4() { 32 } else { 128 };
+    if prefix_len > max_prefix_len {
+        return Err(format!(
+            "Invalid prefix length {} for '{}', it must be at most {}",
+            prefix_len, ip, max_prefix_len
+        ));
+    }
+
+    match ip {
+        IpAddr::V4(ip) => Ok(Ipv4Addr::from(u32::from(ip) & ipv4_mask(prefix_len)).to_string()),
+        IpAddr::V6(ip) => Ok(Ipv6Addr::from(u128::from(ip) & ipv6_mask(prefix_len)).to_string()),
+    }
 }
 
 macro_rules! register_simple_domain_type_run_diff {
@@ -348,6 +591,15 @@ fn register_run_diff(registry: &mut FunctionRegistry) {
         F64,
         OrderedFloat(0.0)
     );
+    // Registered after Float64, so that the other arguments, e.g. decimals, are still
+    // cast to Float64.
+    register_simple_domain_type_run_diff!(
+        registry,
+        NumberType<F32>,
+        NumberType<F32>,
+        F32,
+        OrderedFloat(0.0)
+    );
 }
 
 fn register_grouping(registry: &mut FunctionRegistry) {
@@ -356,7 +608,7 @@ fn register_grouping(registry: &mut FunctionRegistry) {
             return None;
         }
 
-        let params = params.to_vec();
+        let mapping = Arc::new(GroupingMapping::new(params));
         Some(Arc::new(Function {
             signature: FunctionSignature {
                 name: "grouping".to_string(),
@@ -366,14 +618,11 @@ fn register_grouping(registry: &mut FunctionRegistry) {
             },
             calc_domain: Box::new(|_| FunctionDomain::Full),
             eval: Box::new(move |args, _| match &args[0] {
-                ValueRef::Scalar(ScalarRef::Number(NumberScalar::UInt32(v))) => Value::Scalar(
-                    Scalar::Number(NumberScalar::UInt32(compute_grouping(&params, *v))),
-                ),
+                ValueRef::Scalar(ScalarRef::Number(NumberScalar::UInt32(v))) => {
+                    Value::Scalar(Scalar::Number(NumberScalar::UInt32(mapping.compute(*v))))
+                }
                 ValueRef::Column(Column::Number(NumberColumn::UInt32(col))) => {
-                    let output = col
-                        .iter()
-                        .map(|v| compute_grouping(&params, *v))
-                        .collect::<Vec<_>>();
+                    let output = col.iter().map(|v| mapping.compute(*v)).collect::<Vec<_>>();
                     Value::Column(Column::Number(NumberColumn::UInt32(output.into())))
                 }
                 _ => unreachable!(),
@@ -382,6 +631,68 @@ fn register_grouping(registry: &mut FunctionRegistry) {
     })
 }
 
+fn register_complex_to_string(registry: &mut FunctionRegistry) {
+    // Render nested values (arrays, maps and tuples) in the same text form as
+    // the display of their scalars, e.g. `[1, 2, 3]` or `(1, true)`.
+    registry.register_function_factory("to_string", |_, args_type| {
+        if args_type.len() != 1 {
+            return None;
+        }
+
+        let arg_type = args_type[0].clone();
+        if !matches!(
+            arg_type.remove_nullable(),
+            DataType::EmptyArray
+                | DataType::EmptyMap
+                | DataType::Array(_)
+                | DataType::Map(_)
+                | DataType::Tuple(_)
+        ) {
+            return None;
+        }
+
+        let return_type = if arg_type.is_nullable() {
+            DataType::Nullable(Box::new(DataType::String))
+        } else {
+            DataType::String
+        };
+
+        Some(Arc::new(Function {
+            signature: FunctionSignature {
+                name: "to_string".to_string(),
+                args_type: vec![arg_type],
+                return_type,
+                property: FunctionProperty::default(),
+            },
+            calc_domain: Box::new(|_| FunctionDomain::Full),
+            eval: Box::new(|args, _| match &args[0] {
+                ValueRef::Scalar(ScalarRef::Null) => Value::Scalar(Scalar::Null),
+                ValueRef::Scalar(scalar) => {
+                    Value::Scalar(Scalar::String(scalar.to_string().into_bytes()))
+                }
+                ValueRef::Column(Column::Nullable(box NullableColumn { column, validity })) => {
+                    Value::Column(Column::Nullable(Box::new(NullableColumn {
+                        column: Column::String(complex_column_to_string(column)),
+                        validity: validity.clone(),
+                    })))
+                }
+                ValueRef::Column(column) => {
+                    Value::Column(Column::String(complex_column_to_string(column)))
+                }
+            }),
+        }))
+    });
+}
+
+fn complex_column_to_string(column: &Column) -> StringColumn {
+    let mut builder = StringColumnBuilder::with_capacity(column.len(), 0);
+    for scalar in column.iter() {
+        builder.put_str(&scalar.to_string());
+        builder.commit_row();
+    }
+    builder.build()
+}
+
 /// Compute `grouping` by `grouping_id` and `cols`.
 ///
 /// `cols` are indices of the column represented in `_grouping_id`.
@@ -394,3 +705,31 @@ pub fn compute_grouping(cols: &[usize], grouping_id: u32) -> u32 {
     }
     grouping
 }
+
+/// The bit mapping from `_grouping_id` to the result of `grouping`,
+/// computed once per function instead of once per row.
+///
+/// Each entry is `(src, dst)`: bit `src` of `_grouping_id` is moved to bit `dst`
+/// of the result, which gives the same result as [`compute_grouping`].
+pub struct GroupingMapping {
+    bits: Vec<(u32, u32)>,
+}
+
+impl GroupingMapping {
+    pub fn new(cols: &[usize]) -> Self {
+        let bits = cols
+            .iter()
+            .rev()
+            .enumerate()
+            .map(|(dst, &src)| (src as u32, dst as u32))
+            .collect();
+        Self { bits }
+    }
+
+    #[inline(always)]
+    pub fn compute(&self, grouping_id: u32) -> u32 {
+        self.bits.iter().fold(0, |grouping, &(src, dst)| {
+            grouping | (((grouping_id >> src) & 1) << dst)
+        })
+    }
+}
@@ -75,6 +75,7 @@ use jsonb::to_bool;
 use jsonb::to_f64;
 use jsonb::to_i64;
 use jsonb::to_str;
+use jsonb::to_string;
 use jsonb::to_u64;
 use jsonb::JsonPathRef;
 
@@ -492,6 +493,33 @@ pub fn register(registry: &mut FunctionRegistry) {
         }),
     );
 
+    registry.register_passthrough_nullable_1_arg::<VariantType, StringType, _, _>(
+        "to_json",
+        FunctionProperty::default(),
+        |_| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<VariantType, StringType>(|val, output, _| {
+            if !val.is_empty() {
+                output.put_str(&to_string(val));
+            }
+            output.commit_row();
+        }),
+    );
+
+    registry.register_combine_nullable_1_arg::<VariantType, StringType, _, _>(
+        "try_to_json",
+        FunctionProperty::default(),
+        |_| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<VariantType, NullableType<StringType>>(|val, output, _| {
+            if val.is_empty() {
+                output.push_null();
+            } else {
+                output.validity.push(true);
+                output.builder.put_str(&to_string(val));
+                output.builder.commit_row();
+            }
+        }),
+    );
+
     registry.register_passthrough_nullable_1_arg::<VariantType, DateType, _, _>(
         "to_date",
         FunctionProperty::default(),
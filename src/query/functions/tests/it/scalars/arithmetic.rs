@@ -58,6 +58,7 @@ fn test_arithmetic() {
     test_bitwise_not(file, columns);
     test_bitwise_shift_left(file, columns);
     test_bitwise_shift_right(file, columns);
+    test_div0(file);
 }
 
 fn test_add(file: &mut impl Write, columns: &[(&str, Column)]) {
@@ -206,3 +207,9 @@ fn test_bitwise_shift_right(file: &mut impl Write, columns: &[(&str, Column)]) {
     run_ast(file, "a2 >> 2", columns);
     run_ast(file, "c >> 2", columns);
 }
+
+fn test_div0(file: &mut impl Write) {
+    run_ast(file, "div0(10, 4)", &[]);
+    run_ast(file, "div0(10, 0)", &[]);
+    run_ast(file, "safe_divide(10, 0)", &[]);
+}
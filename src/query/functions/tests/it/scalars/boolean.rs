@@ -39,6 +39,8 @@ fn test_boolean() {
     test_or(file);
     test_xor(file);
     test_is_true(file);
+    test_is_distinct_from(file);
+    test_to_boolean(file);
 }
 
 fn test_and(file: &mut impl Write) {
@@ -128,3 +130,36 @@ fn test_is_true(file: &mut impl Write) {
         ]),
     )]);
 }
+
+fn test_is_distinct_from(file: &mut impl Write) {
+    run_ast(file, "NULL IS DISTINCT FROM NULL", &[]);
+    run_ast(file, "1 IS NOT DISTINCT FROM NULL", &[]);
+
+    let columns = [
+        (
+            "a",
+            UInt8Type::from_data_with_validity(vec![1, 2, 3, 4], vec![true, true, false, false]),
+        ),
+        (
+            "b",
+            UInt8Type::from_data_with_validity(vec![1, 3, 5, 4], vec![true, true, false, true]),
+        ),
+    ];
+    run_ast(file, "a IS DISTINCT FROM b", &columns);
+    run_ast(file, "a IS NOT DISTINCT FROM b", &columns);
+}
+
+fn test_to_boolean(file: &mut impl Write) {
+    run_ast(file, "to_boolean(a)", &[(
+        "a",
+        Int32Type::from_data(vec![0, 0]),
+    )]);
+    run_ast(file, "to_boolean(a)", &[(
+        "a",
+        Int32Type::from_data(vec![1, 3, 5]),
+    )]);
+    run_ast(file, "to_boolean(a)", &[(
+        "a",
+        Int32Type::from_data(vec![-3, 0, 3]),
+    )]);
+}
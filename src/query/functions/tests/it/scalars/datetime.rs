@@ -28,6 +28,8 @@ fn test_datetime() {
     test_to_timestamp(file);
     test_to_datetime(file);
     test_to_date(file);
+    test_to_timestamp_with_format(file);
+    test_to_date_with_format(file);
     test_date_add_subtract(file);
     test_timestamp_add_subtract(file);
     test_date_date_add_sub(file);
@@ -103,6 +105,36 @@ fn test_to_date(file: &mut impl Write) {
     )]);
 }
 
+fn test_to_timestamp_with_format(file: &mut impl Write) {
+    run_ast(
+        file,
+        "to_timestamp('2022-03-04 05:06:07', '%Y-%m-%d %H:%M:%S')",
+        &[],
+    );
+    run_ast(file, "to_timestamp('04/03/2022', '%d/%m/%Y')", &[]);
+    run_ast(
+        file,
+        "to_timestamp('2022-03-04 05:06:07 +0800', '%Y-%m-%d %H:%M:%S %z')",
+        &[],
+    );
+    run_ast(file, "to_timestamp('2022-13-04', '%Y-%m-%d')", &[]);
+    run_ast(file, "to_timestamp('2022/03/04', '%Y-%m-%d')", &[]);
+    run_ast(file, "try_to_timestamp('2022/03/04', '%Y-%m-%d')", &[]);
+    run_ast(file, "try_to_timestamp('2022-03-04', '%Y-%m-%d')", &[]);
+}
+
+fn test_to_date_with_format(file: &mut impl Write) {
+    run_ast(file, "to_date('2022-03-04', '%Y-%m-%d')", &[]);
+    run_ast(file, "to_date('March 4, 2022', '%B %d, %Y')", &[]);
+    run_ast(file, "to_date('2022-03', '%Y-%m')", &[]);
+    run_ast(file, "try_to_date('2022-02-30', '%Y-%m-%d')", &[]);
+    run_ast(
+        file,
+        "try_to_date('2022-03-04 05:06:07', '%Y-%m-%d %H:%M:%S')",
+        &[],
+    );
+}
+
 fn test_date_add_subtract(file: &mut impl Write) {
     run_ast(file, "add_years(to_date(0), 10000)", &[]); // failed
     run_ast(file, "add_years(to_date(0), 100)", &[]);
@@ -27,6 +27,7 @@ fn test_map() {
 
     test_create(file);
     test_get(file);
+    test_map_keys_values(file);
 }
 
 fn test_create(file: &mut impl Write) {
@@ -81,3 +82,11 @@ fn test_get(file: &mut impl Write) {
         ("v2", StringType::from_data(vec!["v3", "v4"])),
     ]);
 }
+
+fn test_map_keys_values(file: &mut impl Write) {
+    run_ast(file, "map_keys({})", &[]);
+    run_ast(file, "map_keys({'k1':'v1','k2':'v2'})", &[]);
+    run_ast(file, "map_values({'k1':'v1','k2':'v2'})", &[]);
+    run_ast(file, "element_at({'k1':'v1','k2':'v2'}, 'k1')", &[]);
+    run_ast(file, "element_at({'k1':'v1','k2':'v2'}, 'k3')", &[]);
+}
@@ -14,10 +14,14 @@
 
 use std::io::Write;
 
+use common_expression::types::Float32Type;
 use common_expression::types::Float64Type;
 use common_expression::types::UInt16Type;
+use common_expression::types::UInt32Type;
 use common_expression::types::UInt8Type;
 use common_expression::FromData;
+use common_functions::scalars::compute_grouping;
+use common_functions::scalars::GroupingMapping;
 use goldenfile::Mint;
 
 use super::run_ast;
@@ -37,6 +41,11 @@ fn test_other() {
     test_try_inet_aton(file);
     test_inet_ntoa(file);
     test_try_inet_ntoa(file);
+    test_ip_in_cidr(file);
+    test_cidr_to_range(file);
+    test_ip_network(file);
+    test_complex_to_string(file);
+    test_grouping(file);
 }
 
 fn test_run_diff(file: &mut impl Write) {
@@ -52,6 +61,10 @@ fn test_run_diff(file: &mut impl Write) {
         "a",
         Float64Type::from_data(vec![37.617673, 38.617673, 39.617673]),
     )]);
+    run_ast(file, "running_difference(a)", &[(
+        "a",
+        Float32Type::from_data(vec![1.5f32, 2.5, 4.0]),
+    )]);
 }
 
 fn test_humanize(file: &mut impl Write) {
@@ -98,8 +111,70 @@ fn test_try_inet_aton(file: &mut impl Write) {
 
 fn test_inet_ntoa(file: &mut impl Write) {
     run_ast(file, "inet_ntoa(16909060)", &[]);
+    run_ast(file, "inet_ntoa(4294967295)", &[]);
+    run_ast(file, "inet_ntoa(4294967296)", &[]);
+    run_ast(file, "inet_ntoa(a)", &[(
+        "a",
+        UInt32Type::from_data(vec![0u32, 16909060, 4294967295]),
+    )]);
 }
 
 fn test_try_inet_ntoa(file: &mut impl Write) {
     run_ast(file, "try_inet_ntoa(121211111111111)", &[]);
 }
+
+fn test_ip_in_cidr(file: &mut impl Write) {
+    run_ast(file, "ip_in_cidr('192.168.1.10', '192.168.1.0/24')", &[]);
+    run_ast(file, "ip_in_cidr('192.168.2.10', '192.168.1.0/24')", &[]);
+    run_ast(file, "ip_in_cidr('2001:db8::1', '2001:db8::/32')", &[]);
+    run_ast(file, "ip_in_cidr('2001:db9::1', '2001:db8::/32')", &[]);
+    run_ast(file, "ip_in_cidr('192.168.1.10', '2001:db8::/32')", &[]);
+    run_ast(file, "ip_in_cidr('192.168.1.10', '192.168.1.0/33')", &[]);
+    run_ast(file, "ip_in_cidr('192.168.1.10', '192.168.1.0')", &[]);
+}
+
+fn test_cidr_to_range(file: &mut impl Write) {
+    run_ast(file, "cidr_to_range('192.168.1.77/24')", &[]);
+    run_ast(file, "cidr_to_range('10.1.2.3/32')", &[]);
+    run_ast(file, "cidr_to_range('2001:db8::/32')", &[]);
+    run_ast(file, "cidr_to_range('10.1.2.3/33')", &[]);
+}
+
+fn test_ip_network(file: &mut impl Write) {
+    run_ast(file, "ip_network('192.168.1.77', 16)", &[]);
+    run_ast(file, "ip_network('2001:db8:1:2:3:4:5:6', 64)", &[]);
+    run_ast(file, "ip_network('192.168.1.77', 33)", &[]);
+}
+
+fn test_complex_to_string(file: &mut impl Write) {
+    run_ast(file, "to_string([1, 2, 3])", &[]);
+    run_ast(file, "to_string((1, true))", &[]);
+    run_ast(file, "to_string([1, NULL])", &[]);
+    run_ast(file, "to_string(CAST(NULL AS ARRAY(UINT8) NULL))", &[]);
+}
+
+fn test_grouping(file: &mut impl Write) {
+    run_ast(file, "grouping(0, 1)(a)", &[(
+        "a",
+        UInt32Type::from_data(vec![0u32, 1, 2, 3]),
+    )]);
+    run_ast(file, "grouping(1, 0)(a)", &[(
+        "a",
+        UInt32Type::from_data(vec![0u32, 1, 2, 3]),
+    )]);
+}
+
+#[test]
+fn test_grouping_mapping() {
+    let cols_list: [&[usize]; 5] = [&[], &[0], &[2, 0], &[0, 1, 2], &[3, 1, 0, 2, 4]];
+    for cols in cols_list {
+        let mapping = GroupingMapping::new(cols);
+        for grouping_id in 0..32 {
+            assert_eq!(
+                mapping.compute(grouping_id),
+                compute_grouping(cols, grouping_id),
+                "cols: {cols:?}, grouping_id: {grouping_id}"
+            );
+        }
+    }
+}
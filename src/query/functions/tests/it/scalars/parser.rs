@@ -381,6 +381,24 @@ pub fn transform_expr(ast: AExpr, columns: &[(&str, DataType)]) -> RawExpr {
                 }
             }
         }
+        AExpr::IsDistinctFrom {
+            span,
+            left,
+            right,
+            not,
+        } => RawExpr::FunctionCall {
+            span,
+            name: if not {
+                "is_not_distinct_from".to_string()
+            } else {
+                "is_distinct_from".to_string()
+            },
+            params: vec![],
+            args: vec![
+                transform_expr(*left, columns),
+                transform_expr(*right, columns),
+            ],
+        },
         AExpr::DateAdd {
             span,
             unit,
@@ -39,6 +39,7 @@ fn test_variant() {
     test_try_to_type(file);
     test_json_object(file);
     test_json_object_keep_null(file);
+    test_to_json(file);
 }
 
 fn test_parse_json(file: &mut impl Write) {
@@ -583,3 +584,18 @@ fn test_json_object_keep_null(file: &mut impl Write) {
         ),
     ]);
 }
+
+fn test_to_json(file: &mut impl Write) {
+    run_ast(file, "to_json(NULL)", &[]);
+    run_ast(file, "to_json(parse_json('null'))", &[]);
+    run_ast(file, "to_json(try_parse_json('nuLL'))", &[]);
+    run_ast(
+        file,
+        r#"to_json(parse_json('{"a":1,"b":{"c":[1,"x",null]}}'))"#,
+        &[],
+    );
+
+    run_ast(file, "try_to_json(NULL)", &[]);
+    run_ast(file, "try_to_json(parse_json('[1,\"x\"]'))", &[]);
+    run_ast(file, "try_to_json(try_parse_json('nuLL'))", &[]);
+}
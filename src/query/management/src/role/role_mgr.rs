@@ -73,6 +73,11 @@ impl RoleMgr {
         }
     }
 
+    /// The prefix of the keys of all the roles of the tenant, e.g. to watch the changes of them.
+    pub fn role_key_prefix(tenant: &str) -> String {
+        format!("{}/{}/", ROLE_API_KEY_PREFIX, tenant)
+    }
+
     fn make_role_key(&self, role: &str) -> String {
         format!("{}/{}", self.role_prefix, role)
     }
@@ -107,7 +107,8 @@ impl InputFormatTextBase for InputFormatCSV {
         let columns = &mut builder.mutable_columns;
         let n_column = columns.len();
         let mut start = 0usize;
-        let mut num_rows = 0usize;
+        // The rows of the previous batches may be not flushed yet.
+        let mut num_rows = columns.first().map_or(0, |c| c.len());
         let mut error_map: HashMap<u16, InputError> = HashMap::new();
         let mut field_end_idx = 0;
         let field_decoder = builder
@@ -179,6 +179,7 @@ impl InputFormatPipe for ParquetFormatPipe {
 
         RowGroupInMemory::read_async(
             split_info.to_string(),
+            split_info.file.path.clone(),
             &mut reader,
             meta.meta.clone(),
             input_fields,
@@ -226,6 +227,7 @@ impl DynData for SplitMeta {
 
 pub struct RowGroupInMemory {
     pub split_info: String,
+    pub file_path: String,
     pub meta: RowGroupMetaData,
     // for input, they are in the order of schema.
     // for select, they are the fields used in query.
@@ -248,6 +250,7 @@ impl RowBatchTrait for RowGroupInMemory {
 impl RowGroupInMemory {
     fn read<R: Read + Seek>(
         split_info: String,
+        file_path: String,
         reader: &mut R,
         meta: RowGroupMetaData,
         fields: Arc<Vec<Field>>,
@@ -262,6 +265,7 @@ impl RowGroupInMemory {
         }
         Ok(Self {
             split_info,
+            file_path,
             meta,
             field_meta_indexes,
             field_arrays: filed_arrays,
@@ -271,6 +275,7 @@ impl RowGroupInMemory {
 
     async fn read_async<R: AsyncRead + AsyncSeek + Send + Unpin>(
         split_info: String,
+        file_path: String,
         reader: &mut R,
         meta: RowGroupMetaData,
         fields: Arc<Vec<Field>>,
@@ -285,6 +290,7 @@ impl RowGroupInMemory {
         }
         Ok(Self {
             split_info,
+            file_path,
             meta,
             field_meta_indexes,
             field_arrays: filed_arrays,
@@ -374,6 +380,10 @@ impl BlockBuilderTrait for ParquetBlockBuilder {
             let block = DataBlock::from_arrow_chunk(&chunk, &input_schema)?;
 
             let block_total_rows = block.num_rows();
+            if let Some(ref loaded_rows_map) = self.ctx.loaded_rows_map {
+                *loaded_rows_map.entry(rg.file_path.clone()).or_default() +=
+                    block_total_rows as u64;
+            }
             let num_rows_per_block = self.ctx.block_compact_thresholds.max_rows_per_block;
             let blocks: Vec<DataBlock> = (0..block_total_rows)
                 .step_by(num_rows_per_block)
@@ -437,6 +447,7 @@ impl AligningStateTrait for AligningState {
             for row_group in file_meta.row_groups.into_iter() {
                 row_batches.push(RowGroupInMemory::read(
                     split_info.clone(),
+                    self.split_info.file.path.clone(),
                     &mut cursor,
                     row_group,
                     fields.clone(),
@@ -147,7 +147,8 @@ impl InputFormatTextBase for InputFormatTSV {
         let schema = &builder.ctx.schema;
         let columns = &mut builder.mutable_columns;
         let mut start = 0usize;
-        let mut num_rows = 0usize;
+        // The rows of the previous batches may be not flushed yet.
+        let mut num_rows = columns.first().map_or(0, |c| c.len());
         let mut error_map: HashMap<u16, InputError> = HashMap::new();
         for (i, end) in batch.row_ends.iter().enumerate() {
             let buf = &batch.data[start..*end]; // include \n
@@ -17,6 +17,7 @@ use std::fmt::Debug;
 use std::fmt::Formatter;
 use std::mem;
 use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::sync::Mutex;
 
@@ -36,6 +37,7 @@ use common_meta_app::principal::StageFileCompression;
 use common_meta_app::principal::StageFileFormatType;
 use common_meta_app::principal::StageInfo;
 use common_settings::Settings;
+use common_storage::FileLoadStatus;
 use dashmap::DashMap;
 use opendal::Operator;
 
@@ -124,6 +126,8 @@ pub struct InputContext {
     pub on_error_mode: OnErrorMode,
     pub on_error_count: AtomicU64,
     pub on_error_map: Option<DashMap<String, HashMap<u16, InputError>>>,
+    /// The number of rows loaded from each file, only for copy.
+    pub loaded_rows_map: Option<DashMap<String, u64>>,
 }
 
 impl Debug for InputContext {
@@ -188,6 +192,7 @@ impl InputContext {
             on_error_mode,
             on_error_count: AtomicU64::new(0),
             on_error_map: Some(DashMap::new()),
+            loaded_rows_map: Some(DashMap::new()),
         })
     }
 
@@ -230,6 +235,7 @@ impl InputContext {
             on_error_mode: OnErrorMode::AbortNum(1),
             on_error_count: AtomicU64::new(0),
             on_error_map: None,
+            loaded_rows_map: None,
         })
     }
 
@@ -269,6 +275,7 @@ impl InputContext {
             on_error_mode: OnErrorMode::AbortNum(1),
             on_error_count: AtomicU64::new(0),
             on_error_map: None,
+            loaded_rows_map: None,
         })
     }
 
@@ -353,20 +360,40 @@ impl InputContext {
         ErrorCode::BadBytes(msg)
     }
 
-    pub fn get_maximum_error_per_file(&self) -> Option<HashMap<String, ErrorCode>> {
+    /// The number of rows skipped because of errors, under `ON_ERROR = ABORT_<num>` or
+    /// `ON_ERROR = CONTINUE`.
+    pub fn get_error_rows(&self) -> u64 {
+        let continued = match self.on_error_map {
+            Some(ref on_error_map) => on_error_map
+                .iter()
+                .map(|x| x.value().values().map(|e| e.num as u64).sum::<u64>())
+                .sum(),
+            None => 0,
+        };
+        self.on_error_count.load(Ordering::Relaxed) + continued
+    }
+
+    /// The rows loaded and the errors of each file copied, the files without any row
+    /// loaded or skipped are absent.
+    pub fn get_file_load_status(&self) -> HashMap<String, FileLoadStatus> {
+        let mut status = HashMap::<String, FileLoadStatus>::new();
+        if let Some(ref loaded_rows_map) = self.loaded_rows_map {
+            for x in loaded_rows_map.iter() {
+                status.entry(x.key().to_string()).or_default().rows_loaded = *x.value();
+            }
+        }
         if let Some(ref on_error_map) = self.on_error_map {
-            if on_error_map.is_empty() {
-                return None;
+            for x in on_error_map.iter() {
+                let file_status = status.entry(x.key().to_string()).or_default();
+                file_status.errors_seen = x.value().values().map(|e| e.num as u64).sum();
+                file_status.error = x
+                    .value()
+                    .values()
+                    .max_by_key(|e| e.num)
+                    .map(|e| e.err.clone());
             }
-            let mut m = HashMap::<String, ErrorCode>::new();
-            on_error_map.iter().for_each(|x| {
-                if let Some(max_v) = x.value().iter().max_by_key(|entry| entry.1.num) {
-                    m.insert(x.key().to_string(), max_v.1.err.clone());
-                }
-            });
-            return Some(m);
         }
-        None
+        status
     }
 }
 
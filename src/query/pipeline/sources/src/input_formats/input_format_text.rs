@@ -484,6 +484,16 @@ impl<T: InputFormatTextBase> BlockBuilder<T> {
         self.mutable_columns.iter().map(|x| x.memory_size()).sum()
     }
 
+    fn num_columns_rows(&self) -> usize {
+        self.mutable_columns.first().map_or(0, |c| c.len())
+    }
+
+    fn merge_loaded_rows(&self, rows: usize, file_name: &str) {
+        if let Some(ref loaded_rows_map) = self.ctx.loaded_rows_map {
+            *loaded_rows_map.entry(file_name.to_string()).or_default() += rows as u64;
+        }
+    }
+
     fn merge_map(&self, error_map: HashMap<u16, InputError>, file_name: String) {
         if let Some(ref on_error_map) = self.ctx.on_error_map {
             on_error_map
@@ -528,7 +538,10 @@ impl<T: InputFormatTextBase> BlockBuilderTrait for BlockBuilder<T> {
         if let Some(b) = batch {
             let file_name = b.split_info.file.path.clone();
             self.num_rows += b.row_ends.len();
+            let rows_before = self.num_columns_rows();
             let r = T::deserialize(self, b)?;
+            let rows_loaded = self.num_columns_rows() - rows_before;
+            self.merge_loaded_rows(rows_loaded, &file_name);
             self.merge_map(r, file_name);
             let mem = self.memory_size();
             tracing::debug!(
@@ -15,6 +15,7 @@
 // The api module only used for internal communication, such as GRPC between cluster and the managed HTTP REST API.
 
 pub use http_service::HttpService;
+pub use rpc::recv_with_timeout;
 pub use rpc::serialize_block;
 pub use rpc::BroadcastExchange;
 pub use rpc::BroadcastFlightScatter;
@@ -26,6 +27,7 @@ pub use rpc::DataPacket;
 pub use rpc::DatabendQueryFlightService;
 pub use rpc::DefaultExchangeInjector;
 pub use rpc::ExchangeDeserializeMeta;
+pub use rpc::ExchangeFormat;
 pub use rpc::ExchangeInjector;
 pub use rpc::ExchangeSerializeMeta;
 pub use rpc::ExchangeShuffleMeta;
@@ -12,8 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_exception::ErrorCode;
+use common_exception::Result;
 use common_expression::RemoteExpr;
 
+use crate::clusters::Cluster;
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum DataExchange {
     Merge(MergeExchange),
@@ -37,6 +41,23 @@ impl DataExchange {
             DataExchange::Broadcast(exchange) => exchange.from_multiple_nodes,
         }
     }
+
+    /// Checks that the destinations of the exchange are still members of the cluster.
+    ///
+    /// The destinations and the hash partition mapping are fixed at plan time, so the query fails
+    /// fast if a destination left the cluster since then, instead of misrouting the data.
+    pub fn check_destinations(&self, cluster: &Cluster) -> Result<()> {
+        for destination in self.get_destinations() {
+            if !cluster.nodes.iter().any(|node| node.id == destination) {
+                return Err(ErrorCode::ClusterUnknownNode(format!(
+                    "Cluster membership changed during the query, the exchange destination \"{}\" is no longer in the cluster, please retry the query",
+                    destination
+                )));
+            }
+        }
+
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -148,7 +148,12 @@ impl ExchangeInjector for DefaultExchangeInjector {
 
             let schema = &params.schema;
             items.push(PipeItem::create(
-                TransformExchangeDeserializer::create(input.clone(), output.clone(), schema),
+                TransformExchangeDeserializer::create(
+                    input.clone(),
+                    output.clone(),
+                    schema,
+                    params.format,
+                ),
                 vec![input],
                 vec![output],
             ));
@@ -185,7 +190,12 @@ impl ExchangeInjector for DefaultExchangeInjector {
 
             let schema = &params.schema;
             items.push(PipeItem::create(
-                TransformExchangeDeserializer::create(input.clone(), output.clone(), schema),
+                TransformExchangeDeserializer::create(
+                    input.clone(),
+                    output.clone(),
+                    schema,
+                    params.format,
+                ),
                 vec![input],
                 vec![output],
             ));
@@ -32,6 +32,7 @@ use common_sql::executor::PhysicalPlan;
 use parking_lot::Mutex;
 use parking_lot::ReentrantMutex;
 
+use crate::api::rpc::exchange::exchange_params::ExchangeFormat;
 use crate::api::rpc::exchange::exchange_params::ExchangeParams;
 use crate::api::rpc::exchange::exchange_params::MergeExchangeParams;
 use crate::api::rpc::exchange::exchange_params::ShuffleExchangeParams;
@@ -366,6 +367,7 @@ impl DataExchangeManager {
 struct QueryInfo {
     query_id: String,
     current_executor: String,
+    exchange_format: ExchangeFormat,
     query_ctx: Arc<QueryContext>,
     query_executor: Option<Arc<PipelineCompleteExecutor>>,
 }
@@ -461,6 +463,7 @@ impl QueryCoordinator {
             query_ctx: ctx.clone(),
             query_id: packet.query_id.clone(),
             current_executor: packet.executor.clone(),
+            exchange_format: packet.exchange_format,
             query_executor: None,
         });
 
@@ -655,6 +658,8 @@ impl FragmentCoordinator {
         exchange_injector: Arc<dyn ExchangeInjector>,
     ) -> Result<ExchangeParams> {
         if let Some(data_exchange) = &self.data_exchange {
+            data_exchange.check_destinations(&info.query_ctx.get_cluster())?;
+
             return match data_exchange {
                 DataExchange::Merge(exchange) => {
                     Ok(ExchangeParams::MergeExchange(MergeExchangeParams {
@@ -663,6 +668,7 @@ impl FragmentCoordinator {
                         fragment_id: self.fragment_id,
                         query_id: info.query_id.to_string(),
                         destination_id: exchange.destination_id.clone(),
+                        format: info.exchange_format,
                     }))
                 }
                 DataExchange::Broadcast(exchange) => {
@@ -673,6 +679,7 @@ impl FragmentCoordinator {
                         query_id: info.query_id.to_string(),
                         executor_id: info.current_executor.to_string(),
                         destination_ids: exchange.destination_ids.to_owned(),
+                        format: info.exchange_format,
                         shuffle_scatter: exchange_injector
                             .flight_scatter(&info.query_ctx, data_exchange)?,
                     }))
@@ -685,6 +692,7 @@ impl FragmentCoordinator {
                         query_id: info.query_id.to_string(),
                         executor_id: info.current_executor.to_string(),
                         destination_ids: exchange.destination_ids.to_owned(),
+                        format: info.exchange_format,
                         shuffle_scatter: exchange_injector
                             .flight_scatter(&info.query_ctx, data_exchange)?,
                     }))
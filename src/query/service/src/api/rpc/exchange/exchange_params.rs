@@ -20,10 +20,32 @@ use common_arrow::arrow::io::ipc::IpcField;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::DataSchemaRef;
+use common_settings::Settings;
 
 use crate::api::rpc::flight_scatter::FlightScatter;
 use crate::api::ExchangeInjector;
 
+/// How the blocks of an exchange are serialized between cluster nodes.
+///
+/// It is decided by the coordinator of the query and sent to every node with the fragments,
+/// so the serializers and the deserializers of an exchange always agree on it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum ExchangeFormat {
+    /// Arrow flight record batch messages, the schema is known by both sides of the exchange.
+    Internal,
+    /// Self-describing Arrow IPC files, which carry their own schema.
+    ArrowIpc,
+}
+
+impl ExchangeFormat {
+    pub fn try_create(settings: &Settings) -> Result<ExchangeFormat> {
+        match settings.get_exchange_serialization_format()?.as_str() {
+            "arrow_ipc" => Ok(ExchangeFormat::ArrowIpc),
+            _ => Ok(ExchangeFormat::Internal),
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct SerializeParams {
     pub options: WriteOptions,
@@ -38,6 +60,7 @@ pub struct ShuffleExchangeParams {
     pub fragment_id: usize,
     pub schema: DataSchemaRef,
     pub destination_ids: Vec<String>,
+    pub format: ExchangeFormat,
     pub shuffle_scatter: Arc<Box<dyn FlightScatter>>,
     pub exchange_injector: Arc<dyn ExchangeInjector>,
 }
@@ -48,6 +71,7 @@ pub struct MergeExchangeParams {
     pub fragment_id: usize,
     pub destination_id: String,
     pub schema: DataSchemaRef,
+    pub format: ExchangeFormat,
     pub exchange_injector: Arc<dyn ExchangeInjector>,
 }
 
@@ -13,6 +13,7 @@
 //  limitations under the License.
 
 use std::sync::Arc;
+use std::time::Duration;
 
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
@@ -52,6 +53,11 @@ pub fn via_exchange_source(
     let exchange_manager = ctx.get_exchange_manager();
     let flight_exchanges = exchange_manager.get_flight_exchanges(&exchange_params)?;
 
+    let read_timeout = match ctx.get_settings().get_exchange_source_read_timeout()? {
+        0 => None,
+        timeout => Some(Duration::from_secs(timeout)),
+    };
+
     let last_output_len = pipeline.output_len();
     let flight_exchanges_len = flight_exchanges.len();
     exchange_source_reader::via_reader(
@@ -60,6 +66,7 @@ pub fn via_exchange_source(
         pipeline,
         params.query_id.clone(),
         params.fragment_id,
+        read_timeout,
     );
 
     injector.apply_merge_deserializer(flight_exchanges_len, params, pipeline)
@@ -13,8 +13,13 @@
 //  limitations under the License.
 
 use std::any::Any;
+use std::future::Future;
 use std::sync::Arc;
+use std::time::Duration;
 
+use common_base::base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
 use common_expression::DataBlock;
 use common_pipeline_core::pipe::Pipe;
 use common_pipeline_core::pipe::PipeItem;
@@ -38,6 +43,7 @@ pub struct ExchangeSourceReader {
     output: Arc<OutputPort>,
     output_data: Option<DataPacket>,
     flight_exchange: FlightExchangeRef,
+    read_timeout: Option<Duration>,
 }
 
 impl ExchangeSourceReader {
@@ -46,6 +52,7 @@ impl ExchangeSourceReader {
         flight_exchange: FlightExchangeRef,
         query_id: String,
         fragment: usize,
+        read_timeout: Option<Duration>,
     ) -> ProcessorPtr {
         flight_exchange.dec_output_ref();
 
@@ -56,6 +63,7 @@ impl ExchangeSourceReader {
             output_data: None,
             fragment,
             query_id,
+            read_timeout,
         }))
     }
 }
@@ -99,7 +107,9 @@ impl Processor for ExchangeSourceReader {
 
     async fn async_process(&mut self) -> common_exception::Result<()> {
         if self.output_data.is_none() {
-            if let Some(output_data) = self.flight_exchange.recv().await? {
+            let recv = self.flight_exchange.recv();
+            let recv = recv_with_timeout(recv, self.read_timeout, &self.query_id, self.fragment);
+            if let Some(output_data) = recv.await? {
                 self.output_data = Some(output_data);
                 return Ok(());
             }
@@ -123,12 +133,37 @@ impl Processor for ExchangeSourceReader {
     }
 }
 
+/// Waits for the next packet of a remote fragment.
+///
+/// Fails with `CannotConnectNode` if nothing arrives within `timeout`, so a remote fragment that
+/// never sends (e.g. its error was lost) cannot block the exchange source forever.
+pub async fn recv_with_timeout(
+    recv: impl Future<Output = Result<Option<DataPacket>>>,
+    timeout: Option<Duration>,
+    query_id: &str,
+    fragment: usize,
+) -> Result<Option<DataPacket>> {
+    let timeout = match timeout {
+        None => return recv.await,
+        Some(timeout) => timeout,
+    };
+
+    match tokio::time::timeout(timeout, recv).await {
+        Ok(res) => res,
+        Err(_) => Err(ErrorCode::CannotConnectNode(format!(
+            "No data received from query:{:?}, fragment:{:?} exchange in {:?} (exchange_source_read_timeout)",
+            query_id, fragment, timeout
+        ))),
+    }
+}
+
 pub fn via_reader(
     prefix_size: usize,
     exchanges: Vec<FlightExchangeRef>,
     pipeline: &mut Pipeline,
     query_id: String,
     fragment: usize,
+    read_timeout: Option<Duration>,
 ) {
     let mut items = Vec::with_capacity(prefix_size + exchanges.len());
 
@@ -151,6 +186,7 @@ pub fn via_reader(
                 flight_exchange,
                 query_id.clone(),
                 fragment,
+                read_timeout,
             ),
             vec![],
             vec![output],
@@ -164,10 +200,11 @@ pub fn create_reader_item(
     exchange: FlightExchangeRef,
     query_id: String,
     fragment: usize,
+    read_timeout: Option<Duration>,
 ) -> PipeItem {
     let output = OutputPort::create();
     PipeItem::create(
-        ExchangeSourceReader::create(output.clone(), exchange, query_id, fragment),
+        ExchangeSourceReader::create(output.clone(), exchange, query_id, fragment, read_timeout),
         vec![],
         vec![output],
     )
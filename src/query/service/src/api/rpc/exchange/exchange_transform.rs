@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::sync::Arc;
+use std::time::Duration;
 
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
@@ -48,6 +49,10 @@ impl ExchangeTransform {
                 // exchange writer sink and resize and exchange reader
                 let len = params.destination_ids.len();
                 let max_threads = ctx.get_settings().get_max_threads()? as usize;
+                let read_timeout = match ctx.get_settings().get_exchange_source_read_timeout()? {
+                    0 => None,
+                    timeout => Some(Duration::from_secs(timeout)),
+                };
 
                 let mut items = Vec::with_capacity(len);
                 let exchange_params = ExchangeParams::ShuffleExchange(params.clone());
@@ -76,6 +81,7 @@ impl ExchangeTransform {
                             exchange,
                             params.query_id.clone(),
                             params.fragment_id,
+                            read_timeout,
                         ));
                     }
                 }
@@ -35,9 +35,11 @@ pub use data_exchange::ShuffleDataExchange;
 pub use exchange_injector::DefaultExchangeInjector;
 pub use exchange_injector::ExchangeInjector;
 pub use exchange_manager::DataExchangeManager;
+pub use exchange_params::ExchangeFormat;
 pub use exchange_params::MergeExchangeParams;
 pub use exchange_params::ShuffleExchangeParams;
 pub use exchange_sorting::ExchangeSorting;
+pub use exchange_source_reader::recv_with_timeout;
 pub use exchange_transform_shuffle::ExchangeShuffleMeta;
 
 pub use self::serde::exchange_deserializer::ExchangeDeserializeMeta;
@@ -15,11 +15,14 @@
 use std::any::Any;
 use std::fmt::Debug;
 use std::fmt::Formatter;
+use std::io::Cursor;
 use std::sync::Arc;
 
 use common_arrow::arrow::datatypes::Schema as ArrowSchema;
 use common_arrow::arrow::io::flight::default_ipc_fields;
 use common_arrow::arrow::io::flight::deserialize_batch;
+use common_arrow::arrow::io::ipc::read::read_file_metadata;
+use common_arrow::arrow::io::ipc::read::FileReader;
 use common_arrow::arrow::io::ipc::IpcSchema;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -37,10 +40,12 @@ use common_pipeline_transforms::processors::transforms::Transformer;
 use serde::Deserializer;
 use serde::Serializer;
 
+use crate::api::rpc::exchange::exchange_params::ExchangeFormat;
 use crate::api::DataPacket;
 use crate::api::FragmentData;
 
 pub struct TransformExchangeDeserializer {
+    format: ExchangeFormat,
     schema: DataSchemaRef,
     ipc_schema: IpcSchema,
     arrow_schema: Arc<ArrowSchema>,
@@ -51,7 +56,16 @@ impl TransformExchangeDeserializer {
         input: Arc<InputPort>,
         output: Arc<OutputPort>,
         schema: &DataSchemaRef,
+        format: ExchangeFormat,
     ) -> ProcessorPtr {
+        ProcessorPtr::create(Transformer::create(
+            input,
+            output,
+            TransformExchangeDeserializer::new(schema, format),
+        ))
+    }
+
+    pub fn new(schema: &DataSchemaRef, format: ExchangeFormat) -> TransformExchangeDeserializer {
         let arrow_schema = Arc::new(schema.to_arrow());
         let ipc_fields = default_ipc_fields(&arrow_schema.fields);
         let ipc_schema = IpcSchema {
@@ -59,15 +73,12 @@ impl TransformExchangeDeserializer {
             is_little_endian: true,
         };
 
-        ProcessorPtr::create(Transformer::create(
-            input,
-            output,
-            TransformExchangeDeserializer {
-                ipc_schema,
-                arrow_schema,
-                schema: schema.clone(),
-            },
-        ))
+        TransformExchangeDeserializer {
+            format,
+            ipc_schema,
+            arrow_schema,
+            schema: schema.clone(),
+        }
     }
 
     fn recv_data(&self, fragment_data: FragmentData) -> Result<DataBlock> {
@@ -87,14 +98,19 @@ impl TransformExchangeDeserializer {
             return Ok(DataBlock::new_with_meta(vec![], 0, meta));
         }
 
-        let batch = deserialize_batch(
-            &fragment_data.data,
-            &self.arrow_schema.fields,
-            &self.ipc_schema,
-            &Default::default(),
-        )?;
+        let data_block = match self.format {
+            ExchangeFormat::Internal => {
+                let batch = deserialize_batch(
+                    &fragment_data.data,
+                    &self.arrow_schema.fields,
+                    &self.ipc_schema,
+                    &Default::default(),
+                )?;
 
-        let data_block = DataBlock::from_arrow_chunk(&batch, &self.schema)?;
+                DataBlock::from_arrow_chunk(&batch, &self.schema)?
+            }
+            ExchangeFormat::ArrowIpc => self.recv_ipc_data(&fragment_data.data.data_body)?,
+        };
 
         if data_block.num_columns() == 0 {
             return Ok(DataBlock::new_with_meta(vec![], row_count as usize, meta));
@@ -102,6 +118,19 @@ impl TransformExchangeDeserializer {
 
         data_block.add_meta(meta)
     }
+
+    fn recv_ipc_data(&self, data: &[u8]) -> Result<DataBlock> {
+        let mut cursor = Cursor::new(data);
+        let metadata = read_file_metadata(&mut cursor)?;
+        let mut reader = FileReader::new(cursor, metadata, None, None);
+
+        match reader.next() {
+            Some(batch) => DataBlock::from_arrow_chunk(&batch?, &self.schema),
+            None => Err(ErrorCode::BadBytes(
+                "arrow ipc record batch not found when exchange",
+            )),
+        }
+    }
 }
 
 impl Transform for TransformExchangeDeserializer {
@@ -18,10 +18,16 @@ use std::fmt::Formatter;
 use std::sync::Arc;
 
 use common_arrow::arrow::chunk::Chunk;
+use common_arrow::arrow::datatypes::Field;
+use common_arrow::arrow::datatypes::Schema as ArrowSchema;
 use common_arrow::arrow::io::flight::default_ipc_fields;
 use common_arrow::arrow::io::flight::serialize_batch;
 use common_arrow::arrow::io::flight::WriteOptions;
+use common_arrow::arrow::io::ipc::write::FileWriter;
+use common_arrow::arrow::io::ipc::write::WriteOptions as IpcWriteOptions;
 use common_arrow::arrow::io::ipc::IpcField;
+use common_arrow::arrow_format::flight::data::FlightData;
+use common_arrow::ArrayRef;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::BlockMetaInfo;
@@ -37,6 +43,7 @@ use common_pipeline_transforms::processors::transforms::Transformer;
 use serde::Deserializer;
 use serde::Serializer;
 
+use crate::api::rpc::exchange::exchange_params::ExchangeFormat;
 use crate::api::rpc::exchange::exchange_params::MergeExchangeParams;
 use crate::api::rpc::exchange::exchange_params::ShuffleExchangeParams;
 use crate::api::rpc::exchange::exchange_transform_shuffle::ExchangeShuffleMeta;
@@ -94,6 +101,7 @@ impl BlockMetaInfo for ExchangeSerializeMeta {
 }
 
 pub struct TransformExchangeSerializer {
+    format: ExchangeFormat,
     options: WriteOptions,
     ipc_fields: Vec<IpcField>,
     sorting: Option<Arc<dyn ExchangeSorting>>,
@@ -114,6 +122,7 @@ impl TransformExchangeSerializer {
             TransformExchangeSerializer {
                 sorting,
                 ipc_fields,
+                format: params.format,
                 options: WriteOptions { compression: None },
             },
         )))
@@ -130,12 +139,19 @@ impl Transform for TransformExchangeSerializer {
             block_num = sorting.block_number(&data_block)?;
         }
 
-        serialize_block(block_num, data_block, &self.ipc_fields, &self.options)
+        serialize_block(
+            block_num,
+            data_block,
+            &self.ipc_fields,
+            &self.options,
+            self.format,
+        )
     }
 }
 
 pub struct TransformScatterExchangeSerializer {
     local_pos: usize,
+    format: ExchangeFormat,
     options: WriteOptions,
     ipc_fields: Vec<IpcField>,
     sorting: Option<Arc<dyn ExchangeSorting>>,
@@ -157,6 +173,7 @@ impl TransformScatterExchangeSerializer {
             TransformScatterExchangeSerializer {
                 sorting,
                 ipc_fields,
+                format: params.format,
                 options: WriteOptions { compression: None },
                 local_pos: params
                     .destination_ids
@@ -184,12 +201,19 @@ impl Transform for TransformScatterExchangeSerializer {
                     new_blocks.push(match self.local_pos == index {
                         true => block,
                         false => match &self.sorting {
-                            None => serialize_block(0, block, &self.ipc_fields, &self.options)?,
+                            None => serialize_block(
+                                0,
+                                block,
+                                &self.ipc_fields,
+                                &self.options,
+                                self.format,
+                            )?,
                             Some(sorting) => serialize_block(
                                 sorting.block_number(&data_block)?,
                                 block,
                                 &self.ipc_fields,
                                 &self.options,
+                                self.format,
                             )?,
                         },
                     });
@@ -212,6 +236,7 @@ pub fn serialize_block(
     data_block: DataBlock,
     ipc_field: &[IpcField],
     options: &WriteOptions,
+    format: ExchangeFormat,
 ) -> Result<DataBlock> {
     if data_block.is_empty() && data_block.get_meta().is_none() {
         return Ok(DataBlock::empty_with_meta(ExchangeSerializeMeta::create(
@@ -224,9 +249,11 @@ pub fn serialize_block(
     bincode::serialize_into(&mut meta, &data_block.get_meta())
         .map_err(|_| ErrorCode::BadBytes("block meta serialize error when exchange"))?;
 
-    let values = match data_block.is_empty() {
-        true => serialize_batch(&Chunk::new(vec![]), &[], options)?.1,
-        false => {
+    let values = match (data_block.is_empty(), format) {
+        (true, ExchangeFormat::Internal) => serialize_batch(&Chunk::new(vec![]), &[], options)?.1,
+        (true, ExchangeFormat::ArrowIpc) => ipc_flight_data(vec![]),
+        (false, ExchangeFormat::ArrowIpc) => ipc_flight_data(serialize_ipc(data_block)?),
+        (false, ExchangeFormat::Internal) => {
             let chunks = data_block.try_into()?;
             let (dicts, values) = serialize_batch(&chunks, ipc_field, options)?;
 
@@ -245,3 +272,35 @@ pub fn serialize_block(
         Some(DataPacket::FragmentData(FragmentData::create(meta, values))),
     )))
 }
+
+/// Serializes the block as an Arrow IPC file, the schema is written with the record batch.
+fn serialize_ipc(data_block: DataBlock) -> Result<Vec<u8>> {
+    let chunk: Chunk<ArrayRef> = data_block.try_into()?;
+    let fields = chunk
+        .arrays()
+        .iter()
+        .enumerate()
+        .map(|(index, array)| Field::new(index.to_string(), array.data_type().clone(), true))
+        .collect::<Vec<_>>();
+
+    let mut buffer = vec![];
+    let mut writer = FileWriter::new(
+        &mut buffer,
+        ArrowSchema::from(fields),
+        None,
+        IpcWriteOptions::default(),
+    );
+    writer.start()?;
+    writer.write(&chunk, None)?;
+    writer.finish()?;
+    Ok(buffer)
+}
+
+fn ipc_flight_data(data_body: Vec<u8>) -> FlightData {
+    FlightData {
+        data_body,
+        app_metadata: vec![],
+        data_header: vec![],
+        flight_descriptor: None,
+    }
+}
@@ -26,12 +26,14 @@ mod flight_service;
 mod packets;
 mod request_builder;
 
+pub use exchange::recv_with_timeout;
 pub use exchange::serialize_block;
 pub use exchange::BroadcastExchange;
 pub use exchange::DataExchange;
 pub use exchange::DataExchangeManager;
 pub use exchange::DefaultExchangeInjector;
 pub use exchange::ExchangeDeserializeMeta;
+pub use exchange::ExchangeFormat;
 pub use exchange::ExchangeInjector;
 pub use exchange::ExchangeSerializeMeta;
 pub use exchange::ExchangeShuffleMeta;
@@ -24,6 +24,7 @@ use crate::api::rpc::flight_actions::InitQueryFragmentsPlan;
 use crate::api::rpc::packets::packet::create_client;
 use crate::api::rpc::packets::packet::Packet;
 use crate::api::rpc::packets::packet_fragment::FragmentPlanPacket;
+use crate::api::ExchangeFormat;
 use crate::api::FlightAction;
 
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
@@ -32,6 +33,7 @@ pub struct QueryFragmentsPlanPacket {
     pub executor: String,
     pub request_executor: String,
     pub fragments: Vec<FragmentPlanPacket>,
+    pub exchange_format: ExchangeFormat,
     // We send nodes info for each node. This is a bad choice
     pub executors_info: HashMap<String, Arc<NodeInfo>>,
 }
@@ -41,6 +43,7 @@ impl QueryFragmentsPlanPacket {
         query_id: String,
         executor: String,
         fragments: Vec<FragmentPlanPacket>,
+        exchange_format: ExchangeFormat,
         executors_info: HashMap<String, Arc<NodeInfo>>,
         request_executor: String,
     ) -> QueryFragmentsPlanPacket {
@@ -48,6 +51,7 @@ impl QueryFragmentsPlanPacket {
             query_id,
             executor,
             fragments,
+            exchange_format,
             executors_info,
             request_executor,
         }
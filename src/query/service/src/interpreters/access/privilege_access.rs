@@ -260,6 +260,12 @@ impl AccessChecker for PrivilegeAccess {
             }
             // Others.
             Plan::Insert(plan) => {
+                // The RETURNING clause reads the inserted rows back.
+                let privileges = if plan.has_returning() {
+                    vec![UserPrivilegeType::Insert, UserPrivilegeType::Select]
+                } else {
+                    vec![UserPrivilegeType::Insert]
+                };
                 session
                     .validate_privilege(
                         &GrantObject::Table(
@@ -267,7 +273,7 @@ impl AccessChecker for PrivilegeAccess {
                             plan.database.clone(),
                             plan.table.clone(),
                         ),
-                        vec![UserPrivilegeType::Insert],
+                        privileges,
                     )
                     .await?;
             }
@@ -306,6 +312,9 @@ impl AccessChecker for PrivilegeAccess {
                         vec![UserPrivilegeType::Update],
                     )
                     .await?;
+                if let Some(from) = &plan.from {
+                    self.check(from).await?;
+                }
             }
             Plan::CreateView(plan) => {
                 session
@@ -17,6 +17,7 @@ use std::sync::Arc;
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
 use common_meta_app::principal::GrantObject;
+use common_users::UserApiProvider;
 
 use crate::procedures::ProcedureFactory;
 use crate::sessions::QueryContext;
@@ -59,6 +60,16 @@ pub async fn validate_grant_object_exists(
                 )));
             }
         }
+        GrantObject::Stage(stage_name) => {
+            UserApiProvider::instance()
+                .get_stage(tenant.as_str(), stage_name)
+                .await?;
+        }
+        GrantObject::UDF(udf_name) => {
+            UserApiProvider::instance()
+                .get_udf(tenant.as_str(), udf_name)
+                .await?;
+        }
         GrantObject::Global => (),
     }
 
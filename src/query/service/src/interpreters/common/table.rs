@@ -26,7 +26,7 @@ use crate::pipelines::processors::TransformResortAddOn;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
 
-fn fill_missing_columns(
+pub fn fill_missing_columns(
     ctx: Arc<QueryContext>,
     source_schema: &DataSchemaRef,
     table: Arc<dyn Table>,
@@ -23,9 +23,13 @@ use common_catalog::table::Table;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::infer_table_schema;
+use common_expression::types::StringType;
+use common_expression::DataBlock;
 use common_expression::DataField;
 use common_expression::DataSchemaRef;
 use common_expression::DataSchemaRefExt;
+use common_expression::FromData;
+use common_meta_app::principal::OnErrorMode;
 use common_meta_app::principal::StageInfo;
 use common_meta_app::schema::TableCopiedFileInfo;
 use common_pipeline_core::processors::processor::ProcessorPtr;
@@ -39,15 +43,22 @@ use tracing::error;
 use tracing::info;
 
 use crate::interpreters::common::append2table;
+use crate::interpreters::common::fill_missing_columns;
 use crate::interpreters::Interpreter;
 use crate::interpreters::SelectInterpreter;
+use crate::pipelines::executor::ExecutorSettings;
+use crate::pipelines::executor::PipelineCompleteExecutor;
+use crate::pipelines::executor::PipelinePullingExecutor;
 use crate::pipelines::processors::TransformCastSchema;
 use crate::pipelines::processors::TransformLimit;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
 use crate::sessions::TableContext;
+use crate::sql::plans::CopyFileSummary;
 use crate::sql::plans::CopyPlan;
+use crate::sql::plans::CopySummary;
 use crate::sql::plans::Plan;
+use crate::sql::plans::ValidationMode;
 
 pub struct CopyInterpreter {
     ctx: Arc<QueryContext>,
@@ -103,7 +114,7 @@ impl CopyInterpreter {
         stage: &StageInfo,
         path: &str,
         query: &Plan,
-    ) -> Result<PipelineBuildResult> {
+    ) -> Result<(PipelineBuildResult, Arc<dyn Table>)> {
         let (mut build_res, data_schema) = self.build_query(query).await?;
         let table_schema = infer_table_schema(&data_schema)?;
         let stage_table_info = StageTableInfo {
@@ -119,14 +130,14 @@ impl CopyInterpreter {
         let table = StageTable::try_create(stage_table_info)?;
         append2table(
             self.ctx.clone(),
-            table,
+            table.clone(),
             data_schema,
             &mut build_res,
             false,
             true,
             AppendMode::Normal,
         )?;
-        Ok(build_res)
+        Ok((build_res, table))
     }
 
     async fn try_purge_files(
@@ -223,15 +234,16 @@ impl CopyInterpreter {
         Ok(build_res)
     }
 
-    #[allow(clippy::too_many_arguments)]
-    async fn build_copy_into_table_pipeline(
+    /// List the files of the stage, returns all the files and the ones need to be copied.
+    /// Unless `force` is true, the files already copied into the table are not copied again.
+    async fn list_files_to_copy(
         &self,
         catalog_name: &str,
         database_name: &str,
         table_name: &str,
         force: bool,
         stage_table_info: &StageTableInfo,
-    ) -> Result<PipelineBuildResult> {
+    ) -> Result<(Vec<StageFileInfo>, Vec<StageFileInfo>)> {
         let start = Instant::now();
         let ctx = self.ctx.clone();
         let table_ctx: Arc<dyn TableContext> = ctx.clone();
@@ -243,8 +255,7 @@ impl CopyInterpreter {
             info!(status);
         }
 
-        let mut stage_table_info = stage_table_info.clone();
-        let mut all_source_file_infos = StageTable::list_files(&stage_table_info).await?;
+        let mut all_source_file_infos = StageTable::list_files(stage_table_info).await?;
 
         info!("end to list files: {}", all_source_file_infos.len());
 
@@ -282,9 +293,36 @@ impl CopyInterpreter {
             start.elapsed().as_secs()
         );
 
+        Ok((all_source_file_infos, need_copy_file_infos))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    async fn build_copy_into_table_pipeline(
+        &self,
+        catalog_name: &str,
+        database_name: &str,
+        table_name: &str,
+        force: bool,
+        stage_table_info: &StageTableInfo,
+    ) -> Result<(PipelineBuildResult, Vec<StageFileInfo>)> {
+        let start = Instant::now();
+        let ctx = self.ctx.clone();
+        let table_ctx: Arc<dyn TableContext> = ctx.clone();
+
+        let mut stage_table_info = stage_table_info.clone();
+        let (all_source_file_infos, need_copy_file_infos) = self
+            .list_files_to_copy(
+                catalog_name,
+                database_name,
+                table_name,
+                force,
+                &stage_table_info,
+            )
+            .await?;
+
         let mut build_res = PipelineBuildResult::create();
         if need_copy_file_infos.is_empty() {
-            return Ok(build_res);
+            return Ok((build_res, need_copy_file_infos));
         }
 
         // Status.
@@ -318,6 +356,17 @@ impl CopyInterpreter {
         stage_table.set_block_compact_thresholds(to_table.get_block_compact_thresholds());
         stage_table.read_data(table_ctx, &read_source_plan, &mut build_res.main_pipeline)?;
 
+        // Fill the columns not given by `COPY INTO <table> (<columns>)` with default values.
+        if stage_table_info.schema != to_table.schema() {
+            let source_schema: DataSchemaRef = Arc::new(stage_table_info.schema.clone().into());
+            fill_missing_columns(
+                ctx.clone(),
+                &source_schema,
+                to_table.clone(),
+                &mut build_res.main_pipeline,
+            )?;
+        }
+
         // Build Limit pipeline.
         let limit = stage_table_info.stage_info.copy_options.size_limit;
         if limit > 0 {
@@ -343,6 +392,7 @@ impl CopyInterpreter {
         )?;
 
         let stage_table_info_clone = stage_table_info.clone();
+        let copied_file_infos = need_copy_file_infos.clone();
         let database_name = database_name.to_string();
         let catalog_name = catalog_name.to_string();
         let table_name = table_name.to_string();
@@ -353,7 +403,7 @@ impl CopyInterpreter {
                     to_table,
                     stage_table_info_clone.stage_info,
                     all_source_file_infos,
-                    need_copy_file_infos,
+                    copied_file_infos,
                     catalog_name,
                     database_name,
                     table_name,
@@ -368,7 +418,157 @@ impl CopyInterpreter {
             }
         });
 
-        Ok(build_res)
+        Ok((build_res, need_copy_file_infos))
+    }
+
+    /// Validate the files to be copied into the table without loading them.
+    /// - `RETURN_<n>_ROWS` returns the first n rows, it fails on the first error like `ON_ERROR = ABORT`.
+    /// - `RETURN_ERRORS` and `RETURN_ALL_ERRORS` read all the files and return the error found in each file.
+    ///   There is no partially loaded file, so both of them return the same errors.
+    #[allow(clippy::too_many_arguments)]
+    async fn build_copy_into_table_validation_pipeline(
+        &self,
+        catalog_name: &str,
+        database_name: &str,
+        table_name: &str,
+        force: bool,
+        stage_table_info: &StageTableInfo,
+        validation_mode: &ValidationMode,
+    ) -> Result<PipelineBuildResult> {
+        let ctx = self.ctx.clone();
+        let table_ctx: Arc<dyn TableContext> = ctx.clone();
+
+        let mut stage_table_info = stage_table_info.clone();
+        let (_, need_copy_file_infos) = self
+            .list_files_to_copy(
+                catalog_name,
+                database_name,
+                table_name,
+                force,
+                &stage_table_info,
+            )
+            .await?;
+
+        let mut build_res = PipelineBuildResult::create();
+        if need_copy_file_infos.is_empty() {
+            return Ok(build_res);
+        }
+
+        stage_table_info.files_to_copy = Some(need_copy_file_infos);
+        stage_table_info.stage_info.copy_options.on_error = match validation_mode {
+            ValidationMode::ReturnNRows(_) => OnErrorMode::AbortNum(1),
+            _ => OnErrorMode::Continue,
+        };
+        let stage_table = StageTable::try_create(stage_table_info)?;
+        let read_source_plan = stage_table
+            .read_plan_with_catalog(ctx.clone(), catalog_name.to_string(), None, None)
+            .await?;
+        stage_table.read_data(table_ctx, &read_source_plan, &mut build_res.main_pipeline)?;
+
+        if let ValidationMode::ReturnNRows(n) = validation_mode {
+            build_res.main_pipeline.resize(1)?;
+            build_res.main_pipeline.add_transform(
+                |transform_input_port, transform_output_port| {
+                    Ok(ProcessorPtr::create(TransformLimit::try_create(
+                        Some(*n as usize),
+                        0,
+                        transform_input_port,
+                        transform_output_port,
+                    )?))
+                },
+            )?;
+            return Ok(build_res);
+        }
+
+        // Drain the data, only the errors are returned.
+        let settings = ctx.get_settings();
+        build_res.set_max_threads(settings.get_max_threads()? as usize);
+        let settings = ExecutorSettings::try_create(&settings, ctx.get_id())?;
+        let mut pulling_executor = PipelinePullingExecutor::from_pipelines(build_res, settings)?;
+        pulling_executor.start();
+        while (pulling_executor.pull_data()?).is_some() {}
+
+        let mut errors = ctx
+            .get_file_load_status()
+            .into_iter()
+            .filter_map(|(file, status)| status.error.map(|e| (file, e)))
+            .collect::<Vec<_>>();
+        if errors.is_empty() {
+            return Ok(PipelineBuildResult::create());
+        }
+        errors.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let (files, messages): (Vec<_>, Vec<_>) = errors
+            .into_iter()
+            .map(|(file, e)| (file, e.message()))
+            .unzip();
+        PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
+            StringType::from_data(files),
+            StringType::from_data(messages),
+        ])])
+    }
+
+    /// Run the copy pipeline to the end, the summary is only known once it is finished.
+    fn execute_copy_pipeline(&self, mut build_res: PipelineBuildResult) -> Result<()> {
+        if build_res.main_pipeline.is_empty() {
+            return Ok(());
+        }
+
+        let settings = self.ctx.get_settings();
+        build_res.set_max_threads(settings.get_max_threads()? as usize);
+        let settings = ExecutorSettings::try_create(&settings, self.ctx.get_id())?;
+
+        if build_res.main_pipeline.is_complete_pipeline()? {
+            let mut pipelines = build_res.sources_pipelines;
+            pipelines.push(build_res.main_pipeline);
+
+            let complete_executor = PipelineCompleteExecutor::from_pipelines(pipelines, settings)?;
+            self.ctx
+                .set_executor(Arc::downgrade(&complete_executor.get_inner()));
+            complete_executor.execute()
+        } else {
+            let mut pulling_executor =
+                PipelinePullingExecutor::from_pipelines(build_res, settings)?;
+            self.ctx
+                .set_executor(Arc::downgrade(&pulling_executor.get_inner()));
+            pulling_executor.start();
+            while (pulling_executor.pull_data()?).is_some() {}
+            Ok(())
+        }
+    }
+
+    /// The summary of loading the files into a table, it is built once the copy is finished.
+    /// With `ON_ERROR = CONTINUE` the files may be loaded partially, so the rows loaded and
+    /// the errors of each file are returned instead.
+    fn copy_into_table_summary(
+        &self,
+        on_error: &OnErrorMode,
+        copied_files: &[StageFileInfo],
+    ) -> DataBlock {
+        if *on_error == OnErrorMode::Continue {
+            let mut status = self.ctx.get_file_load_status();
+            let summaries = copied_files
+                .iter()
+                .map(|file| {
+                    let status = status.remove(&file.path).unwrap_or_default();
+                    CopyFileSummary {
+                        file: file.path.clone(),
+                        rows_loaded: status.rows_loaded,
+                        errors_seen: status.errors_seen,
+                        error: status.error.map(|e| e.message()),
+                    }
+                })
+                .collect::<Vec<_>>();
+            return CopyFileSummary::to_block(&summaries);
+        }
+
+        CopySummary {
+            files_processed: copied_files.len() as u64,
+            rows_loaded: self.ctx.get_write_progress_value().rows as u64,
+            rows_failed: self.ctx.get_on_error_rows(),
+            bytes: copied_files.iter().map(|f| f.size).sum(),
+        }
+        .to_block()
     }
 
     /// Pipeline finish.
@@ -423,8 +623,8 @@ impl CopyInterpreter {
 
             // 3. log on_error mode errors.
             // todo(ariesdevil): persist errors with query_id
-            if let Some(error_map) = ctx.get_on_error_map() {
-                for (file_name, e) in error_map {
+            for (file_name, status) in ctx.get_file_load_status() {
+                if let Some(e) = status.error {
                     error!(
                         "copy(on_error={}): file {} encounter error {},",
                         stage_info.copy_options.on_error,
@@ -467,6 +667,10 @@ impl Interpreter for CopyInterpreter {
         "CopyInterpreterV2"
     }
 
+    fn schema(&self) -> DataSchemaRef {
+        self.plan.schema()
+    }
+
     #[tracing::instrument(level = "debug", name = "copy_interpreter_execute_v2", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         match &self.plan {
@@ -476,23 +680,52 @@ impl Interpreter for CopyInterpreter {
                 table_name,
                 from,
                 force,
+                validation_mode,
                 ..
             } => match &from.source_info {
-                DataSourceInfo::StageSource(table_info) => {
-                    self.build_copy_into_table_pipeline(
-                        catalog_name,
-                        database_name,
-                        table_name,
-                        *force,
-                        table_info,
-                    )
-                    .await
-                }
+                DataSourceInfo::StageSource(table_info) => match validation_mode {
+                    ValidationMode::None => {
+                        let (build_res, copied_files) = self
+                            .build_copy_into_table_pipeline(
+                                catalog_name,
+                                database_name,
+                                table_name,
+                                *force,
+                                table_info,
+                            )
+                            .await?;
+                        self.execute_copy_pipeline(build_res)?;
+                        let summary = self.copy_into_table_summary(
+                            &table_info.stage_info.copy_options.on_error,
+                            &copied_files,
+                        );
+                        PipelineBuildResult::from_blocks(vec![summary])
+                    }
+                    _ => {
+                        self.build_copy_into_table_validation_pipeline(
+                            catalog_name,
+                            database_name,
+                            table_name,
+                            *force,
+                            table_info,
+                            validation_mode,
+                        )
+                        .await
+                    }
+                },
                 other => Err(ErrorCode::Internal(format!(
                     "Cannot list files for the source info: {:?}",
                     other
                 ))),
             },
+            CopyPlan::IntoTableWithTransform {
+                validation_mode, ..
+            }
+            | CopyPlan::IntoStage {
+                validation_mode, ..
+            } if *validation_mode != ValidationMode::None => Err(ErrorCode::Unimplemented(
+                "validation_mode is only supported when copying files into a table without transform",
+            )),
             CopyPlan::IntoTableWithTransform {
                 catalog_name,
                 database_name,
@@ -503,20 +736,44 @@ impl Interpreter for CopyInterpreter {
                 need_copy_file_infos,
                 ..
             } => {
-                self.build_copy_into_table_with_transform_pipeline(
-                    catalog_name,
-                    database_name,
-                    table_name,
-                    from,
-                    *stage_info.clone(),
-                    all_source_file_infos.clone(),
-                    need_copy_file_infos.clone(),
-                )
-                .await
+                let build_res = self
+                    .build_copy_into_table_with_transform_pipeline(
+                        catalog_name,
+                        database_name,
+                        table_name,
+                        from,
+                        *stage_info.clone(),
+                        all_source_file_infos.clone(),
+                        need_copy_file_infos.clone(),
+                    )
+                    .await?;
+                self.execute_copy_pipeline(build_res)?;
+                let summary = self.copy_into_table_summary(
+                    &stage_info.copy_options.on_error,
+                    need_copy_file_infos,
+                );
+                PipelineBuildResult::from_blocks(vec![summary])
             }
             CopyPlan::IntoStage {
                 stage, from, path, ..
-            } => self.build_copy_into_stage_pipeline(stage, path, from).await,
+            } => {
+                let (build_res, table) =
+                    self.build_copy_into_stage_pipeline(stage, path, from).await?;
+                self.execute_copy_pipeline(build_res)?;
+
+                let write_progress = self.ctx.get_write_progress_value();
+                let unloaded_files = match table.as_any().downcast_ref::<StageTable>() {
+                    Some(stage_table) => stage_table.unloaded_files(),
+                    None => 0,
+                };
+                let summary = CopySummary {
+                    files_processed: unloaded_files as u64,
+                    rows_loaded: write_progress.rows as u64,
+                    rows_failed: 0,
+                    bytes: write_progress.bytes as u64,
+                };
+                PipelineBuildResult::from_blocks(vec![summary.to_block()])
+            }
         }
     }
 }
@@ -82,6 +82,28 @@ impl Interpreter for ExplainInterpreter {
                 _ => self.explain_plan(&self.plan)?,
             },
 
+            ExplainKind::JsonPlan => match &self.plan {
+                Plan::Query {
+                    s_expr, metadata, ..
+                } => {
+                    let ctx = self.ctx.clone();
+                    let settings = ctx.get_settings();
+
+                    let enable_distributed_eval_index =
+                        settings.get_enable_distributed_eval_index()?;
+                    settings.set_enable_distributed_eval_index(false)?;
+                    scopeguard::defer! {
+                        let _ = settings.set_enable_distributed_eval_index(enable_distributed_eval_index);
+                    }
+                    let mut builder = PhysicalPlanBuilder::new(metadata.clone(), ctx);
+                    let plan = builder.build(s_expr).await?;
+                    self.explain_physical_plan_json(&plan, metadata)?
+                }
+                _ => Err(ErrorCode::Unimplemented(
+                    "Unsupported EXPLAIN FORMAT JSON statement",
+                ))?,
+            },
+
             ExplainKind::AnalyzePlan => match &self.plan {
                 Plan::Query {
                     s_expr,
@@ -142,7 +164,8 @@ impl Interpreter for ExplainInterpreter {
 
             ExplainKind::Ast(display_string)
             | ExplainKind::Syntax(display_string)
-            | ExplainKind::Memo(display_string) => {
+            | ExplainKind::Memo(display_string)
+            | ExplainKind::Optimize(display_string) => {
                 let line_split_result: Vec<&str> = display_string.lines().collect();
                 let column = StringType::from_data(line_split_result);
                 vec![DataBlock::new_from_columns(vec![column])]
@@ -178,13 +201,25 @@ impl ExplainInterpreter {
         metadata: &MetadataRef,
     ) -> Result<Vec<DataBlock>> {
         let result = plan
-            .format(metadata.clone(), ProfSpanSetRef::default())?
+            .format(metadata.clone(), ProfSpanSetRef::default(), false)?
             .format_pretty()?;
         let line_split_result: Vec<&str> = result.lines().collect();
         let formatted_plan = StringType::from_data(line_split_result);
         Ok(vec![DataBlock::new_from_columns(vec![formatted_plan])])
     }
 
+    pub fn explain_physical_plan_json(
+        &self,
+        plan: &PhysicalPlan,
+        metadata: &MetadataRef,
+    ) -> Result<Vec<DataBlock>> {
+        let result = plan
+            .format_json(metadata.clone(), ProfSpanSetRef::default(), false)?
+            .to_string();
+        let formatted_plan = StringType::from_data(vec![result.as_str()]);
+        Ok(vec![DataBlock::new_from_columns(vec![formatted_plan])])
+    }
+
     pub async fn explain_pipeline(
         &self,
         s_expr: SExpr,
@@ -272,7 +307,7 @@ impl ExplainInterpreter {
         }
 
         let result = plan
-            .format(metadata.clone(), prof_span_set)?
+            .format(metadata.clone(), prof_span_set, true)?
             .format_pretty()?;
         let line_split_result: Vec<&str> = result.lines().collect();
         let formatted_plan = StringType::from_data(line_split_result);
@@ -44,18 +44,25 @@ use common_expression::DataField;
 use common_expression::DataSchema;
 use common_expression::DataSchemaRef;
 use common_expression::Expr;
+use common_expression::FunctionContext;
 use common_expression::Scalar;
 use common_expression::Value;
 use common_formats::FastFieldDecoderValues;
 use common_io::cursor_ext::ReadBytesExt;
 use common_io::cursor_ext::ReadCheckPointExt;
 use common_meta_app::principal::FileFormatOptions;
+use common_meta_app::principal::GrantObject;
 use common_meta_app::principal::StageFileFormatType;
 use common_meta_app::principal::StageInfo;
+use common_meta_app::principal::UserPrivilegeType;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::ProcessorPtr;
 use common_pipeline_core::Pipeline;
 use common_pipeline_sources::AsyncSource;
 use common_pipeline_sources::AsyncSourcer;
 use common_pipeline_transforms::processors::transforms::Transform;
+use common_pipeline_transforms::processors::transforms::Transformer;
 use common_sql::binder::wrap_cast;
 use common_sql::evaluator::BlockOperator;
 use common_sql::evaluator::CompoundBlockOperator;
@@ -67,6 +74,7 @@ use common_sql::plans::Insert;
 use common_sql::plans::InsertInputSource;
 use common_sql::plans::Plan;
 use common_sql::BindContext;
+use common_sql::ColumnBinding;
 use common_sql::Metadata;
 use common_sql::MetadataRef;
 use common_sql::NameResolutionContext;
@@ -85,8 +93,11 @@ use tracing::info;
 use crate::interpreters::common::append2table;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
+use crate::pipelines::executor::ExecutorSettings;
+use crate::pipelines::executor::PipelineCompleteExecutor;
 use crate::pipelines::processors::transforms::TransformAddConstColumns;
 use crate::pipelines::processors::transforms::TransformRuntimeCastSchema;
+use crate::pipelines::processors::TransformCastSchema;
 use crate::pipelines::processors::TransformResortAddOn;
 use crate::pipelines::PipelineBuildResult;
 use crate::pipelines::SourcePipeBuilder;
@@ -192,6 +203,148 @@ impl InsertInterpreter {
         Ok((Arc::new(DataSchema::new(attachment_fields)), const_values))
     }
 
+    fn build_values_source(&self, data: &str, pipeline: &mut Pipeline) -> Result<()> {
+        let settings = self.ctx.get_settings();
+        pipeline.add_source(
+            |output| {
+                let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
+                let inner = ValueSource::new(
+                    data.to_string(),
+                    self.ctx.clone(),
+                    name_resolution_ctx,
+                    self.plan.schema(),
+                );
+                AsyncSourcer::create(self.ctx.clone(), output, inner)
+            },
+            1,
+        )
+    }
+
+    async fn build_select_plan(&self, plan: &Plan) -> Result<(PhysicalPlan, Vec<ColumnBinding>)> {
+        match plan {
+            Plan::Query {
+                s_expr,
+                metadata,
+                bind_context,
+                ..
+            } => {
+                let mut builder = PhysicalPlanBuilder::new(metadata.clone(), self.ctx.clone());
+                Ok((builder.build(s_expr).await?, bind_context.columns.clone()))
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    /// Inserts the rows and evaluates the `RETURNING` expressions on them at the same time.
+    ///
+    /// The rows are inserted by the coordinator, so the results can be collected here and
+    /// returned after the insertion is committed.
+    async fn execute_with_returning(&self, table: Arc<dyn Table>) -> Result<PipelineBuildResult> {
+        let mut build_res = match &self.plan.source {
+            InsertInputSource::Values(data) => {
+                let mut build_res = PipelineBuildResult::create();
+                self.build_values_source(data, &mut build_res.main_pipeline)?;
+                build_res
+            }
+            InsertInputSource::SelectPlan(plan) => {
+                let (select_plan, select_column_bindings) = self.build_select_plan(plan).await?;
+                let mut build_res = build_query_pipeline(
+                    &self.ctx,
+                    &select_column_bindings,
+                    &select_plan,
+                    false,
+                    false,
+                )
+                .await?;
+                if self.check_schema_cast(plan)? {
+                    let select_schema = plan.schema();
+                    let insert_schema = self.plan.schema();
+                    let func_ctx = self.ctx.get_function_context()?;
+                    build_res.main_pipeline.add_transform(
+                        |transform_input_port, transform_output_port| {
+                            TransformCastSchema::try_create(
+                                transform_input_port,
+                                transform_output_port,
+                                select_schema.clone(),
+                                insert_schema.clone(),
+                                func_ctx,
+                            )
+                        },
+                    )?;
+                }
+                build_res
+            }
+            _ => {
+                return Err(ErrorCode::Unimplemented(
+                    "RETURNING is only supported by INSERT ... VALUES and INSERT ... SELECT",
+                ));
+            }
+        };
+
+        // Fill the missing columns first, so the default values can be returned.
+        let source_schema = self.plan.schema();
+        build_res
+            .main_pipeline
+            .add_transform(|transform_input_port, transform_output_port| {
+                TransformResortAddOn::try_create(
+                    self.ctx.clone(),
+                    transform_input_port,
+                    transform_output_port,
+                    source_schema.clone(),
+                    table.clone(),
+                )
+            })?;
+
+        let exprs = self
+            .plan
+            .returning
+            .iter()
+            .map(|scalar| scalar.as_expr_with_col_index())
+            .collect::<Result<Vec<_>>>()?;
+        let returning = Arc::new(Mutex::new(vec![]));
+        let func_ctx = self.ctx.get_function_context()?;
+        let num_columns = table.schema().num_fields();
+        build_res
+            .main_pipeline
+            .add_transform(|transform_input_port, transform_output_port| {
+                Ok(TransformInsertReturning::create(
+                    transform_input_port,
+                    transform_output_port,
+                    func_ctx,
+                    exprs.clone(),
+                    num_columns,
+                    returning.clone(),
+                ))
+            })?;
+
+        let table_schema: DataSchema = table.schema().into();
+        append2table(
+            self.ctx.clone(),
+            table.clone(),
+            Arc::new(table_schema),
+            &mut build_res,
+            self.plan.overwrite,
+            true,
+            AppendMode::Normal,
+        )?;
+
+        let settings = self.ctx.get_settings();
+        build_res.set_max_threads(settings.get_max_threads()? as usize);
+        let executor_settings = ExecutorSettings::try_create(&settings, self.ctx.get_id())?;
+        let mut pipelines = build_res.sources_pipelines;
+        pipelines.push(build_res.main_pipeline);
+        let executor = PipelineCompleteExecutor::from_pipelines(pipelines, executor_settings)?;
+        self.ctx.set_executor(Arc::downgrade(&executor.get_inner()));
+        executor.execute()?;
+        drop(executor);
+
+        let blocks = std::mem::take(&mut *returning.lock());
+        if blocks.is_empty() {
+            return Ok(PipelineBuildResult::create());
+        }
+        PipelineBuildResult::from_blocks(blocks)
+    }
+
     async fn build_insert_from_stage_pipeline(
         &self,
         table: Arc<dyn Table>,
@@ -344,6 +497,10 @@ impl Interpreter for InsertInterpreter {
         "InsertIntoInterpreter"
     }
 
+    fn schema(&self) -> DataSchemaRef {
+        self.plan.returning_schema.clone()
+    }
+
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         let plan = &self.plan;
         let table = self
@@ -351,26 +508,15 @@ impl Interpreter for InsertInterpreter {
             .get_table(&plan.catalog, &plan.database, &plan.table)
             .await?;
 
+        if plan.has_returning() {
+            return self.execute_with_returning(table).await;
+        }
+
         let mut build_res = PipelineBuildResult::create();
 
         match &self.plan.source {
             InsertInputSource::Values(data) => {
-                let settings = self.ctx.get_settings();
-
-                build_res.main_pipeline.add_source(
-                    |output| {
-                        let name_resolution_ctx =
-                            NameResolutionContext::try_from(settings.as_ref())?;
-                        let inner = ValueSource::new(
-                            data.to_string(),
-                            self.ctx.clone(),
-                            name_resolution_ctx,
-                            plan.schema(),
-                        );
-                        AsyncSourcer::create(self.ctx.clone(), output, inner)
-                    },
-                    1,
-                )?;
+                self.build_values_source(data, &mut build_res.main_pipeline)?;
             }
             InsertInputSource::StreamingWithFormat(format, _, input_context) => {
                 let input_context = input_context.as_ref().expect("must success").clone();
@@ -428,19 +574,8 @@ impl Interpreter for InsertInterpreter {
             }
             InsertInputSource::SelectPlan(plan) => {
                 let table1 = table.clone();
-                let (mut select_plan, select_column_bindings) = match plan.as_ref() {
-                    Plan::Query {
-                        s_expr,
-                        metadata,
-                        bind_context,
-                        ..
-                    } => {
-                        let mut builder1 =
-                            PhysicalPlanBuilder::new(metadata.clone(), self.ctx.clone());
-                        (builder1.build(s_expr).await?, bind_context.columns.clone())
-                    }
-                    _ => unreachable!(),
-                };
+                let (mut select_plan, select_column_bindings) =
+                    self.build_select_plan(plan).await?;
 
                 let catalog = self.plan.catalog.clone();
 
@@ -527,6 +662,47 @@ impl Interpreter for InsertInterpreter {
     }
 }
 
+/// Evaluates the `RETURNING` expressions on the rows to be inserted, the rows are passed
+/// through unchanged.
+struct TransformInsertReturning {
+    expression_transform: CompoundBlockOperator,
+    num_columns: usize,
+    returning: Arc<Mutex<Vec<DataBlock>>>,
+}
+
+impl TransformInsertReturning {
+    fn create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        func_ctx: FunctionContext,
+        exprs: Vec<Expr>,
+        num_columns: usize,
+        returning: Arc<Mutex<Vec<DataBlock>>>,
+    ) -> ProcessorPtr {
+        ProcessorPtr::create(Transformer::create(input, output, Self {
+            expression_transform: CompoundBlockOperator {
+                ctx: func_ctx,
+                operators: vec![BlockOperator::Map { exprs }],
+            },
+            num_columns,
+            returning,
+        }))
+    }
+}
+
+impl Transform for TransformInsertReturning {
+    const NAME: &'static str = "InsertReturningTransform";
+
+    fn transform(&mut self, block: DataBlock) -> Result<DataBlock> {
+        let evaluated = self.expression_transform.transform(block.clone())?;
+        let columns = evaluated.columns()[self.num_columns..].to_owned();
+        self.returning
+            .lock()
+            .push(DataBlock::new(columns, block.num_rows()));
+        Ok(block)
+    }
+}
+
 pub struct ValueSource {
     data: String,
     ctx: Arc<dyn TableContext>,
@@ -904,9 +1080,14 @@ async fn parse_stage_location(
     let stage = if names[0] == "~" {
         StageInfo::new_user_stage(&ctx.get_current_user()?.name)
     } else {
-        UserApiProvider::instance()
+        let stage = UserApiProvider::instance()
             .get_stage(&ctx.get_tenant(), names[0])
-            .await?
+            .await?;
+        ctx.validate_privilege(&GrantObject::Stage(stage.stage_name.clone()), vec![
+            UserPrivilegeType::Read,
+        ])
+        .await?;
+        stage
     };
 
     let path = names.get(1).unwrap_or(&"").trim_start_matches('/');
@@ -19,6 +19,7 @@ use common_meta_app::principal::GrantObject;
 use common_meta_app::principal::PrincipalIdentity;
 use common_meta_app::principal::UserPrivilegeSet;
 use common_sql::plans::GrantPrivilegePlan;
+use common_users::RoleCacheManager;
 use common_users::UserApiProvider;
 
 use crate::interpreters::common::validate_grant_object_exists;
@@ -70,6 +71,7 @@ impl Interpreter for GrantPrivilegeInterpreter {
             }
         }
 
+        RoleCacheManager::instance().force_reload(&tenant).await?;
         Ok(PipelineBuildResult::create())
     }
 }
@@ -17,6 +17,7 @@ use std::sync::Arc;
 use common_exception::Result;
 use common_meta_app::principal::PrincipalIdentity;
 use common_sql::plans::RevokePrivilegePlan;
+use common_users::RoleCacheManager;
 use common_users::UserApiProvider;
 
 use crate::interpreters::common::validate_grant_object_exists;
@@ -68,6 +69,7 @@ impl Interpreter for RevokePrivilegeInterpreter {
             }
         }
 
+        RoleCacheManager::instance().force_reload(&tenant).await?;
         Ok(PipelineBuildResult::create())
     }
 }
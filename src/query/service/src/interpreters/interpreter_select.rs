@@ -190,7 +190,13 @@ impl Interpreter for SelectInterpreter {
         // 0. Need to build pipeline first to get the partitions.
         let mut build_res = self.build_pipeline().await?;
         if self.ctx.get_settings().get_enable_query_result_cache()? && self.ctx.get_cacheable() {
-            let key = gen_result_cache_key(self.formatted_ast.as_ref().unwrap());
+            // The unqualified table names are resolved in the current database,
+            // so the same statement may read different tables in different databases.
+            let key = gen_result_cache_key(&format!(
+                "{}.{}",
+                self.ctx.get_current_database(),
+                self.formatted_ast.as_ref().unwrap()
+            ));
             // 1. Try to get result from cache.
             let kv_store = UserApiProvider::instance().get_meta_store_client();
 
@@ -17,6 +17,7 @@ use std::sync::Arc;
 use common_config::GlobalConfig;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::DataSchemaRefExt;
 use common_expression::TableSchemaRefExt;
 use common_meta_app::schema::CreateTableReq;
 use common_meta_app::schema::TableMeta;
@@ -143,6 +144,8 @@ impl CreateTableInterpreter {
             schema: self.plan.schema.clone(),
             overwrite: false,
             source: InsertInputSource::SelectPlan(select_plan),
+            returning: vec![],
+            returning_schema: DataSchemaRefExt::create(vec![]),
         };
 
         InsertInterpreter::try_create(self.ctx.clone(), insert_plan)?
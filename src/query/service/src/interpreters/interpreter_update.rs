@@ -14,11 +14,15 @@
 
 use std::sync::Arc;
 
+use common_catalog::plan::MatchedRows;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::infer_schema_type;
 use common_expression::types::DataType;
 use common_expression::DataSchema;
 use common_expression::DataSchemaRef;
+use common_expression::TableField;
+use common_expression::TableSchema;
 use common_pipeline_core::Pipeline;
 use common_sql::executor::cast_expr_to_non_null_boolean;
 use common_sql::plans::BoundColumnRef;
@@ -30,11 +34,14 @@ use common_sql::ScalarExpr;
 use common_sql::Visibility;
 
 use crate::interpreters::Interpreter;
+use crate::interpreters::SelectInterpreter;
 use crate::pipelines::executor::ExecutorSettings;
 use crate::pipelines::executor::PipelineCompleteExecutor;
+use crate::pipelines::executor::PipelinePullingExecutor;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
 use crate::sessions::TableContext;
+use crate::sql::plans::Plan;
 use crate::sql::plans::UpdatePlan;
 
 /// interprets UpdatePlan
@@ -48,6 +55,62 @@ impl UpdateInterpreter {
     pub fn try_create(ctx: Arc<QueryContext>, plan: UpdatePlan) -> Result<Self> {
         Ok(UpdateInterpreter { ctx, plan })
     }
+
+    /// Runs the query of UPDATE ... FROM to collect the matched rows and their new values.
+    /// Returns `None` if no row is matched.
+    async fn matched_rows(&self, from: &Plan) -> Result<Option<MatchedRows>> {
+        let (s_expr, metadata, bind_context, formatted_ast) = match from {
+            Plan::Query {
+                s_expr,
+                metadata,
+                bind_context,
+                formatted_ast,
+                ..
+            } => (s_expr, metadata, bind_context, formatted_ast),
+            v => unreachable!("Input plan must be Query, but it's {}", v),
+        };
+
+        let select_interpreter = SelectInterpreter::try_create(
+            self.ctx.clone(),
+            *(bind_context.clone()),
+            *s_expr.clone(),
+            metadata.clone(),
+            formatted_ast.clone(),
+            false,
+        )?;
+        let mut build_res = select_interpreter.execute2().await?;
+
+        let settings = self.ctx.get_settings();
+        build_res.set_max_threads(settings.get_max_threads()? as usize);
+        let settings = ExecutorSettings::try_create(&settings, self.ctx.get_id())?;
+        let mut pulling_executor = PipelinePullingExecutor::from_pipelines(build_res, settings)?;
+        self.ctx
+            .set_executor(Arc::downgrade(&pulling_executor.get_inner()));
+        pulling_executor.start();
+        let mut blocks = vec![];
+        while let Some(block) = pulling_executor.pull_data()? {
+            if !block.is_empty() {
+                blocks.push(block);
+            }
+        }
+        drop(pulling_executor);
+        if blocks.is_empty() {
+            return Ok(None);
+        }
+
+        // The new values follow `_block_name` and `_row_id`.
+        let fields = bind_context.columns[2..]
+            .iter()
+            .map(|column| {
+                Ok(TableField::new(
+                    &column.column_name,
+                    infer_schema_type(&column.data_type)?,
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let matched_rows = MatchedRows::try_create(&blocks, Arc::new(TableSchema::new(fields)))?;
+        Ok(Some(matched_rows))
+    }
 }
 
 #[async_trait::async_trait]
@@ -69,6 +132,15 @@ impl Interpreter for UpdateInterpreter {
         let tbl_name = self.plan.table.as_str();
         let tbl = self.ctx.get_table(catalog_name, db_name, tbl_name).await?;
 
+        let matched_rows = match &self.plan.from {
+            Some(from) => match self.matched_rows(from).await? {
+                Some(matched_rows) => Some(matched_rows),
+                // No row is matched, do nothing.
+                None => return Ok(PipelineBuildResult::create()),
+            },
+            None => None,
+        };
+
         let (filter, col_indices) = if let Some(scalar) = &self.plan.selection {
             let filter =
                 cast_expr_to_non_null_boolean(scalar.as_expr_with_col_name()?)?.as_remote_expr();
@@ -101,7 +173,7 @@ impl Interpreter for UpdateInterpreter {
                     argument: Box::new(scalar.clone()),
                     target_type: Box::new(field.data_type().clone()),
                 });
-                let scalar = if col_indices.is_empty() {
+                let scalar = if col_indices.is_empty() && matched_rows.is_none() {
                     // The condition is always true.
                     // Replace column to the result of the following expression:
                     // CAST(expression, type)
@@ -143,6 +215,7 @@ impl Interpreter for UpdateInterpreter {
             filter,
             col_indices,
             update_list,
+            matched_rows,
             &mut pipeline,
         )
         .await?;
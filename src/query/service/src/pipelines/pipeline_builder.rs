@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
+use std::sync::atomic::AtomicUsize;
 use std::sync::Arc;
 
 use async_channel::Receiver;
@@ -45,6 +47,7 @@ use common_sql::executor::AggregateExpand;
 use common_sql::executor::AggregateFinal;
 use common_sql::executor::AggregateFunctionDesc;
 use common_sql::executor::AggregatePartial;
+use common_sql::executor::CteScan;
 use common_sql::executor::DistributedInsertSelect;
 use common_sql::executor::EvalScalar;
 use common_sql::executor::ExchangeSink;
@@ -52,6 +55,7 @@ use common_sql::executor::ExchangeSource;
 use common_sql::executor::Filter;
 use common_sql::executor::HashJoin;
 use common_sql::executor::Limit;
+use common_sql::executor::MaterializedCte;
 use common_sql::executor::PhysicalPlan;
 use common_sql::executor::Project;
 use common_sql::executor::RuntimeFilterSource;
@@ -59,23 +63,29 @@ use common_sql::executor::Sort;
 use common_sql::executor::TableScan;
 use common_sql::executor::UnionAll;
 use common_sql::executor::Unnest;
+use common_sql::executor::Window;
 use common_sql::plans::JoinType;
 use common_sql::ColumnBinding;
 use common_sql::IndexType;
-use common_storage::DataOperator;
 use common_storages_fuse::operations::FillInternalColumnProcessor;
 
+use super::processors::ProfileOutputStats;
 use super::processors::ProfileWrapper;
 use super::processors::TransformExpandGroupingSets;
 use crate::api::DefaultExchangeInjector;
 use crate::api::ExchangeInjector;
 use crate::pipelines::processors::transforms::build_partition_bucket;
+use crate::pipelines::processors::transforms::spill_operator;
 use crate::pipelines::processors::transforms::AggregateInjector;
 use crate::pipelines::processors::transforms::FinalSingleStateAggregator;
 use crate::pipelines::processors::transforms::HashJoinDesc;
+use crate::pipelines::processors::transforms::MaterializedCteSink;
+use crate::pipelines::processors::transforms::MaterializedCteSource;
+use crate::pipelines::processors::transforms::MaterializedCteState;
 use crate::pipelines::processors::transforms::PartialSingleStateAggregator;
 use crate::pipelines::processors::transforms::RightSemiAntiJoinCompactor;
 use crate::pipelines::processors::transforms::RuntimeFilterState;
+use crate::pipelines::processors::transforms::SpillLocations;
 use crate::pipelines::processors::transforms::TransformAggregateSpillWriter;
 use crate::pipelines::processors::transforms::TransformGroupBySpillWriter;
 use crate::pipelines::processors::transforms::TransformLeftJoin;
@@ -85,6 +95,7 @@ use crate::pipelines::processors::transforms::TransformPartialAggregate;
 use crate::pipelines::processors::transforms::TransformPartialGroupBy;
 use crate::pipelines::processors::transforms::TransformRightJoin;
 use crate::pipelines::processors::transforms::TransformRightSemiAntiJoin;
+use crate::pipelines::processors::transforms::TransformWindow;
 use crate::pipelines::processors::AggregatorParams;
 use crate::pipelines::processors::JoinHashTable;
 use crate::pipelines::processors::LeftJoinCompactor;
@@ -113,6 +124,8 @@ pub struct PipelineBuilder {
     pub join_state: Option<Arc<JoinHashTable>>,
     // record the index of join build side pipeline in `pipelines`
     pub index: Option<usize>,
+    // The states of materialized CTEs, keyed by the index of CTE
+    pub cte_state: HashMap<IndexType, Arc<MaterializedCteState>>,
 
     enable_profiling: bool,
     prof_span_set: ProfSpanSetRef,
@@ -134,6 +147,7 @@ impl PipelineBuilder {
             prof_span_set,
             exchange_injector: DefaultExchangeInjector::create(),
             index: None,
+            cte_state: HashMap::new(),
         }
     }
 
@@ -157,6 +171,24 @@ impl PipelineBuilder {
     }
 
     fn build_pipeline(&mut self, plan: &PhysicalPlan) -> Result<()> {
+        self.build_plan(plan)?;
+
+        if self.enable_profiling && self.main_pipeline.output_len() > 0 {
+            if let Some(plan_id) = plan.plan_id() {
+                self.main_pipeline.add_transform(|input, output| {
+                    Ok(ProfileOutputStats::create(
+                        input,
+                        output,
+                        plan_id,
+                        self.prof_span_set.clone(),
+                    ))
+                })?;
+            }
+        }
+        Ok(())
+    }
+
+    fn build_plan(&mut self, plan: &PhysicalPlan) -> Result<()> {
         match plan {
             PhysicalPlan::TableScan(scan) => self.build_table_scan(scan),
             PhysicalPlan::Filter(filter) => self.build_filter(filter),
@@ -166,6 +198,7 @@ impl PipelineBuilder {
             PhysicalPlan::AggregatePartial(aggregate) => self.build_aggregate_partial(aggregate),
             PhysicalPlan::AggregateFinal(aggregate) => self.build_aggregate_final(aggregate),
             PhysicalPlan::Sort(sort) => self.build_sort(sort),
+            PhysicalPlan::Window(window) => self.build_window(window),
             PhysicalPlan::Limit(limit) => self.build_limit(limit),
             PhysicalPlan::HashJoin(join) => self.build_join(join),
             PhysicalPlan::ExchangeSink(sink) => self.build_exchange_sink(sink),
@@ -181,6 +214,10 @@ impl PipelineBuilder {
             PhysicalPlan::RuntimeFilterSource(runtime_filter_source) => {
                 self.build_runtime_filter_source(runtime_filter_source)
             }
+            PhysicalPlan::MaterializedCte(materialized_cte) => {
+                self.build_materialized_cte(materialized_cte)
+            }
+            PhysicalPlan::CteScan(cte_scan) => self.build_cte_scan(cte_scan),
         }
     }
 
@@ -207,11 +244,12 @@ impl PipelineBuilder {
         join_state: Arc<JoinHashTable>,
     ) -> Result<()> {
         let build_side_context = QueryContext::create_from(self.ctx.clone());
-        let build_side_builder = PipelineBuilder::create(
+        let mut build_side_builder = PipelineBuilder::create(
             build_side_context,
             self.enable_profiling,
             self.prof_span_set.clone(),
         );
+        build_side_builder.cte_state = self.cte_state.clone();
         let mut build_res = build_side_builder.finalize(build)?;
 
         assert!(build_res.main_pipeline.is_pulling_pipeline()?);
@@ -544,9 +582,10 @@ impl PipelineBuilder {
             }
         })?;
 
+        let spill_locations =
+            SpillLocations::try_create(&self.ctx.get_settings(), &self.ctx.get_tenant())?;
         if self.ctx.get_cluster().is_empty() {
-            let operator = DataOperator::instance().operator();
-            let location_prefix = format!("_aggregate_spill/{}", self.ctx.get_tenant());
+            let operator = spill_operator();
             self.main_pipeline.add_transform(|input, output| {
                 let transform = match params.aggregate_functions.is_empty() {
                     true => with_mappedhash_method!(|T| match method.clone() {
@@ -555,7 +594,7 @@ impl PipelineBuilder {
                             output,
                             method,
                             operator.clone(),
-                            location_prefix.clone()
+                            spill_locations.clone()
                         ),
                     }),
                     false => with_mappedhash_method!(|T| match method.clone() {
@@ -565,10 +604,10 @@ impl PipelineBuilder {
                             method,
                             operator.clone(),
                             params.clone(),
-                            location_prefix.clone()
+                            spill_locations.clone()
                         ),
                     }),
-                };
+                }?;
 
                 if self.enable_profiling {
                     Ok(ProcessorPtr::create(ProfileWrapper::create(
@@ -582,15 +621,20 @@ impl PipelineBuilder {
             })?;
         }
 
-        let tenant = self.ctx.get_tenant();
         self.exchange_injector = match params.aggregate_functions.is_empty() {
             true => with_mappedhash_method!(|T| match method.clone() {
-                HashMethodKind::T(method) =>
-                    AggregateInjector::<_, ()>::create(tenant.clone(), method, params.clone()),
+                HashMethodKind::T(method) => AggregateInjector::<_, ()>::create(
+                    spill_locations.clone(),
+                    method,
+                    params.clone()
+                ),
             }),
             false => with_mappedhash_method!(|T| match method.clone() {
-                HashMethodKind::T(method) =>
-                    AggregateInjector::<_, usize>::create(tenant.clone(), method, params.clone()),
+                HashMethodKind::T(method) => AggregateInjector::<_, usize>::create(
+                    spill_locations.clone(),
+                    method,
+                    params.clone()
+                ),
             }),
         };
 
@@ -628,7 +672,9 @@ impl PipelineBuilder {
         let sample_block = DataBlock::empty_with_schema(schema_before_group_by);
         let method = DataBlock::choose_hash_method(&sample_block, group_cols)?;
 
-        let tenant = self.ctx.get_tenant();
+        let spill_locations =
+            SpillLocations::try_create(&self.ctx.get_settings(), &self.ctx.get_tenant())?;
+        let prefetch_depth = self.ctx.get_settings().get_spilling_prefetch_depth()?;
         let old_inject = self.exchange_injector.clone();
 
         match params.aggregate_functions.is_empty() {
@@ -636,13 +682,21 @@ impl PipelineBuilder {
                 HashMethodKind::T(v) => {
                     let input: &PhysicalPlan = &aggregate.input;
                     if matches!(input, PhysicalPlan::ExchangeSource(_)) {
-                        self.exchange_injector =
-                            AggregateInjector::<_, ()>::create(tenant, v.clone(), params.clone());
+                        self.exchange_injector = AggregateInjector::<_, ()>::create(
+                            spill_locations,
+                            v.clone(),
+                            params.clone(),
+                        );
                     }
 
                     self.build_pipeline(&aggregate.input)?;
                     self.exchange_injector = old_inject;
-                    build_partition_bucket::<_, ()>(v, &mut self.main_pipeline, params.clone())
+                    build_partition_bucket::<_, ()>(
+                        v,
+                        &mut self.main_pipeline,
+                        params.clone(),
+                        prefetch_depth,
+                    )
                 }
             }),
             false => with_hash_method!(|T| match method {
@@ -650,14 +704,19 @@ impl PipelineBuilder {
                     let input: &PhysicalPlan = &aggregate.input;
                     if matches!(input, PhysicalPlan::ExchangeSource(_)) {
                         self.exchange_injector = AggregateInjector::<_, usize>::create(
-                            tenant,
+                            spill_locations,
                             v.clone(),
                             params.clone(),
                         );
                     }
                     self.build_pipeline(&aggregate.input)?;
                     self.exchange_injector = old_inject;
-                    build_partition_bucket::<_, usize>(v, &mut self.main_pipeline, params.clone())
+                    build_partition_bucket::<_, usize>(
+                        v,
+                        &mut self.main_pipeline,
+                        params.clone(),
+                        prefetch_depth,
+                    )
                 }
             }),
         }
@@ -784,6 +843,67 @@ impl PipelineBuilder {
         )
     }
 
+    fn build_window(&mut self, window: &Window) -> Result<()> {
+        self.build_pipeline(&window.input)?;
+
+        let input_schema = window.input.output_schema()?;
+
+        let partition_by = window
+            .partition_by
+            .iter()
+            .map(|index| input_schema.index_of(&index.to_string()))
+            .collect::<Result<Vec<_>>>()?;
+
+        let order_by = window
+            .order_by
+            .iter()
+            .map(|desc| {
+                let offset = input_schema.index_of(&desc.order_by.to_string())?;
+                Ok(SortColumnDescription {
+                    offset,
+                    asc: desc.asc,
+                    nulls_first: desc.nulls_first,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let agg_func = AggregateFunctionFactory::instance().get(
+            window.agg_func.sig.name.as_str(),
+            window
+                .agg_func
+                .sig
+                .params
+                .iter()
+                .map(|p| p.clone().into_scalar())
+                .collect(),
+            window.agg_func.sig.args.clone(),
+        )?;
+
+        // A partition must be processed by a single processor.
+        self.main_pipeline.resize(1)?;
+        self.main_pipeline.add_transform(|input, output| {
+            let transform = TransformWindow::try_create(
+                input,
+                output,
+                agg_func.clone(),
+                window.agg_func.args.clone(),
+                partition_by.clone(),
+                order_by.clone(),
+                window.window_frame.clone(),
+            )?;
+
+            if self.enable_profiling {
+                Ok(ProcessorPtr::create(ProfileWrapper::create(
+                    transform,
+                    window.plan_id,
+                    self.prof_span_set.clone(),
+                )))
+            } else {
+                Ok(ProcessorPtr::create(transform))
+            }
+        })
+    }
+
     fn build_limit(&mut self, limit: &Limit) -> Result<()> {
         self.build_pipeline(&limit.input)?;
 
@@ -941,8 +1061,9 @@ impl PipelineBuilder {
         union_plan: &UnionAll,
     ) -> Result<Receiver<DataBlock>> {
         let union_ctx = QueryContext::create_from(self.ctx.clone());
-        let pipeline_builder =
+        let mut pipeline_builder =
             PipelineBuilder::create(union_ctx, self.enable_profiling, self.prof_span_set.clone());
+        pipeline_builder.cte_state = self.cte_state.clone();
         let mut build_res = pipeline_builder.finalize(input)?;
 
         assert!(build_res.main_pipeline.is_pulling_pipeline()?);
@@ -996,6 +1117,83 @@ impl PipelineBuilder {
         Ok(())
     }
 
+    fn build_materialized_cte(&mut self, materialized_cte: &MaterializedCte) -> Result<()> {
+        let state = self.expand_materialized_cte_pipeline(materialized_cte)?;
+        self.cte_state.insert(materialized_cte.cte_idx, state);
+        self.build_pipeline(&materialized_cte.right)
+    }
+
+    // The CTE query is built into a separate pipeline, which collects its result into
+    // the state read by `CteScan`s.
+    fn expand_materialized_cte_pipeline(
+        &mut self,
+        materialized_cte: &MaterializedCte,
+    ) -> Result<Arc<MaterializedCteState>> {
+        let left_schema = materialized_cte.left.output_schema()?;
+        let projections = materialized_cte
+            .left_output_columns
+            .iter()
+            .map(|index| left_schema.index_of(&index.to_string()))
+            .collect::<Result<Vec<_>>>()?;
+        let state = MaterializedCteState::try_create(&self.ctx, projections)?;
+
+        let cte_context = QueryContext::create_from(self.ctx.clone());
+        let mut cte_builder = PipelineBuilder::create(
+            cte_context,
+            self.enable_profiling,
+            self.prof_span_set.clone(),
+        );
+        cte_builder.cte_state = self.cte_state.clone();
+        let mut build_res = cte_builder.finalize(&materialized_cte.left)?;
+
+        assert!(build_res.main_pipeline.is_pulling_pipeline()?);
+
+        build_res.main_pipeline.add_sink(|input| {
+            let sink = MaterializedCteSink::create(input, state.clone());
+
+            if self.enable_profiling {
+                Ok(ProcessorPtr::create(ProfileWrapper::create(
+                    sink,
+                    materialized_cte.plan_id,
+                    self.prof_span_set.clone(),
+                )))
+            } else {
+                Ok(ProcessorPtr::create(sink))
+            }
+        })?;
+
+        self.pipelines.push(build_res.main_pipeline);
+        self.pipelines
+            .extend(build_res.sources_pipelines.into_iter());
+        Ok(state)
+    }
+
+    fn build_cte_scan(&mut self, cte_scan: &CteScan) -> Result<()> {
+        let state = self
+            .cte_state
+            .get(&cte_scan.cte_idx)
+            .cloned()
+            .ok_or_else(|| {
+                ErrorCode::Internal(format!(
+                    "Cannot find the materialized cte {}",
+                    cte_scan.cte_idx
+                ))
+            })?;
+
+        let next_block = Arc::new(AtomicUsize::new(0));
+        let max_threads = self.ctx.get_settings().get_max_threads()? as usize;
+        self.main_pipeline.add_source(
+            |output| {
+                Ok(ProcessorPtr::create(MaterializedCteSource::create(
+                    output,
+                    state.clone(),
+                    next_block.clone(),
+                )))
+            },
+            max_threads,
+        )
+    }
+
     pub fn build_distributed_insert_select(
         &mut self,
         insert_select: &DistributedInsertSelect,
@@ -1065,6 +1263,10 @@ impl PipelineBuilder {
         runtime_filter_source: &RuntimeFilterSource,
     ) -> Result<()> {
         let state = self.build_runtime_filter_state(self.ctx.clone(), runtime_filter_source)?;
+        // The scans of the left side wait for the runtime filters pushed down into them.
+        for (table_index, info) in state.scan_filter_infos.iter() {
+            self.ctx.add_runtime_filter(*table_index, info.clone());
+        }
         self.expand_runtime_filter_source(&runtime_filter_source.right_side, state.clone())?;
         self.build_runtime_filter(&runtime_filter_source.left_side, state)?;
         Ok(())
@@ -1132,6 +1334,7 @@ impl PipelineBuilder {
             ctx,
             runtime_filter_source.left_runtime_filters.clone(),
             runtime_filter_source.right_runtime_filters.clone(),
+            runtime_filter_source.scan_runtime_filters.clone(),
         )))
     }
 }
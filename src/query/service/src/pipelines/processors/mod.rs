@@ -23,17 +23,24 @@ pub use transforms::HashTable;
 pub use transforms::JoinHashTable;
 pub use transforms::LeftJoinCompactor;
 pub use transforms::MarkJoinCompactor;
+pub use transforms::ProfileOutputStats;
 pub use transforms::ProfileWrapper;
 pub use transforms::RightJoinCompactor;
 pub use transforms::SerializerHashTable;
 pub use transforms::SinkBuildHashTable;
 pub use transforms::SinkRuntimeFilterSource;
 pub use transforms::SortMergeCompactor;
+pub use transforms::SpillEncryption;
+pub use transforms::SpilledColumnsReader;
+pub use transforms::SpillLocations;
+pub use transforms::SpillPrefetcher;
+pub use transforms::SpillStrategy;
 pub use transforms::TransformBlockCompact;
 pub use transforms::TransformCastSchema;
 pub use transforms::TransformCompact;
 pub use transforms::TransformCreateSets;
 pub use transforms::TransformExpandGroupingSets;
+pub use transforms::TransformGroupBySpillWriter;
 pub use transforms::TransformHashJoinProbe;
 pub use transforms::TransformLimit;
 pub use transforms::TransformResortAddOn;
@@ -42,6 +42,8 @@ use crate::api::ShuffleExchangeParams;
 use crate::api::TransformExchangeDeserializer;
 use crate::pipelines::processors::transforms::aggregator::aggregate_meta::AggregateMeta;
 use crate::pipelines::processors::transforms::aggregator::aggregate_meta::HashTablePayload;
+use crate::pipelines::processors::transforms::aggregator::serde::spill_operator;
+use crate::pipelines::processors::transforms::aggregator::serde::SpillLocations;
 use crate::pipelines::processors::transforms::aggregator::serde::TransformScatterAggregateSerializer;
 use crate::pipelines::processors::transforms::aggregator::serde::TransformScatterAggregateSpillWriter;
 use crate::pipelines::processors::transforms::aggregator::serde::TransformScatterGroupBySerializer;
@@ -77,6 +79,7 @@ impl<Method: HashMethodBounds, V: Send + Sync + 'static> ExchangeSorting
                     ))),
                     Some(meta_info) => match meta_info {
                         AggregateMeta::Partitioned { .. } => unreachable!(),
+                        AggregateMeta::PartiallyRestored { .. } => unreachable!(),
                         AggregateMeta::Serialized(v) => Ok(v.bucket),
                         AggregateMeta::HashTable(v) => Ok(v.bucket),
                         AggregateMeta::Spilling(_) | AggregateMeta::Spilled(_) => Ok(-1),
@@ -150,6 +153,7 @@ impl<Method: HashMethodBounds, V: Copy + Send + Sync + 'static> FlightScatter
                     AggregateMeta::Spilled(_) => unreachable!(),
                     AggregateMeta::Serialized(_) => unreachable!(),
                     AggregateMeta::Partitioned { .. } => unreachable!(),
+                    AggregateMeta::PartiallyRestored { .. } => unreachable!(),
                     AggregateMeta::Spilling(payload) => {
                         let bucket = payload.bucket;
                         for hashtable_cell in self.scatter(payload)? {
@@ -192,20 +196,20 @@ impl<Method: HashMethodBounds, V: Copy + Send + Sync + 'static> FlightScatter
 
 pub struct AggregateInjector<Method: HashMethodBounds, V: Copy + Send + Sync + 'static> {
     method: Method,
-    tenant: String,
+    spill_locations: Arc<SpillLocations>,
     aggregator_params: Arc<AggregatorParams>,
     _phantom: PhantomData<V>,
 }
 
 impl<Method: HashMethodBounds, V: Copy + Send + Sync + 'static> AggregateInjector<Method, V> {
     pub fn create(
-        tenant: String,
+        spill_locations: Arc<SpillLocations>,
         method: Method,
         params: Arc<AggregatorParams>,
     ) -> Arc<dyn ExchangeInjector> {
         Arc::new(AggregateInjector::<Method, V> {
             method,
-            tenant,
+            spill_locations,
             aggregator_params: params,
             _phantom: Default::default(),
         })
@@ -246,8 +250,8 @@ impl<Method: HashMethodBounds, V: Copy + Send + Sync + 'static> ExchangeInjector
     ) -> Result<()> {
         let method = &self.method;
         let params = self.aggregator_params.clone();
-        let operator = DataOperator::instance().operator();
-        let location_prefix = format!("_aggregate_spill/{}", self.tenant);
+        let operator = spill_operator();
+        let spill_locations = self.spill_locations.clone();
 
         pipeline.add_transform(|input, output| {
             Ok(ProcessorPtr::create(
@@ -257,7 +261,7 @@ impl<Method: HashMethodBounds, V: Copy + Send + Sync + 'static> ExchangeInjector
                         output,
                         method.clone(),
                         operator.clone(),
-                        location_prefix.clone(),
+                        spill_locations.clone(),
                     ),
                     false => TransformAggregateSpillWriter::create(
                         input,
@@ -265,9 +269,9 @@ impl<Method: HashMethodBounds, V: Copy + Send + Sync + 'static> ExchangeInjector
                         method.clone(),
                         operator.clone(),
                         params.clone(),
-                        location_prefix.clone(),
+                        spill_locations.clone(),
                     ),
-                },
+                }?,
             ))
         })?;
 
@@ -292,7 +296,7 @@ impl<Method: HashMethodBounds, V: Copy + Send + Sync + 'static> ExchangeInjector
         let method = &self.method;
         let params = self.aggregator_params.clone();
         let operator = DataOperator::instance().operator();
-        let location_prefix = format!("_aggregate_spill/{}", self.tenant);
+        let spill_locations = self.spill_locations.clone();
 
         pipeline.add_transform(|input, output| {
             Ok(ProcessorPtr::create(
@@ -302,20 +306,21 @@ impl<Method: HashMethodBounds, V: Copy + Send + Sync + 'static> ExchangeInjector
                         output,
                         method.clone(),
                         operator.clone(),
-                        location_prefix.clone(),
+                        spill_locations.clone(),
                     ),
                     false => TransformScatterAggregateSpillWriter::create(
                         input,
                         output,
                         method.clone(),
                         operator.clone(),
-                        location_prefix.clone(),
+                        spill_locations.clone(),
                         params.clone(),
                     ),
                 },
             ))
         })?;
 
+        let format = shuffle_params.format;
         let schema = shuffle_params.schema.clone();
         let local_id = &shuffle_params.executor_id;
         let local_pos = shuffle_params
@@ -332,6 +337,7 @@ impl<Method: HashMethodBounds, V: Copy + Send + Sync + 'static> ExchangeInjector
                     method.clone(),
                     schema.clone(),
                     local_pos,
+                    format,
                 ),
                 false => TransformScatterAggregateSerializer::try_create(
                     input,
@@ -339,6 +345,7 @@ impl<Method: HashMethodBounds, V: Copy + Send + Sync + 'static> ExchangeInjector
                     method.clone(),
                     schema.clone(),
                     local_pos,
+                    format,
                     params.clone(),
                 ),
             },
@@ -371,7 +378,12 @@ impl<Method: HashMethodBounds, V: Copy + Send + Sync + 'static> ExchangeInjector
 
             let schema = &params.schema;
             items.push(PipeItem::create(
-                TransformExchangeDeserializer::create(input.clone(), output.clone(), schema),
+                TransformExchangeDeserializer::create(
+                    input.clone(),
+                    output.clone(),
+                    schema,
+                    params.format,
+                ),
                 vec![input],
                 vec![output],
             ));
@@ -440,7 +452,12 @@ impl<Method: HashMethodBounds, V: Copy + Send + Sync + 'static> ExchangeInjector
 
             let schema = &params.schema;
             items.push(PipeItem::create(
-                TransformExchangeDeserializer::create(input.clone(), output.clone(), schema),
+                TransformExchangeDeserializer::create(
+                    input.clone(),
+                    output.clone(),
+                    schema,
+                    params.format,
+                ),
                 vec![input],
                 vec![output],
             ));
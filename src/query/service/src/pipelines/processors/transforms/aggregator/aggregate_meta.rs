@@ -21,6 +21,7 @@ use common_expression::BlockMetaInfoPtr;
 use common_expression::Column;
 use common_expression::DataBlock;
 
+use crate::pipelines::processors::transforms::aggregator::serde::SpillEncryption;
 use crate::pipelines::processors::transforms::group_by::ArenaHolder;
 use crate::pipelines::processors::transforms::group_by::HashMethodBounds;
 use crate::pipelines::processors::transforms::HashTableCell;
@@ -43,10 +44,13 @@ impl SerializedPayload {
     }
 }
 
+#[derive(Clone)]
 pub struct SpilledPayload {
     pub bucket: isize,
     pub location: String,
     pub columns_layout: Vec<usize>,
+    /// Set if the spill file is encrypted.
+    pub encryption: Option<SpillEncryption>,
 }
 
 pub enum AggregateMeta<Method: HashMethodBounds, V: Send + Sync + 'static> {
@@ -55,7 +59,16 @@ pub enum AggregateMeta<Method: HashMethodBounds, V: Send + Sync + 'static> {
     Spilling(HashTablePayload<Method, V>),
     Spilled(SpilledPayload),
 
-    Partitioned { bucket: isize, data: Vec<Self> },
+    Partitioned {
+        bucket: isize,
+        data: Vec<Self>,
+    },
+    /// A part of a partitioned bucket restored from the spill files, the rest of the bucket
+    /// follows and ends with `Partitioned`. It's restored part by part to bound the memory.
+    PartiallyRestored {
+        bucket: isize,
+        data: Vec<Self>,
+    },
 }
 
 impl<Method: HashMethodBounds, V: Send + Sync + 'static> AggregateMeta<Method, V> {
@@ -86,11 +99,13 @@ impl<Method: HashMethodBounds, V: Send + Sync + 'static> AggregateMeta<Method, V
         bucket: isize,
         location: String,
         columns_layout: Vec<usize>,
+        encryption: Option<SpillEncryption>,
     ) -> BlockMetaInfoPtr {
         Box::new(AggregateMeta::<Method, V>::Spilled(SpilledPayload {
             bucket,
             location,
             columns_layout,
+            encryption,
         }))
     }
 
@@ -124,6 +139,9 @@ impl<Method: HashMethodBounds, V: Send + Sync + 'static> Debug for AggregateMeta
             AggregateMeta::Partitioned { .. } => {
                 f.debug_struct("AggregateMeta::Partitioned").finish()
             }
+            AggregateMeta::PartiallyRestored { .. } => {
+                f.debug_struct("AggregateMeta::PartiallyRestored").finish()
+            }
             AggregateMeta::Serialized { .. } => {
                 f.debug_struct("AggregateMeta::Serialized").finish()
             }
@@ -41,6 +41,13 @@ pub use transform_single_key::FinalSingleStateAggregator;
 pub use transform_single_key::PartialSingleStateAggregator;
 pub use utils::*;
 
+pub use self::serde::deserialize_spilled_columns;
+pub use self::serde::spill_operator;
+pub use self::serde::SpillEncryption;
+pub use self::serde::SpillLocations;
+pub use self::serde::SpillPrefetcher;
+pub use self::serde::SpillStrategy;
+pub use self::serde::SpilledColumnsReader;
 pub use self::serde::TransformAggregateDeserializer;
 pub use self::serde::TransformAggregateSerializer;
 pub use self::serde::TransformAggregateSpillReader;
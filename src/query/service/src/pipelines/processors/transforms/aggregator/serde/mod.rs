@@ -13,6 +13,10 @@
 // limitations under the License.
 
 mod serde_meta;
+mod spill_columns_reader;
+mod spill_encryption;
+mod spill_locations;
+mod spill_prefetcher;
 mod transform_aggregate_serializer;
 mod transform_aggregate_spill_writer;
 mod transform_deserializer;
@@ -27,6 +31,13 @@ mod transform_spill_reader;
 pub use serde_meta::AggregateSerdeMeta;
 pub use serde_meta::BUCKET_TYPE;
 pub use serde_meta::SPILLED_TYPE;
+pub use spill_columns_reader::deserialize_spilled_columns;
+pub use spill_columns_reader::SpilledColumnsReader;
+pub use spill_encryption::SpillEncryption;
+pub use spill_locations::spill_operator;
+pub use spill_locations::SpillLocations;
+pub use spill_locations::SpillStrategy;
+pub use spill_prefetcher::SpillPrefetcher;
 pub use transform_aggregate_serializer::TransformAggregateSerializer;
 pub use transform_aggregate_spill_writer::TransformAggregateSpillWriter;
 pub use transform_deserializer::TransformAggregateDeserializer;
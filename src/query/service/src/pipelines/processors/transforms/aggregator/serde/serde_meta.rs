@@ -18,6 +18,8 @@ use common_expression::BlockMetaInfo;
 use common_expression::BlockMetaInfoDowncast;
 use common_expression::BlockMetaInfoPtr;
 
+use crate::pipelines::processors::transforms::aggregator::serde::spill_encryption::SpillEncryption;
+
 pub const BUCKET_TYPE: usize = 1;
 pub const SPILLED_TYPE: usize = 2;
 
@@ -28,6 +30,7 @@ pub struct AggregateSerdeMeta {
     pub bucket: isize,
     pub location: Option<String>,
     pub columns_layout: Vec<usize>,
+    pub encryption: Option<SpillEncryption>,
 }
 
 impl AggregateSerdeMeta {
@@ -37,6 +40,7 @@ impl AggregateSerdeMeta {
             bucket,
             location: None,
             columns_layout: vec![],
+            encryption: None,
         })
     }
 
@@ -44,12 +48,14 @@ impl AggregateSerdeMeta {
         bucket: isize,
         location: String,
         columns_layout: Vec<usize>,
+        encryption: Option<SpillEncryption>,
     ) -> BlockMetaInfoPtr {
         Box::new(AggregateSerdeMeta {
             typ: SPILLED_TYPE,
             bucket,
             columns_layout,
             location: Some(location),
+            encryption,
         })
     }
 }
@@ -0,0 +1,157 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::ops::Range;
+
+use common_base::rangemap::RangeMerger;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::arrow::deserialize_column;
+use common_expression::Column;
+use opendal::Operator;
+
+/// Adjacent columns are fetched with one range read up to this size.
+const MAX_READ_BYTES: u64 = 16 * 1024 * 1024;
+
+/// Streaming deserializer of an aggregate spill file.
+///
+/// A spill file is the concatenation of its serialized columns, whose sizes are kept in
+/// `columns_layout`. The ranges of adjacent columns are merged up to `MAX_READ_BYTES`, and each
+/// merged range is fetched with one range read and released once its columns are deserialized,
+/// so only one merged range is held in memory instead of the whole file.
+pub struct SpilledColumnsReader {
+    operator: Operator,
+    location: String,
+    columns_layout: Vec<usize>,
+    range_merger: RangeMerger,
+
+    next_column: usize,
+    offset: u64,
+    buffer: Vec<u8>,
+    buffer_range: Range<u64>,
+}
+
+impl SpilledColumnsReader {
+    pub fn create(
+        operator: Operator,
+        location: String,
+        columns_layout: Vec<usize>,
+    ) -> SpilledColumnsReader {
+        let mut begin = 0;
+        let ranges = columns_layout.iter().map(|column_size| {
+            let range = begin..begin + *column_size as u64;
+            begin = range.end;
+            range
+        });
+        let range_merger = RangeMerger::from_iter(ranges, 0, MAX_READ_BYTES);
+
+        SpilledColumnsReader {
+            operator,
+            location,
+            columns_layout,
+            range_merger,
+            next_column: 0,
+            offset: 0,
+            buffer: vec![],
+            buffer_range: 0..0,
+        }
+    }
+
+    pub async fn next_column(&mut self) -> Result<Option<Column>> {
+        let column_size = match self.columns_layout.get(self.next_column) {
+            None => return Ok(None),
+            Some(column_size) => *column_size as u64,
+        };
+
+        let range = self.offset..self.offset + column_size;
+        if range.end > self.buffer_range.end {
+            let merged_range = match self.range_merger.get(range.clone()) {
+                Some((_, merged_range)) => merged_range,
+                None => {
+                    return Err(ErrorCode::Internal(format!(
+                        "It's a bug, cannot find the merged range of {:?} in spill file {}",
+                        range, self.location
+                    )));
+                }
+            };
+
+            // Release the previous range before fetching the next one.
+            self.buffer = vec![];
+            self.buffer = self
+                .operator
+                .range_read(&self.location, merged_range.clone())
+                .await?;
+            self.buffer_range = merged_range;
+        }
+
+        let begin = (range.start - self.buffer_range.start) as usize;
+        let end = (range.end - self.buffer_range.start) as usize;
+        if end > self.buffer.len() {
+            return Err(ErrorCode::StorageOther(format!(
+                "Spill file {} is truncated, expect at least {} bytes, but got {}",
+                self.location,
+                range.end,
+                self.buffer_range.start as usize + self.buffer.len()
+            )));
+        }
+
+        self.next_column += 1;
+        self.offset = range.end;
+        deserialize_spilled_column(&self.location, &self.buffer[begin..end]).map(Some)
+    }
+
+    pub async fn read_columns(&mut self) -> Result<Vec<Column>> {
+        let mut columns = Vec::with_capacity(self.columns_layout.len());
+        while let Some(column) = self.next_column().await? {
+            columns.push(column);
+        }
+
+        Ok(columns)
+    }
+}
+
+/// Deserializes the columns of a fully buffered spill file.
+pub fn deserialize_spilled_columns(
+    location: &str,
+    data: &[u8],
+    columns_layout: &[usize],
+) -> Result<Vec<Column>> {
+    let mut begin = 0;
+    let mut columns = Vec::with_capacity(columns_layout.len());
+    for column_layout in columns_layout {
+        let end = begin + column_layout;
+        if end > data.len() {
+            return Err(ErrorCode::StorageOther(format!(
+                "Spill file {} is truncated, expect at least {} bytes, but got {}",
+                location,
+                end,
+                data.len()
+            )));
+        }
+
+        columns.push(deserialize_spilled_column(location, &data[begin..end])?);
+        begin = end;
+    }
+
+    Ok(columns)
+}
+
+fn deserialize_spilled_column(location: &str, data: &[u8]) -> Result<Column> {
+    deserialize_column(data).ok_or_else(|| {
+        ErrorCode::StorageOther(format!(
+            "Cannot deserialize column of spill file {}",
+            location
+        ))
+    })
+}
@@ -0,0 +1,78 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Debug;
+use std::fmt::Formatter;
+
+use aes_gcm::aead::Aead;
+use aes_gcm::aead::AeadCore;
+use aes_gcm::aead::KeyInit;
+use aes_gcm::aead::OsRng;
+use aes_gcm::Aes256Gcm;
+use aes_gcm::Key;
+use aes_gcm::Nonce;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+pub type SpillEncryptionKey = [u8; 32];
+
+/// The AES-GCM key and nonce of an encrypted spill file.
+///
+/// It only travels with the spilled meta (in memory or between query nodes), the spill file
+/// on the storage never contains it.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, PartialEq, Eq)]
+pub struct SpillEncryption {
+    key: SpillEncryptionKey,
+    nonce: [u8; 12],
+}
+
+impl SpillEncryption {
+    pub fn generate_key() -> SpillEncryptionKey {
+        Aes256Gcm::generate_key(&mut OsRng).into()
+    }
+
+    /// Creates the encryption of a new spill file, with a random nonce.
+    pub fn create(key: SpillEncryptionKey) -> SpillEncryption {
+        SpillEncryption {
+            key,
+            nonce: Aes256Gcm::generate_nonce(&mut OsRng).into(),
+        }
+    }
+
+    pub fn encrypt(&self, data: &[u8]) -> Result<Vec<u8>> {
+        self.cipher()
+            .encrypt(Nonce::from_slice(&self.nonce), data)
+            .map_err(|cause| {
+                ErrorCode::Internal(format!("Cannot encrypt spill file, cause: {:?}", cause))
+            })
+    }
+
+    pub fn decrypt(&self, data: &[u8]) -> Result<Vec<u8>> {
+        self.cipher()
+            .decrypt(Nonce::from_slice(&self.nonce), data)
+            .map_err(|cause| {
+                ErrorCode::Internal(format!("Cannot decrypt spill file, cause: {:?}", cause))
+            })
+    }
+
+    fn cipher(&self) -> Aes256Gcm {
+        Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&self.key))
+    }
+}
+
+impl Debug for SpillEncryption {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SpillEncryption").finish_non_exhaustive()
+    }
+}
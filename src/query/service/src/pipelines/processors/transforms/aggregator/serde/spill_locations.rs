@@ -0,0 +1,204 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use common_base::base::GlobalUniqName;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::storage::StorageParams;
+use common_settings::Settings;
+use common_storage::DataOperator;
+use opendal::Operator;
+
+use crate::pipelines::processors::transforms::aggregator::serde::spill_encryption::SpillEncryption;
+use crate::pipelines::processors::transforms::aggregator::serde::spill_encryption::SpillEncryptionKey;
+
+const DEFAULT_SPILL_PREFIX: &str = "_aggregate_spill";
+
+/// How the prefix of an aggregate spill file is chosen among the configured prefixes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SpillStrategy {
+    /// Cycles through the prefixes in order.
+    RoundRobin,
+    /// Picks the prefix with the fewest bytes spilled so far.
+    LeastBytes,
+}
+
+/// The locations aggregate spill files are written to, shared by all the spill writers of a query.
+pub struct SpillLocations {
+    strategy: SpillStrategy,
+    prefixes: Vec<String>,
+    next: AtomicUsize,
+    spilled_bytes: Vec<AtomicUsize>,
+    total_spilled_bytes: AtomicUsize,
+    max_bytes: Option<usize>,
+    encryption_key: Option<SpillEncryptionKey>,
+    spilling_enabled: bool,
+}
+
+impl SpillLocations {
+    pub fn create(
+        strategy: SpillStrategy,
+        prefixes: Vec<String>,
+        max_bytes: Option<usize>,
+        encryption_key: Option<SpillEncryptionKey>,
+        spilling_enabled: bool,
+    ) -> Arc<SpillLocations> {
+        assert!(!prefixes.is_empty());
+        let spilled_bytes = prefixes.iter().map(|_| AtomicUsize::new(0)).collect();
+        Arc::new(SpillLocations {
+            strategy,
+            prefixes,
+            next: AtomicUsize::new(0),
+            spilled_bytes,
+            total_spilled_bytes: AtomicUsize::new(0),
+            max_bytes,
+            encryption_key,
+            spilling_enabled,
+        })
+    }
+
+    /// Creates the locations from `spilling_location_prefixes` and `spilling_location_strategy`,
+    /// every prefix is suffixed by the tenant. The spilled bytes are limited by `spilling_max_bytes`,
+    /// and a random encryption key is generated if `enable_spilling_encryption` is set.
+    /// Spilling is enabled if `spilling_bytes_threshold_per_proc` is set.
+    pub fn try_create(settings: &Settings, tenant: &str) -> Result<Arc<SpillLocations>> {
+        let strategy = match settings.get_spilling_location_strategy()?.as_str() {
+            "least_bytes" => SpillStrategy::LeastBytes,
+            _ => SpillStrategy::RoundRobin,
+        };
+
+        let mut prefixes = settings.get_spilling_location_prefixes()?;
+        if prefixes.is_empty() {
+            prefixes.push(DEFAULT_SPILL_PREFIX.to_string());
+        }
+
+        let prefixes = prefixes
+            .iter()
+            .map(|prefix| format!("{}/{}", prefix.trim_end_matches('/'), tenant))
+            .collect();
+
+        let max_bytes = match settings.get_spilling_max_bytes()? {
+            0 => None,
+            max_bytes => Some(max_bytes),
+        };
+
+        let encryption_key = match settings.get_enable_spilling_encryption()? {
+            true => Some(SpillEncryption::generate_key()),
+            false => None,
+        };
+
+        let spilling_enabled = settings.get_spilling_bytes_threshold_per_proc()? != 0;
+
+        Ok(Self::create(
+            strategy,
+            prefixes,
+            max_bytes,
+            encryption_key,
+            spilling_enabled,
+        ))
+    }
+
+    /// Returns the location of a new spill file of `size` bytes.
+    ///
+    /// Fails with `StorageUnavailable` before anything is written if the file would exceed
+    /// the spilling quota.
+    pub fn next_location(&self, size: usize) -> Result<String> {
+        if let Some(max_bytes) = self.max_bytes {
+            let reserved = self.total_spilled_bytes.fetch_update(
+                Ordering::Relaxed,
+                Ordering::Relaxed,
+                |spilled| {
+                    spilled
+                        .checked_add(size)
+                        .filter(|total| *total <= max_bytes)
+                },
+            );
+
+            if let Err(spilled) = reserved {
+                return Err(ErrorCode::StorageUnavailable(format!(
+                    "Cannot spill {} bytes, spilling quota exhausted: {} of {} bytes (spilling_max_bytes) used",
+                    size, spilled, max_bytes
+                )));
+            }
+        }
+
+        let idx = match self.strategy {
+            SpillStrategy::RoundRobin => {
+                self.next.fetch_add(1, Ordering::Relaxed) % self.prefixes.len()
+            }
+            SpillStrategy::LeastBytes => self
+                .spilled_bytes
+                .iter()
+                .enumerate()
+                .min_by_key(|(_, bytes)| bytes.load(Ordering::Relaxed))
+                .map(|(idx, _)| idx)
+                .unwrap(),
+        };
+
+        self.spilled_bytes[idx].fetch_add(size, Ordering::Relaxed);
+        Ok(format!(
+            "{}/{}",
+            self.prefixes[idx],
+            GlobalUniqName::unique()
+        ))
+    }
+
+    /// Returns the encryption of a new spill file, `None` if spill files are not encrypted.
+    pub fn next_encryption(&self) -> Option<SpillEncryption> {
+        self.encryption_key.map(SpillEncryption::create)
+    }
+
+    pub fn prefixes(&self) -> &[String] {
+        &self.prefixes
+    }
+
+    /// Whether the aggregation may spill, if not, nothing is written to the spill storage.
+    pub fn spilling_enabled(&self) -> bool {
+        self.spilling_enabled
+    }
+}
+
+/// Returns the operator aggregate spill files are written to, `None` if the storage of the
+/// query node is not configured or is read only.
+pub fn spill_operator() -> Option<Operator> {
+    let data_operator = DataOperator::instance();
+    match data_operator.params() {
+        StorageParams::None | StorageParams::Http(_) | StorageParams::Ipfs(_) => None,
+        _ => Some(data_operator.operator()),
+    }
+}
+
+/// Fails if spilling is enabled but there is no spill storage, so that the query fails
+/// before the memory is exhausted.
+pub fn check_spill_operator(
+    operator: &Option<Operator>,
+    spill_locations: &SpillLocations,
+) -> Result<()> {
+    if operator.is_none() && spill_locations.spilling_enabled() {
+        return Err(spill_storage_unavailable());
+    }
+    Ok(())
+}
+
+pub fn spill_storage_unavailable() -> ErrorCode {
+    ErrorCode::StorageUnavailable(
+        "Aggregation may need to spill, but no writable storage is configured for the spill files. \
+         Please configure the [storage] section of databend-query with a writable storage (e.g. fs or s3), \
+         or disable spilling by `SET spilling_bytes_threshold_per_proc = 0`",
+    )
+}
@@ -0,0 +1,77 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+use std::future::Future;
+
+use common_base::base::tokio;
+use common_base::base::tokio::task::JoinHandle;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+/// Reads spill files ahead of the one being restored.
+///
+/// Up to `depth` reads run in the background, so that downloading the next buckets
+/// overlaps with processing the current one. The results are returned in the order
+/// the reads are started.
+pub struct SpillPrefetcher<T> {
+    depth: usize,
+    reading: VecDeque<JoinHandle<Result<T>>>,
+}
+
+impl<T: Send + 'static> SpillPrefetcher<T> {
+    pub fn create(depth: usize) -> Self {
+        // At least the current spill file is read.
+        let depth = depth.max(1);
+        SpillPrefetcher {
+            depth,
+            reading: VecDeque::with_capacity(depth),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.reading.is_empty()
+    }
+
+    /// Returns true if no more reads should be started before [`Self::next`] is called.
+    pub fn is_full(&self) -> bool {
+        self.reading.len() >= self.depth
+    }
+
+    /// Starts the read in background.
+    pub fn prefetch(&mut self, read: impl Future<Output = Result<T>> + Send + 'static) {
+        debug_assert!(!self.is_full());
+        self.reading.push_back(tokio::spawn(read));
+    }
+
+    /// Waits for the earliest started read, returns `None` if nothing is being read.
+    pub async fn next(&mut self) -> Result<Option<T>> {
+        match self.reading.pop_front() {
+            None => Ok(None),
+            Some(handle) => match handle.await {
+                Ok(res) => res.map(Some),
+                Err(_) => Err(ErrorCode::TokioError("Cannot join tokio job")),
+            },
+        }
+    }
+}
+
+impl<T> Drop for SpillPrefetcher<T> {
+    fn drop(&mut self) {
+        // The reads are no longer needed, e.g. the query is aborted.
+        for handle in &self.reading {
+            handle.abort();
+        }
+    }
+}
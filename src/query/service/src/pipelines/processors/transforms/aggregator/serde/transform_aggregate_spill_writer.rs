@@ -16,7 +16,6 @@ use std::any::Any;
 use std::sync::Arc;
 use std::time::Instant;
 
-use common_base::base::GlobalUniqName;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::arrow::serialize_column;
@@ -33,6 +32,9 @@ use tracing::info;
 
 use crate::pipelines::processors::transforms::aggregator::aggregate_meta::AggregateMeta;
 use crate::pipelines::processors::transforms::aggregator::aggregate_meta::HashTablePayload;
+use crate::pipelines::processors::transforms::aggregator::serde::spill_locations::check_spill_operator;
+use crate::pipelines::processors::transforms::aggregator::serde::spill_locations::spill_storage_unavailable;
+use crate::pipelines::processors::transforms::aggregator::serde::spill_locations::SpillLocations;
 use crate::pipelines::processors::transforms::aggregator::serde::transform_aggregate_serializer::serialize_aggregate;
 use crate::pipelines::processors::transforms::group_by::HashMethodBounds;
 use crate::pipelines::processors::AggregatorParams;
@@ -43,8 +45,8 @@ pub struct TransformAggregateSpillWriter<Method: HashMethodBounds> {
     output: Arc<OutputPort>,
     params: Arc<AggregatorParams>,
 
-    operator: Operator,
-    location_prefix: String,
+    operator: Option<Operator>,
+    spill_locations: Arc<SpillLocations>,
     output_block: Option<DataBlock>,
     spilling_meta: Option<AggregateMeta<Method, usize>>,
     spilling_future: Option<BoxFuture<'static, Result<()>>>,
@@ -55,21 +57,22 @@ impl<Method: HashMethodBounds> TransformAggregateSpillWriter<Method> {
         input: Arc<InputPort>,
         output: Arc<OutputPort>,
         method: Method,
-        operator: Operator,
+        operator: Option<Operator>,
         params: Arc<AggregatorParams>,
-        location_prefix: String,
-    ) -> Box<dyn Processor> {
-        Box::new(TransformAggregateSpillWriter::<Method> {
+        spill_locations: Arc<SpillLocations>,
+    ) -> Result<Box<dyn Processor>> {
+        check_spill_operator(&operator, &spill_locations)?;
+        Ok(Box::new(TransformAggregateSpillWriter::<Method> {
             method,
             input,
             output,
             params,
             operator,
-            location_prefix,
+            spill_locations,
             output_block: None,
             spilling_meta: None,
             spilling_future: None,
-        })
+        }))
     }
 }
 
@@ -141,9 +144,11 @@ impl<Method: HashMethodBounds> Processor for TransformAggregateSpillWriter<Metho
         if let Some(spilling_meta) = self.spilling_meta.take() {
             if let AggregateMeta::Spilling(payload) = spilling_meta {
                 let (output_block, spilling_future) = spilling_aggregate_payload(
-                    self.operator.clone(),
+                    self.operator
+                        .clone()
+                        .ok_or_else(spill_storage_unavailable)?,
                     &self.method,
-                    &self.location_prefix,
+                    &self.spill_locations,
                     &self.params,
                     payload,
                 )?;
@@ -197,18 +202,22 @@ fn serialize_spill_file<Method: HashMethodBounds>(
 pub fn spilling_aggregate_payload<Method: HashMethodBounds>(
     operator: Operator,
     method: &Method,
-    location_prefix: &str,
+    spill_locations: &SpillLocations,
     params: &Arc<AggregatorParams>,
     payload: HashTablePayload<Method, usize>,
 ) -> Result<(DataBlock, BoxFuture<'static, Result<()>>)> {
     let (bucket, total_size, data) = serialize_spill_file(method, params, payload)?;
 
-    let unique_name = GlobalUniqName::unique();
-    let location = format!("{}/{}", location_prefix, unique_name);
+    let location = spill_locations.next_location(total_size)?;
+    let encryption = spill_locations.next_encryption();
     let columns_layout = data.iter().map(Vec::len).collect::<Vec<_>>();
-    let output_data_block = DataBlock::empty_with_meta(
-        AggregateMeta::<Method, usize>::create_spilled(bucket, location.clone(), columns_layout),
-    );
+    let output_data_block =
+        DataBlock::empty_with_meta(AggregateMeta::<Method, usize>::create_spilled(
+            bucket,
+            location.clone(),
+            columns_layout,
+            encryption,
+        ));
 
     Ok((
         output_data_block,
@@ -222,6 +231,10 @@ pub fn spilling_aggregate_payload<Method: HashMethodBounds>(
                 write_data.extend(data);
             }
 
+            if let Some(encryption) = &encryption {
+                write_data = encryption.encrypt(&write_data)?;
+            }
+
             operator.write(&location, write_data).await?;
 
             info!(
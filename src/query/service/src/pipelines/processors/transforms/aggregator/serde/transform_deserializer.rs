@@ -89,6 +89,7 @@ where
                         meta.bucket,
                         meta.location.unwrap(),
                         meta.columns_layout,
+                        meta.encryption,
                     ),
                 },
             )));
@@ -58,12 +58,14 @@ where Method: HashMethodBounds
         match meta {
             AggregateMeta::Spilling(_) => unreachable!(),
             AggregateMeta::Partitioned { .. } => unreachable!(),
+            AggregateMeta::PartiallyRestored { .. } => unreachable!(),
             AggregateMeta::Serialized(_) => unreachable!(),
             AggregateMeta::Spilled(payload) => Ok(DataBlock::empty_with_meta(
                 AggregateSerdeMeta::create_spilled(
                     payload.bucket,
                     payload.location,
                     payload.columns_layout,
+                    payload.encryption,
                 ),
             )),
             AggregateMeta::HashTable(payload) => {
@@ -28,6 +28,7 @@ use common_pipeline_transforms::processors::transforms::BlockMetaTransform;
 use common_pipeline_transforms::processors::transforms::BlockMetaTransformer;
 
 use crate::api::serialize_block;
+use crate::api::ExchangeFormat;
 use crate::api::ExchangeShuffleMeta;
 use crate::pipelines::processors::transforms::aggregator::aggregate_meta::AggregateMeta;
 use crate::pipelines::processors::transforms::aggregator::serde::serde_meta::AggregateSerdeMeta;
@@ -38,6 +39,7 @@ use crate::pipelines::processors::AggregatorParams;
 pub struct TransformScatterAggregateSerializer<Method: HashMethodBounds> {
     method: Method,
     local_pos: usize,
+    format: ExchangeFormat,
     options: WriteOptions,
     ipc_fields: Vec<IpcField>,
     params: Arc<AggregatorParams>,
@@ -50,6 +52,7 @@ impl<Method: HashMethodBounds> TransformScatterAggregateSerializer<Method> {
         method: Method,
         schema: DataSchemaRef,
         local_pos: usize,
+        format: ExchangeFormat,
         params: Arc<AggregatorParams>,
     ) -> Result<ProcessorPtr> {
         let arrow_schema = schema.to_arrow();
@@ -62,6 +65,7 @@ impl<Method: HashMethodBounds> TransformScatterAggregateSerializer<Method> {
                 method,
                 params,
                 local_pos,
+                format,
                 ipc_fields,
                 options: Default::default(),
             },
@@ -90,6 +94,7 @@ where Method: HashMethodBounds
                 new_blocks.push(match meta {
                     AggregateMeta::Spilling(_) => unreachable!(),
                     AggregateMeta::Partitioned { .. } => unreachable!(),
+                    AggregateMeta::PartiallyRestored { .. } => unreachable!(),
                     AggregateMeta::Serialized(_) => unreachable!(),
                     AggregateMeta::Spilled(payload) => {
                         let bucket = payload.bucket;
@@ -98,16 +103,29 @@ where Method: HashMethodBounds
                                 bucket,
                                 payload.location,
                                 payload.columns_layout,
+                                payload.encryption,
                             ));
 
-                        serialize_block(bucket, data_block, &self.ipc_fields, &self.options)?
+                        serialize_block(
+                            bucket,
+                            data_block,
+                            &self.ipc_fields,
+                            &self.options,
+                            self.format,
+                        )?
                     }
                     AggregateMeta::HashTable(payload) => {
                         let bucket = payload.bucket;
                         let data_block = serialize_aggregate(&self.method, &self.params, payload)?;
                         let data_block =
                             data_block.add_meta(Some(AggregateSerdeMeta::create(bucket)))?;
-                        serialize_block(bucket, data_block, &self.ipc_fields, &self.options)?
+                        serialize_block(
+                            bucket,
+                            data_block,
+                            &self.ipc_fields,
+                            &self.options,
+                            self.format,
+                        )?
                     }
                 });
 
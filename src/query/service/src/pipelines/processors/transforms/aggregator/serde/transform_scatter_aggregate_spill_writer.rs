@@ -27,6 +27,7 @@ use opendal::Operator;
 
 use crate::api::ExchangeShuffleMeta;
 use crate::pipelines::processors::transforms::aggregator::aggregate_meta::AggregateMeta;
+use crate::pipelines::processors::transforms::aggregator::serde::spill_locations::SpillLocations;
 use crate::pipelines::processors::transforms::aggregator::serde::transform_aggregate_spill_writer::spilling_aggregate_payload;
 use crate::pipelines::processors::transforms::group_by::HashMethodBounds;
 use crate::pipelines::processors::AggregatorParams;
@@ -37,7 +38,7 @@ pub struct TransformScatterAggregateSpillWriter<Method: HashMethodBounds> {
     output: Arc<OutputPort>,
 
     operator: Operator,
-    location_prefix: String,
+    spill_locations: Arc<SpillLocations>,
     params: Arc<AggregatorParams>,
     input_data_block: Option<DataBlock>,
     output_data_block: Option<DataBlock>,
@@ -50,7 +51,7 @@ impl<Method: HashMethodBounds> TransformScatterAggregateSpillWriter<Method> {
         output: Arc<OutputPort>,
         method: Method,
         operator: Operator,
-        location_prefix: String,
+        spill_locations: Arc<SpillLocations>,
         params: Arc<AggregatorParams>,
     ) -> Box<dyn Processor> {
         Box::new(TransformScatterAggregateSpillWriter::<Method> {
@@ -59,7 +60,7 @@ impl<Method: HashMethodBounds> TransformScatterAggregateSpillWriter<Method> {
             output,
             params,
             operator,
-            location_prefix,
+            spill_locations,
             input_data_block: None,
             output_data_block: None,
             spilling_futures: vec![],
@@ -138,7 +139,7 @@ impl<Method: HashMethodBounds> Processor for TransformScatterAggregateSpillWrite
                             let (output_block, spilling_future) = spilling_aggregate_payload(
                                 self.operator.clone(),
                                 &self.method,
-                                &self.location_prefix,
+                                &self.spill_locations,
                                 &self.params,
                                 payload,
                             )?;
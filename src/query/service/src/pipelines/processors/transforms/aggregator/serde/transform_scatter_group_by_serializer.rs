@@ -28,6 +28,7 @@ use common_pipeline_transforms::processors::transforms::BlockMetaTransform;
 use common_pipeline_transforms::processors::transforms::BlockMetaTransformer;
 
 use crate::api::serialize_block;
+use crate::api::ExchangeFormat;
 use crate::api::ExchangeShuffleMeta;
 use crate::pipelines::processors::transforms::aggregator::aggregate_meta::AggregateMeta;
 use crate::pipelines::processors::transforms::aggregator::serde::transform_group_by_serializer::serialize_group_by;
@@ -48,6 +49,7 @@ impl<Method: HashMethodBounds> TransformScatterGroupBySerializer<Method> {
         method: Method,
         schema: DataSchemaRef,
         local_pos: usize,
+        format: ExchangeFormat,
     ) -> Result<ProcessorPtr> {
         let arrow_schema = schema.to_arrow();
         let ipc_fields = default_ipc_fields(&arrow_schema.fields);
@@ -57,6 +59,7 @@ impl<Method: HashMethodBounds> TransformScatterGroupBySerializer<Method> {
             TransformScatterGroupBySerializer {
                 method,
                 local_pos,
+                format,
                 ipc_fields,
                 options: WriteOptions { compression: None },
             },
@@ -85,6 +88,7 @@ where Method: HashMethodBounds
                 new_blocks.push(match meta {
                     AggregateMeta::Spilling(_) => unreachable!(),
                     AggregateMeta::Partitioned { .. } => unreachable!(),
+                    AggregateMeta::PartiallyRestored { .. } => unreachable!(),
                     AggregateMeta::Serialized(_) => unreachable!(),
                     AggregateMeta::Spilled(payload) => {
                         let bucket = payload.bucket;
@@ -93,16 +97,29 @@ where Method: HashMethodBounds
                                 bucket,
                                 payload.location,
                                 payload.columns_layout,
+                                payload.encryption,
                             ));
 
-                        serialize_block(bucket, data_block, &self.ipc_fields, &self.options)?
+                        serialize_block(
+                            bucket,
+                            data_block,
+                            &self.ipc_fields,
+                            &self.options,
+                            self.format,
+                        )?
                     }
                     AggregateMeta::HashTable(payload) => {
                         let bucket = payload.bucket;
                         let data_block = serialize_group_by(&self.method, payload)?;
                         let data_block =
                             data_block.add_meta(Some(AggregateSerdeMeta::create(bucket)))?;
-                        serialize_block(bucket, data_block, &self.ipc_fields, &self.options)?
+                        serialize_block(
+                            bucket,
+                            data_block,
+                            &self.ipc_fields,
+                            &self.options,
+                            self.format,
+                        )?
                     }
                 });
 
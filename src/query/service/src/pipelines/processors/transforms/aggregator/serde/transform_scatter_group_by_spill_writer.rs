@@ -27,6 +27,7 @@ use opendal::Operator;
 
 use crate::api::ExchangeShuffleMeta;
 use crate::pipelines::processors::transforms::aggregator::aggregate_meta::AggregateMeta;
+use crate::pipelines::processors::transforms::aggregator::serde::spill_locations::SpillLocations;
 use crate::pipelines::processors::transforms::aggregator::serde::transform_group_by_spill_writer::spilling_group_by_payload;
 use crate::pipelines::processors::transforms::group_by::HashMethodBounds;
 
@@ -36,7 +37,7 @@ pub struct TransformScatterGroupBySpillWriter<Method: HashMethodBounds> {
     output: Arc<OutputPort>,
 
     operator: Operator,
-    location_prefix: String,
+    spill_locations: Arc<SpillLocations>,
     input_data_block: Option<DataBlock>,
     output_data_block: Option<DataBlock>,
     spilling_futures: Vec<BoxFuture<'static, Result<()>>>,
@@ -48,14 +49,14 @@ impl<Method: HashMethodBounds> TransformScatterGroupBySpillWriter<Method> {
         output: Arc<OutputPort>,
         method: Method,
         operator: Operator,
-        location_prefix: String,
+        spill_locations: Arc<SpillLocations>,
     ) -> Box<dyn Processor> {
         Box::new(TransformScatterGroupBySpillWriter::<Method> {
             method,
             input,
             output,
             operator,
-            location_prefix,
+            spill_locations,
             input_data_block: None,
             output_data_block: None,
             spilling_futures: vec![],
@@ -134,7 +135,7 @@ impl<Method: HashMethodBounds> Processor for TransformScatterGroupBySpillWriter<
                             let (output_block, spilling_future) = spilling_group_by_payload(
                                 self.operator.clone(),
                                 &self.method,
-                                &self.location_prefix,
+                                &self.spill_locations,
                                 payload,
                             )?;
 
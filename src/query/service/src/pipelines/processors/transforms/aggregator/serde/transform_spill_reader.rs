@@ -19,9 +19,9 @@ use std::time::Instant;
 
 use common_exception::ErrorCode;
 use common_exception::Result;
-use common_expression::arrow::deserialize_column;
 use common_expression::BlockMetaInfoDowncast;
 use common_expression::BlockMetaInfoPtr;
+use common_expression::Column;
 use common_expression::DataBlock;
 use common_pipeline_core::processors::port::InputPort;
 use common_pipeline_core::processors::port::OutputPort;
@@ -36,9 +36,12 @@ use tracing::info;
 use crate::pipelines::processors::transforms::aggregator::aggregate_meta::AggregateMeta;
 use crate::pipelines::processors::transforms::aggregator::aggregate_meta::SerializedPayload;
 use crate::pipelines::processors::transforms::aggregator::aggregate_meta::SpilledPayload;
+use crate::pipelines::processors::transforms::aggregator::serde::spill_columns_reader::deserialize_spilled_columns;
+use crate::pipelines::processors::transforms::aggregator::serde::spill_columns_reader::SpilledColumnsReader;
+use crate::pipelines::processors::transforms::aggregator::serde::spill_prefetcher::SpillPrefetcher;
 use crate::pipelines::processors::transforms::group_by::HashMethodBounds;
 
-type DeserializingMeta<Method, V> = (AggregateMeta<Method, V>, VecDeque<Vec<u8>>);
+type DeserializingMeta<Method, V> = (AggregateMeta<Method, V>, VecDeque<Vec<Column>>);
 
 pub struct TransformSpillReader<Method: HashMethodBounds, V: Send + Sync + 'static> {
     input: Arc<InputPort>,
@@ -46,8 +49,14 @@ pub struct TransformSpillReader<Method: HashMethodBounds, V: Send + Sync + 'stat
 
     operator: Operator,
     deserialized_meta: Option<BlockMetaInfoPtr>,
-    reading_meta: Option<AggregateMeta<Method, V>>,
+    // The metas waiting to be read. A partitioned bucket is restored part by part, one spill
+    // file each, so that only the spill files being prefetched are held in memory.
+    reading_metas: VecDeque<AggregateMeta<Method, V>>,
     deserializing_meta: Option<DeserializingMeta<Method, V>>,
+    prefetcher: SpillPrefetcher<DeserializingMeta<Method, V>>,
+    // A block without spilled data, which is pulled while the spilled data ahead of it is
+    // still being read, it's kept to be pushed in order.
+    pending_block: Option<DataBlock>,
 }
 
 #[async_trait::async_trait]
@@ -84,38 +93,48 @@ impl<Method: HashMethodBounds, V: Send + Sync + 'static> Processor
             return Ok(Event::Sync);
         }
 
-        if self.reading_meta.is_some() {
+        if !self.reading_metas.is_empty() && !self.prefetcher.is_full() {
             self.input.set_not_need_data();
             return Ok(Event::Async);
         }
 
-        if self.input.has_data() {
+        if self.pending_block.is_none()
+            && self.reading_metas.is_empty()
+            && !self.prefetcher.is_full()
+            && self.input.has_data()
+        {
             let mut data_block = self.input.pull_data().unwrap()?;
 
-            if let Some(block_meta) = data_block
-                .get_meta()
-                .and_then(AggregateMeta::<Method, V>::downcast_ref_from)
-            {
-                if matches!(block_meta, AggregateMeta::Spilled(_)) {
-                    self.input.set_not_need_data();
-                    let block_meta = data_block.take_meta().unwrap();
-                    self.reading_meta = AggregateMeta::<Method, V>::downcast_from(block_meta);
-                    return Ok(Event::Async);
+            if Self::has_spilled(&data_block) {
+                self.input.set_not_need_data();
+                let block_meta = data_block.take_meta().unwrap();
+                if let Some(meta) = AggregateMeta::<Method, V>::downcast_from(block_meta) {
+                    self.reading_metas.extend(Self::split_restoring(meta));
                 }
+                return Ok(Event::Async);
+            }
 
-                if let AggregateMeta::Partitioned { data, .. } = block_meta {
-                    for meta in data {
-                        if matches!(meta, AggregateMeta::Spilled(_)) {
-                            self.input.set_not_need_data();
-                            let block_meta = data_block.take_meta().unwrap();
-                            self.reading_meta =
-                                AggregateMeta::<Method, V>::downcast_from(block_meta);
-                            return Ok(Event::Async);
-                        }
-                    }
-                }
+            if self.prefetcher.is_empty() {
+                self.output.push_data(Ok(data_block));
+                return Ok(Event::NeedConsume);
             }
 
+            self.pending_block = Some(data_block);
+        }
+
+        if !self.prefetcher.is_empty() {
+            // Keep pulling the following blocks to read ahead, while waiting for the first one.
+            match self.pending_block.is_none()
+                && self.reading_metas.is_empty()
+                && !self.prefetcher.is_full()
+            {
+                true => self.input.set_need_data(),
+                false => self.input.set_not_need_data(),
+            }
+            return Ok(Event::Async);
+        }
+
+        if let Some(data_block) = self.pending_block.take() {
             self.output.push_data(Ok(data_block));
             return Ok(Event::NeedConsume);
         }
@@ -137,30 +156,24 @@ impl<Method: HashMethodBounds, V: Send + Sync + 'static> Processor
                 AggregateMeta::Serialized(_) => unreachable!(),
                 AggregateMeta::Spilled(payload) => {
                     debug_assert!(read_data.len() == 1);
-                    let data = read_data.pop_front().unwrap();
+                    let columns = read_data.pop_front().unwrap();
 
-                    self.deserialized_meta = Some(Box::new(Self::deserialize(payload, data)));
+                    self.deserialized_meta = Some(Box::new(Self::deserialize(payload, columns)));
                 }
                 AggregateMeta::Partitioned { bucket, data } => {
-                    let mut new_data = Vec::with_capacity(data.len());
-
-                    for meta in data {
-                        if matches!(&meta, AggregateMeta::Spilled(_)) {
-                            if let AggregateMeta::Spilled(payload) = meta {
-                                let data = read_data.pop_front().unwrap();
-                                new_data.push(Self::deserialize(payload, data));
-                            }
-
-                            continue;
-                        }
-
-                        new_data.push(meta);
-                    }
-
+                    let new_data = Self::deserialize_partitioned(data, &mut read_data);
                     self.deserialized_meta = Some(AggregateMeta::<Method, V>::create_partitioned(
                         bucket, new_data,
                     ));
                 }
+                AggregateMeta::PartiallyRestored { bucket, data } => {
+                    let new_data = Self::deserialize_partitioned(data, &mut read_data);
+                    self.deserialized_meta =
+                        Some(Box::new(AggregateMeta::<Method, V>::PartiallyRestored {
+                            bucket,
+                            data: new_data,
+                        }));
+                }
             }
         }
 
@@ -168,73 +181,17 @@ impl<Method: HashMethodBounds, V: Send + Sync + 'static> Processor
     }
 
     async fn async_process(&mut self) -> Result<()> {
-        if let Some(block_meta) = self.reading_meta.take() {
-            match &block_meta {
-                AggregateMeta::Spilling(_) => unreachable!(),
-                AggregateMeta::HashTable(_) => unreachable!(),
-                AggregateMeta::Serialized(_) => unreachable!(),
-                AggregateMeta::Spilled(payload) => {
-                    let instant = Instant::now();
-                    let data = self.operator.read(&payload.location).await?;
-
-                    if let Err(cause) = self.operator.delete(&payload.location).await {
-                        error!(
-                            "Cannot delete spill file {}, cause: {:?}",
-                            &payload.location, cause
-                        );
-                    }
-
-                    info!(
-                        "Read aggregate spill {} successfully, elapsed: {:?}",
-                        &payload.location,
-                        instant.elapsed()
-                    );
-
-                    self.deserializing_meta = Some((block_meta, VecDeque::from(vec![data])));
-                }
-                AggregateMeta::Partitioned { data, .. } => {
-                    let mut read_data = Vec::with_capacity(data.len());
-                    for meta in data {
-                        if let AggregateMeta::Spilled(payload) = meta {
-                            let location = payload.location.clone();
-                            let operator = self.operator.clone();
-                            read_data.push(common_base::base::tokio::spawn(async move {
-                                let instant = Instant::now();
-                                let data = operator.read(&location).await?;
-
-                                if let Err(cause) = operator.delete(&location).await {
-                                    error!(
-                                        "Cannot delete spill file {}, cause: {:?}",
-                                        location, cause
-                                    );
-                                }
-
-                                info!(
-                                    "Read aggregate spill {} successfully, elapsed: {:?}",
-                                    location,
-                                    instant.elapsed()
-                                );
-
-                                Ok(data)
-                            }));
-                        }
-                    }
-
-                    match futures::future::try_join_all(read_data).await {
-                        Err(_) => {
-                            return Err(ErrorCode::TokioError("Cannot join tokio job"));
-                        }
-                        Ok(read_data) => {
-                            let read_data: Result<VecDeque<Vec<u8>>, opendal::Error> =
-                                read_data.into_iter().try_collect();
-
-                            self.deserializing_meta = Some((block_meta, read_data?));
-                        }
-                    };
-                }
+        if !self.prefetcher.is_full() {
+            if let Some(block_meta) = self.reading_metas.pop_front() {
+                // Start reading in background, and return to pull the next block.
+                let operator = self.operator.clone();
+                self.prefetcher
+                    .prefetch(Self::read_meta(operator, block_meta));
+                return Ok(());
             }
         }
 
+        self.deserializing_meta = self.prefetcher.next().await?;
         Ok(())
     }
 }
@@ -244,6 +201,7 @@ impl<Method: HashMethodBounds, V: Send + Sync + 'static> TransformSpillReader<Me
         input: Arc<InputPort>,
         output: Arc<OutputPort>,
         operator: Operator,
+        prefetch_depth: usize,
     ) -> Result<ProcessorPtr> {
         Ok(ProcessorPtr::create(Box::new(TransformSpillReader::<
             Method,
@@ -253,19 +211,149 @@ impl<Method: HashMethodBounds, V: Send + Sync + 'static> TransformSpillReader<Me
             output,
             operator,
             deserialized_meta: None,
-            reading_meta: None,
+            reading_metas: VecDeque::new(),
             deserializing_meta: None,
+            prefetcher: SpillPrefetcher::create(prefetch_depth),
+            pending_block: None,
         })))
     }
 
-    fn deserialize(payload: SpilledPayload, data: Vec<u8>) -> AggregateMeta<Method, V> {
-        let mut begin = 0;
-        let mut columns = Vec::with_capacity(payload.columns_layout.len());
-        for column_layout in payload.columns_layout {
-            columns.push(deserialize_column(&data[begin..begin + column_layout]).unwrap());
-            begin += column_layout;
+    fn has_spilled(data_block: &DataBlock) -> bool {
+        match data_block
+            .get_meta()
+            .and_then(AggregateMeta::<Method, V>::downcast_ref_from)
+        {
+            Some(AggregateMeta::Spilled(_)) => true,
+            Some(AggregateMeta::Partitioned { data, .. }) => data
+                .iter()
+                .any(|meta| matches!(meta, AggregateMeta::Spilled(_))),
+            _ => false,
         }
+    }
+
+    /// Splits a partitioned bucket into parts of one spill file each, the in-memory data
+    /// goes with the first part. All the parts but the last are `PartiallyRestored`.
+    fn split_restoring(meta: AggregateMeta<Method, V>) -> Vec<AggregateMeta<Method, V>> {
+        let (bucket, data) = match meta {
+            AggregateMeta::Partitioned { bucket, data } => (bucket, data),
+            meta => return vec![meta],
+        };
+
+        let (spilled, mut in_memory): (Vec<_>, Vec<_>) = data
+            .into_iter()
+            .partition(|meta| matches!(meta, AggregateMeta::Spilled(_)));
+
+        let parts = spilled.len();
+        let mut metas = Vec::with_capacity(parts);
+        for (index, spilled) in spilled.into_iter().enumerate() {
+            let mut data = std::mem::take(&mut in_memory);
+            data.push(spilled);
+            metas.push(match index + 1 == parts {
+                true => AggregateMeta::Partitioned { bucket, data },
+                false => AggregateMeta::PartiallyRestored { bucket, data },
+            });
+        }
+        metas
+    }
+
+    async fn read_meta(
+        operator: Operator,
+        block_meta: AggregateMeta<Method, V>,
+    ) -> Result<DeserializingMeta<Method, V>> {
+        match &block_meta {
+            AggregateMeta::Spilling(_) => unreachable!(),
+            AggregateMeta::HashTable(_) => unreachable!(),
+            AggregateMeta::Serialized(_) => unreachable!(),
+            AggregateMeta::Spilled(payload) => {
+                let columns = Self::read_columns(operator, payload).await?;
+                Ok((block_meta, VecDeque::from(vec![columns])))
+            }
+            AggregateMeta::Partitioned { data, .. }
+            | AggregateMeta::PartiallyRestored { data, .. } => {
+                let mut read_data = Vec::with_capacity(data.len());
+                for meta in data {
+                    if let AggregateMeta::Spilled(payload) = meta {
+                        let payload = payload.clone();
+                        let operator = operator.clone();
+                        read_data.push(common_base::base::tokio::spawn(async move {
+                            Self::read_columns(operator, &payload).await
+                        }));
+                    }
+                }
+
+                match futures::future::try_join_all(read_data).await {
+                    Err(_) => Err(ErrorCode::TokioError("Cannot join tokio job")),
+                    Ok(read_data) => {
+                        let read_data: Result<VecDeque<Vec<Column>>> =
+                            read_data.into_iter().try_collect();
+                        Ok((block_meta, read_data?))
+                    }
+                }
+            }
+        }
+    }
+
+    /// Reads and deserializes the columns of a spill file, then deletes it.
+    ///
+    /// Plain spill files are read by ranges of adjacent columns. Encrypted spill files are
+    /// authenticated as a whole, so they are fully buffered.
+    async fn read_columns(operator: Operator, payload: &SpilledPayload) -> Result<Vec<Column>> {
+        let instant = Instant::now();
+        let columns = match &payload.encryption {
+            None => {
+                let mut reader = SpilledColumnsReader::create(
+                    operator.clone(),
+                    payload.location.clone(),
+                    payload.columns_layout.clone(),
+                );
+                reader.read_columns().await?
+            }
+            Some(encryption) => {
+                let data = operator.read(&payload.location).await?;
+                let data = encryption.decrypt(&data)?;
+                deserialize_spilled_columns(&payload.location, &data, &payload.columns_layout)?
+            }
+        };
+
+        if let Err(cause) = operator.delete(&payload.location).await {
+            error!(
+                "Cannot delete spill file {}, cause: {:?}",
+                &payload.location, cause
+            );
+        }
+
+        info!(
+            "Read aggregate spill {} successfully, elapsed: {:?}",
+            &payload.location,
+            instant.elapsed()
+        );
+
+        Ok(columns)
+    }
+
+    fn deserialize_partitioned(
+        data: Vec<AggregateMeta<Method, V>>,
+        read_data: &mut VecDeque<Vec<Column>>,
+    ) -> Vec<AggregateMeta<Method, V>> {
+        let mut new_data = Vec::with_capacity(data.len());
+
+        for meta in data {
+            if matches!(&meta, AggregateMeta::Spilled(_)) {
+                if let AggregateMeta::Spilled(payload) = meta {
+                    let columns = read_data.pop_front().unwrap();
+                    new_data.push(Self::deserialize(payload, columns));
+                }
+
+                continue;
+            }
+
+            new_data.push(meta);
+        }
+
+        new_data
+    }
 
+    fn deserialize(payload: SpilledPayload, columns: Vec<Column>) -> AggregateMeta<Method, V> {
         AggregateMeta::<Method, V>::Serialized(SerializedPayload {
             bucket: payload.bucket,
             data_block: DataBlock::new_from_columns(columns),
@@ -41,6 +41,15 @@ use crate::pipelines::processors::AggregatorParams;
 pub struct TransformFinalAggregate<Method: HashMethodBounds> {
     method: Method,
     params: Arc<AggregatorParams>,
+    // The bucket being merged, which is restored from the spill files part by part.
+    merging: Option<MergingBucket<Method>>,
+}
+
+struct MergingBucket<Method: HashMethodBounds> {
+    bucket: isize,
+    hash_cell: HashTableCell<Method, usize>,
+    temp_place: StateAddr,
+    reach_limit: bool,
 }
 
 impl<Method: HashMethodBounds> TransformFinalAggregate<Method> {
@@ -53,7 +62,11 @@ impl<Method: HashMethodBounds> TransformFinalAggregate<Method> {
         Ok(ProcessorPtr::create(BlockMetaTransformer::create(
             input,
             output,
-            TransformFinalAggregate::<Method> { method, params },
+            TransformFinalAggregate::<Method> {
+                method,
+                params,
+                merging: None,
+            },
         )))
     }
 }
@@ -64,184 +77,223 @@ where Method: HashMethodBounds
     const NAME: &'static str = "TransformFinalAggregate";
 
     fn transform(&mut self, meta: AggregateMeta<Method, usize>) -> Result<DataBlock> {
-        if let AggregateMeta::Partitioned { bucket, data } = meta {
-            let mut reach_limit = false;
-            let hashtable = self.method.create_hash_table::<usize>()?;
-            let _dropper = AggregateHashTableDropper::create(self.params.clone());
-            let mut hash_cell = HashTableCell::<Method, usize>::create(hashtable, _dropper);
-            let temp_place = self.params.alloc_layout(&mut hash_cell.arena);
-            hash_cell.temp_values.push(temp_place.addr());
-
-            for bucket_data in data {
-                match bucket_data {
-                    AggregateMeta::Spilled(_) => unreachable!(),
-                    AggregateMeta::Spilling(_) => unreachable!(),
-                    AggregateMeta::Partitioned { .. } => unreachable!(),
-                    AggregateMeta::Serialized(payload) => {
-                        debug_assert!(bucket == payload.bucket);
-
-                        let aggregate_function_len = self.params.aggregate_functions.len();
-
-                        let column = payload.get_group_by_column();
-                        let keys_iter = self.method.keys_iter_from_column(column)?;
-
-                        // first state places of current block
-                        let places = {
-                            let keys_iter = keys_iter.iter();
-                            let (len, _) = keys_iter.size_hint();
-                            let mut places = Vec::with_capacity(len);
-
-                            let mut current_len = hash_cell.hashtable.len();
-                            unsafe {
-                                for (row, key) in keys_iter.enumerate() {
-                                    if reach_limit {
-                                        let entry = hash_cell.hashtable.entry(key);
-                                        if let Some(entry) = entry {
-                                            let place = Into::<StateAddr>::into(*entry.get());
-                                            places.push((row, place));
-                                        }
-                                        continue;
+        match meta {
+            AggregateMeta::PartiallyRestored { bucket, data } => {
+                self.merge_bucket(bucket, data)?;
+                // The bucket is not complete yet, nothing to output.
+                let empty = self.create_merging_bucket(bucket)?;
+                self.finish_bucket(empty)
+            }
+            AggregateMeta::Partitioned { bucket, data } => {
+                self.merge_bucket(bucket, data)?;
+                let merging = self.merging.take().unwrap();
+                self.finish_bucket(merging)
+            }
+            _ => Err(ErrorCode::Internal(
+                "TransformFinalAggregate only recv AggregateMeta::Partitioned",
+            )),
+        }
+    }
+}
+
+impl<Method: HashMethodBounds> TransformFinalAggregate<Method> {
+    fn create_merging_bucket(&self, bucket: isize) -> Result<MergingBucket<Method>> {
+        let hashtable = self.method.create_hash_table::<usize>()?;
+        let _dropper = AggregateHashTableDropper::create(self.params.clone());
+        let mut hash_cell = HashTableCell::<Method, usize>::create(hashtable, _dropper);
+        let temp_place = self.params.alloc_layout(&mut hash_cell.arena);
+        hash_cell.temp_values.push(temp_place.addr());
+
+        Ok(MergingBucket {
+            bucket,
+            hash_cell,
+            temp_place,
+            reach_limit: false,
+        })
+    }
+
+    fn merge_bucket(
+        &mut self,
+        bucket: isize,
+        data: Vec<AggregateMeta<Method, usize>>,
+    ) -> Result<()> {
+        if self.merging.is_none() {
+            self.merging = Some(self.create_merging_bucket(bucket)?);
+        }
+
+        let merging = self.merging.as_mut().unwrap();
+        debug_assert!(bucket == merging.bucket);
+        let hash_cell = &mut merging.hash_cell;
+        let temp_place = merging.temp_place;
+        let reach_limit = &mut merging.reach_limit;
+
+        for bucket_data in data {
+            match bucket_data {
+                AggregateMeta::Spilled(_) => unreachable!(),
+                AggregateMeta::Spilling(_) => unreachable!(),
+                AggregateMeta::Partitioned { .. } => unreachable!(),
+                AggregateMeta::PartiallyRestored { .. } => unreachable!(),
+                AggregateMeta::Serialized(payload) => {
+                    debug_assert!(bucket == payload.bucket);
+
+                    let aggregate_function_len = self.params.aggregate_functions.len();
+
+                    let column = payload.get_group_by_column();
+                    let keys_iter = self.method.keys_iter_from_column(column)?;
+
+                    // first state places of current block
+                    let places = {
+                        let keys_iter = keys_iter.iter();
+                        let (len, _) = keys_iter.size_hint();
+                        let mut places = Vec::with_capacity(len);
+
+                        let mut current_len = hash_cell.hashtable.len();
+                        unsafe {
+                            for (row, key) in keys_iter.enumerate() {
+                                if *reach_limit {
+                                    let entry = hash_cell.hashtable.entry(key);
+                                    if let Some(entry) = entry {
+                                        let place = Into::<StateAddr>::into(*entry.get());
+                                        places.push((row, place));
                                     }
+                                    continue;
+                                }
 
-                                    match hash_cell.hashtable.insert_and_entry(key) {
-                                        Ok(mut entry) => {
-                                            let place =
-                                                self.params.alloc_layout(&mut hash_cell.arena);
-                                            places.push((row, place));
+                                match hash_cell.hashtable.insert_and_entry(key) {
+                                    Ok(mut entry) => {
+                                        let place = self.params.alloc_layout(&mut hash_cell.arena);
+                                        places.push((row, place));
 
-                                            *entry.get_mut() = place.addr();
+                                        *entry.get_mut() = place.addr();
 
-                                            if let Some(limit) = self.params.limit {
-                                                current_len += 1;
-                                                if current_len >= limit {
-                                                    reach_limit = true;
-                                                }
+                                        if let Some(limit) = self.params.limit {
+                                            current_len += 1;
+                                            if current_len >= limit {
+                                                *reach_limit = true;
                                             }
                                         }
-                                        Err(entry) => {
-                                            let place = Into::<StateAddr>::into(*entry.get());
-                                            places.push((row, place));
-                                        }
+                                    }
+                                    Err(entry) => {
+                                        let place = Into::<StateAddr>::into(*entry.get());
+                                        places.push((row, place));
                                     }
                                 }
                             }
-
-                            places
-                        };
-
-                        let states_columns = (0..aggregate_function_len)
-                            .map(|i| payload.data_block.get_by_offset(i))
-                            .collect::<Vec<_>>();
-                        let mut states_binary_columns = Vec::with_capacity(states_columns.len());
-
-                        for agg in states_columns.iter().take(aggregate_function_len) {
-                            let aggr_column =
-                                agg.value.as_column().unwrap().as_string().ok_or_else(|| {
-                                    ErrorCode::IllegalDataType(format!(
-                                        "Aggregation column should be StringType, but got {:?}",
-                                        agg.value
-                                    ))
-                                })?;
-                            states_binary_columns.push(aggr_column);
                         }
 
-                        let aggregate_functions = &self.params.aggregate_functions;
-                        let offsets_aggregate_states = &self.params.offsets_aggregate_states;
+                        places
+                    };
+
+                    let states_columns = (0..aggregate_function_len)
+                        .map(|i| payload.data_block.get_by_offset(i))
+                        .collect::<Vec<_>>();
+                    let mut states_binary_columns = Vec::with_capacity(states_columns.len());
+
+                    for agg in states_columns.iter().take(aggregate_function_len) {
+                        let aggr_column =
+                            agg.value.as_column().unwrap().as_string().ok_or_else(|| {
+                                ErrorCode::IllegalDataType(format!(
+                                    "Aggregation column should be StringType, but got {:?}",
+                                    agg.value
+                                ))
+                            })?;
+                        states_binary_columns.push(aggr_column);
+                    }
 
-                        for (row, place) in places.iter() {
-                            for (idx, aggregate_function) in aggregate_functions.iter().enumerate()
-                            {
-                                let final_place = place.next(offsets_aggregate_states[idx]);
-                                let state_place = temp_place.next(offsets_aggregate_states[idx]);
+                    let aggregate_functions = &self.params.aggregate_functions;
+                    let offsets_aggregate_states = &self.params.offsets_aggregate_states;
 
-                                let mut data =
-                                    unsafe { states_binary_columns[idx].index_unchecked(*row) };
-                                aggregate_function.deserialize(state_place, &mut data)?;
-                                aggregate_function.merge(final_place, state_place)?;
-                            }
+                    for (row, place) in places.iter() {
+                        for (idx, aggregate_function) in aggregate_functions.iter().enumerate() {
+                            let final_place = place.next(offsets_aggregate_states[idx]);
+                            let state_place = temp_place.next(offsets_aggregate_states[idx]);
+
+                            let mut data =
+                                unsafe { states_binary_columns[idx].index_unchecked(*row) };
+                            aggregate_function.deserialize(state_place, &mut data)?;
+                            aggregate_function.merge(final_place, state_place)?;
                         }
                     }
-                    AggregateMeta::HashTable(payload) => unsafe {
-                        debug_assert!(bucket == payload.bucket);
-
-                        let aggregate_functions = &self.params.aggregate_functions;
-                        let offsets_aggregate_states = &self.params.offsets_aggregate_states;
-
-                        for entry in payload.cell.hashtable.iter() {
-                            let place = match hash_cell.hashtable.insert(entry.key()) {
-                                Err(place) => StateAddr::new(*place),
-                                Ok(entry) => {
-                                    let place = self.params.alloc_layout(&mut hash_cell.arena);
-                                    entry.write(place.addr());
-                                    place
-                                }
-                            };
-
-                            let old_place = StateAddr::new(*entry.get());
-                            for (idx, aggregate_function) in aggregate_functions.iter().enumerate()
-                            {
-                                let final_place = place.next(offsets_aggregate_states[idx]);
-                                let state_place = old_place.next(offsets_aggregate_states[idx]);
-                                aggregate_function.merge(final_place, state_place)?;
+                }
+                AggregateMeta::HashTable(payload) => unsafe {
+                    debug_assert!(bucket == payload.bucket);
+
+                    let aggregate_functions = &self.params.aggregate_functions;
+                    let offsets_aggregate_states = &self.params.offsets_aggregate_states;
+
+                    for entry in payload.cell.hashtable.iter() {
+                        let place = match hash_cell.hashtable.insert(entry.key()) {
+                            Err(place) => StateAddr::new(*place),
+                            Ok(entry) => {
+                                let place = self.params.alloc_layout(&mut hash_cell.arena);
+                                entry.write(place.addr());
+                                place
                             }
+                        };
+
+                        let old_place = StateAddr::new(*entry.get());
+                        for (idx, aggregate_function) in aggregate_functions.iter().enumerate() {
+                            let final_place = place.next(offsets_aggregate_states[idx]);
+                            let state_place = old_place.next(offsets_aggregate_states[idx]);
+                            aggregate_function.merge(final_place, state_place)?;
                         }
-                    },
-                }
+                    }
+                },
             }
+        }
 
-            let keys_len = hash_cell.hashtable.len();
-            let value_size = estimated_key_size(&hash_cell.hashtable);
+        Ok(())
+    }
 
-            let mut group_columns_builder =
-                self.method
-                    .group_columns_builder(keys_len, value_size, &self.params);
+    fn finish_bucket(&self, merging: MergingBucket<Method>) -> Result<DataBlock> {
+        let hash_cell = merging.hash_cell;
+        let keys_len = hash_cell.hashtable.len();
+        let value_size = estimated_key_size(&hash_cell.hashtable);
 
-            let aggregate_functions = &self.params.aggregate_functions;
-            let offsets_aggregate_states = &self.params.offsets_aggregate_states;
+        let mut group_columns_builder =
+            self.method
+                .group_columns_builder(keys_len, value_size, &self.params);
 
-            let mut aggregates_column_builder = {
-                let mut values = vec![];
-                for aggregate_function in aggregate_functions {
-                    let data_type = aggregate_function.return_type()?;
-                    let builder = ColumnBuilder::with_capacity(&data_type, keys_len);
-                    values.push(builder)
-                }
-                values
-            };
+        let aggregate_functions = &self.params.aggregate_functions;
+        let offsets_aggregate_states = &self.params.offsets_aggregate_states;
 
-            let mut places = Vec::with_capacity(keys_len);
-            for group_entity in hash_cell.hashtable.iter() {
-                places.push(StateAddr::new(*group_entity.get()));
-                group_columns_builder.append_value(group_entity.key());
+        let mut aggregates_column_builder = {
+            let mut values = vec![];
+            for aggregate_function in aggregate_functions {
+                let data_type = aggregate_function.return_type()?;
+                let builder = ColumnBuilder::with_capacity(&data_type, keys_len);
+                values.push(builder)
             }
+            values
+        };
 
-            for (idx, aggregate_function) in aggregate_functions.iter().enumerate() {
-                let builder = aggregates_column_builder[idx].borrow_mut();
+        let mut places = Vec::with_capacity(keys_len);
+        for group_entity in hash_cell.hashtable.iter() {
+            places.push(StateAddr::new(*group_entity.get()));
+            group_columns_builder.append_value(group_entity.key());
+        }
 
-                if idx > 0 {
-                    for place in places.iter_mut() {
-                        *place = place.next(
-                            offsets_aggregate_states[idx] - offsets_aggregate_states[idx - 1],
-                        );
-                    }
+        for (idx, aggregate_function) in aggregate_functions.iter().enumerate() {
+            let builder = aggregates_column_builder[idx].borrow_mut();
+
+            if idx > 0 {
+                for place in places.iter_mut() {
+                    *place = place
+                        .next(offsets_aggregate_states[idx] - offsets_aggregate_states[idx - 1]);
                 }
-                aggregate_function.batch_merge_result(&places, builder)?;
             }
+            aggregate_function.batch_merge_result(&places, builder)?;
+        }
 
-            // Build final state block.
-            let mut columns = aggregates_column_builder
-                .into_iter()
-                .map(|builder| builder.build())
-                .collect::<Vec<_>>();
-
-            let group_columns = group_columns_builder.finish()?;
-            columns.extend_from_slice(&group_columns);
+        // Build final state block.
+        let mut columns = aggregates_column_builder
+            .into_iter()
+            .map(|builder| builder.build())
+            .collect::<Vec<_>>();
 
-            return Ok(DataBlock::new_from_columns(columns));
-        }
+        let group_columns = group_columns_builder.finish()?;
+        columns.extend_from_slice(&group_columns);
 
-        Err(ErrorCode::Internal(
-            "TransformFinalAggregate only recv AggregateMeta::Partitioned",
-        ))
+        Ok(DataBlock::new_from_columns(columns))
     }
 }
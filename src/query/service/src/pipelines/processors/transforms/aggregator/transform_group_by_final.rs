@@ -35,6 +35,8 @@ use crate::pipelines::processors::AggregatorParams;
 pub struct TransformFinalGroupBy<Method: HashMethodBounds> {
     method: Method,
     params: Arc<AggregatorParams>,
+    // The bucket being merged, which is restored from the spill files part by part.
+    merging: Option<(isize, Method::HashTable<()>)>,
 }
 
 impl<Method: HashMethodBounds> TransformFinalGroupBy<Method> {
@@ -47,7 +49,11 @@ impl<Method: HashMethodBounds> TransformFinalGroupBy<Method> {
         Ok(ProcessorPtr::create(BlockMetaTransformer::create(
             input,
             output,
-            TransformFinalGroupBy::<Method> { method, params },
+            TransformFinalGroupBy::<Method> {
+                method,
+                params,
+                merging: None,
+            },
         )))
     }
 }
@@ -58,35 +64,48 @@ where Method: HashMethodBounds
     const NAME: &'static str = "TransformFinalGroupBy";
 
     fn transform(&mut self, meta: AggregateMeta<Method, ()>) -> Result<DataBlock> {
-        if let AggregateMeta::Partitioned { bucket, data } = meta {
-            let mut hashtable = self.method.create_hash_table::<()>()?;
-            'merge_hashtable: for bucket_data in data {
-                match bucket_data {
-                    AggregateMeta::Spilled(_) => unreachable!(),
-                    AggregateMeta::Spilling(_) => unreachable!(),
-                    AggregateMeta::Partitioned { .. } => unreachable!(),
-                    AggregateMeta::Serialized(payload) => {
-                        debug_assert!(bucket == payload.bucket);
-                        let column = payload.get_group_by_column();
-                        let keys_iter = self.method.keys_iter_from_column(column)?;
-
-                        unsafe {
-                            for key in keys_iter.iter() {
-                                let _ = hashtable.insert_and_entry(key);
-                            }
+        match meta {
+            AggregateMeta::PartiallyRestored { bucket, data } => {
+                self.merge_bucket(bucket, data)?;
+                // The bucket is not complete yet, nothing to output.
+                let empty = self.method.create_hash_table::<()>()?;
+                self.finish_bucket(empty)
+            }
+            AggregateMeta::Partitioned { bucket, data } => {
+                self.merge_bucket(bucket, data)?;
+                let (_, hashtable) = self.merging.take().unwrap();
+                self.finish_bucket(hashtable)
+            }
+            _ => Err(ErrorCode::Internal(
+                "TransformFinalGroupBy only recv AggregateMeta::Partitioned",
+            )),
+        }
+    }
+}
 
-                            if let Some(limit) = self.params.limit {
-                                if hashtable.len() >= limit {
-                                    break 'merge_hashtable;
-                                }
-                            }
-                        }
-                    }
-                    AggregateMeta::HashTable(payload) => unsafe {
-                        debug_assert!(bucket == payload.bucket);
+impl<Method: HashMethodBounds> TransformFinalGroupBy<Method> {
+    fn merge_bucket(&mut self, bucket: isize, data: Vec<AggregateMeta<Method, ()>>) -> Result<()> {
+        if self.merging.is_none() {
+            self.merging = Some((bucket, self.method.create_hash_table::<()>()?));
+        }
 
-                        for key in payload.cell.hashtable.iter() {
-                            let _ = hashtable.insert_and_entry(key.key());
+        let (merging_bucket, hashtable) = self.merging.as_mut().unwrap();
+        debug_assert!(bucket == *merging_bucket);
+
+        'merge_hashtable: for bucket_data in data {
+            match bucket_data {
+                AggregateMeta::Spilled(_) => unreachable!(),
+                AggregateMeta::Spilling(_) => unreachable!(),
+                AggregateMeta::Partitioned { .. } => unreachable!(),
+                AggregateMeta::PartiallyRestored { .. } => unreachable!(),
+                AggregateMeta::Serialized(payload) => {
+                    debug_assert!(bucket == payload.bucket);
+                    let column = payload.get_group_by_column();
+                    let keys_iter = self.method.keys_iter_from_column(column)?;
+
+                    unsafe {
+                        for key in keys_iter.iter() {
+                            let _ = hashtable.insert_and_entry(key);
                         }
 
                         if let Some(limit) = self.params.limit {
@@ -94,26 +113,39 @@ where Method: HashMethodBounds
                                 break 'merge_hashtable;
                             }
                         }
-                    },
+                    }
                 }
+                AggregateMeta::HashTable(payload) => unsafe {
+                    debug_assert!(bucket == payload.bucket);
+
+                    for key in payload.cell.hashtable.iter() {
+                        let _ = hashtable.insert_and_entry(key.key());
+                    }
+
+                    if let Some(limit) = self.params.limit {
+                        if hashtable.len() >= limit {
+                            break 'merge_hashtable;
+                        }
+                    }
+                },
             }
+        }
 
-            let value_size = estimated_key_size(&hashtable);
-            let keys_len = hashtable.len();
+        Ok(())
+    }
 
-            let mut group_columns_builder =
-                self.method
-                    .group_columns_builder(keys_len, value_size, &self.params);
+    fn finish_bucket(&self, hashtable: Method::HashTable<()>) -> Result<DataBlock> {
+        let value_size = estimated_key_size(&hashtable);
+        let keys_len = hashtable.len();
 
-            for group_entity in hashtable.iter() {
-                group_columns_builder.append_value(group_entity.key());
-            }
+        let mut group_columns_builder =
+            self.method
+                .group_columns_builder(keys_len, value_size, &self.params);
 
-            return Ok(DataBlock::new_from_columns(group_columns_builder.finish()?));
+        for group_entity in hashtable.iter() {
+            group_columns_builder.append_value(group_entity.key());
         }
 
-        Err(ErrorCode::Internal(
-            "TransformFinalGroupBy only recv AggregateMeta::Partitioned",
-        ))
+        Ok(DataBlock::new_from_columns(group_columns_builder.finish()?))
     }
 }
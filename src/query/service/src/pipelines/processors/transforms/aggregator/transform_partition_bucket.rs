@@ -146,6 +146,7 @@ impl<Method: HashMethodBounds, V: Copy + Send + Sync + 'static>
                 let (bucket, res) = match block_meta {
                     AggregateMeta::Spilling(_) => unreachable!(),
                     AggregateMeta::Partitioned { .. } => unreachable!(),
+                    AggregateMeta::PartiallyRestored { .. } => unreachable!(),
                     AggregateMeta::Spilled(payload) => (payload.bucket, SINGLE_LEVEL_BUCKET_NUM),
                     AggregateMeta::Serialized(payload) => (payload.bucket, payload.bucket),
                     AggregateMeta::HashTable(payload) => (payload.bucket, payload.bucket),
@@ -379,6 +380,7 @@ impl<Method: HashMethodBounds, V: Copy + Send + Sync + 'static> Processor
                     AggregateMeta::Spilled(_) => unreachable!(),
                     AggregateMeta::Spilling(_) => unreachable!(),
                     AggregateMeta::Partitioned { .. } => unreachable!(),
+                    AggregateMeta::PartiallyRestored { .. } => unreachable!(),
                     AggregateMeta::Serialized(payload) => self.partition_block(payload)?,
                     AggregateMeta::HashTable(payload) => self.partition_hashtable(payload)?,
                 };
@@ -406,6 +408,7 @@ pub fn build_partition_bucket<Method: HashMethodBounds, V: Copy + Send + Sync +
     method: Method,
     pipeline: &mut Pipeline,
     params: Arc<AggregatorParams>,
+    prefetch_depth: usize,
 ) -> Result<()> {
     let input_nums = pipeline.output_len();
     let transform = TransformPartitionBucket::<Method, V>::create(method.clone(), input_nums)?;
@@ -425,8 +428,18 @@ pub fn build_partition_bucket<Method: HashMethodBounds, V: Copy + Send + Sync +
     pipeline.add_transform(|input, output| {
         let operator = operator.clone();
         match params.aggregate_functions.is_empty() {
-            true => TransformGroupBySpillReader::<Method>::create(input, output, operator),
-            false => TransformAggregateSpillReader::<Method>::create(input, output, operator),
+            true => TransformGroupBySpillReader::<Method>::create(
+                input,
+                output,
+                operator,
+                prefetch_depth,
+            ),
+            false => TransformAggregateSpillReader::<Method>::create(
+                input,
+                output,
+                operator,
+                prefetch_depth,
+            ),
         }
     })?;
 
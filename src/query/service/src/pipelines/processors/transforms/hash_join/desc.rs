@@ -62,6 +62,8 @@ impl JoinState {
 pub struct HashJoinDesc {
     pub(crate) build_keys: Vec<Expr>,
     pub(crate) probe_keys: Vec<Expr>,
+    /// The indexes of the null-safe equal keys, whose NULLs are probed as other values.
+    pub(crate) is_null_equal: Vec<usize>,
     pub(crate) join_type: JoinType,
     pub(crate) other_predicate: Option<Expr>,
     pub(crate) marker_join_desc: MarkJoinDesc,
@@ -89,6 +91,7 @@ impl HashJoinDesc {
             join_type: join.join_type.clone(),
             build_keys,
             probe_keys,
+            is_null_equal: join.is_null_equal.clone(),
             other_predicate,
             marker_join_desc: MarkJoinDesc {
                 has_null: RwLock::new(false),
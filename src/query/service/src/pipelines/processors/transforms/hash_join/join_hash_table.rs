@@ -269,7 +269,11 @@ impl JoinHashTable {
             .any(|(_, ty)| ty.is_nullable() || ty.is_null())
         {
             let mut valids = None;
-            for (col, _) in probe_keys.iter() {
+            for (idx, (col, _)) in probe_keys.iter().enumerate() {
+                // The NULLs of a null-safe equal key are encoded into the hash keys as well.
+                if self.hash_join_desc.is_null_equal.contains(&idx) {
+                    continue;
+                }
                 let (is_all_null, tmp_valids) = col.validity();
                 if is_all_null {
                     let mut m = MutableBitmap::with_capacity(input.num_rows());
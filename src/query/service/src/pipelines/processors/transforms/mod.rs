@@ -21,6 +21,7 @@ mod transform_hash_join;
 mod transform_left_join;
 mod transform_limit;
 mod transform_mark_join;
+mod transform_materialized_cte;
 
 mod profile_wrapper;
 mod runtime_filter;
@@ -31,14 +32,21 @@ mod transform_right_join;
 mod transform_right_semi_anti_join;
 mod transform_runtime_cast_schema;
 mod transform_runtime_filter;
+mod transform_window;
 
 pub use aggregator::build_partition_bucket;
+pub use aggregator::spill_operator;
 pub use aggregator::AggregateInjector;
 pub use aggregator::AggregatorParams;
 pub use aggregator::FinalSingleStateAggregator;
 pub use aggregator::HashTableCell;
 pub use aggregator::PartialSingleStateAggregator;
 pub use aggregator::PartitionedHashTableDropper;
+pub use aggregator::SpillEncryption;
+pub use aggregator::SpilledColumnsReader;
+pub use aggregator::SpillLocations;
+pub use aggregator::SpillPrefetcher;
+pub use aggregator::SpillStrategy;
 pub use aggregator::TransformAggregateDeserializer;
 pub use aggregator::TransformAggregateSerializer;
 pub use aggregator::TransformAggregateSpillReader;
@@ -62,6 +70,7 @@ pub use hash_join::HashJoinState;
 pub use hash_join::HashTable;
 pub use hash_join::JoinHashTable;
 pub use hash_join::SerializerHashTable;
+pub use profile_wrapper::ProfileOutputStats;
 pub use profile_wrapper::ProfileWrapper;
 pub use runtime_filter::RuntimeFilterState;
 pub use transform_add_const_columns::TransformAddConstColumns;
@@ -79,6 +88,9 @@ pub use transform_left_join::TransformLeftJoin;
 pub use transform_limit::TransformLimit;
 pub use transform_mark_join::MarkJoinCompactor;
 pub use transform_mark_join::TransformMarkJoin;
+pub use transform_materialized_cte::MaterializedCteSink;
+pub use transform_materialized_cte::MaterializedCteSource;
+pub use transform_materialized_cte::MaterializedCteState;
 pub use transform_merge_block::TransformMergeBlock;
 pub use transform_resort_addon::TransformResortAddOn;
 pub use transform_right_join::RightJoinCompactor;
@@ -90,3 +102,4 @@ pub use transform_runtime_filter::SinkRuntimeFilterSource;
 pub use transform_runtime_filter::TransformRuntimeFilter;
 pub use transform_sort_merge::SortMergeCompactor;
 pub use transform_sort_partial::TransformSortPartial;
+pub use transform_window::TransformWindow;
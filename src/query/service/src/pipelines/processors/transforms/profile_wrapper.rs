@@ -12,11 +12,20 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
 use std::time::Instant;
 
+use common_base::runtime::MemStat;
+use common_base::runtime::ThreadTracker;
 use common_exception::Result;
+use common_expression::DataBlock;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::port::OutputPort;
 use common_pipeline_core::processors::processor::Event;
+use common_pipeline_core::processors::processor::ProcessorPtr;
 use common_pipeline_core::processors::Processor;
+use common_pipeline_transforms::processors::transforms::Transform;
+use common_pipeline_transforms::processors::transforms::Transformer;
 use common_profile::ProfSpanBuilder;
 use common_profile::ProfSpanSetRef;
 
@@ -25,6 +34,9 @@ pub struct ProfileWrapper<T> {
     prof_span_id: u32,
     prof_span_set: ProfSpanSetRef,
     prof_span_builder: ProfSpanBuilder,
+    // The memory allocated while processing, created on the first process
+    // to be a child of the memory stat of the query.
+    mem_stat: Option<Arc<MemStat>>,
 }
 
 impl<T> ProfileWrapper<T>
@@ -40,8 +52,16 @@ where T: Processor + 'static
             prof_span_id,
             prof_span_set,
             prof_span_builder: ProfSpanBuilder::default(),
+            mem_stat: None,
         })
     }
+
+    fn mem_stat(&mut self) -> Option<Arc<MemStat>> {
+        if self.mem_stat.is_none() {
+            self.mem_stat = Some(MemStat::create(self.inner.name()));
+        }
+        self.mem_stat.clone()
+    }
 }
 
 #[async_trait::async_trait]
@@ -59,6 +79,11 @@ where T: Processor + 'static
     fn event(&mut self) -> Result<Event> {
         match self.inner.event()? {
             Event::Finished => {
+                if let Some(mem_stat) = &self.mem_stat {
+                    let peak_memory_usage = mem_stat.get_peak_memory_usage().max(0) as usize;
+                    self.prof_span_builder
+                        .update_peak_memory_usage(peak_memory_usage);
+                }
                 self.prof_span_set
                     .lock()
                     .unwrap()
@@ -70,6 +95,7 @@ where T: Processor + 'static
     }
 
     fn process(&mut self) -> Result<()> {
+        let _guard = ThreadTracker::enter(self.mem_stat());
         let instant = Instant::now();
         self.inner.process()?;
         let elapsed = instant.elapsed();
@@ -83,3 +109,46 @@ where T: Processor + 'static
         self.inner.async_process().await
     }
 }
+
+/// Records the rows and bytes produced by the plan node of `prof_span_id`.
+///
+/// It's appended to the output of each profiled plan node, since the output of a
+/// processor is not visible to the `ProfileWrapper`.
+pub struct ProfileOutputStats {
+    prof_span_id: u32,
+    prof_span_set: ProfSpanSetRef,
+    prof_span_builder: ProfSpanBuilder,
+}
+
+impl ProfileOutputStats {
+    pub fn create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        prof_span_id: u32,
+        prof_span_set: ProfSpanSetRef,
+    ) -> ProcessorPtr {
+        ProcessorPtr::create(Transformer::create(input, output, Self {
+            prof_span_id,
+            prof_span_set,
+            prof_span_builder: ProfSpanBuilder::default(),
+        }))
+    }
+}
+
+impl Transform for ProfileOutputStats {
+    const NAME: &'static str = "ProfileOutputStats";
+
+    fn transform(&mut self, data: DataBlock) -> Result<DataBlock> {
+        self.prof_span_builder
+            .accumulate_output(data.num_rows(), data.memory_size());
+        Ok(data)
+    }
+
+    fn on_finish(&mut self) -> Result<()> {
+        self.prof_span_set.lock().unwrap().update(
+            self.prof_span_id,
+            std::mem::take(&mut self.prof_span_builder).finish(),
+        );
+        Ok(())
+    }
+}
@@ -18,6 +18,7 @@ use std::sync::Arc;
 
 use common_arrow::arrow::bitmap::MutableBitmap;
 use common_base::base::tokio::sync::Notify;
+use common_catalog::runtime_filter_info::RuntimeFilterInfo;
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
 use common_expression::DataBlock;
@@ -25,16 +26,23 @@ use common_expression::Evaluator;
 use common_expression::RemoteExpr;
 use common_functions::scalars::BUILTIN_FUNCTIONS;
 use common_sql::plans::RuntimeFilterId;
+use common_sql::IndexType;
 use parking_lot::Mutex;
 use parking_lot::RwLock;
 use storages_common_index::filters::Filter;
 use storages_common_index::filters::FilterBuilder;
 use storages_common_index::filters::Xor8Builder;
 use storages_common_index::filters::Xor8Filter;
+use storages_common_index::RuntimeBloomFilter;
+use storages_common_index::RuntimeBloomFilterBuilder;
 
 use crate::pipelines::processors::transforms::runtime_filter::RuntimeFilterConnector;
 use crate::sessions::QueryContext;
 
+// The keys of a runtime filter pushed down into a table scan are dropped if there are more
+// than this, then the blocks are only pruned by the range of the keys.
+const SCAN_RUNTIME_FILTER_MAX_KEYS: usize = 8192;
+
 pub struct RuntimeFilterState {
     pub(crate) ctx: Arc<QueryContext>,
     pub(crate) channel_filter_builders: RwLock<HashMap<RuntimeFilterId, Xor8Builder>>,
@@ -44,6 +52,10 @@ pub struct RuntimeFilterState {
     pub(crate) sinker_count: Mutex<usize>,
     pub(crate) finished_notify: Arc<Notify>,
     pub(crate) finished: Mutex<bool>,
+    // Runtime filters pushed down into the table scans of join probe side
+    pub(crate) scan_runtime_filters: BTreeMap<RuntimeFilterId, (IndexType, String)>,
+    pub(crate) scan_filter_builders: Mutex<HashMap<RuntimeFilterId, RuntimeBloomFilterBuilder>>,
+    pub(crate) scan_filter_infos: HashMap<IndexType, Arc<RuntimeFilterInfo>>,
 }
 
 impl RuntimeFilterState {
@@ -51,7 +63,28 @@ impl RuntimeFilterState {
         ctx: Arc<QueryContext>,
         left_runtime_filters: BTreeMap<RuntimeFilterId, RemoteExpr>,
         right_runtime_filters: BTreeMap<RuntimeFilterId, RemoteExpr>,
+        scan_runtime_filters: BTreeMap<RuntimeFilterId, (IndexType, String)>,
     ) -> Self {
+        let mut scan_filter_builders = HashMap::new();
+        let mut scan_filter_infos = HashMap::new();
+        for (id, (table_index, column_name)) in scan_runtime_filters.iter() {
+            let data_type = right_runtime_filters[id]
+                .as_expr(&BUILTIN_FUNCTIONS)
+                .data_type()
+                .clone();
+            scan_filter_builders.insert(
+                id.clone(),
+                RuntimeBloomFilterBuilder::create(
+                    column_name.clone(),
+                    &data_type,
+                    SCAN_RUNTIME_FILTER_MAX_KEYS,
+                ),
+            );
+            scan_filter_infos
+                .entry(*table_index)
+                .or_insert_with(|| Arc::new(RuntimeFilterInfo::default()));
+        }
+
         RuntimeFilterState {
             ctx,
             channel_filter_builders: Default::default(),
@@ -61,7 +94,28 @@ impl RuntimeFilterState {
             sinker_count: Mutex::new(0),
             finished_notify: Arc::new(Default::default()),
             finished: Default::default(),
+            scan_runtime_filters,
+            scan_filter_builders: Mutex::new(scan_filter_builders),
+            scan_filter_infos,
+        }
+    }
+
+    // Build the runtime filters of the table scans, and wake up the scans waiting for them.
+    fn finish_scan_filters(&self) -> Result<()> {
+        let func_ctx = self.ctx.get_function_context()?;
+        let mut scan_filters = HashMap::<IndexType, Vec<RuntimeBloomFilter>>::new();
+        for (id, builder) in std::mem::take(&mut *self.scan_filter_builders.lock()) {
+            let (table_index, _) = &self.scan_runtime_filters[&id];
+            scan_filters
+                .entry(*table_index)
+                .or_default()
+                .push(builder.build(func_ctx)?);
+        }
+
+        for (table_index, info) in self.scan_filter_infos.iter() {
+            info.set_filters(scan_filters.remove(table_index).unwrap_or_default());
         }
+        Ok(())
     }
 }
 
@@ -81,6 +135,7 @@ impl RuntimeFilterConnector for RuntimeFilterState {
             for (id, filter_builder) in channel_filter_builders.iter_mut() {
                 channel_filters.insert(id.clone(), filter_builder.build()?);
             }
+            self.finish_scan_filters()?;
             let mut finished = self.finished.lock();
             *finished = true;
             self.finished_notify.notify_waiters();
@@ -136,6 +191,10 @@ impl RuntimeFilterConnector for RuntimeFilterState {
                 .run(&expr)?
                 .convert_to_full_column(expr.data_type(), data.num_rows());
 
+            if let Some(builder) = self.scan_filter_builders.lock().get_mut(id) {
+                builder.add_column(&column);
+            }
+
             // Generate Xor8 filter by column
             let mut channel_filter_builders = self.channel_filter_builders.write();
             if let Some(filter_builder) = channel_filter_builders.get_mut(id) {
@@ -0,0 +1,361 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Instant;
+
+use common_base::base::tokio::sync::Notify;
+use common_base::runtime::GlobalIORuntime;
+use common_base::runtime::TrySpawn;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::arrow::serialize_column;
+use common_expression::DataBlock;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::Event;
+use common_pipeline_core::processors::Processor;
+use common_pipeline_sinks::AsyncSink;
+use common_pipeline_sinks::AsyncSinker;
+use opendal::Operator;
+use parking_lot::Mutex;
+use parking_lot::RwLock;
+use tracing::error;
+use tracing::info;
+
+use crate::pipelines::processors::transforms::aggregator::deserialize_spilled_columns;
+use crate::pipelines::processors::transforms::spill_operator;
+use crate::pipelines::processors::transforms::SpillEncryption;
+use crate::pipelines::processors::transforms::SpillLocations;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+enum MaterializedBlock {
+    Memory(DataBlock),
+    Spilled {
+        location: String,
+        columns_layout: Vec<usize>,
+        encryption: Option<SpillEncryption>,
+    },
+}
+
+/// The result of a materialized CTE, written by `MaterializedCteSink`s and read by
+/// `MaterializedCteSource`s once all the sinks are finished.
+///
+/// Blocks are kept in memory until their size exceeds `spilling_bytes_threshold_per_proc`,
+/// the following blocks are spilled to the storage.
+pub struct MaterializedCteState {
+    // Offsets of the CTE output columns in the input blocks
+    projections: Vec<usize>,
+    blocks: RwLock<Vec<MaterializedBlock>>,
+    memory_bytes: AtomicUsize,
+
+    spilling_bytes_threshold: usize,
+    operator: Option<Operator>,
+    spill_locations: Arc<SpillLocations>,
+
+    attached_sinks: Mutex<usize>,
+    is_finished: AtomicBool,
+    interrupt: AtomicBool,
+    finished_notify: Notify,
+}
+
+impl MaterializedCteState {
+    pub fn try_create(
+        ctx: &Arc<QueryContext>,
+        projections: Vec<usize>,
+    ) -> Result<Arc<MaterializedCteState>> {
+        let settings = ctx.get_settings();
+        let spilling_bytes_threshold = match settings.get_spilling_bytes_threshold_per_proc()? {
+            0 => usize::MAX,
+            value => value,
+        };
+
+        Ok(Arc::new(MaterializedCteState {
+            projections,
+            blocks: RwLock::new(vec![]),
+            memory_bytes: AtomicUsize::new(0),
+            spilling_bytes_threshold,
+            operator: spill_operator(),
+            spill_locations: SpillLocations::try_create(&settings, &ctx.get_tenant())?,
+            attached_sinks: Mutex::new(0),
+            is_finished: AtomicBool::new(false),
+            interrupt: AtomicBool::new(false),
+            finished_notify: Notify::new(),
+        }))
+    }
+
+    fn attach(&self) {
+        *self.attached_sinks.lock() += 1;
+    }
+
+    fn detach(&self) {
+        let mut attached_sinks = self.attached_sinks.lock();
+        *attached_sinks -= 1;
+        if *attached_sinks == 0 {
+            self.is_finished.store(true, Ordering::Release);
+            self.finished_notify.notify_waiters();
+        }
+    }
+
+    fn interrupt(&self) {
+        self.interrupt.store(true, Ordering::Release);
+        self.finished_notify.notify_waiters();
+    }
+
+    async fn append(&self, data_block: DataBlock) -> Result<()> {
+        let columns = self
+            .projections
+            .iter()
+            .map(|offset| data_block.get_by_offset(*offset).clone())
+            .collect();
+        let data_block = DataBlock::new(columns, data_block.num_rows());
+        let memory_size = data_block.memory_size();
+        let memory_bytes = self.memory_bytes.fetch_add(memory_size, Ordering::Relaxed);
+
+        let block = match &self.operator {
+            Some(operator) if memory_bytes + memory_size > self.spilling_bytes_threshold => {
+                self.memory_bytes.fetch_sub(memory_size, Ordering::Relaxed);
+                self.spill(operator, data_block).await?
+            }
+            _ => MaterializedBlock::Memory(data_block),
+        };
+
+        self.blocks.write().push(block);
+        Ok(())
+    }
+
+    async fn spill(&self, operator: &Operator, data_block: DataBlock) -> Result<MaterializedBlock> {
+        let instant = Instant::now();
+        let data_block = data_block.convert_to_full();
+
+        let mut columns_layout = Vec::with_capacity(data_block.num_columns());
+        let mut write_data = vec![];
+        for entry in data_block.columns() {
+            let column_data = serialize_column(entry.value.as_column().unwrap());
+            columns_layout.push(column_data.len());
+            write_data.extend(column_data);
+        }
+
+        let location = self.spill_locations.next_location(write_data.len())?;
+        let encryption = self.spill_locations.next_encryption();
+        if let Some(encryption) = &encryption {
+            write_data = encryption.encrypt(&write_data)?;
+        }
+
+        operator.write(&location, write_data).await?;
+
+        info!(
+            "Write materialized cte spill {} successfully, elapsed: {:?}",
+            location,
+            instant.elapsed()
+        );
+
+        Ok(MaterializedBlock::Spilled {
+            location,
+            columns_layout,
+            encryption,
+        })
+    }
+
+    async fn wait_finish(&self) -> Result<()> {
+        loop {
+            // Register the waiter before checking the flags, so that a notification
+            // between the check and the await is not missed.
+            let notified = self.finished_notify.notified();
+
+            if self.interrupt.load(Ordering::Acquire) {
+                return Err(ErrorCode::AbortedQuery(
+                    "Aborted query, because the materialized cte is interrupted.",
+                ));
+            }
+
+            if self.is_finished.load(Ordering::Acquire) {
+                return Ok(());
+            }
+
+            notified.await;
+        }
+    }
+
+    async fn read_block(&self, index: usize) -> Result<Option<DataBlock>> {
+        let (location, columns_layout, encryption) = match self.blocks.read().get(index) {
+            None => return Ok(None),
+            Some(MaterializedBlock::Memory(data_block)) => return Ok(Some(data_block.clone())),
+            Some(MaterializedBlock::Spilled {
+                location,
+                columns_layout,
+                encryption,
+            }) => (location.clone(), columns_layout.clone(), *encryption),
+        };
+
+        let operator = self.operator.as_ref().unwrap();
+        let mut data = operator.read(&location).await?;
+        if let Some(encryption) = &encryption {
+            data = encryption.decrypt(&data)?;
+        }
+
+        let columns = deserialize_spilled_columns(&location, &data, &columns_layout)?;
+        Ok(Some(DataBlock::new_from_columns(columns)))
+    }
+}
+
+impl Drop for MaterializedCteState {
+    // The spilled blocks are read by all the `CteScan`s of the CTE, so they are deleted
+    // once the state is released by the last of them.
+    fn drop(&mut self) {
+        let operator = match &self.operator {
+            Some(operator) => operator.clone(),
+            None => return,
+        };
+
+        let locations = self
+            .blocks
+            .get_mut()
+            .drain(..)
+            .filter_map(|block| match block {
+                MaterializedBlock::Memory(_) => None,
+                MaterializedBlock::Spilled { location, .. } => Some(location),
+            })
+            .collect::<Vec<_>>();
+        if locations.is_empty() {
+            return;
+        }
+
+        GlobalIORuntime::instance().spawn(async move {
+            for location in locations {
+                if let Err(cause) = operator.delete(&location).await {
+                    error!(
+                        "Cannot delete materialized cte spill {}, cause: {:?}",
+                        &location, cause
+                    );
+                }
+            }
+        });
+    }
+}
+
+/// Collect the blocks of the CTE query into the `MaterializedCteState`.
+pub struct MaterializedCteSink {
+    state: Arc<MaterializedCteState>,
+}
+
+impl MaterializedCteSink {
+    pub fn create(input: Arc<InputPort>, state: Arc<MaterializedCteState>) -> Box<dyn Processor> {
+        state.attach();
+        AsyncSinker::create(input, MaterializedCteSink { state })
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSink for MaterializedCteSink {
+    const NAME: &'static str = "MaterializedCteSink";
+
+    async fn on_finish(&mut self) -> Result<()> {
+        self.state.detach();
+        Ok(())
+    }
+
+    #[async_trait::unboxed_simple]
+    async fn consume(&mut self, data_block: DataBlock) -> Result<bool> {
+        self.state.append(data_block).await?;
+        Ok(false)
+    }
+}
+
+/// Read the blocks of a materialized CTE after it's finished.
+///
+/// The sources of the same `CteScan` share `next_block`, so each block is read once by
+/// one of them.
+pub struct MaterializedCteSource {
+    output: Arc<OutputPort>,
+    state: Arc<MaterializedCteState>,
+    next_block: Arc<AtomicUsize>,
+
+    is_ready: bool,
+    is_finished: bool,
+    output_data: Option<DataBlock>,
+}
+
+impl MaterializedCteSource {
+    pub fn create(
+        output: Arc<OutputPort>,
+        state: Arc<MaterializedCteState>,
+        next_block: Arc<AtomicUsize>,
+    ) -> Box<dyn Processor> {
+        Box::new(MaterializedCteSource {
+            output,
+            state,
+            next_block,
+            is_ready: false,
+            is_finished: false,
+            output_data: None,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for MaterializedCteSource {
+    fn name(&self) -> String {
+        "MaterializedCteSource".to_string()
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if self.output.is_finished() {
+            return Ok(Event::Finished);
+        }
+
+        if !self.output.can_push() {
+            return Ok(Event::NeedConsume);
+        }
+
+        if let Some(data_block) = self.output_data.take() {
+            self.output.push_data(Ok(data_block));
+            return Ok(Event::NeedConsume);
+        }
+
+        if self.is_finished {
+            self.output.finish();
+            return Ok(Event::Finished);
+        }
+
+        Ok(Event::Async)
+    }
+
+    fn interrupt(&self) {
+        self.state.interrupt()
+    }
+
+    async fn async_process(&mut self) -> Result<()> {
+        if !self.is_ready {
+            self.state.wait_finish().await?;
+            self.is_ready = true;
+        }
+
+        let index = self.next_block.fetch_add(1, Ordering::Relaxed);
+        match self.state.read_block(index).await? {
+            Some(data_block) => self.output_data = Some(data_block),
+            None => self.is_finished = true,
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,233 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use bumpalo::Bump;
+use common_exception::Result;
+use common_expression::BlockEntry;
+use common_expression::Column;
+use common_expression::ColumnBuilder;
+use common_expression::DataBlock;
+use common_expression::SortColumnDescription;
+use common_expression::Value;
+use common_functions::aggregates::AggregateFunctionRef;
+use common_functions::aggregates::StateAddr;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::Processor;
+use common_pipeline_transforms::processors::transforms::transform_accumulating::AccumulatingTransform;
+use common_pipeline_transforms::processors::transforms::transform_accumulating::AccumulatingTransformer;
+use common_sql::executor::WindowFrame;
+use common_sql::executor::WindowFrameBound;
+use common_sql::executor::WindowFrameUnits;
+
+/// Evaluate an aggregate function over a window frame for each input row.
+///
+/// All input blocks are buffered, then sorted by the partition keys and the
+/// order keys, so that every partition is a continuous range of rows. The
+/// output contains all input columns followed by the window function result.
+pub struct TransformWindow {
+    func: AggregateFunctionRef,
+    arguments: Vec<usize>,
+    partition_by: Vec<usize>,
+    order_by: Vec<usize>,
+    sort_desc: Vec<SortColumnDescription>,
+    frame: WindowFrame,
+
+    arena: Bump,
+    blocks: Vec<DataBlock>,
+}
+
+impl TransformWindow {
+    pub fn try_create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        func: AggregateFunctionRef,
+        arguments: Vec<usize>,
+        partition_by: Vec<usize>,
+        order_by: Vec<SortColumnDescription>,
+        frame: WindowFrame,
+    ) -> Result<Box<dyn Processor>> {
+        let sort_desc = partition_by
+            .iter()
+            .map(|offset| SortColumnDescription {
+                offset: *offset,
+                asc: true,
+                nulls_first: true,
+            })
+            .chain(order_by.iter().cloned())
+            .collect();
+
+        Ok(AccumulatingTransformer::create(input, output, Self {
+            func,
+            arguments,
+            partition_by,
+            order_by: order_by.iter().map(|desc| desc.offset).collect(),
+            sort_desc,
+            frame,
+            arena: Bump::new(),
+            blocks: vec![],
+        }))
+    }
+
+    fn is_same_keys(columns: &[Column], keys: &[usize], lhs: usize, rhs: usize) -> bool {
+        keys.iter()
+            .all(|key| columns[*key].index(lhs) == columns[*key].index(rhs))
+    }
+
+    // Returns the offset of `bound` as a position between rows, e.g. the frame
+    // of the current row is `[start, end)`.
+    fn bound_offset(
+        &self,
+        bound: &WindowFrameBound,
+        is_end: bool,
+        row: usize,
+        partition: (usize, usize),
+        peers: (usize, usize),
+    ) -> usize {
+        let pos = if is_end { row + 1 } else { row };
+        match bound {
+            WindowFrameBound::Preceding(None) => partition.0,
+            WindowFrameBound::Following(None) => partition.1,
+            WindowFrameBound::CurrentRow => match (&self.frame.units, is_end) {
+                (WindowFrameUnits::Range, false) => peers.0,
+                (WindowFrameUnits::Range, true) => peers.1,
+                (WindowFrameUnits::Rows, _) => pos,
+            },
+            WindowFrameBound::Preceding(Some(n)) => pos.saturating_sub(*n).max(partition.0),
+            WindowFrameBound::Following(Some(n)) => (pos + *n).min(partition.1),
+        }
+    }
+
+    fn compute_partition(
+        &self,
+        place: StateAddr,
+        columns: &[Column],
+        arguments: &[Column],
+        partition: (usize, usize),
+        builder: &mut ColumnBuilder,
+    ) -> Result<()> {
+        // If the frame always starts from the beginning of the partition, the
+        // frame end only moves forward, so the state can be accumulated incrementally.
+        let is_running = matches!(self.frame.start_bound, WindowFrameBound::Preceding(None));
+        let mut accumulated = partition.0;
+        if is_running {
+            self.func.init_state(place);
+        }
+
+        let mut peers = (partition.0, partition.0);
+        for row in partition.0..partition.1 {
+            if row >= peers.1 {
+                peers = (row, row + 1);
+                while peers.1 < partition.1
+                    && Self::is_same_keys(columns, &self.order_by, row, peers.1)
+                {
+                    peers.1 += 1;
+                }
+            }
+
+            let end = self.bound_offset(&self.frame.end_bound, true, row, partition, peers);
+            if is_running {
+                for r in accumulated..end {
+                    self.func.accumulate_row(place, arguments, r)?;
+                }
+                accumulated = accumulated.max(end);
+            } else {
+                let start =
+                    self.bound_offset(&self.frame.start_bound, false, row, partition, peers);
+                self.func.init_state(place);
+                for r in start..end {
+                    self.func.accumulate_row(place, arguments, r)?;
+                }
+            }
+
+            self.func.merge_result(place, builder)?;
+
+            if !is_running && self.func.need_manual_drop_state() {
+                unsafe { self.func.drop_state(place) }
+            }
+        }
+
+        if is_running && self.func.need_manual_drop_state() {
+            unsafe { self.func.drop_state(place) }
+        }
+
+        Ok(())
+    }
+}
+
+impl AccumulatingTransform for TransformWindow {
+    const NAME: &'static str = "TransformWindow";
+
+    fn transform(&mut self, data: DataBlock) -> Result<Vec<DataBlock>> {
+        if !data.is_empty() {
+            self.blocks.push(data);
+        }
+        Ok(vec![])
+    }
+
+    fn on_finish(&mut self, output: bool) -> Result<Vec<DataBlock>> {
+        if !output || self.blocks.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let blocks = std::mem::take(&mut self.blocks);
+        let mut block = DataBlock::concat(&blocks)?.convert_to_full();
+        if !self.sort_desc.is_empty() {
+            block = DataBlock::sort(&block, &self.sort_desc, None)?;
+        }
+
+        let num_rows = block.num_rows();
+        let columns = block
+            .columns()
+            .iter()
+            .map(|entry| entry.value.as_column().unwrap().clone())
+            .collect::<Vec<_>>();
+        let arguments = self
+            .arguments
+            .iter()
+            .map(|offset| columns[*offset].clone())
+            .collect::<Vec<_>>();
+
+        let return_type = self.func.return_type()?;
+        let mut builder = ColumnBuilder::with_capacity(&return_type, num_rows);
+        let place: StateAddr = self.arena.alloc_layout(self.func.state_layout()).into();
+
+        let mut partition_start = 0;
+        while partition_start < num_rows {
+            let mut partition_end = partition_start + 1;
+            while partition_end < num_rows
+                && Self::is_same_keys(&columns, &self.partition_by, partition_start, partition_end)
+            {
+                partition_end += 1;
+            }
+
+            self.compute_partition(
+                place,
+                &columns,
+                &arguments,
+                (partition_start, partition_end),
+                &mut builder,
+            )?;
+            partition_start = partition_end;
+        }
+
+        block.add_column(BlockEntry {
+            data_type: return_type,
+            value: Value::Column(builder.build()),
+        });
+        Ok(vec![block])
+    }
+}
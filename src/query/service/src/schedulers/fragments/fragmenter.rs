@@ -147,6 +147,7 @@ impl PhysicalPlanReplacer for Fragmenter {
             build_keys: plan.build_keys.clone(),
             probe_keys: plan.probe_keys.clone(),
             non_equi_conditions: plan.non_equi_conditions.clone(),
+            is_null_equal: plan.is_null_equal.clone(),
             join_type: plan.join_type.clone(),
             marker_index: plan.marker_index,
             from_correlated_subquery: plan.from_correlated_subquery,
@@ -26,6 +26,7 @@ use itertools::Itertools;
 
 use crate::api::ConnectionInfo;
 use crate::api::DataExchange;
+use crate::api::ExchangeFormat;
 use crate::api::ExecutePartialQueryPacket;
 use crate::api::FragmentPlanPacket;
 use crate::api::InitNodesChannelPacket;
@@ -170,6 +171,7 @@ impl QueryFragmentsActions {
         &self,
     ) -> Result<(QueryFragmentsPlanPacket, Vec<QueryFragmentsPlanPacket>)> {
         let nodes_info = Self::nodes_info(&self.ctx);
+        let exchange_format = ExchangeFormat::try_create(&self.ctx.get_settings())?;
 
         let mut fragments_packets = self.get_executors_fragments();
         let mut query_fragments_plan_packets = Vec::with_capacity(fragments_packets.len());
@@ -179,6 +181,7 @@ impl QueryFragmentsActions {
             self.ctx.get_id(),
             cluster.local_id.clone(),
             fragments_packets.remove(&cluster.local_id).unwrap(),
+            exchange_format,
             nodes_info.clone(),
             cluster.local_id(),
         );
@@ -191,6 +194,7 @@ impl QueryFragmentsActions {
                 query_id,
                 executor,
                 fragments,
+                exchange_format,
                 executors_info,
                 cluster.local_id(),
             ));
@@ -80,7 +80,7 @@ impl<'a> Display for QueryFragmentActionsWrap<'a> {
             let fragment_action = &self.inner.fragment_actions[0];
             let plan_display_string = fragment_action
                 .physical_plan
-                .format(self.metadata.clone(), ProfSpanSetRef::default())
+                .format(self.metadata.clone(), ProfSpanSetRef::default(), false)
                 .and_then(|node| node.format_pretty_with_prefix("    "))
                 .unwrap();
             write!(f, "{}", plan_display_string)?;
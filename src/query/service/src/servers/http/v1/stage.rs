@@ -12,7 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_meta_app::principal::GrantObject;
 use common_meta_app::principal::StageInfo;
+use common_meta_app::principal::UserPrivilegeType;
 use common_storages_stage::StageTable;
 use common_users::UserApiProvider;
 use poem::error::InternalServerError;
@@ -68,10 +70,17 @@ pub async fn upload_to_stage(
                 .as_str(),
         )
     } else {
-        UserApiProvider::instance()
+        let stage = UserApiProvider::instance()
             .get_stage(context.get_tenant().as_str(), stage_name)
             .await
-            .map_err(InternalServerError)?
+            .map_err(InternalServerError)?;
+        context
+            .validate_privilege(&GrantObject::Stage(stage.stage_name.clone()), vec![
+                UserPrivilegeType::Write,
+            ])
+            .await
+            .map_err(InternalServerError)?;
+        stage
     };
 
     let op = StageTable::get_op(&stage).map_err(InternalServerError)?;
@@ -79,7 +79,7 @@ fn has_result_set_by_plan(plan: &Plan) -> bool {
             | Plan::ShowGrants(_)
             | Plan::ListStage(_)
             | Plan::Presign(_)
-    )
+    ) || matches!(plan, Plan::Insert(insert) if insert.has_returning())
 }
 
 struct InteractiveWorkerBase<W: AsyncWrite + Send + Unpin> {
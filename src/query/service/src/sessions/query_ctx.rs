@@ -36,6 +36,7 @@ use common_catalog::plan::DataSourcePlan;
 use common_catalog::plan::PartInfoPtr;
 use common_catalog::plan::Partitions;
 use common_catalog::plan::StageTableInfo;
+use common_catalog::runtime_filter_info::RuntimeFilterInfo;
 use common_catalog::table_args::TableArgs;
 use common_catalog::table_context::StageAttachment;
 use common_config::DATABEND_COMMIT_VERSION;
@@ -46,15 +47,18 @@ use common_expression::DataBlock;
 use common_expression::FunctionContext;
 use common_io::prelude::FormatSettings;
 use common_meta_app::principal::FileFormatOptions;
+use common_meta_app::principal::GrantObject;
 use common_meta_app::principal::RoleInfo;
 use common_meta_app::principal::StageFileFormatType;
 use common_meta_app::principal::UserInfo;
+use common_meta_app::principal::UserPrivilegeType;
 use common_meta_app::schema::GetTableCopiedFileReq;
 use common_meta_app::schema::TableCopiedFileInfo;
 use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::UpsertTableCopiedFileReq;
 use common_settings::Settings;
 use common_storage::DataOperator;
+use common_storage::FileLoadStatus;
 use common_storage::StageFileInfo;
 use common_storage::StageFileStatus;
 use common_storage::StorageMetrics;
@@ -214,8 +218,12 @@ impl QueryContext {
         self.shared.created_time
     }
 
-    pub fn get_on_error_map(&self) -> Option<HashMap<String, ErrorCode>> {
-        self.shared.get_on_error_map()
+    pub fn get_file_load_status(&self) -> HashMap<String, FileLoadStatus> {
+        self.shared.get_file_load_status()
+    }
+
+    pub fn get_on_error_rows(&self) -> u64 {
+        self.shared.get_on_error_rows()
     }
 }
 
@@ -325,6 +333,19 @@ impl TableContext for QueryContext {
         self.shared.cacheable.store(cacheable, Ordering::Release);
     }
 
+    fn add_runtime_filter(&self, table_index: usize, info: Arc<RuntimeFilterInfo>) {
+        let mut runtime_filters = self.shared.runtime_filters.write();
+        runtime_filters.entry(table_index).or_default().push(info);
+    }
+
+    fn get_runtime_filters(&self, table_index: usize) -> Vec<Arc<RuntimeFilterInfo>> {
+        let runtime_filters = self.shared.runtime_filters.read();
+        runtime_filters
+            .get(&table_index)
+            .cloned()
+            .unwrap_or_default()
+    }
+
     fn attach_query_str(&self, kind: String, query: String) {
         self.shared.attach_query_str(kind, query);
     }
@@ -439,8 +460,12 @@ impl TableContext for QueryContext {
             .update_query_ids_results(query_id, Some(result_cache_key))
     }
 
-    fn set_on_error_map(&self, map: Option<HashMap<String, ErrorCode>>) {
-        self.shared.set_on_error_map(map);
+    fn set_file_load_status(&self, status: HashMap<String, FileLoadStatus>) {
+        self.shared.set_file_load_status(status);
+    }
+
+    fn set_on_error_rows(&self, rows: u64) {
+        self.shared.set_on_error_rows(rows);
     }
 
     fn apply_changed_settings(&self, changed_settings: Arc<Settings>) -> Result<()> {
@@ -479,6 +504,16 @@ impl TableContext for QueryContext {
         Ok(opt)
     }
 
+    async fn validate_privilege(
+        &self,
+        object: &GrantObject,
+        privileges: Vec<UserPrivilegeType>,
+    ) -> Result<()> {
+        self.get_current_session()
+            .validate_privilege(object, privileges)
+            .await
+    }
+
     /// Fetch a Table by db and table name.
     ///
     /// It guaranteed to return a consistent result for multiple calls, in a same query.
@@ -15,6 +15,7 @@
 use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicU64;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::sync::Weak;
@@ -22,6 +23,7 @@ use std::time::SystemTime;
 
 use common_base::base::Progress;
 use common_base::runtime::Runtime;
+use common_catalog::runtime_filter_info::RuntimeFilterInfo;
 use common_catalog::table_context::StageAttachment;
 use common_config::InnerConfig;
 use common_exception::ErrorCode;
@@ -31,6 +33,7 @@ use common_meta_app::principal::RoleInfo;
 use common_meta_app::principal::UserInfo;
 use common_settings::Settings;
 use common_storage::DataOperator;
+use common_storage::FileLoadStatus;
 use common_storage::StorageMetrics;
 use parking_lot::Mutex;
 use parking_lot::RwLock;
@@ -79,10 +82,14 @@ pub struct QueryContextShared {
     pub(in crate::sessions) precommit_blocks: Arc<RwLock<Vec<DataBlock>>>,
     pub(in crate::sessions) stage_attachment: Arc<RwLock<Option<StageAttachment>>>,
     pub(in crate::sessions) created_time: SystemTime,
-    pub(in crate::sessions) on_error_map: Arc<RwLock<Option<HashMap<String, ErrorCode>>>>,
+    pub(in crate::sessions) file_load_status: Arc<RwLock<HashMap<String, FileLoadStatus>>>,
+    pub(in crate::sessions) on_error_rows: Arc<AtomicU64>,
     /// partitions_sha for each table in the query. Not empty only when enabling query result cache.
     pub(in crate::sessions) partitions_shas: Arc<RwLock<Vec<String>>>,
     pub(in crate::sessions) cacheable: Arc<AtomicBool>,
+    /// The runtime filters pushed down into the table scans, by the table index.
+    pub(in crate::sessions) runtime_filters:
+        Arc<RwLock<HashMap<usize, Vec<Arc<RuntimeFilterInfo>>>>>,
     // Status info.
     pub(in crate::sessions) status: Arc<RwLock<String>>,
 }
@@ -114,9 +121,11 @@ impl QueryContextShared {
             precommit_blocks: Arc::new(RwLock::new(vec![])),
             stage_attachment: Arc::new(RwLock::new(None)),
             created_time: SystemTime::now(),
-            on_error_map: Arc::new(RwLock::new(None)),
+            file_load_status: Arc::new(RwLock::new(HashMap::new())),
+            on_error_rows: Arc::new(AtomicU64::new(0)),
             partitions_shas: Arc::new(RwLock::new(vec![])),
             cacheable: Arc::new(AtomicBool::new(true)),
+            runtime_filters: Arc::new(RwLock::new(HashMap::new())),
             status: Arc::new(RwLock::new("null".to_string())),
         }))
     }
@@ -126,13 +135,21 @@ impl QueryContextShared {
         *guard = Some(err);
     }
 
-    pub fn set_on_error_map(&self, map: Option<HashMap<String, ErrorCode>>) {
-        let mut guard = self.on_error_map.write();
-        *guard = map;
+    pub fn set_file_load_status(&self, status: HashMap<String, FileLoadStatus>) {
+        let mut guard = self.file_load_status.write();
+        *guard = status;
     }
 
-    pub fn get_on_error_map(&self) -> Option<HashMap<String, ErrorCode>> {
-        self.on_error_map.read().as_ref().cloned()
+    pub fn get_file_load_status(&self) -> HashMap<String, FileLoadStatus> {
+        self.file_load_status.read().clone()
+    }
+
+    pub fn set_on_error_rows(&self, rows: u64) {
+        self.on_error_rows.store(rows, Ordering::Release);
+    }
+
+    pub fn get_on_error_rows(&self) -> u64 {
+        self.on_error_rows.load(Ordering::Acquire)
     }
 
     pub fn kill(&self, cause: ErrorCode) {
@@ -283,8 +283,14 @@ impl Session {
         };
 
         let tenant = self.get_current_tenant();
+        let stale_tolerance = self.get_settings().get_role_cache_stale_tolerance()?;
         let related_roles = RoleCacheManager::instance()
-            .find_related_roles(&tenant, &roles)
+            .find_related_roles_cached(
+                &tenant,
+                &roles,
+                stale_tolerance,
+                self.session_ctx.get_privilege_cache(),
+            )
             .await?;
         Ok(related_roles)
     }
@@ -24,6 +24,7 @@ use common_exception::Result;
 use common_meta_app::principal::RoleInfo;
 use common_meta_app::principal::UserInfo;
 use common_settings::Settings;
+use common_users::PrivilegeCache;
 use parking_lot::RwLock;
 
 use crate::sessions::QueryContextShared;
@@ -47,6 +48,8 @@ pub struct SessionContext {
     // The role granted to user by external auth provider, when auth_role is provided, the current
     // user's all other roles are overridden by this role.
     auth_role: RwLock<Option<String>>,
+    // The roles related to the current user's roles, which are found on each privilege check.
+    privilege_cache: PrivilegeCache,
     // The client IP from the client.
     client_host: RwLock<Option<SocketAddr>>,
     io_shutdown_tx: RwLock<Option<Box<dyn FnOnce() + Send + Sync + 'static>>>,
@@ -64,6 +67,7 @@ impl SessionContext {
             current_user: Default::default(),
             current_role: Default::default(),
             auth_role: Default::default(),
+            privilege_cache: Default::default(),
             current_tenant: Default::default(),
             client_host: Default::default(),
             current_catalog: RwLock::new("default".to_string()),
@@ -179,6 +183,11 @@ impl SessionContext {
         *lock = role;
     }
 
+    // Get the cache of the roles related to the session's roles, see `PrivilegeCache`.
+    pub fn get_privilege_cache(&self) -> &PrivilegeCache {
+        &self.privilege_cache
+    }
+
     pub fn get_client_host(&self) -> Option<SocketAddr> {
         let lock = self.client_host.read();
         *lock
@@ -31,6 +31,7 @@ use common_exception::Result;
 use common_metrics::label_counter;
 use common_metrics::label_gauge;
 use common_settings::Settings;
+use common_users::RoleCacheManager;
 use common_users::UserApiProvider;
 use futures::future::Either;
 use futures::StreamExt;
@@ -109,6 +110,7 @@ impl SessionManager {
         let session_settings = Settings::try_create(user_api, tenant).await?;
         let session_ctx = SessionContext::try_create(session_settings)?;
         let session = Session::try_create(id.clone(), typ.clone(), session_ctx, mysql_conn_id)?;
+        RoleCacheManager::instance().warmup(&config.query.tenant_id);
 
         let mut sessions = self.active_sessions.write();
         self.validate_max_active_sessions(sessions.len(), "active sessions")?;
@@ -0,0 +1,69 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::NodeInfo;
+use databend_query::api::BroadcastExchange;
+use databend_query::api::MergeExchange;
+use databend_query::api::ShuffleDataExchange;
+use databend_query::clusters::Cluster;
+use databend_query::clusters::ClusterHelper;
+
+fn create_cluster(nodes: &[&str]) -> Arc<Cluster> {
+    let nodes = nodes
+        .iter()
+        .map(|id| {
+            Arc::new(NodeInfo::create(
+                id.to_string(),
+                0,
+                format!("{}:9090", id),
+                String::new(),
+            ))
+        })
+        .collect();
+
+    Cluster::create(nodes, String::from("node1"))
+}
+
+#[test]
+fn test_check_exchange_destinations() -> Result<()> {
+    let destination_ids = vec![String::from("node1"), String::from("node2")];
+    let shuffle = ShuffleDataExchange::create(destination_ids.clone(), vec![]);
+    let broadcast = BroadcastExchange::create(true, destination_ids);
+    let merge = MergeExchange::create(String::from("node1"));
+
+    // The cluster the query was planned with.
+    let cluster = create_cluster(&["node1", "node2"]);
+    shuffle.check_destinations(&cluster)?;
+    broadcast.check_destinations(&cluster)?;
+    merge.check_destinations(&cluster)?;
+
+    // A node joined the cluster, the destinations are still there.
+    let cluster = create_cluster(&["node1", "node2", "node3"]);
+    shuffle.check_destinations(&cluster)?;
+
+    // node2 left the cluster between the fragments.
+    let cluster = create_cluster(&["node1", "node3"]);
+    merge.check_destinations(&cluster)?;
+    for exchange in [shuffle, broadcast] {
+        let res = exchange.check_destinations(&cluster);
+        assert!(res.is_err());
+        assert_eq!(res.unwrap_err().code(), ErrorCode::CLUSTER_UNKNOWN_NODE);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,88 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_arrow::arrow::io::flight::default_ipc_fields;
+use common_arrow::arrow::io::flight::WriteOptions;
+use common_arrow::arrow_format::flight::data::FlightData;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::types::Int32Type;
+use common_expression::types::Int64Type;
+use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
+use common_expression::BlockMetaInfoDowncast;
+use common_expression::DataBlock;
+use common_expression::DataField;
+use common_expression::DataSchemaRef;
+use common_expression::DataSchemaRefExt;
+use common_expression::FromData;
+use common_expression::FromOptData;
+use common_pipeline_transforms::processors::transforms::Transform;
+use databend_query::api::serialize_block;
+use databend_query::api::DataPacket;
+use databend_query::api::ExchangeDeserializeMeta;
+use databend_query::api::ExchangeFormat;
+use databend_query::api::ExchangeSerializeMeta;
+use databend_query::api::TransformExchangeDeserializer;
+
+fn exchange_round_trip(
+    schema: &DataSchemaRef,
+    block: DataBlock,
+    format: ExchangeFormat,
+) -> Result<DataBlock> {
+    let ipc_fields = default_ipc_fields(&schema.to_arrow().fields);
+    let options = WriteOptions { compression: None };
+    let mut serialized = serialize_block(0, block, &ipc_fields, &options, format)?;
+
+    let packet = serialized
+        .take_meta()
+        .and_then(ExchangeSerializeMeta::downcast_from)
+        .and_then(|meta| meta.packet)
+        .unwrap();
+
+    // Same as sending the packet to another node.
+    let packet = DataPacket::try_from(FlightData::from(packet))?;
+
+    let mut deserializer = TransformExchangeDeserializer::new(schema, format);
+    deserializer.transform(DataBlock::empty_with_meta(ExchangeDeserializeMeta::create(
+        packet,
+    )))
+}
+
+#[test]
+fn test_exchange_arrow_ipc_round_trip() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Number(NumberDataType::Int32)),
+        DataField::new(
+            "b",
+            DataType::Nullable(Box::new(DataType::Number(NumberDataType::Int64))),
+        ),
+        DataField::new("c", DataType::String),
+    ]);
+
+    let block = DataBlock::new_from_columns(vec![
+        Int32Type::from_data(vec![1, 2, 3]),
+        Int64Type::from_opt_data(vec![Some(4), None, Some(6)]),
+        StringType::from_data(vec!["x", "y", "z"]),
+    ]);
+
+    let arrow_ipc = exchange_round_trip(&schema, block.clone(), ExchangeFormat::ArrowIpc)?;
+    assert_eq!(arrow_ipc.num_rows(), 3);
+    assert_eq!(arrow_ipc.to_string(), block.to_string());
+
+    let internal = exchange_round_trip(&schema, block, ExchangeFormat::Internal)?;
+    assert_eq!(internal.to_string(), arrow_ipc.to_string());
+
+    Ok(())
+}
@@ -0,0 +1,45 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::future::pending;
+use std::time::Duration;
+
+use common_base::base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use databend_query::api::recv_with_timeout;
+use databend_query::api::DataPacket;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_exchange_source_read_timeout() -> Result<()> {
+    // A remote fragment which never sends anything.
+    let silent_remote = pending::<Result<Option<DataPacket>>>();
+    let timeout = Some(Duration::from_millis(100));
+    let res = recv_with_timeout(silent_remote, timeout, "query_id", 1).await;
+
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().code(), ErrorCode::CANNOT_CONNECT_NODE);
+
+    // The remote fragment finished in time.
+    let finished_remote = async { Ok(None) };
+    let res = recv_with_timeout(finished_remote, timeout, "query_id", 1).await?;
+    assert!(res.is_none());
+
+    // Closing packets pass through.
+    let closing_remote = async { Ok(Some(DataPacket::ClosingOutput)) };
+    let res = recv_with_timeout(closing_remote, None, "query_id", 1).await?;
+    assert!(matches!(res, Some(DataPacket::ClosingOutput)));
+
+    Ok(())
+}
@@ -12,4 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod data_exchange;
+mod exchange_serde;
+mod exchange_source_reader;
 mod packets;
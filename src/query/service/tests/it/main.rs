@@ -15,6 +15,8 @@
 #![allow(clippy::uninlined_format_args)]
 #![feature(thread_local)]
 
+use common_base::mem_allocator::GlobalAllocator;
+
 mod api;
 mod auth;
 mod catalogs;
@@ -28,3 +30,7 @@ mod sql;
 mod storages;
 mod table_functions;
 mod tests;
+
+// The memory usage of spill readers is measured with the memory stat collector.
+#[global_allocator]
+pub static GLOBAL_ALLOCATOR: GlobalAllocator = GlobalAllocator;
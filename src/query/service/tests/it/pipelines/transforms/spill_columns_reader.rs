@@ -0,0 +1,102 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::base::tokio;
+use common_base::runtime::MemStat;
+use common_base::runtime::TrackedFuture;
+use common_exception::Result;
+use common_expression::arrow::serialize_column;
+use common_expression::types::Int64Type;
+use common_expression::types::StringType;
+use common_expression::Column;
+use common_expression::FromData;
+use databend_query::pipelines::processors::SpilledColumnsReader;
+use opendal::services::Memory;
+use opendal::Operator;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_spilled_columns_reader() -> Result<()> {
+    let rows = 500_000;
+    let columns: Vec<Column> = (0..16)
+        .map(|idx| match idx % 2 {
+            0 => Int64Type::from_data((0..rows).map(|v| v * idx).collect::<Vec<i64>>()),
+            _ => StringType::from_data(
+                (0..rows)
+                    .map(|v| format!("value_{}", v * idx))
+                    .collect::<Vec<_>>(),
+            ),
+        })
+        .collect();
+
+    // Same layout as the spill writers: the serialized columns back to back.
+    let mut data = vec![];
+    let mut columns_layout = vec![];
+    for column in &columns {
+        let column_data = serialize_column(column);
+        columns_layout.push(column_data.len());
+        data.extend(column_data);
+    }
+
+    let total_size = data.len();
+    let operator = Operator::new(Memory::default())?.finish();
+    operator.write("spill/file", data).await?;
+
+    let mut reader = SpilledColumnsReader::create(
+        operator.clone(),
+        "spill/file".to_string(),
+        columns_layout.clone(),
+    );
+    let read_columns = reader.read_columns().await?;
+    assert_eq!(read_columns, columns);
+    assert!(reader.next_column().await?.is_none());
+
+    // Consume the columns one by one, so the peak memory is the buffered ranges of the file.
+    let mem_stat = MemStat::create("test_spilled_columns_reader".to_string());
+    TrackedFuture::create_with_mem_stat(Some(mem_stat.clone()), async move {
+        let mut reader =
+            SpilledColumnsReader::create(operator, "spill/file".to_string(), columns_layout);
+        let mut num_columns = 0;
+        while let Some(column) = reader.next_column().await? {
+            assert_eq!(column, columns[num_columns]);
+            num_columns += 1;
+        }
+        assert_eq!(num_columns, columns.len());
+        Result::Ok(())
+    })
+    .await?;
+
+    let peak_memory_usage = mem_stat.get_peak_memory_usage() as usize;
+    assert!(
+        peak_memory_usage < total_size / 2,
+        "peak memory usage {} of reading a spill file of {} bytes",
+        peak_memory_usage,
+        total_size
+    );
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_spilled_columns_reader_truncated() -> Result<()> {
+    let column = Int64Type::from_data(vec![1i64, 2, 3]);
+    let column_data = serialize_column(&column);
+    let columns_layout = vec![column_data.len(), column_data.len()];
+
+    let operator = Operator::new(Memory::default())?.finish();
+    operator.write("spill/file", column_data).await?;
+
+    let mut reader =
+        SpilledColumnsReader::create(operator, "spill/file".to_string(), columns_layout);
+    assert!(reader.read_columns().await.is_err());
+    Ok(())
+}
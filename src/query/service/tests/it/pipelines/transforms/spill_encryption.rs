@@ -0,0 +1,63 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::base::tokio;
+use common_exception::Result;
+use common_settings::Settings;
+use databend_query::pipelines::processors::SpillEncryption;
+use databend_query::pipelines::processors::SpillLocations;
+use opendal::services::Memory;
+use opendal::Operator;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_spill_encryption_round_trip() -> Result<()> {
+    let settings = Settings::default_test_settings()?;
+    settings.set_settings(
+        "enable_spilling_encryption".to_string(),
+        "1".to_string(),
+        false,
+    )?;
+
+    let locations = SpillLocations::try_create(&settings, "tenant")?;
+    let operator = Operator::new(Memory::default())?.finish();
+
+    let data = (0..4096).map(|i| (i % 251) as u8).collect::<Vec<_>>();
+    let location = locations.next_location(data.len())?;
+    let encryption = locations.next_encryption().unwrap();
+    operator
+        .write(&location, encryption.encrypt(&data)?)
+        .await?;
+
+    let spilled = operator.read(&location).await?;
+    assert_ne!(spilled, data);
+    assert_eq!(encryption.decrypt(&spilled)?, data);
+
+    // Every spill file gets its own nonce.
+    let other = locations.next_encryption().unwrap();
+    assert_ne!(other, encryption);
+    assert!(other.decrypt(&spilled).is_err());
+
+    // A different query cannot decrypt the file.
+    let other = SpillEncryption::create(SpillEncryption::generate_key());
+    assert!(other.decrypt(&spilled).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_spill_encryption_disabled() -> Result<()> {
+    let settings = Settings::default_test_settings()?;
+    let locations = SpillLocations::try_create(&settings, "tenant")?;
+    assert!(locations.next_encryption().is_none());
+    Ok(())
+}
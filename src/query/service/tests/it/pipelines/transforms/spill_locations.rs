@@ -0,0 +1,118 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_settings::Settings;
+use databend_query::pipelines::processors::SpillLocations;
+use databend_query::pipelines::processors::SpillStrategy;
+
+fn count_by_prefix(locations: &SpillLocations, spilled: &[String]) -> Vec<usize> {
+    locations
+        .prefixes()
+        .iter()
+        .map(|prefix| {
+            spilled
+                .iter()
+                .filter(|location| location.starts_with(&format!("{}/", prefix)))
+                .count()
+        })
+        .collect()
+}
+
+#[test]
+fn test_spill_locations_round_robin() -> Result<()> {
+    let locations = SpillLocations::create(
+        SpillStrategy::RoundRobin,
+        vec!["a".to_string(), "b".to_string(), "c".to_string()],
+        None,
+        None,
+        true,
+    );
+
+    let spilled = (0..6)
+        .map(|_| locations.next_location(1024))
+        .collect::<Result<Vec<_>>>()?;
+    assert_eq!(count_by_prefix(&locations, &spilled), vec![2, 2, 2]);
+    Ok(())
+}
+
+#[test]
+fn test_spill_locations_least_bytes() -> Result<()> {
+    let locations = SpillLocations::create(
+        SpillStrategy::LeastBytes,
+        vec!["a".to_string(), "b".to_string()],
+        None,
+        None,
+        true,
+    );
+
+    // The large file goes to `a`, the following small files all go to `b`.
+    let mut spilled = vec![locations.next_location(1000)?];
+    for _ in 0..3 {
+        spilled.push(locations.next_location(100)?);
+    }
+    assert!(spilled[0].starts_with("a/"));
+    assert_eq!(count_by_prefix(&locations, &spilled), vec![1, 3]);
+    Ok(())
+}
+
+#[test]
+fn test_spill_locations_from_settings() -> Result<()> {
+    let settings = Settings::default_test_settings()?;
+    let locations = SpillLocations::try_create(&settings, "tenant")?;
+    assert_eq!(locations.prefixes(), &[
+        "_aggregate_spill/tenant".to_string()
+    ]);
+
+    settings.set_settings(
+        "spilling_location_prefixes".to_string(),
+        "spill_a, spill_b/".to_string(),
+        false,
+    )?;
+    let locations = SpillLocations::try_create(&settings, "tenant")?;
+    assert_eq!(locations.prefixes(), &[
+        "spill_a/tenant".to_string(),
+        "spill_b/tenant".to_string()
+    ]);
+
+    let spilled = (0..4)
+        .map(|_| locations.next_location(1024))
+        .collect::<Result<Vec<_>>>()?;
+    assert_eq!(count_by_prefix(&locations, &spilled), vec![2, 2]);
+    Ok(())
+}
+
+#[test]
+fn test_spill_locations_quota_exhausted() -> Result<()> {
+    let settings = Settings::default_test_settings()?;
+    settings.set_settings("spilling_max_bytes".to_string(), "2048".to_string(), false)?;
+    let locations = SpillLocations::try_create(&settings, "tenant")?;
+
+    locations.next_location(1024)?;
+    locations.next_location(1024)?;
+
+    let err = locations.next_location(1).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::STORAGE_UNAVAILABLE);
+    assert_eq!(
+        err.message(),
+        "Cannot spill 1 bytes, spilling quota exhausted: 2048 of 2048 bytes (spilling_max_bytes) used"
+    );
+
+    // The rejected file is not accounted.
+    let locations = SpillLocations::try_create(&settings, "tenant")?;
+    assert!(locations.next_location(4096).is_err());
+    locations.next_location(2048)?;
+    Ok(())
+}
@@ -0,0 +1,113 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+
+use common_base::base::tokio;
+use common_exception::Result;
+use databend_query::pipelines::processors::SpillPrefetcher;
+use opendal::services::Memory;
+use opendal::Operator;
+
+/// Counts the spill files being read at the same time.
+#[derive(Default)]
+struct ReadCounter {
+    reading: AtomicUsize,
+    peak: AtomicUsize,
+}
+
+async fn instrumented_read(
+    operator: Operator,
+    counter: Arc<ReadCounter>,
+    location: String,
+) -> Result<Vec<u8>> {
+    let reading = counter.reading.fetch_add(1, Ordering::SeqCst) + 1;
+    counter.peak.fetch_max(reading, Ordering::SeqCst);
+    // Simulate a slow download.
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    let data = operator.read(&location).await?;
+    counter.reading.fetch_sub(1, Ordering::SeqCst);
+    Ok(data)
+}
+
+async fn restore(operator: &Operator, files: usize, depth: usize) -> Result<(Vec<Vec<u8>>, usize)> {
+    let counter = Arc::new(ReadCounter::default());
+    let mut prefetcher = SpillPrefetcher::create(depth);
+    let mut locations = (0..files).map(|i| format!("spill/{}", i));
+    let mut restored = vec![];
+    loop {
+        while !prefetcher.is_full() {
+            match locations.next() {
+                None => break,
+                Some(location) => prefetcher.prefetch(instrumented_read(
+                    operator.clone(),
+                    counter.clone(),
+                    location,
+                )),
+            }
+        }
+        match prefetcher.next().await? {
+            None => break,
+            Some(data) => restored.push(data),
+        }
+    }
+    Ok((restored, counter.peak.load(Ordering::SeqCst)))
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_spill_prefetcher() -> Result<()> {
+    let operator = Operator::new(Memory::default())?.finish();
+    let files = (0..8)
+        .map(|i| vec![i as u8; 1024 * (i + 1)])
+        .collect::<Vec<_>>();
+    for (i, data) in files.iter().enumerate() {
+        operator
+            .write(&format!("spill/{}", i), data.clone())
+            .await?;
+    }
+
+    // Without read ahead, the files are read one by one.
+    let (restored, peak) = restore(&operator, files.len(), 1).await?;
+    assert_eq!(restored, files);
+    assert_eq!(peak, 1);
+
+    // The next files are downloaded while the current one is restored, in the same order.
+    let (restored, peak) = restore(&operator, files.len(), 3).await?;
+    assert_eq!(restored, files);
+    assert!(peak > 1 && peak <= 3, "peak: {}", peak);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_spill_prefetcher_error() -> Result<()> {
+    let operator = Operator::new(Memory::default())?.finish();
+    let counter = Arc::new(ReadCounter::default());
+
+    let mut prefetcher = SpillPrefetcher::create(2);
+    prefetcher.prefetch(instrumented_read(
+        operator,
+        counter,
+        "spill/missing".to_string(),
+    ));
+    assert!(!prefetcher.is_full());
+    assert!(prefetcher.next().await.is_err());
+    assert!(prefetcher.is_empty());
+    assert!(prefetcher.next().await?.is_none());
+
+    Ok(())
+}
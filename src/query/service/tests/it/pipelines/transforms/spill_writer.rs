@@ -0,0 +1,56 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::HashMethodSerializer;
+use common_settings::Settings;
+use databend_query::pipelines::processors::port::InputPort;
+use databend_query::pipelines::processors::port::OutputPort;
+use databend_query::pipelines::processors::SpillLocations;
+use databend_query::pipelines::processors::TransformGroupBySpillWriter;
+use opendal::services::Memory;
+use opendal::Operator;
+
+#[test]
+fn test_group_by_spill_writer_without_storage() -> Result<()> {
+    let create = |operator: Option<Operator>, settings: &Settings| {
+        TransformGroupBySpillWriter::create(
+            InputPort::create(),
+            OutputPort::create(),
+            HashMethodSerializer::default(),
+            operator,
+            SpillLocations::try_create(settings, "tenant")?,
+        )
+    };
+
+    let settings = Settings::default_test_settings()?;
+    settings.set_spilling_bytes_threshold_per_proc(1024 * 1024)?;
+
+    // Spilling is enabled, but there is nowhere to write the spill files.
+    let err = create(None, &settings).err().unwrap();
+    assert_eq!(err.code(), ErrorCode::STORAGE_UNAVAILABLE);
+    assert!(err.message().contains("[storage]"));
+    assert!(err
+        .message()
+        .contains("spilling_bytes_threshold_per_proc = 0"));
+
+    let operator = Operator::new(Memory::default())?.finish();
+    assert!(create(Some(operator), &settings).is_ok());
+
+    // Nothing is spilled if spilling is disabled.
+    settings.set_spilling_bytes_threshold_per_proc(0)?;
+    assert!(create(None, &settings).is_ok());
+    Ok(())
+}
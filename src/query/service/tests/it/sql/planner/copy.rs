@@ -0,0 +1,121 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::tokio;
+use common_exception::Result;
+use common_expression::DataBlock;
+use databend_query::interpreters::Interpreter;
+use databend_query::interpreters::InterpreterFactory;
+use databend_query::sessions::QueryContext;
+use databend_query::sql::plans::CopyFileSummary;
+use databend_query::sql::plans::CopyPlan;
+use databend_query::sql::plans::CopySummary;
+use databend_query::sql::plans::Plan;
+use databend_query::sql::Planner;
+use futures::TryStreamExt;
+
+use crate::storages::fuse::table_test_fixture::execute_command;
+use crate::storages::fuse::table_test_fixture::TestFixture;
+
+async fn plan_copy(ctx: Arc<QueryContext>, sql: &str) -> Result<Plan> {
+    let (plan, _) = Planner::new(ctx).plan_sql(sql).await?;
+    Ok(plan)
+}
+
+fn copy_plan(plan: &Plan) -> &CopyPlan {
+    match plan {
+        Plan::Copy(copy_plan) => copy_plan,
+        other => unreachable!("expect a copy plan, but got {}", other),
+    }
+}
+
+async fn execute_copy(ctx: Arc<QueryContext>, plan: &Plan) -> Result<Vec<DataBlock>> {
+    let interpreter = InterpreterFactory::get(ctx.clone(), plan).await?;
+    let stream = interpreter.execute(ctx).await?;
+    stream.try_collect::<Vec<_>>().await
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_copy_summary_schema() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+    let db = fixture.default_db_name();
+
+    execute_command(ctx.clone(), &format!("create table {db}.t(a int, b int)")).await?;
+    execute_command(
+        ctx.clone(),
+        &format!("insert into {db}.t values(1, 2), (3, 4)"),
+    )
+    .await?;
+    execute_command(ctx.clone(), "create stage copy_summary").await?;
+
+    // Unload into a stage, from a table and from a query.
+    for sql in [
+        format!("copy into @copy_summary from {db}.t"),
+        format!("copy into @copy_summary from (select a, b from {db}.t)"),
+    ] {
+        let plan = plan_copy(ctx.clone(), &sql).await?;
+        assert!(matches!(copy_plan(&plan), CopyPlan::IntoStage { .. }));
+        assert_eq!(plan.schema(), CopySummary::schema());
+
+        let blocks = execute_copy(ctx.clone(), &plan).await?;
+        assert_eq!(blocks.len(), 1);
+        assert_eq!(blocks[0].num_rows(), 1);
+        assert_eq!(blocks[0].num_columns(), CopySummary::schema().num_fields());
+    }
+
+    // Load the unloaded files into a table.
+    let sql = format!("copy into {db}.t from @copy_summary");
+    let plan = plan_copy(ctx.clone(), &sql).await?;
+    assert!(matches!(copy_plan(&plan), CopyPlan::IntoTable { .. }));
+    assert_eq!(plan.schema(), CopySummary::schema());
+
+    // Load them with a transform query.
+    let sql = format!("copy into {db}.t from (select a, b from @copy_summary)");
+    let plan = plan_copy(ctx.clone(), &sql).await?;
+    assert!(matches!(
+        copy_plan(&plan),
+        CopyPlan::IntoTableWithTransform { .. }
+    ));
+    assert_eq!(plan.schema(), CopySummary::schema());
+
+    // Validating the files returns the rows or the errors instead of the summary.
+    let sql = format!("copy into {db}.t from @copy_summary validation_mode = 'RETURN_ERRORS'");
+    let plan = plan_copy(ctx.clone(), &sql).await?;
+    assert_ne!(plan.schema(), CopySummary::schema());
+
+    // The summary is returned once the files are loaded.
+    let sql = format!("copy into {db}.t from @copy_summary");
+    let plan = plan_copy(ctx.clone(), &sql).await?;
+    let blocks = execute_copy(ctx.clone(), &plan).await?;
+    assert_eq!(blocks.len(), 1);
+    assert_eq!(blocks[0].num_rows(), 1);
+    assert_eq!(blocks[0].num_columns(), CopySummary::schema().num_fields());
+
+    // The summary of each file is returned with `ON_ERROR = CONTINUE`.
+    let sql = format!("copy into {db}.t from @copy_summary on_error = continue force = true");
+    let plan = plan_copy(ctx.clone(), &sql).await?;
+    assert_eq!(plan.schema(), CopyFileSummary::schema());
+    let blocks = execute_copy(ctx.clone(), &plan).await?;
+    assert_eq!(blocks.len(), 1);
+    assert_eq!(blocks[0].num_rows(), 2);
+    assert_eq!(
+        blocks[0].num_columns(),
+        CopyFileSummary::schema().num_fields()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,120 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use databend_query::interpreters::Interpreter;
+use databend_query::interpreters::InterpreterFactory;
+use databend_query::sessions::TableContext;
+use databend_query::sql::optimizer::SExpr;
+use databend_query::sql::plans::Plan;
+use databend_query::sql::plans::RelOperator;
+use databend_query::sql::DecorrelatedColumnOrigin;
+use databend_query::sql::Planner;
+use futures::TryStreamExt;
+
+use crate::storages::fuse::table_test_fixture::execute_command;
+use crate::storages::fuse::table_test_fixture::TestFixture;
+
+fn has_join(s_expr: &SExpr) -> bool {
+    matches!(s_expr.plan(), RelOperator::Join(_)) || s_expr.children().iter().any(has_join)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_disable_subquery_decorrelation() -> Result<()> {
+    let (_guard, ctx) = crate::tests::create_query_context().await?;
+    let sql = "select number from numbers(3) t \
+               where exists (select 1 from numbers(3) n where n.number = t.number)";
+
+    // The subquery is decorrelated into a semi join by default.
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _) = planner.plan_sql(sql).await?;
+    match &plan {
+        Plan::Query { s_expr, .. } => assert!(has_join(s_expr)),
+        _ => unreachable!(),
+    }
+
+    // The subquery is left in the plan if decorrelation is disabled.
+    ctx.get_settings().set_settings(
+        "enable_subquery_decorrelation".to_string(),
+        "0".to_string(),
+        false,
+    )?;
+    let (plan, _) = planner.plan_sql(sql).await?;
+    match &plan {
+        Plan::Query { s_expr, .. } => assert!(!has_join(s_expr)),
+        _ => unreachable!(),
+    }
+
+    // And the plan can't be executed.
+    let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+    let result = match interpreter.execute(ctx.clone()).await {
+        Ok(stream) => stream.try_collect::<Vec<_>>().await.map(|_| ()),
+        Err(err) => Err(err),
+    };
+    let err = result.unwrap_err();
+    assert_eq!(err.code(), ErrorCode::UNIMPLEMENTED);
+    assert!(err.message().contains("enable_subquery_decorrelation"));
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_correlated_subquery_over_derived_table() -> Result<()> {
+    let (_guard, ctx) = crate::tests::create_query_context().await?;
+    let sql = "select t2.a from (select number + 1 as a from numbers(3)) as t2 \
+               where (select sum(number) from numbers(3) as t1 where t1.number < t2.a) = 1";
+
+    let mut planner = Planner::new(ctx.clone());
+    let err = planner.plan_sql(sql).await.unwrap_err();
+    assert_eq!(err.code(), ErrorCode::UNIMPLEMENTED);
+    assert!(err.message().contains("derived table"));
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_decorrelated_columns() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+    let db = fixture.default_db_name();
+    execute_command(ctx.clone(), &format!("create table {db}.t(a int, b int)")).await?;
+
+    // Both `t.a` and `t.b` are used in the aggregation, so the subquery is flattened
+    // with a column for each of them.
+    let sql = format!("select (select sum(n.number + t.a + t.b) from numbers(3) n) from {db}.t");
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _) = planner.plan_sql(&sql).await?;
+    let decorrelated_columns = match &plan {
+        Plan::Query { metadata, .. } => metadata.read().decorrelated_columns().to_vec(),
+        _ => unreachable!(),
+    };
+    assert_eq!(decorrelated_columns.len(), 2);
+    assert!(decorrelated_columns
+        .iter()
+        .all(|(_, origin)| matches!(origin, DecorrelatedColumnOrigin::CorrelatedColumn(_))));
+
+    // A subquery decorrelated into a semi join creates no columns.
+    let sql = format!(
+        "select a from {db}.t where exists (select 1 from numbers(3) n where n.number = t.a)"
+    );
+    let (plan, _) = planner.plan_sql(&sql).await?;
+    match &plan {
+        Plan::Query { metadata, .. } => assert!(metadata.read().decorrelated_columns().is_empty()),
+        _ => unreachable!(),
+    }
+
+    Ok(())
+}
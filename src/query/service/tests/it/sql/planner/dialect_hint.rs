@@ -0,0 +1,47 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::base::tokio;
+use common_exception::Result;
+use databend_query::sessions::TableContext;
+use databend_query::sql::Planner;
+
+// With the default PostgreSQL dialect `"a"` is an identifier, while it's a string literal in MySQL.
+const MYSQL_HINTED_SQL: &str = "-- dialect: mysql\nselect \"a\"";
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_dialect_hint() -> Result<()> {
+    let (_guard, ctx) = crate::tests::create_query_context().await?;
+    ctx.get_settings()
+        .set_settings("enable_dialect_hint".to_string(), "1".to_string(), false)?;
+
+    let mut planner = Planner::new(ctx.clone());
+    planner.plan_sql(MYSQL_HINTED_SQL).await?;
+
+    // Without hint, the session dialect is used.
+    let err = planner.plan_sql("select \"a\"").await.unwrap_err();
+    assert!(err.message().contains("column a doesn't exist"));
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_dialect_hint_disabled() -> Result<()> {
+    let (_guard, ctx) = crate::tests::create_query_context().await?;
+
+    let mut planner = Planner::new(ctx.clone());
+    assert!(planner.plan_sql(MYSQL_HINTED_SQL).await.is_err());
+
+    Ok(())
+}
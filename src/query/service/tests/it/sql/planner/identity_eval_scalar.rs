@@ -0,0 +1,52 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::base::tokio;
+use common_exception::Result;
+use databend_query::sql::optimizer::SExpr;
+use databend_query::sql::plans::Plan;
+use databend_query::sql::plans::RelOperator;
+use databend_query::sql::plans::ScalarExpr;
+use databend_query::sql::Planner;
+
+fn has_identity_eval_scalar(s_expr: &SExpr) -> bool {
+    if let RelOperator::EvalScalar(eval_scalar) = s_expr.plan() {
+        if eval_scalar.items.iter().all(|item| {
+            matches!(&item.scalar, ScalarExpr::BoundColumnRef(column) if column.column.index == item.index)
+        }) {
+            return true;
+        }
+    }
+    s_expr.children().iter().any(has_identity_eval_scalar)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_eliminate_identity_eval_scalar() -> Result<()> {
+    let (_guard, ctx) = crate::tests::create_query_context().await?;
+    let mut planner = Planner::new(ctx.clone());
+
+    // The flattened subquery re-exposes the derived columns of decorrelation.
+    let (plan, _) = planner
+        .plan_sql(
+            "select number, (select n.number + 1 from numbers(10) n where n.number = t.number) \
+             from numbers(10) t",
+        )
+        .await?;
+    match plan {
+        Plan::Query { s_expr, .. } => assert!(!has_identity_eval_scalar(&s_expr)),
+        _ => unreachable!(),
+    }
+
+    Ok(())
+}
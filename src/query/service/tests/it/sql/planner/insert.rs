@@ -0,0 +1,46 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use databend_query::sql::Planner;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_insert_probing_diagnostic() -> Result<()> {
+    let (_guard, ctx) = crate::tests::create_query_context().await?;
+    let mut planner = Planner::new(ctx);
+
+    // Long enough to be tokenized more than once.
+    let values = ["(1, 'a')"; 100].join(", ");
+    let sql = format!("insert into t valuse {values}");
+    let err = planner.plan_sql(&sql).await.unwrap_err();
+    assert_eq!(err.code(), ErrorCode::SYNTAX_EXCEPTION);
+    assert!(err
+        .message()
+        .contains("the INSERT statement was parsed incrementally and failed after"));
+    assert!(err
+        .message()
+        .contains("the error is likely in the VALUES payload"));
+
+    // Short statements are tokenized at once, nothing is probed.
+    let err = planner
+        .plan_sql("insert into t valuse (1, 'a')")
+        .await
+        .unwrap_err();
+    assert_eq!(err.code(), ErrorCode::SYNTAX_EXCEPTION);
+    assert!(!err.message().contains("probed"));
+
+    Ok(())
+}
@@ -13,5 +13,13 @@
 // limitations under the License.
 
 mod builders;
+mod copy;
+mod decorrelation;
+mod dialect_hint;
 mod format;
+mod identity_eval_scalar;
+mod insert;
+mod multi_statement;
+mod rewrite;
 mod semantic;
+mod statement_limit;
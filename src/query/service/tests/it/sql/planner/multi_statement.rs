@@ -0,0 +1,62 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use databend_query::sql::plans::Plan;
+use databend_query::sql::Planner;
+
+const SQL: &str = "select 1; select 2;";
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_plan_sql_multiple_statements() -> Result<()> {
+    let (_guard, ctx) = crate::tests::create_query_context().await?;
+    let mut planner = Planner::new(ctx);
+
+    let err = planner.plan_sql(SQL).await.unwrap_err();
+    assert_eq!(err.code(), ErrorCode::SYNTAX_EXCEPTION);
+    assert_eq!(
+        err.message(),
+        "multiple statements are not supported in this endpoint"
+    );
+    let span = err.span().unwrap();
+    assert_eq!(&SQL[span.start..span.end], "select");
+    assert_eq!(span.start, 10);
+
+    // A single statement with a trailing semicolon.
+    planner.plan_sql("select 1;").await?;
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_plan_sqls() -> Result<()> {
+    let (_guard, ctx) = crate::tests::create_query_context().await?;
+    let mut planner = Planner::new(ctx);
+
+    let plans = planner.plan_sqls(SQL).await?;
+    assert_eq!(plans.len(), 2);
+    for (plan, _) in plans {
+        assert!(matches!(plan, Plan::Query { .. }));
+    }
+
+    // The last statement doesn't need to end with a semicolon.
+    let plans = planner.plan_sqls("select 1;\nselect 2").await?;
+    assert_eq!(plans.len(), 2);
+
+    assert!(planner.plan_sqls("").await?.is_empty());
+
+    Ok(())
+}
@@ -0,0 +1,49 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::base::tokio;
+use common_exception::Result;
+use databend_query::sessions::TableContext;
+use databend_query::sql::Planner;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_planner_rewrite_diff() -> Result<()> {
+    let (_guard, ctx) = crate::tests::create_query_context().await?;
+
+    // No rewrite applies.
+    let mut planner = Planner::new(ctx.clone());
+    let (_, extras) = planner.plan_sql("select number from numbers(10)").await?;
+    assert!(extras.rewrite_diff.is_empty());
+
+    // `count(distinct)` is rewritten to a count over a grouped subquery.
+    let (_, extras) = planner
+        .plan_sql("select count(distinct number) from numbers(10)")
+        .await?;
+    let lines = extras.rewrite_diff.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[0], "- SELECT count(DISTINCT number) FROM numbers(10)");
+    assert!(lines[1].starts_with("+ SELECT count() FROM (SELECT "));
+    assert!(lines[1].contains("GROUP BY number"));
+
+    // The limit of `max_result_rows` is added to queries.
+    ctx.get_settings()
+        .set_settings("max_result_rows".to_string(), "5".to_string(), false)?;
+    let (_, extras) = planner.plan_sql("select number from numbers(10)").await?;
+    assert_eq!(
+        extras.rewrite_diff,
+        "- SELECT number FROM numbers(10)\n+ SELECT number FROM numbers(10) LIMIT 5"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use databend_query::sql::Planner;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_aggregate_mixed_with_bare_column() -> Result<()> {
+    let (_guard, ctx) = crate::tests::create_query_context().await?;
+    let mut planner = Planner::new(ctx);
+
+    let sql = "select number % 3 as a, count(*) from numbers(10)";
+    let err = planner.plan_sql(sql).await.unwrap_err();
+    assert_eq!(err.code(), ErrorCode::SEMANTIC_ERROR);
+    assert_eq!(
+        err.message(),
+        "column \"number\" must appear in the GROUP BY clause or be used in an aggregate function"
+    );
+    let span = err.span().unwrap();
+    assert_eq!(&sql[span.start..span.end], "number");
+    assert_eq!(span.start, 7);
+
+    // The same projection is valid once the column is covered by the GROUP BY.
+    let sql = "select number % 3 as a, count(*) from numbers(10) group by a";
+    planner.plan_sql(sql).await?;
+
+    Ok(())
+}
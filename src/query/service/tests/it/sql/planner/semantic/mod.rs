@@ -12,4 +12,6 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod aggregate;
 mod name_resolution;
+mod order_by;
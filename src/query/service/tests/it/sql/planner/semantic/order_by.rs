@@ -0,0 +1,76 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use databend_query::sql::Planner;
+
+async fn plan_error(sql: &str) -> Result<ErrorCode> {
+    let (_guard, ctx) = crate::tests::create_query_context().await?;
+    let mut planner = Planner::new(ctx);
+    Ok(planner.plan_sql(sql).await.unwrap_err())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_set_operation_order_by_aggregate() -> Result<()> {
+    let sql =
+        "select number from numbers(3) union all select number from numbers(3) order by count(*)";
+    let err = plan_error(sql).await?;
+
+    assert_eq!(err.code(), ErrorCode::UNSUPPORTED_ORDER_BY);
+    assert_eq!(
+        err.message(),
+        "aggregate function `COUNT(*)` is not supported in ORDER BY of set operation, only output columns can be referenced"
+    );
+    let span = err.span().unwrap();
+    assert_eq!(&sql[span.start..span.end], "count(*)");
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_set_operation_order_by_position() -> Result<()> {
+    let sql = "select number from numbers(3) union all select number from numbers(3) order by 1";
+    let err = plan_error(sql).await?;
+
+    assert_eq!(err.code(), ErrorCode::UNSUPPORTED_ORDER_BY);
+    assert_eq!(
+        err.message(),
+        "position `1` is not supported in ORDER BY of set operation, only output columns can be referenced"
+    );
+    let span = err.span().unwrap();
+    assert_eq!(&sql[span.start..span.end], "1");
+    assert_eq!(span.start, sql.len() - 1);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_order_by_ungrouped_column() -> Result<()> {
+    let sql = "select a from (select number as a, number + 1 as b from numbers(3)) t group by a order by b";
+    let err = plan_error(sql).await?;
+
+    assert_eq!(err.code(), ErrorCode::SEMANTIC_ERROR);
+    // The derived column is displayed by its alias, not by the expression `number + 1`.
+    assert_eq!(
+        err.message(),
+        "ORDER BY column `b` must appear in GROUP BY or be used in an aggregate"
+    );
+    let span = err.span().unwrap();
+    assert_eq!(&sql[span.start..span.end], "b");
+    assert_eq!(span.start, sql.len() - 1);
+
+    Ok(())
+}
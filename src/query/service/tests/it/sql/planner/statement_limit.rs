@@ -0,0 +1,77 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use databend_query::sessions::TableContext;
+use databend_query::sql::Planner;
+
+const SQL: &str = "select 1 + 1";
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_max_statement_bytes() -> Result<()> {
+    let (_guard, ctx) = crate::tests::create_query_context().await?;
+    let settings = ctx.get_settings();
+    let mut planner = Planner::new(ctx.clone());
+
+    settings.set_settings(
+        "max_statement_bytes".to_string(),
+        SQL.len().to_string(),
+        false,
+    )?;
+    planner.plan_sql(SQL).await?;
+
+    settings.set_settings(
+        "max_statement_bytes".to_string(),
+        (SQL.len() - 1).to_string(),
+        false,
+    )?;
+    let err = planner.plan_sql(SQL).await.unwrap_err();
+    assert_eq!(err.code(), ErrorCode::BAD_ARGUMENTS);
+    assert_eq!(
+        err.message(),
+        "Statement of 12 bytes exceeds the limit of 11 bytes (max_statement_bytes)"
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_max_statement_tokens() -> Result<()> {
+    let (_guard, ctx) = crate::tests::create_query_context().await?;
+    let settings = ctx.get_settings();
+    let mut planner = Planner::new(ctx.clone());
+
+    // `select`, `1`, `+` and `1`.
+    settings.set_settings("max_statement_tokens".to_string(), "4".to_string(), false)?;
+    planner.plan_sql(SQL).await?;
+
+    settings.set_settings("max_statement_tokens".to_string(), "3".to_string(), false)?;
+    let err = planner.plan_sql(SQL).await.unwrap_err();
+    assert_eq!(err.code(), ErrorCode::BAD_ARGUMENTS);
+    assert_eq!(
+        err.message(),
+        "Statement of 4 tokens exceeds the limit of 3 tokens (max_statement_tokens)"
+    );
+
+    // The limit doesn't apply to INSERT statements.
+    let err = planner
+        .plan_sql("insert into t values (1)")
+        .await
+        .unwrap_err();
+    assert_ne!(err.code(), ErrorCode::BAD_ARGUMENTS);
+
+    Ok(())
+}
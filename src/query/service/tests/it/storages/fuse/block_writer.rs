@@ -102,8 +102,13 @@ impl<'a> BlockWriter<'a> {
             .location_generator
             .block_bloom_index_location(&block_id);
 
-        let maybe_bloom_index =
-            BloomIndex::try_create(FunctionContext::default(), schema, location.1, &[block])?;
+        let maybe_bloom_index = BloomIndex::try_create(
+            FunctionContext::default(),
+            schema,
+            location.1,
+            &[block],
+            None,
+        )?;
         if let Some(bloom_index) = maybe_bloom_index {
             let index_block = bloom_index.serialize_to_data_block()?;
             let filter_schema = bloom_index.filter_schema;
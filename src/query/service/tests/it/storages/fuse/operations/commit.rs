@@ -28,6 +28,7 @@ use common_catalog::database::Database;
 use common_catalog::plan::DataSourcePlan;
 use common_catalog::plan::PartInfoPtr;
 use common_catalog::plan::Partitions;
+use common_catalog::runtime_filter_info::RuntimeFilterInfo;
 use common_catalog::table::Table;
 use common_catalog::table_context::ProcessInfo;
 use common_catalog::table_context::StageAttachment;
@@ -38,8 +39,10 @@ use common_expression::DataBlock;
 use common_expression::FunctionContext;
 use common_io::prelude::FormatSettings;
 use common_meta_app::principal::FileFormatOptions;
+use common_meta_app::principal::GrantObject;
 use common_meta_app::principal::RoleInfo;
 use common_meta_app::principal::UserInfo;
+use common_meta_app::principal::UserPrivilegeType;
 use common_meta_app::schema::CountTablesReply;
 use common_meta_app::schema::CountTablesReq;
 use common_meta_app::schema::CreateDatabaseReply;
@@ -73,6 +76,7 @@ use common_meta_app::schema::UpsertTableOptionReq;
 use common_meta_types::MetaId;
 use common_settings::Settings;
 use common_storage::DataOperator;
+use common_storage::FileLoadStatus;
 use common_storage::StageFileInfo;
 use common_storages_fuse::operations::AppendOperationLogEntry;
 use common_storages_fuse::FuseTable;
@@ -382,6 +386,14 @@ impl TableContext for CtxDelegation {
         todo!()
     }
 
+    fn add_runtime_filter(&self, _table_index: usize, _info: Arc<RuntimeFilterInfo>) {
+        todo!()
+    }
+
+    fn get_runtime_filters(&self, _table_index: usize) -> Vec<Arc<RuntimeFilterInfo>> {
+        todo!()
+    }
+
     fn attach_query_str(&self, _kind: String, _query: String) {
         todo!()
     }
@@ -475,7 +487,11 @@ impl TableContext for CtxDelegation {
         todo!()
     }
 
-    fn set_on_error_map(&self, _map: Option<HashMap<String, ErrorCode>>) {
+    fn set_file_load_status(&self, _status: HashMap<String, FileLoadStatus>) {
+        todo!()
+    }
+
+    fn set_on_error_rows(&self, _rows: u64) {
         todo!()
     }
 
@@ -503,6 +519,14 @@ impl TableContext for CtxDelegation {
         todo!()
     }
 
+    async fn validate_privilege(
+        &self,
+        _object: &GrantObject,
+        _privileges: Vec<UserPrivilegeType>,
+    ) -> Result<()> {
+        todo!()
+    }
+
     async fn get_table(
         &self,
         _catalog: &str,
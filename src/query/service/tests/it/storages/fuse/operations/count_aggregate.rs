@@ -0,0 +1,77 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::base::tokio;
+use common_exception::Result;
+use common_expression::DataBlock;
+use databend_query::sessions::TableContext;
+use futures::TryStreamExt;
+
+use crate::storages::fuse::table_test_fixture::execute_query;
+use crate::storages::fuse::table_test_fixture::TestFixture;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_fuse_fold_count_aggregate() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    fixture.create_default_table().await?;
+
+    // two blocks, three rows for each block, the ids are 1 and 2
+    let table = fixture.latest_default_table().await?;
+    let blocks = TestFixture::gen_sample_blocks_stream_ex(2, 3, 1)
+        .try_collect()
+        .await?;
+    fixture
+        .append_commit_blocks(table, blocks, false, true)
+        .await?;
+
+    // unfiltered COUNT(*) is answered by the row count of the snapshot
+    {
+        let ctx = fixture.ctx();
+        let query = format!("select count(*) from {}.{}", db, tbl);
+        let stream = execute_query(ctx.clone(), &query).await?;
+        let blocks = stream.try_collect::<Vec<DataBlock>>().await?;
+
+        let expected = vec![
+            "+----------+",
+            "| Column 0 |",
+            "+----------+",
+            "| 6        |",
+            "+----------+",
+        ];
+        common_expression::block_debug::assert_blocks_sorted_eq(expected, blocks.as_slice());
+        assert_eq!(ctx.get_scan_progress_value().rows, 0);
+    }
+
+    // the rows are read if there is a filter
+    {
+        let ctx = fixture.ctx();
+        let query = format!("select count(*) from {}.{} where id < 2", db, tbl);
+        let stream = execute_query(ctx.clone(), &query).await?;
+        let blocks = stream.try_collect::<Vec<DataBlock>>().await?;
+
+        let expected = vec![
+            "+----------+",
+            "| Column 0 |",
+            "+----------+",
+            "| 3        |",
+            "+----------+",
+        ];
+        common_expression::block_debug::assert_blocks_sorted_eq(expected, blocks.as_slice());
+        assert!(ctx.get_scan_progress_value().rows > 0);
+    }
+
+    Ok(())
+}
@@ -17,8 +17,10 @@ mod alter_table;
 mod analyze;
 mod clustering;
 mod commit;
+mod count_aggregate;
 mod gc;
 mod internal_column;
+mod min_max_aggregate;
 mod mutation;
 mod navigate;
 mod optimize;
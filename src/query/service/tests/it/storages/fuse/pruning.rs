@@ -16,6 +16,7 @@ use std::sync::Arc;
 
 use common_ast::ast::Engine;
 use common_base::base::tokio;
+use common_catalog::plan::PruningStatistics;
 use common_catalog::plan::PushDownInfo;
 use common_exception::Result;
 use common_expression::types::number::Int64Type;
@@ -29,6 +30,9 @@ use common_expression::TableDataType;
 use common_expression::TableField;
 use common_expression::TableSchemaRef;
 use common_expression::TableSchemaRefExt;
+use common_meta_app::schema::UpsertTableOptionReq;
+use common_meta_types::MatchSeq;
+use common_sql::executor::PhysicalPlanBuilder;
 use common_sql::parse_to_remote_string_expr;
 use common_sql::plans::CreateTablePlan;
 use common_storages_fuse::pruning::FusePruner;
@@ -37,9 +41,13 @@ use databend_query::interpreters::CreateTableInterpreter;
 use databend_query::interpreters::Interpreter;
 use databend_query::sessions::QueryContext;
 use databend_query::sessions::TableContext;
+use databend_query::sql::plans::Plan;
+use databend_query::sql::Planner;
 use databend_query::storages::fuse::io::MetaReaders;
 use databend_query::storages::fuse::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
+use databend_query::storages::fuse::FUSE_OPT_KEY_BLOOM_INDEX_COLUMNS;
 use databend_query::storages::fuse::FUSE_OPT_KEY_ROW_PER_BLOCK;
+use futures::TryStreamExt;
 use opendal::Operator;
 use storages_common_cache::LoadParams;
 use storages_common_table_meta::meta::BlockMeta;
@@ -48,6 +56,8 @@ use storages_common_table_meta::meta::Versioned;
 use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 
+use crate::storages::fuse::table_test_fixture::execute_command;
+use crate::storages::fuse::table_test_fixture::execute_query;
 use crate::storages::fuse::table_test_fixture::TestFixture;
 
 async fn apply_block_pruning(
@@ -254,3 +264,204 @@ async fn test_block_pruner() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_physical_plan_pruning_stats() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+    let db = fixture.default_db_name();
+
+    execute_command(ctx.clone(), &format!("create table {db}.t(a int)")).await?;
+    // One segment per insertion: [1, 2], [3, 4] and [5, 6].
+    for values in ["(1),(2)", "(3),(4)", "(5),(6)"] {
+        execute_command(ctx.clone(), &format!("insert into {db}.t values {values}")).await?;
+    }
+
+    let query = format!("select * from {db}.t where a > 4");
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _) = planner.plan_sql(&query).await?;
+    let (s_expr, metadata) = match plan {
+        Plan::Query {
+            s_expr, metadata, ..
+        } => (s_expr, metadata),
+        _ => unreachable!(),
+    };
+
+    let physical_plan = PhysicalPlanBuilder::new(metadata.clone(), ctx.clone())
+        .build(&s_expr)
+        .await?;
+    let pruning_stats = physical_plan.pruning_stats(&metadata);
+
+    assert_eq!(pruning_stats.len(), 1);
+    let (table_name, stats) = &pruning_stats[0];
+    assert_eq!(table_name, &format!("default.{db}.t"));
+    assert_eq!(stats.segments_range_pruning_before, 3);
+    assert_eq!(stats.segments_range_pruning_after, 1);
+    assert_eq!(stats.blocks_range_pruning_before, 1);
+    assert_eq!(stats.blocks_range_pruning_after, 1);
+
+    Ok(())
+}
+
+async fn table_pruning_stats(ctx: Arc<QueryContext>, query: &str) -> Result<PruningStatistics> {
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _) = planner.plan_sql(query).await?;
+    let (s_expr, metadata) = match plan {
+        Plan::Query {
+            s_expr, metadata, ..
+        } => (s_expr, metadata),
+        _ => unreachable!(),
+    };
+
+    let physical_plan = PhysicalPlanBuilder::new(metadata.clone(), ctx)
+        .build(&s_expr)
+        .await?;
+    let (_, stats) = physical_plan.pruning_stats(&metadata).remove(0);
+    Ok(stats)
+}
+
+async fn bloom_pruning_stats(ctx: Arc<QueryContext>, query: &str) -> Result<(usize, usize)> {
+    let stats = table_pruning_stats(ctx, query).await?;
+    Ok((
+        stats.blocks_bloom_pruning_before,
+        stats.blocks_bloom_pruning_after,
+    ))
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_bloom_index_columns_pruning() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+    let db = fixture.default_db_name();
+
+    // Only column `a` gets a bloom filter.
+    execute_command(
+        ctx.clone(),
+        &format!("create table {db}.t(a int, b int) bloom_index_columns='a'"),
+    )
+    .await?;
+    // Two blocks, whose min/max can't rule out `a = 2` or `b = 20`.
+    for _ in 0..2 {
+        execute_command(
+            ctx.clone(),
+            &format!("insert into {db}.t values (1, 10), (3, 30)"),
+        )
+        .await?;
+    }
+
+    // The filter of `a` prunes both blocks.
+    let query = format!("select * from {db}.t where a = 2");
+    assert_eq!(bloom_pruning_stats(ctx.clone(), &query).await?, (2, 0));
+    // `b` has no filter, nothing is pruned.
+    let query = format!("select * from {db}.t where b = 20");
+    assert_eq!(bloom_pruning_stats(ctx.clone(), &query).await?, (2, 2));
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_bloom_memory_budget_pruning() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+    let db = fixture.default_db_name();
+
+    execute_command(ctx.clone(), &format!("create table {db}.t(a int, b int)")).await?;
+    for _ in 0..2 {
+        execute_command(
+            ctx.clone(),
+            &format!("insert into {db}.t values (1, 10), (3, 30)"),
+        )
+        .await?;
+    }
+
+    let query = format!("select * from {db}.t where a = 2");
+    let stats = table_pruning_stats(ctx.clone(), &query).await?;
+    assert_eq!(stats.blocks_bloom_pruning_before, 2);
+    assert_eq!(stats.blocks_bloom_pruning_after, 0);
+    assert_eq!(stats.blocks_bloom_pruning_skipped, 0);
+
+    // No filter fits in the budget, the blocks are kept by range pruning only.
+    ctx.get_settings().set_settings(
+        "max_bloom_filter_memory_per_segment".to_string(),
+        "1".to_string(),
+        false,
+    )?;
+    let stats = table_pruning_stats(ctx.clone(), &query).await?;
+    assert_eq!(stats.blocks_bloom_pruning_before, 2);
+    assert_eq!(stats.blocks_bloom_pruning_after, 2);
+    assert_eq!(stats.blocks_bloom_pruning_skipped, 2);
+
+    // Which is conservative, the result is still correct.
+    let blocks = execute_query(ctx.clone(), &query)
+        .await?
+        .try_collect::<Vec<DataBlock>>()
+        .await?;
+    assert_eq!(blocks.iter().map(|b| b.num_rows()).sum::<usize>(), 0);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_backfill_bloom_index() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+    let tenant = fixture.default_tenant();
+    let db = fixture.default_db_name();
+
+    execute_command(
+        ctx.clone(),
+        &format!("create table {db}.t(a int, b int) bloom_index_columns='a'"),
+    )
+    .await?;
+    for _ in 0..2 {
+        execute_command(
+            ctx.clone(),
+            &format!("insert into {db}.t values (1, 10), (3, 30)"),
+        )
+        .await?;
+    }
+    let query = format!("select * from {db}.t where b = 20");
+    assert_eq!(bloom_pruning_stats(ctx.clone(), &query).await?, (2, 2));
+
+    // Enable the bloom filter of `b`, it only applies to new blocks.
+    let catalog = ctx.get_catalog("default")?;
+    let table = catalog.get_table(&tenant, &db, "t").await?;
+    let table_info = table.get_table_info();
+    catalog
+        .upsert_table_option(&tenant, &db, UpsertTableOptionReq {
+            table_id: table_info.ident.table_id,
+            seq: MatchSeq::Exact(table_info.ident.seq),
+            options: [(
+                FUSE_OPT_KEY_BLOOM_INDEX_COLUMNS.to_owned(),
+                Some("a,b".to_owned()),
+            )]
+            .into(),
+        })
+        .await?;
+    assert_eq!(bloom_pruning_stats(ctx.clone(), &query).await?, (2, 2));
+
+    // Backfill the existing blocks, twice to make sure it can be run again.
+    for _ in 0..2 {
+        let table = catalog.get_table(&tenant, &db, "t").await?;
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+        assert_eq!(fuse_table.backfill_bloom_index(ctx.clone()).await?, 2);
+        assert_eq!(bloom_pruning_stats(ctx.clone(), &query).await?, (2, 0));
+    }
+
+    // The data is left untouched.
+    let query = format!("select count(*) from {db}.t where b = 30");
+    let blocks = execute_query(ctx.clone(), &query)
+        .await?
+        .try_collect::<Vec<DataBlock>>()
+        .await?;
+    let expected = vec![
+        "+----------+",
+        "| Column 0 |",
+        "+----------+",
+        "| 2        |",
+        "+----------+",
+    ];
+    common_expression::block_debug::assert_blocks_sorted_eq(expected, blocks.as_slice());
+
+    Ok(())
+}
@@ -21,6 +21,7 @@ use std::fmt::Debug;
 use std::fmt::Formatter;
 use std::str;
 use std::sync::Arc;
+use std::time::Duration;
 
 use common_ast::Dialect;
 use common_base::runtime::GlobalIORuntime;
@@ -450,6 +451,16 @@ impl Settings {
                 desc: "Enables generating a bushy join plan with the optimizer.",
                 possible_values: None,
             },
+            SettingValue {
+                default_value: UserSettingValue::UInt64(0),
+                user_setting: UserSetting::create(
+                    "enable_join_reorder",
+                    UserSettingValue::UInt64(0),
+                ),
+                level: ScopeLevel::Session,
+                desc: "Enables reordering the inner joins of more than two tables by the estimated cardinalities.",
+                possible_values: None,
+            },
             SettingValue {
                 default_value: UserSettingValue::UInt64(0),
                 user_setting: UserSetting::create(
@@ -501,6 +512,129 @@ impl Settings {
                 desc: "Sets the maximum amount of memory in bytes that an aggregator can use before spilling data to storage during query execution.",
                 possible_values: None,
             },
+            SettingValue {
+                default_value: UserSettingValue::UInt64(0),
+                user_setting: UserSetting::create(
+                    "enable_strict_default_expr",
+                    UserSettingValue::UInt64(0),
+                ),
+                level: ScopeLevel::Session,
+                desc: "Rejects non-deterministic functions, such as rand() and gen_random_uuid(), in column default expressions.",
+                possible_values: None,
+            },
+            SettingValue {
+                default_value: UserSettingValue::String("".to_owned()),
+                user_setting: UserSetting::create(
+                    "spilling_location_prefixes",
+                    UserSettingValue::String("".to_owned()),
+                ),
+                level: ScopeLevel::Session,
+                desc: "Sets a comma-separated list of storage prefixes that aggregation spill files are spread across. Defaults to \"_aggregate_spill\" when empty.",
+                possible_values: None,
+            },
+            SettingValue {
+                default_value: UserSettingValue::String("round_robin".to_owned()),
+                user_setting: UserSetting::create(
+                    "spilling_location_strategy",
+                    UserSettingValue::String("round_robin".to_owned()),
+                ),
+                level: ScopeLevel::Session,
+                desc: "Sets how a spilling location prefix is chosen for each spill file. Available values include \"round_robin\" and \"least_bytes\".",
+                possible_values: Some(vec!["round_robin", "least_bytes"]),
+            },
+            SettingValue {
+                default_value: UserSettingValue::UInt64(0),
+                user_setting: UserSetting::create(
+                    "enable_spilling_encryption",
+                    UserSettingValue::UInt64(0),
+                ),
+                level: ScopeLevel::Session,
+                desc: "Enables AES-GCM encryption of aggregate spill files.",
+                possible_values: None,
+            },
+            SettingValue {
+                default_value: UserSettingValue::UInt64(0),
+                user_setting: UserSetting::create("spilling_max_bytes", UserSettingValue::UInt64(0)),
+                level: ScopeLevel::Session,
+                desc: "Sets the maximum number of bytes an aggregation can spill to storage, 0 means no limit.",
+                possible_values: None,
+            },
+            SettingValue {
+                default_value: UserSettingValue::UInt64(2),
+                user_setting: UserSetting::create("spilling_prefetch_depth", UserSettingValue::UInt64(2)),
+                level: ScopeLevel::Session,
+                desc: "Sets the number of aggregate spill files read ahead of the one being restored, 1 means no read ahead.",
+                possible_values: None,
+            },
+            SettingValue {
+                default_value: UserSettingValue::String("internal".to_owned()),
+                user_setting: UserSetting::create(
+                    "exchange_serialization_format",
+                    UserSettingValue::String("internal".to_owned()),
+                ),
+                level: ScopeLevel::Session,
+                desc: "Sets the serialization format of the blocks exchanged between cluster nodes. Available values include \"internal\" and \"arrow_ipc\".",
+                possible_values: Some(vec!["internal", "arrow_ipc"]),
+            },
+            SettingValue {
+                default_value: UserSettingValue::UInt64(0),
+                user_setting: UserSetting::create(
+                    "exchange_source_read_timeout",
+                    UserSettingValue::UInt64(0),
+                ),
+                level: ScopeLevel::Session,
+                desc: "Sets the maximum time in seconds an exchange source waits for data from a remote fragment, 0 means no limit.",
+                possible_values: None,
+            },
+            SettingValue {
+                default_value: UserSettingValue::UInt64(0),
+                user_setting: UserSetting::create("enable_dialect_hint", UserSettingValue::UInt64(0)),
+                level: ScopeLevel::Session,
+                desc: "Enables a leading `-- dialect: <name>` comment to override sql_dialect for a single statement.",
+                possible_values: None,
+            },
+            SettingValue {
+                default_value: UserSettingValue::UInt64(0),
+                user_setting: UserSetting::create("max_statement_bytes", UserSettingValue::UInt64(0)),
+                level: ScopeLevel::Session,
+                desc: "Sets the maximum length in bytes of a SQL statement, 0 means no limit.",
+                possible_values: None,
+            },
+            SettingValue {
+                default_value: UserSettingValue::UInt64(0),
+                user_setting: UserSetting::create("max_statement_tokens", UserSettingValue::UInt64(0)),
+                level: ScopeLevel::Session,
+                desc: "Sets the maximum number of tokens of a SQL statement other than INSERT, 0 means no limit.",
+                possible_values: None,
+            },
+            SettingValue {
+                default_value: UserSettingValue::UInt64(1),
+                user_setting: UserSetting::create("enable_subquery_decorrelation", UserSettingValue::UInt64(1)),
+                level: ScopeLevel::Session,
+                desc: "Enables decorrelating subqueries into joins, disable it only to compare plans for debugging, as a query with subqueries can't be executed without it.",
+                possible_values: None,
+            },
+            SettingValue {
+                default_value: UserSettingValue::UInt64(256),
+                user_setting: UserSetting::create("max_decorrelation_depth", UserSettingValue::UInt64(256)),
+                level: ScopeLevel::Session,
+                desc: "Sets the maximum depth of the plan of a correlated subquery that can be decorrelated, a deeper one fails to avoid overflowing the stack.",
+                possible_values: None,
+            },
+            SettingValue {
+                default_value: UserSettingValue::UInt64(0),
+                user_setting: UserSetting::create("role_cache_stale_tolerance_secs", UserSettingValue::UInt64(0)),
+                level: ScopeLevel::Session,
+                desc: "Sets the seconds the cached roles can be used beyond the regular staleness threshold before they are reloaded synchronously on checking privileges, 0 means no tolerance.",
+                possible_values: None,
+            },
+            SettingValue {
+                default_value: UserSettingValue::UInt64(0),
+                user_setting: UserSetting::create("max_bloom_filter_memory_per_segment", UserSettingValue::UInt64(0)),
+                level: ScopeLevel::Session,
+                desc: "Sets the maximum memory in bytes of the bloom filters loaded at the same time when pruning the blocks of a segment, the blocks beyond it are pruned by range index only, 0 means no limit.",
+                possible_values: None,
+            },
         ];
 
         let settings: Arc<DashMap<String, SettingValue>> = Arc::new(DashMap::default());
@@ -634,6 +768,53 @@ impl Settings {
         self.try_get_u64(key)
     }
 
+    pub fn get_exchange_source_read_timeout(&self) -> Result<u64> {
+        let key = "exchange_source_read_timeout";
+        self.try_get_u64(key)
+    }
+
+    pub fn get_enable_dialect_hint(&self) -> Result<bool> {
+        let key = "enable_dialect_hint";
+        self.try_get_u64(key).map(|v| v != 0)
+    }
+
+    pub fn get_max_statement_bytes(&self) -> Result<u64> {
+        let key = "max_statement_bytes";
+        self.try_get_u64(key)
+    }
+
+    pub fn get_max_statement_tokens(&self) -> Result<u64> {
+        let key = "max_statement_tokens";
+        self.try_get_u64(key)
+    }
+
+    pub fn get_enable_subquery_decorrelation(&self) -> Result<bool> {
+        let key = "enable_subquery_decorrelation";
+        self.try_get_u64(key).map(|v| v != 0)
+    }
+
+    pub fn get_max_decorrelation_depth(&self) -> Result<usize> {
+        let key = "max_decorrelation_depth";
+        self.try_get_u64(key).map(|v| v as usize)
+    }
+
+    pub fn get_role_cache_stale_tolerance(&self) -> Result<Duration> {
+        let key = "role_cache_stale_tolerance_secs";
+        self.try_get_u64(key).map(Duration::from_secs)
+    }
+
+    pub fn get_max_bloom_filter_memory_per_segment(&self) -> Result<u64> {
+        let key = "max_bloom_filter_memory_per_segment";
+        self.try_get_u64(key)
+    }
+
+    pub fn get_exchange_serialization_format(&self) -> Result<String> {
+        let key = "exchange_serialization_format";
+        self.check_and_get_setting_value(key)
+            .and_then(|v| v.user_setting.value.as_string())
+            .map(|v| v.to_lowercase())
+    }
+
     // Get storage read buffer size.
     pub fn get_storage_read_buffer_size(&self) -> Result<u64> {
         let key = "storage_read_buffer_size";
@@ -650,6 +831,12 @@ impl Settings {
         self.try_get_u64(KEY)
     }
 
+    pub fn get_enable_join_reorder(&self) -> Result<bool> {
+        static KEY: &str = "enable_join_reorder";
+        let v = self.try_get_u64(KEY)?;
+        Ok(v != 0)
+    }
+
     pub fn get_timezone(&self) -> Result<String> {
         let key = "timezone";
         self.check_and_get_setting_value(key)
@@ -834,6 +1021,46 @@ impl Settings {
         self.try_set_u64(key, value as u64, false)
     }
 
+    pub fn get_spilling_max_bytes(&self) -> Result<usize> {
+        let key = "spilling_max_bytes";
+        self.try_get_u64(key).map(|v| v as usize)
+    }
+
+    pub fn get_spilling_prefetch_depth(&self) -> Result<usize> {
+        let key = "spilling_prefetch_depth";
+        self.try_get_u64(key).map(|v| v as usize)
+    }
+
+    pub fn get_spilling_location_prefixes(&self) -> Result<Vec<String>> {
+        let key = "spilling_location_prefixes";
+        self.check_and_get_setting_value(key)
+            .and_then(|v| v.user_setting.value.as_string())
+            .map(|v| {
+                v.split(',')
+                    .map(str::trim)
+                    .filter(|prefix| !prefix.is_empty())
+                    .map(str::to_string)
+                    .collect()
+            })
+    }
+
+    pub fn get_spilling_location_strategy(&self) -> Result<String> {
+        let key = "spilling_location_strategy";
+        self.check_and_get_setting_value(key)
+            .and_then(|v| v.user_setting.value.as_string())
+            .map(|v| v.to_lowercase())
+    }
+
+    pub fn get_enable_spilling_encryption(&self) -> Result<bool> {
+        let key = "enable_spilling_encryption";
+        self.try_get_u64(key).map(|v| v != 0)
+    }
+
+    pub fn get_enable_strict_default_expr(&self) -> Result<bool> {
+        let key = "enable_strict_default_expr";
+        self.try_get_u64(key).map(|v| v != 0)
+    }
+
     pub fn has_setting(&self, key: &str) -> bool {
         self.settings.get(key).is_some()
     }
@@ -14,6 +14,7 @@
 
 use common_ast::ast::FormatTreeNode;
 use common_catalog::plan::PartStatistics;
+use common_catalog::plan::PruningStatistics;
 use common_exception::Result;
 use common_functions::scalars::BUILTIN_FUNCTIONS;
 use common_profile::ProfSpanSetRef;
@@ -23,17 +24,21 @@ use super::AggregateExpand;
 use super::AggregateFinal;
 use super::AggregateFunctionDesc;
 use super::AggregatePartial;
+use super::CteScan;
 use super::EvalScalar;
 use super::Exchange;
 use super::Filter;
 use super::HashJoin;
 use super::Limit;
+use super::MaterializedCte;
 use super::PhysicalPlan;
 use super::Project;
 use super::Sort;
 use super::TableScan;
 use super::UnionAll;
 use super::Unnest;
+use super::Window;
+use crate::column_name_of;
 use crate::executor::explain::PlanStatsInfo;
 use crate::executor::DistributedInsertSelect;
 use crate::executor::ExchangeSink;
@@ -52,45 +57,146 @@ impl PhysicalPlan {
         &self,
         metadata: MetadataRef,
         prof_span_set: ProfSpanSetRef,
+        include_profiling: bool,
     ) -> Result<FormatTreeNode<String>> {
-        to_format_tree(self, &metadata, &prof_span_set)
+        to_format_tree(self, &metadata, &prof_span_set, include_profiling)
+    }
+
+    /// Same as `format`, but as a JSON tree for programmatic consumers.
+    ///
+    /// Each operator is an object with its `name`, its `properties` (e.g. `filters`
+    /// or `estimated rows`) and its `children` operators.
+    pub fn format_json(
+        &self,
+        metadata: MetadataRef,
+        prof_span_set: ProfSpanSetRef,
+        include_profiling: bool,
+    ) -> Result<serde_json::Value> {
+        let tree = to_format_tree(self, &metadata, &prof_span_set, include_profiling)?;
+        Ok(format_tree_to_json(&tree))
+    }
+
+    /// The pruning statistics of the table scans in the plan, along with their table names.
+    ///
+    /// Same as the `pruning stats` displayed by `format`, but typed for programmatic consumers.
+    pub fn pruning_stats(&self, metadata: &MetadataRef) -> Vec<(String, PruningStatistics)> {
+        let mut pruning_stats = vec![];
+        collect_pruning_stats(self, metadata, &mut pruning_stats);
+        pruning_stats
     }
 }
 
+// The properties of an operator are the leaves formatted as `key: value`,
+// the other children are the child operators.
+fn format_tree_to_json(node: &FormatTreeNode<String>) -> serde_json::Value {
+    let mut properties = serde_json::Map::new();
+    let mut children = vec![];
+    for child in node.children.iter() {
+        match child.payload.split_once(": ") {
+            Some((key, value)) if child.children.is_empty() => {
+                properties.insert(key.to_string(), serde_json::Value::from(value));
+            }
+            _ => children.push(format_tree_to_json(child)),
+        }
+    }
+
+    serde_json::json!({
+        "name": node.payload,
+        "properties": properties,
+        "children": children,
+    })
+}
+
+fn collect_pruning_stats(
+    plan: &PhysicalPlan,
+    metadata: &MetadataRef,
+    pruning_stats: &mut Vec<(String, PruningStatistics)>,
+) {
+    if let PhysicalPlan::TableScan(plan) = plan {
+        if plan.table_index != DUMMY_TABLE_INDEX {
+            let table_name = table_scan_name(plan, metadata);
+            let stats = plan.source.statistics.pruning_stats.clone();
+            pruning_stats.push((table_name, stats));
+        }
+    }
+
+    for child in plan.children() {
+        collect_pruning_stats(child, metadata, pruning_stats);
+    }
+}
+
+fn table_scan_name(plan: &TableScan, metadata: &MetadataRef) -> String {
+    let table = metadata.read().table(plan.table_index).clone();
+    format!("{}.{}.{}", table.catalog(), table.database(), table.name())
+}
+
 fn to_format_tree(
     plan: &PhysicalPlan,
     metadata: &MetadataRef,
     prof_span_set: &ProfSpanSetRef,
+    include_profiling: bool,
 ) -> Result<FormatTreeNode<String>> {
     match plan {
-        PhysicalPlan::TableScan(plan) => table_scan_to_format_tree(plan, metadata),
-        PhysicalPlan::Filter(plan) => filter_to_format_tree(plan, metadata, prof_span_set),
-        PhysicalPlan::Project(plan) => project_to_format_tree(plan, metadata, prof_span_set),
-        PhysicalPlan::EvalScalar(plan) => eval_scalar_to_format_tree(plan, metadata, prof_span_set),
+        PhysicalPlan::TableScan(plan) => {
+            table_scan_to_format_tree(plan, metadata, prof_span_set, include_profiling)
+        }
+        PhysicalPlan::Filter(plan) => {
+            filter_to_format_tree(plan, metadata, prof_span_set, include_profiling)
+        }
+        PhysicalPlan::Project(plan) => {
+            project_to_format_tree(plan, metadata, prof_span_set, include_profiling)
+        }
+        PhysicalPlan::EvalScalar(plan) => {
+            eval_scalar_to_format_tree(plan, metadata, prof_span_set, include_profiling)
+        }
         PhysicalPlan::AggregateExpand(plan) => {
-            aggregate_expand_to_format_tree(plan, metadata, prof_span_set)
+            aggregate_expand_to_format_tree(plan, metadata, prof_span_set, include_profiling)
         }
         PhysicalPlan::AggregatePartial(plan) => {
-            aggregate_partial_to_format_tree(plan, metadata, prof_span_set)
+            aggregate_partial_to_format_tree(plan, metadata, prof_span_set, include_profiling)
         }
         PhysicalPlan::AggregateFinal(plan) => {
-            aggregate_final_to_format_tree(plan, metadata, prof_span_set)
+            aggregate_final_to_format_tree(plan, metadata, prof_span_set, include_profiling)
+        }
+        PhysicalPlan::Sort(plan) => {
+            sort_to_format_tree(plan, metadata, prof_span_set, include_profiling)
+        }
+        PhysicalPlan::Window(plan) => {
+            window_to_format_tree(plan, metadata, prof_span_set, include_profiling)
+        }
+        PhysicalPlan::Limit(plan) => {
+            limit_to_format_tree(plan, metadata, prof_span_set, include_profiling)
+        }
+        PhysicalPlan::HashJoin(plan) => {
+            hash_join_to_format_tree(plan, metadata, prof_span_set, include_profiling)
+        }
+        PhysicalPlan::Exchange(plan) => {
+            exchange_to_format_tree(plan, metadata, prof_span_set, include_profiling)
+        }
+        PhysicalPlan::UnionAll(plan) => {
+            union_all_to_format_tree(plan, metadata, prof_span_set, include_profiling)
         }
-        PhysicalPlan::Sort(plan) => sort_to_format_tree(plan, metadata, prof_span_set),
-        PhysicalPlan::Limit(plan) => limit_to_format_tree(plan, metadata, prof_span_set),
-        PhysicalPlan::HashJoin(plan) => hash_join_to_format_tree(plan, metadata, prof_span_set),
-        PhysicalPlan::Exchange(plan) => exchange_to_format_tree(plan, metadata, prof_span_set),
-        PhysicalPlan::UnionAll(plan) => union_all_to_format_tree(plan, metadata, prof_span_set),
         PhysicalPlan::ExchangeSource(plan) => exchange_source_to_format_tree(plan),
         PhysicalPlan::ExchangeSink(plan) => {
-            exchange_sink_to_format_tree(plan, metadata, prof_span_set)
+            exchange_sink_to_format_tree(plan, metadata, prof_span_set, include_profiling)
         }
-        PhysicalPlan::DistributedInsertSelect(plan) => {
-            distributed_insert_to_format_tree(plan.as_ref(), metadata, prof_span_set)
+        PhysicalPlan::DistributedInsertSelect(plan) => distributed_insert_to_format_tree(
+            plan.as_ref(),
+            metadata,
+            prof_span_set,
+            include_profiling,
+        ),
+        PhysicalPlan::Unnest(plan) => {
+            unnest_to_format_tree(plan, metadata, prof_span_set, include_profiling)
         }
-        PhysicalPlan::Unnest(plan) => unnest_to_format_tree(plan, metadata, prof_span_set),
         PhysicalPlan::RuntimeFilterSource(plan) => {
-            runtime_filter_source_to_format_tree(plan, metadata, prof_span_set)
+            runtime_filter_source_to_format_tree(plan, metadata, prof_span_set, include_profiling)
+        }
+        PhysicalPlan::MaterializedCte(plan) => {
+            materialized_cte_to_format_tree(plan, metadata, prof_span_set, include_profiling)
+        }
+        PhysicalPlan::CteScan(plan) => {
+            cte_scan_to_format_tree(plan, prof_span_set, include_profiling)
         }
     }
 }
@@ -98,12 +204,13 @@ fn to_format_tree(
 fn table_scan_to_format_tree(
     plan: &TableScan,
     metadata: &MetadataRef,
+    prof_span_set: &ProfSpanSetRef,
+    include_profiling: bool,
 ) -> Result<FormatTreeNode<String>> {
     if plan.table_index == DUMMY_TABLE_INDEX {
         return Ok(FormatTreeNode::new("DummyTableScan".to_string()));
     }
-    let table = metadata.read().table(plan.table_index).clone();
-    let table_name = format!("{}.{}.{}", table.catalog(), table.database(), table.name());
+    let table_name = table_scan_name(plan, metadata);
     let filters = plan
         .source
         .push_downs
@@ -151,6 +258,12 @@ fn table_scan_to_format_tree(
         children.extend(items);
     }
 
+    children.extend(prof_span_to_format_tree(
+        plan.plan_id,
+        prof_span_set,
+        include_profiling,
+    ));
+
     Ok(FormatTreeNode::with_children(
         "TableScan".to_string(),
         children,
@@ -161,6 +274,7 @@ fn filter_to_format_tree(
     plan: &Filter,
     metadata: &MetadataRef,
     prof_span_set: &ProfSpanSetRef,
+    include_profiling: bool,
 ) -> Result<FormatTreeNode<String>> {
     let filter = plan
         .predicates
@@ -169,19 +283,42 @@ fn filter_to_format_tree(
         .join(", ");
     let mut children = vec![FormatTreeNode::new(format!("filters: [{filter}]"))];
 
+    // The selectivity of a single predicate is already given by the estimated rows.
+    if plan.predicates.len() > 1 && plan.selectivities.len() == plan.predicates.len() {
+        let selectivities = plan
+            .predicates
+            .iter()
+            .zip(plan.selectivities.iter())
+            .map(|(pred, selectivity)| {
+                format!(
+                    "{}: {:.2}",
+                    pred.as_expr(&BUILTIN_FUNCTIONS).sql_display(),
+                    selectivity
+                )
+            })
+            .join(", ");
+        children.push(FormatTreeNode::new(format!(
+            "estimated selectivities: [{selectivities}]"
+        )));
+    }
+
     if let Some(info) = &plan.stat_info {
         let items = plan_stats_info_to_format_tree(info);
         children.extend(items);
     }
 
-    if let Some(prof_span) = prof_span_set.lock().unwrap().get(&plan.plan_id) {
-        let process_time = prof_span.process_time / 1000 / 1000; // milliseconds
-        children.push(FormatTreeNode::new(format!(
-            "total process time: {process_time}ms"
-        )));
-    }
+    children.extend(prof_span_to_format_tree(
+        plan.plan_id,
+        prof_span_set,
+        include_profiling,
+    ));
 
-    children.push(to_format_tree(&plan.input, metadata, prof_span_set)?);
+    children.push(to_format_tree(
+        &plan.input,
+        metadata,
+        prof_span_set,
+        include_profiling,
+    )?);
 
     Ok(FormatTreeNode::with_children(
         "Filter".to_string(),
@@ -193,6 +330,7 @@ fn project_to_format_tree(
     plan: &Project,
     metadata: &MetadataRef,
     prof_span_set: &ProfSpanSetRef,
+    include_profiling: bool,
 ) -> Result<FormatTreeNode<String>> {
     let columns = plan
         .columns
@@ -221,14 +359,18 @@ fn project_to_format_tree(
         children.extend(items);
     }
 
-    if let Some(prof_span) = prof_span_set.lock().unwrap().get(&plan.plan_id) {
-        let process_time = prof_span.process_time / 1000 / 1000; // milliseconds
-        children.push(FormatTreeNode::new(format!(
-            "total process time: {process_time}ms"
-        )));
-    }
+    children.extend(prof_span_to_format_tree(
+        plan.plan_id,
+        prof_span_set,
+        include_profiling,
+    ));
 
-    children.push(to_format_tree(&plan.input, metadata, prof_span_set)?);
+    children.push(to_format_tree(
+        &plan.input,
+        metadata,
+        prof_span_set,
+        include_profiling,
+    )?);
 
     Ok(FormatTreeNode::with_children(
         "Project".to_string(),
@@ -240,11 +382,21 @@ fn eval_scalar_to_format_tree(
     plan: &EvalScalar,
     metadata: &MetadataRef,
     prof_span_set: &ProfSpanSetRef,
+    include_profiling: bool,
 ) -> Result<FormatTreeNode<String>> {
+    // Nullable outputs are annotated with their data type, to help tracking down unexpected NULLs.
     let scalars = plan
         .exprs
         .iter()
-        .map(|(expr, _)| expr.as_expr(&BUILTIN_FUNCTIONS).sql_display())
+        .map(|(expr, _)| {
+            let expr = expr.as_expr(&BUILTIN_FUNCTIONS);
+            let data_type = expr.data_type();
+            if data_type.is_nullable_or_null() {
+                format!("{}: {}", expr.sql_display(), data_type)
+            } else {
+                expr.sql_display()
+            }
+        })
         .collect::<Vec<_>>()
         .join(", ");
     let mut children = vec![FormatTreeNode::new(format!("expressions: [{scalars}]"))];
@@ -254,14 +406,18 @@ fn eval_scalar_to_format_tree(
         children.extend(items);
     }
 
-    if let Some(prof_span) = prof_span_set.lock().unwrap().get(&plan.plan_id) {
-        let process_time = prof_span.process_time / 1000 / 1000; // milliseconds
-        children.push(FormatTreeNode::new(format!(
-            "total process time: {process_time}ms"
-        )));
-    }
+    children.extend(prof_span_to_format_tree(
+        plan.plan_id,
+        prof_span_set,
+        include_profiling,
+    ));
 
-    children.push(to_format_tree(&plan.input, metadata, prof_span_set)?);
+    children.push(to_format_tree(
+        &plan.input,
+        metadata,
+        prof_span_set,
+        include_profiling,
+    )?);
 
     Ok(FormatTreeNode::with_children(
         "EvalScalar".to_string(),
@@ -270,32 +426,46 @@ fn eval_scalar_to_format_tree(
 }
 
 pub fn pretty_display_agg_desc(desc: &AggregateFunctionDesc, metadata: &MetadataRef) -> String {
-    format!(
-        "{}({})",
-        desc.sig.name,
-        desc.arg_indices
+    let args = desc
+        .arg_indices
+        .iter()
+        .map(|&index| {
+            let column = metadata.read().column(index).clone();
+            match column {
+                ColumnEntry::BaseTableColumn(BaseTableColumn { column_name, .. }) => column_name,
+                ColumnEntry::DerivedColumn(DerivedColumn { alias, .. }) => alias,
+                ColumnEntry::InternalColumn(TableInternalColumn {
+                    internal_column, ..
+                }) => internal_column.column_name().to_string(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    // `xxx(DISTINCT ...)` is planned as `xxx_distinct(...)`.
+    let (name, args) = match desc.sig.name.strip_suffix("_distinct") {
+        Some(name) if desc.sig.distinct => (name, format!("DISTINCT {}", args)),
+        _ => (desc.sig.name.as_str(), args),
+    };
+
+    if desc.sig.params.is_empty() {
+        format!("{}({})", name, args)
+    } else {
+        let params = desc
+            .sig
+            .params
             .iter()
-            .map(|&index| {
-                let column = metadata.read().column(index).clone();
-                match column {
-                    ColumnEntry::BaseTableColumn(BaseTableColumn { column_name, .. }) => {
-                        column_name
-                    }
-                    ColumnEntry::DerivedColumn(DerivedColumn { alias, .. }) => alias,
-                    ColumnEntry::InternalColumn(TableInternalColumn {
-                        internal_column, ..
-                    }) => internal_column.column_name().to_string(),
-                }
-            })
-            .collect::<Vec<_>>()
-            .join(", ")
-    )
+            .map(|param| param.clone().into_scalar().to_string())
+            .join(", ");
+        format!("{}({})({})", name, params, args)
+    }
 }
 
 fn aggregate_expand_to_format_tree(
     plan: &AggregateExpand,
     metadata: &MetadataRef,
     prof_span_set: &ProfSpanSetRef,
+    include_profiling: bool,
 ) -> Result<FormatTreeNode<String>> {
     let sets = plan
         .grouping_sets
@@ -329,14 +499,18 @@ fn aggregate_expand_to_format_tree(
         children.extend(items);
     }
 
-    if let Some(prof_span) = prof_span_set.lock().unwrap().get(&plan.plan_id) {
-        let process_time = prof_span.process_time / 1000 / 1000; // milliseconds
-        children.push(FormatTreeNode::new(format!(
-            "total process time: {process_time}ms"
-        )));
-    }
+    children.extend(prof_span_to_format_tree(
+        plan.plan_id,
+        prof_span_set,
+        include_profiling,
+    ));
 
-    children.push(to_format_tree(&plan.input, metadata, prof_span_set)?);
+    children.push(to_format_tree(
+        &plan.input,
+        metadata,
+        prof_span_set,
+        include_profiling,
+    )?);
 
     Ok(FormatTreeNode::with_children(
         "AggregateExpand".to_string(),
@@ -348,6 +522,7 @@ fn aggregate_partial_to_format_tree(
     plan: &AggregatePartial,
     metadata: &MetadataRef,
     prof_span_set: &ProfSpanSetRef,
+    include_profiling: bool,
 ) -> Result<FormatTreeNode<String>> {
     let group_by = plan
         .group_by
@@ -382,14 +557,18 @@ fn aggregate_partial_to_format_tree(
         children.extend(items);
     }
 
-    if let Some(prof_span) = prof_span_set.lock().unwrap().get(&plan.plan_id) {
-        let process_time = prof_span.process_time / 1000 / 1000; // milliseconds
-        children.push(FormatTreeNode::new(format!(
-            "total process time: {process_time}ms"
-        )));
-    }
+    children.extend(prof_span_to_format_tree(
+        plan.plan_id,
+        prof_span_set,
+        include_profiling,
+    ));
 
-    children.push(to_format_tree(&plan.input, metadata, prof_span_set)?);
+    children.push(to_format_tree(
+        &plan.input,
+        metadata,
+        prof_span_set,
+        include_profiling,
+    )?);
 
     Ok(FormatTreeNode::with_children(
         "AggregatePartial".to_string(),
@@ -401,6 +580,7 @@ fn aggregate_final_to_format_tree(
     plan: &AggregateFinal,
     metadata: &MetadataRef,
     prof_span_set: &ProfSpanSetRef,
+    include_profiling: bool,
 ) -> Result<FormatTreeNode<String>> {
     let group_by = plan
         .group_by
@@ -441,14 +621,18 @@ fn aggregate_final_to_format_tree(
         children.extend(items);
     }
 
-    if let Some(prof_span) = prof_span_set.lock().unwrap().get(&plan.plan_id) {
-        let process_time = prof_span.process_time / 1000 / 1000; // milliseconds
-        children.push(FormatTreeNode::new(format!(
-            "total process time: {process_time}ms"
-        )));
-    }
+    children.extend(prof_span_to_format_tree(
+        plan.plan_id,
+        prof_span_set,
+        include_profiling,
+    ));
 
-    children.push(to_format_tree(&plan.input, metadata, prof_span_set)?);
+    children.push(to_format_tree(
+        &plan.input,
+        metadata,
+        prof_span_set,
+        include_profiling,
+    )?);
 
     Ok(FormatTreeNode::with_children(
         "AggregateFinal".to_string(),
@@ -460,25 +644,16 @@ fn sort_to_format_tree(
     plan: &Sort,
     metadata: &MetadataRef,
     prof_span_set: &ProfSpanSetRef,
+    include_profiling: bool,
 ) -> Result<FormatTreeNode<String>> {
     let sort_keys = plan
         .order_by
         .iter()
         .map(|sort_key| {
             let index = sort_key.order_by;
-            let column = metadata.read().column(index).clone();
             Ok(format!(
                 "{} {} {}",
-                match column {
-                    ColumnEntry::BaseTableColumn(BaseTableColumn { column_name, .. }) =>
-                        column_name,
-                    ColumnEntry::DerivedColumn(DerivedColumn { alias, .. }) => alias,
-                    ColumnEntry::InternalColumn(TableInternalColumn {
-                        internal_column, ..
-                    }) => {
-                        internal_column.column_name().to_string()
-                    }
-                },
+                column_name_of(metadata.read().column(index)),
                 if sort_key.asc { "ASC" } else { "DESC" },
                 if sort_key.nulls_first {
                     "NULLS FIRST"
@@ -497,22 +672,91 @@ fn sort_to_format_tree(
         children.extend(items);
     }
 
-    if let Some(prof_span) = prof_span_set.lock().unwrap().get(&plan.plan_id) {
-        let process_time = prof_span.process_time / 1000 / 1000; // milliseconds
-        children.push(FormatTreeNode::new(format!(
-            "total process time: {process_time}ms"
-        )));
-    }
+    children.extend(prof_span_to_format_tree(
+        plan.plan_id,
+        prof_span_set,
+        include_profiling,
+    ));
 
-    children.push(to_format_tree(&plan.input, metadata, prof_span_set)?);
+    children.push(to_format_tree(
+        &plan.input,
+        metadata,
+        prof_span_set,
+        include_profiling,
+    )?);
 
     Ok(FormatTreeNode::with_children("Sort".to_string(), children))
 }
 
+fn window_to_format_tree(
+    plan: &Window,
+    metadata: &MetadataRef,
+    prof_span_set: &ProfSpanSetRef,
+    include_profiling: bool,
+) -> Result<FormatTreeNode<String>> {
+    let partition_by = plan
+        .partition_by
+        .iter()
+        .map(|&index| column_name_of(metadata.read().column(index)))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let order_by = plan
+        .order_by
+        .iter()
+        .map(|sort_key| {
+            format!(
+                "{} {} {}",
+                column_name_of(metadata.read().column(sort_key.order_by)),
+                if sort_key.asc { "ASC" } else { "DESC" },
+                if sort_key.nulls_first {
+                    "NULLS FIRST"
+                } else {
+                    "NULLS LAST"
+                }
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let agg_func = pretty_display_agg_desc(&plan.agg_func, metadata);
+
+    let mut children = vec![
+        FormatTreeNode::new(format!("aggregate function: [{agg_func}]")),
+        FormatTreeNode::new(format!("partition by: [{partition_by}]")),
+        FormatTreeNode::new(format!("order by: [{order_by}]")),
+        FormatTreeNode::new(format!("frame: [{}]", plan.window_frame)),
+    ];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    children.extend(prof_span_to_format_tree(
+        plan.plan_id,
+        prof_span_set,
+        include_profiling,
+    ));
+
+    children.push(to_format_tree(
+        &plan.input,
+        metadata,
+        prof_span_set,
+        include_profiling,
+    )?);
+
+    Ok(FormatTreeNode::with_children(
+        "Window".to_string(),
+        children,
+    ))
+}
+
 fn limit_to_format_tree(
     plan: &Limit,
     metadata: &MetadataRef,
     prof_span_set: &ProfSpanSetRef,
+    include_profiling: bool,
 ) -> Result<FormatTreeNode<String>> {
     let mut children = vec![
         FormatTreeNode::new(format!(
@@ -528,14 +772,18 @@ fn limit_to_format_tree(
         children.extend(items);
     }
 
-    if let Some(prof_span) = prof_span_set.lock().unwrap().get(&plan.plan_id) {
-        let process_time = prof_span.process_time / 1000 / 1000; // milliseconds
-        children.push(FormatTreeNode::new(format!(
-            "total process time: {process_time}ms"
-        )));
-    }
+    children.extend(prof_span_to_format_tree(
+        plan.plan_id,
+        prof_span_set,
+        include_profiling,
+    ));
 
-    children.push(to_format_tree(&plan.input, metadata, prof_span_set)?);
+    children.push(to_format_tree(
+        &plan.input,
+        metadata,
+        prof_span_set,
+        include_profiling,
+    )?);
 
     Ok(FormatTreeNode::with_children("Limit".to_string(), children))
 }
@@ -544,6 +792,7 @@ fn hash_join_to_format_tree(
     plan: &HashJoin,
     metadata: &MetadataRef,
     prof_span_set: &ProfSpanSetRef,
+    include_profiling: bool,
 ) -> Result<FormatTreeNode<String>> {
     let build_keys = plan
         .build_keys
@@ -564,8 +813,8 @@ fn hash_join_to_format_tree(
         .collect::<Vec<_>>()
         .join(", ");
 
-    let mut build_child = to_format_tree(&plan.build, metadata, prof_span_set)?;
-    let mut probe_child = to_format_tree(&plan.probe, metadata, prof_span_set)?;
+    let mut build_child = to_format_tree(&plan.build, metadata, prof_span_set, include_profiling)?;
+    let mut probe_child = to_format_tree(&plan.probe, metadata, prof_span_set, include_profiling)?;
 
     build_child.payload = format!("{}(Build)", build_child.payload);
     probe_child.payload = format!("{}(Probe)", probe_child.payload);
@@ -582,12 +831,11 @@ fn hash_join_to_format_tree(
         children.extend(items);
     }
 
-    if let Some(prof_span) = prof_span_set.lock().unwrap().get(&plan.plan_id) {
-        let process_time = prof_span.process_time / 1000 / 1000; // milliseconds
-        children.push(FormatTreeNode::new(format!(
-            "total process time: {process_time}ms"
-        )));
-    }
+    children.extend(prof_span_to_format_tree(
+        plan.plan_id,
+        prof_span_set,
+        include_profiling,
+    ));
 
     children.push(build_child);
     children.push(probe_child);
@@ -602,6 +850,7 @@ fn exchange_to_format_tree(
     plan: &Exchange,
     metadata: &MetadataRef,
     prof_span_set: &ProfSpanSetRef,
+    include_profiling: bool,
 ) -> Result<FormatTreeNode<String>> {
     Ok(FormatTreeNode::with_children("Exchange".to_string(), vec![
         FormatTreeNode::new(format!("exchange type: {}", match plan.kind {
@@ -617,7 +866,7 @@ fn exchange_to_format_tree(
             FragmentKind::Expansive => "Broadcast".to_string(),
             FragmentKind::Merge => "Merge".to_string(),
         })),
-        to_format_tree(&plan.input, metadata, prof_span_set)?,
+        to_format_tree(&plan.input, metadata, prof_span_set, include_profiling)?,
     ]))
 }
 
@@ -625,6 +874,7 @@ fn union_all_to_format_tree(
     plan: &UnionAll,
     metadata: &MetadataRef,
     prof_span_set: &ProfSpanSetRef,
+    include_profiling: bool,
 ) -> Result<FormatTreeNode<String>> {
     let mut children = vec![];
 
@@ -633,16 +883,15 @@ fn union_all_to_format_tree(
         children.extend(items);
     }
 
-    if let Some(prof_span) = prof_span_set.lock().unwrap().get(&plan.plan_id) {
-        let process_time = prof_span.process_time / 1000 / 1000; // milliseconds
-        children.push(FormatTreeNode::new(format!(
-            "total process time: {process_time}ms"
-        )));
-    }
+    children.extend(prof_span_to_format_tree(
+        plan.plan_id,
+        prof_span_set,
+        include_profiling,
+    ));
 
     children.extend(vec![
-        to_format_tree(&plan.left, metadata, prof_span_set)?,
-        to_format_tree(&plan.right, metadata, prof_span_set)?,
+        to_format_tree(&plan.left, metadata, prof_span_set, include_profiling)?,
+        to_format_tree(&plan.right, metadata, prof_span_set, include_profiling)?,
     ]);
 
     Ok(FormatTreeNode::with_children(
@@ -651,6 +900,60 @@ fn union_all_to_format_tree(
     ))
 }
 
+fn materialized_cte_to_format_tree(
+    plan: &MaterializedCte,
+    metadata: &MetadataRef,
+    prof_span_set: &ProfSpanSetRef,
+    include_profiling: bool,
+) -> Result<FormatTreeNode<String>> {
+    let mut children = vec![FormatTreeNode::new(format!("cte index: {}", plan.cte_idx))];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    children.extend(prof_span_to_format_tree(
+        plan.plan_id,
+        prof_span_set,
+        include_profiling,
+    ));
+
+    children.extend(vec![
+        to_format_tree(&plan.left, metadata, prof_span_set, include_profiling)?,
+        to_format_tree(&plan.right, metadata, prof_span_set, include_profiling)?,
+    ]);
+
+    Ok(FormatTreeNode::with_children(
+        "MaterializedCte".to_string(),
+        children,
+    ))
+}
+
+fn cte_scan_to_format_tree(
+    plan: &CteScan,
+    prof_span_set: &ProfSpanSetRef,
+    include_profiling: bool,
+) -> Result<FormatTreeNode<String>> {
+    let mut children = vec![FormatTreeNode::new(format!("cte index: {}", plan.cte_idx))];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    children.extend(prof_span_to_format_tree(
+        plan.plan_id,
+        prof_span_set,
+        include_profiling,
+    ));
+
+    Ok(FormatTreeNode::with_children(
+        "CteScan".to_string(),
+        children,
+    ))
+}
+
 fn part_stats_info_to_format_tree(info: &PartStatistics) -> Vec<FormatTreeNode<String>> {
     let mut items = vec![
         FormatTreeNode::new(format!("read rows: {}", info.read_rows)),
@@ -681,6 +984,33 @@ fn plan_stats_info_to_format_tree(info: &PlanStatsInfo) -> Vec<FormatTreeNode<St
     ))]
 }
 
+/// The profiling info of the plan, only included by EXPLAIN ANALYZE.
+fn prof_span_to_format_tree(
+    plan_id: u32,
+    prof_span_set: &ProfSpanSetRef,
+    include_profiling: bool,
+) -> Vec<FormatTreeNode<String>> {
+    if !include_profiling {
+        return vec![];
+    }
+
+    match prof_span_set.lock().unwrap().get(&plan_id) {
+        None => vec![],
+        Some(prof_span) => {
+            let process_time = prof_span.process_time / 1000 / 1000; // milliseconds
+            vec![
+                FormatTreeNode::new(format!("total process time: {process_time}ms")),
+                FormatTreeNode::new(format!("output rows: {}", prof_span.output_rows)),
+                FormatTreeNode::new(format!("output bytes: {}", prof_span.output_bytes)),
+                FormatTreeNode::new(format!(
+                    "peak memory usage: {}",
+                    prof_span.peak_memory_usage
+                )),
+            ]
+        }
+    }
+}
+
 fn exchange_source_to_format_tree(plan: &ExchangeSource) -> Result<FormatTreeNode<String>> {
     let mut children = vec![];
 
@@ -699,6 +1029,7 @@ fn exchange_sink_to_format_tree(
     plan: &ExchangeSink,
     metadata: &MetadataRef,
     prof_span_set: &ProfSpanSetRef,
+    include_profiling: bool,
 ) -> Result<FormatTreeNode<String>> {
     let mut children = vec![];
 
@@ -707,7 +1038,12 @@ fn exchange_sink_to_format_tree(
         plan.destination_fragment_id
     )));
 
-    children.push(to_format_tree(&plan.input, metadata, prof_span_set)?);
+    children.push(to_format_tree(
+        &plan.input,
+        metadata,
+        prof_span_set,
+        include_profiling,
+    )?);
 
     Ok(FormatTreeNode::with_children(
         "ExchangeSink".to_string(),
@@ -719,8 +1055,14 @@ fn distributed_insert_to_format_tree(
     plan: &DistributedInsertSelect,
     metadata: &MetadataRef,
     prof_span_set: &ProfSpanSetRef,
+    include_profiling: bool,
 ) -> Result<FormatTreeNode<String>> {
-    let children = vec![to_format_tree(&plan.input, metadata, prof_span_set)?];
+    let children = vec![to_format_tree(
+        &plan.input,
+        metadata,
+        prof_span_set,
+        include_profiling,
+    )?];
 
     Ok(FormatTreeNode::with_children(
         "DistributedInsertSelect".to_string(),
@@ -732,8 +1074,14 @@ fn unnest_to_format_tree(
     plan: &Unnest,
     metadata: &MetadataRef,
     prof_span_set: &ProfSpanSetRef,
+    include_profiling: bool,
 ) -> Result<FormatTreeNode<String>> {
-    let children = vec![to_format_tree(&plan.input, metadata, prof_span_set)?];
+    let children = vec![to_format_tree(
+        &plan.input,
+        metadata,
+        prof_span_set,
+        include_profiling,
+    )?];
 
     Ok(FormatTreeNode::with_children(
         "Unnest".to_string(),
@@ -745,10 +1093,11 @@ fn runtime_filter_source_to_format_tree(
     plan: &RuntimeFilterSource,
     metadata: &MetadataRef,
     prof_span_set: &ProfSpanSetRef,
+    include_profiling: bool,
 ) -> Result<FormatTreeNode<String>> {
     let children = vec![
-        to_format_tree(&plan.left_side, metadata, prof_span_set)?,
-        to_format_tree(&plan.right_side, metadata, prof_span_set)?,
+        to_format_tree(&plan.left_side, metadata, prof_span_set, include_profiling)?,
+        to_format_tree(&plan.right_side, metadata, prof_span_set, include_profiling)?,
     ];
     Ok(FormatTreeNode::with_children(
         "RuntimeFilterSource".to_string(),
@@ -78,6 +78,9 @@ pub struct Filter {
     // Assumption: expression's data type must be `DataType::Boolean`.
     pub predicates: Vec<RemoteExpr>,
 
+    /// Estimated selectivity of each predicate, only used for explain
+    pub selectivities: Vec<f64>,
+
     /// Only used for explain
     pub stat_info: Option<PlanStatsInfo>,
 }
@@ -282,6 +285,35 @@ impl AggregateFinal {
     }
 }
 
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct Window {
+    /// A unique id of operator in a `PhysicalPlan` tree.
+    /// Only used for display.
+    pub plan_id: u32,
+
+    pub index: IndexType,
+    pub input: Box<PhysicalPlan>,
+    pub agg_func: AggregateFunctionDesc,
+    pub partition_by: Vec<IndexType>,
+    pub order_by: Vec<SortDesc>,
+    pub window_frame: WindowFrame,
+
+    /// Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl Window {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        let input_schema = self.input.output_schema()?;
+        let mut fields = input_schema.fields().clone();
+        fields.push(DataField::new(
+            &self.index.to_string(),
+            self.agg_func.sig.return_type.clone(),
+        ));
+        Ok(DataSchemaRefExt::create(fields))
+    }
+}
+
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct Sort {
     /// A unique id of operator in a `PhysicalPlan` tree.
@@ -334,6 +366,8 @@ pub struct HashJoin {
     pub build_keys: Vec<RemoteExpr>,
     pub probe_keys: Vec<RemoteExpr>,
     pub non_equi_conditions: Vec<RemoteExpr>,
+    /// The indexes of the keys which are null-safe equal, the NULL keys match each other.
+    pub is_null_equal: Vec<usize>,
     pub join_type: JoinType,
     pub marker_index: Option<IndexType>,
     pub from_correlated_subquery: bool,
@@ -508,6 +542,48 @@ impl UnionAll {
     }
 }
 
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct MaterializedCte {
+    /// A unique id of operator in a `PhysicalPlan` tree.
+    /// Only used for display.
+    pub plan_id: u32,
+
+    /// The CTE query, its result is materialized and read by `CteScan`s.
+    pub left: Box<PhysicalPlan>,
+    /// The query reading the CTE.
+    pub right: Box<PhysicalPlan>,
+    pub cte_idx: IndexType,
+    pub left_output_columns: Vec<IndexType>,
+
+    /// Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl MaterializedCte {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        self.right.output_schema()
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct CteScan {
+    /// A unique id of operator in a `PhysicalPlan` tree.
+    /// Only used for display.
+    pub plan_id: u32,
+
+    pub cte_idx: IndexType,
+    pub output_schema: DataSchemaRef,
+
+    /// Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl CteScan {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        Ok(self.output_schema.clone())
+    }
+}
+
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct DistributedInsertSelect {
     pub input: Box<PhysicalPlan>,
@@ -541,6 +617,9 @@ pub struct RuntimeFilterSource {
     pub right_side: Box<PhysicalPlan>,
     pub left_runtime_filters: BTreeMap<RuntimeFilterId, RemoteExpr>,
     pub right_runtime_filters: BTreeMap<RuntimeFilterId, RemoteExpr>,
+    /// The runtime filters which can be pushed down into a table scan of the left side,
+    /// with the index of the table and the name of the column.
+    pub scan_runtime_filters: BTreeMap<RuntimeFilterId, (IndexType, String)>,
 }
 
 impl RuntimeFilterSource {
@@ -560,11 +639,14 @@ pub enum PhysicalPlan {
     AggregatePartial(AggregatePartial),
     AggregateFinal(AggregateFinal),
     Sort(Sort),
+    Window(Window),
     Limit(Limit),
     HashJoin(HashJoin),
     Exchange(Exchange),
     UnionAll(UnionAll),
     RuntimeFilterSource(RuntimeFilterSource),
+    MaterializedCte(MaterializedCte),
+    CteScan(CteScan),
 
     /// For insert into ... select ... in cluster
     DistributedInsertSelect(Box<DistributedInsertSelect>),
@@ -593,6 +675,7 @@ impl PhysicalPlan {
             PhysicalPlan::AggregatePartial(plan) => plan.output_schema(),
             PhysicalPlan::AggregateFinal(plan) => plan.output_schema(),
             PhysicalPlan::Sort(plan) => plan.output_schema(),
+            PhysicalPlan::Window(plan) => plan.output_schema(),
             PhysicalPlan::Limit(plan) => plan.output_schema(),
             PhysicalPlan::HashJoin(plan) => plan.output_schema(),
             PhysicalPlan::Exchange(plan) => plan.output_schema(),
@@ -602,6 +685,8 @@ impl PhysicalPlan {
             PhysicalPlan::DistributedInsertSelect(plan) => plan.output_schema(),
             PhysicalPlan::Unnest(plan) => plan.output_schema(),
             PhysicalPlan::RuntimeFilterSource(plan) => plan.output_schema(),
+            PhysicalPlan::MaterializedCte(plan) => plan.output_schema(),
+            PhysicalPlan::CteScan(plan) => plan.output_schema(),
         }
     }
 
@@ -615,6 +700,7 @@ impl PhysicalPlan {
             PhysicalPlan::AggregatePartial(_) => "AggregatePartial".to_string(),
             PhysicalPlan::AggregateFinal(_) => "AggregateFinal".to_string(),
             PhysicalPlan::Sort(_) => "Sort".to_string(),
+            PhysicalPlan::Window(_) => "Window".to_string(),
             PhysicalPlan::Limit(_) => "Limit".to_string(),
             PhysicalPlan::HashJoin(_) => "HashJoin".to_string(),
             PhysicalPlan::Exchange(_) => "Exchange".to_string(),
@@ -624,6 +710,34 @@ impl PhysicalPlan {
             PhysicalPlan::ExchangeSink(_) => "Exchange Sink".to_string(),
             PhysicalPlan::Unnest(_) => "Unnest".to_string(),
             PhysicalPlan::RuntimeFilterSource(_) => "RuntimeFilterSource".to_string(),
+            PhysicalPlan::MaterializedCte(_) => "MaterializedCte".to_string(),
+            PhysicalPlan::CteScan(_) => "CteScan".to_string(),
+        }
+    }
+
+    /// The id to collect the profiling of the plan node, `None` if it's not profiled.
+    pub fn plan_id(&self) -> Option<u32> {
+        match self {
+            PhysicalPlan::TableScan(plan) => Some(plan.plan_id),
+            PhysicalPlan::Filter(plan) => Some(plan.plan_id),
+            PhysicalPlan::Project(plan) => Some(plan.plan_id),
+            PhysicalPlan::EvalScalar(plan) => Some(plan.plan_id),
+            PhysicalPlan::Unnest(plan) => Some(plan.plan_id),
+            PhysicalPlan::AggregateExpand(plan) => Some(plan.plan_id),
+            PhysicalPlan::AggregatePartial(plan) => Some(plan.plan_id),
+            PhysicalPlan::AggregateFinal(plan) => Some(plan.plan_id),
+            PhysicalPlan::Sort(plan) => Some(plan.plan_id),
+            PhysicalPlan::Window(plan) => Some(plan.plan_id),
+            PhysicalPlan::Limit(plan) => Some(plan.plan_id),
+            PhysicalPlan::HashJoin(plan) => Some(plan.plan_id),
+            PhysicalPlan::UnionAll(plan) => Some(plan.plan_id),
+            PhysicalPlan::RuntimeFilterSource(plan) => Some(plan.plan_id),
+            PhysicalPlan::MaterializedCte(plan) => Some(plan.plan_id),
+            PhysicalPlan::CteScan(plan) => Some(plan.plan_id),
+            PhysicalPlan::Exchange(_)
+            | PhysicalPlan::ExchangeSource(_)
+            | PhysicalPlan::ExchangeSink(_)
+            | PhysicalPlan::DistributedInsertSelect(_) => None,
         }
     }
 
@@ -637,6 +751,7 @@ impl PhysicalPlan {
             PhysicalPlan::AggregatePartial(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::AggregateFinal(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::Sort(plan) => Box::new(std::iter::once(plan.input.as_ref())),
+            PhysicalPlan::Window(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::Limit(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::HashJoin(plan) => Box::new(
                 std::iter::once(plan.probe.as_ref()).chain(std::iter::once(plan.build.as_ref())),
@@ -655,6 +770,10 @@ impl PhysicalPlan {
                 std::iter::once(plan.left_side.as_ref())
                     .chain(std::iter::once(plan.right_side.as_ref())),
             ),
+            PhysicalPlan::MaterializedCte(plan) => Box::new(
+                std::iter::once(plan.left.as_ref()).chain(std::iter::once(plan.right.as_ref())),
+            ),
+            PhysicalPlan::CteScan(_) => Box::new(std::iter::empty()),
         }
     }
 }
@@ -673,6 +792,8 @@ pub struct AggregateFunctionSignature {
     pub args: Vec<DataType>,
     pub params: Vec<Literal>,
     pub return_type: DataType,
+    /// Only used for display, `name` is already the `_distinct` combinator of the function.
+    pub distinct: bool,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -681,3 +802,26 @@ pub struct SortDesc {
     pub nulls_first: bool,
     pub order_by: IndexType,
 }
+
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct WindowFrame {
+    pub units: WindowFrameUnits,
+    pub start_bound: WindowFrameBound,
+    pub end_bound: WindowFrameBound,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+pub enum WindowFrameUnits {
+    Rows,
+    Range,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+pub enum WindowFrameBound {
+    /// `CURRENT ROW`
+    CurrentRow,
+    /// `<N> PRECEDING` or `UNBOUNDED PRECEDING`
+    Preceding(Option<usize>),
+    /// `<N> FOLLOWING` or `UNBOUNDED FOLLOWING`.
+    Following(Option<usize>),
+}
@@ -27,12 +27,14 @@ use common_exception::Result;
 use common_expression::type_check::check_function;
 use common_expression::types::DataType;
 use common_expression::ConstantFolder;
-use common_expression::DataBlock;
+use common_expression::DataField;
 use common_expression::DataSchemaRefExt;
 use common_expression::Expr;
+use common_expression::Literal;
 use common_expression::RemoteExpr;
 use common_expression::TableSchema;
 use common_functions::scalars::BUILTIN_FUNCTIONS;
+use storages_common_index::RuntimeBloomFilter;
 
 use super::cast_expr_to_non_null_boolean;
 use super::AggregateExpand;
@@ -40,13 +42,19 @@ use super::AggregateFinal;
 use super::AggregateFunctionDesc;
 use super::AggregateFunctionSignature;
 use super::AggregatePartial;
+use super::CteScan;
 use super::Exchange as PhysicalExchange;
 use super::Filter;
 use super::HashJoin;
 use super::Limit;
+use super::MaterializedCte;
 use super::Sort;
 use super::TableScan;
 use super::Unnest;
+use super::Window;
+use super::WindowFrame;
+use super::WindowFrameBound;
+use super::WindowFrameUnits;
 use crate::executor::explain::PlanStatsInfo;
 use crate::executor::table_read_plan::ToReadDataSourcePlan;
 use crate::executor::EvalScalar;
@@ -58,15 +66,21 @@ use crate::executor::UnionAll;
 use crate::optimizer::ColumnSet;
 use crate::optimizer::RelExpr;
 use crate::optimizer::SExpr;
+use crate::optimizer::SelectivityEstimator;
 use crate::plans::AggregateMode;
 use crate::plans::AndExpr;
+use crate::plans::ConstantExpr;
 use crate::plans::Exchange;
+use crate::plans::JoinType;
 use crate::plans::RelOperator;
 use crate::plans::ScalarExpr;
 use crate::plans::Scan;
+use crate::plans::WindowFuncFrameBound;
+use crate::plans::WindowFuncFrameUnits;
 use crate::BaseTableColumn;
 use crate::ColumnEntry;
 use crate::DerivedColumn;
+use crate::IndexType;
 use crate::Metadata;
 use crate::MetadataRef;
 use crate::TableInternalColumn;
@@ -158,6 +172,14 @@ impl PhysicalPlanBuilder {
 
     #[async_recursion::async_recursion]
     pub async fn build(&mut self, s_expr: &SExpr) -> Result<PhysicalPlan> {
+        // Subqueries are rewritten into joins by decorrelation, they are left in the plan
+        // only if `enable_subquery_decorrelation` is disabled.
+        if s_expr.plan_contain_subquery() {
+            return Err(ErrorCode::Unimplemented(
+                "Cannot execute a query with subqueries that are not decorrelated, please enable the setting `enable_subquery_decorrelation`",
+            ));
+        }
+
         // Build stat info
         let stat_info = self.build_plan_stat_info(s_expr)?;
 
@@ -221,7 +243,7 @@ impl PhysicalPlanBuilder {
 
                 let push_downs = self.push_downs(scan, &table_schema, has_inner_column)?;
 
-                let source = table
+                let mut source = table
                     .read_plan_with_catalog(
                         self.ctx.clone(),
                         table_entry.catalog().to_string(),
@@ -233,6 +255,7 @@ impl PhysicalPlanBuilder {
                         },
                     )
                     .await?;
+                source.table_index = Some(scan.table_index);
 
                 let internal_column = if project_internal_columns.is_empty() {
                     None
@@ -345,6 +368,7 @@ impl PhysicalPlanBuilder {
                             Ok(expr.as_remote_expr())
                         })
                         .collect::<Result<_>>()?,
+                    is_null_equal: join.is_null_equal.clone(),
                     marker_index: join.marker_index,
                     from_correlated_subquery: join.from_correlated_subquery,
 
@@ -448,6 +472,14 @@ impl PhysicalPlanBuilder {
             }
 
             RelOperator::Filter(filter) => {
+                let input_prop = RelExpr::with_s_expr(s_expr.child(0)?).derive_relational_prop()?;
+                let estimator = SelectivityEstimator::new(&input_prop.statistics);
+                let selectivities = filter
+                    .predicates
+                    .iter()
+                    .map(|pred| estimator.compute_selectivity(pred))
+                    .collect();
+
                 let input = Box::new(self.build(s_expr.child(0)?).await?);
                 let input_schema = input.output_schema()?;
                 Ok(PhysicalPlan::Filter(Filter {
@@ -472,7 +504,7 @@ impl PhysicalPlanBuilder {
                             Ok(expr.as_remote_expr())
                         })
                         .collect::<Result<_>>()?,
-
+                    selectivities,
                     stat_info: Some(stat_info),
                 }))
             }
@@ -493,6 +525,7 @@ impl PhysicalPlanBuilder {
                                         }).collect::<Result<_>>()?,
                                         params: agg.params.clone(),
                                         return_type: *agg.return_type.clone(),
+                                        distinct: agg.distinct,
                                     },
                                     output_column: v.index,
                                     args: agg.args.iter().map(|arg| {
@@ -547,16 +580,13 @@ impl PhysicalPlanBuilder {
                                     }
                                 };
 
-                                let group_by_key_index =
-                                    aggregate_partial.output_schema()?.num_fields() - 1;
+                                // The group by key is computed from the expanded group by columns
+                                // if there are grouping sets, which are nullable and include the
+                                // grouping id, so take its type from the output schema.
+                                let output_schema = aggregate_partial.output_schema()?;
+                                let group_by_key_index = output_schema.num_fields() - 1;
                                 let group_by_key_data_type =
-                                    DataBlock::choose_hash_method_with_types(
-                                        &agg.group_items
-                                            .iter()
-                                            .map(|v| v.scalar.data_type())
-                                            .collect::<Result<Vec<_>>>()?,
-                                    )?
-                                    .data_type();
+                                    output_schema.field(group_by_key_index).data_type().clone();
 
                                 PhysicalPlan::Exchange(PhysicalExchange {
                                     kind,
@@ -629,6 +659,7 @@ impl PhysicalPlanBuilder {
                                         }).collect::<Result<_>>()?,
                                         params: agg.params.clone(),
                                         return_type: *agg.return_type.clone(),
+                                        distinct: agg.distinct,
                                     },
                                     output_column: v.index,
                                     args: agg.args.iter().map(|arg| {
@@ -721,6 +752,85 @@ impl PhysicalPlanBuilder {
 
                 stat_info: Some(stat_info),
             })),
+            RelOperator::Window(window) => {
+                let input = self.build(s_expr.child(0)?).await?;
+                let input_schema = input.output_schema()?;
+
+                let agg_func = if let ScalarExpr::AggregateFunction(agg) =
+                    &window.aggregate_function.scalar
+                {
+                    let arg_indices = agg
+                        .args
+                        .iter()
+                        .map(|arg| {
+                            if let ScalarExpr::BoundColumnRef(col) = arg {
+                                Ok(col.column.index)
+                            } else {
+                                Err(ErrorCode::Internal(
+                                    "Window function argument must be a BoundColumnRef".to_string(),
+                                ))
+                            }
+                        })
+                        .collect::<Result<Vec<_>>>()?;
+                    AggregateFunctionDesc {
+                        sig: AggregateFunctionSignature {
+                            name: agg.func_name.clone(),
+                            args: agg
+                                .args
+                                .iter()
+                                .map(|s| s.data_type())
+                                .collect::<Result<_>>()?,
+                            params: agg.params.clone(),
+                            return_type: *agg.return_type.clone(),
+                            distinct: agg.distinct,
+                        },
+                        output_column: window.aggregate_function.index,
+                        args: arg_indices
+                            .iter()
+                            .map(|index| input_schema.index_of(&index.to_string()))
+                            .collect::<Result<_>>()?,
+                        arg_indices,
+                    }
+                } else {
+                    return Err(ErrorCode::Internal(
+                        "Expected aggregate function in window".to_string(),
+                    ));
+                };
+
+                // null is the largest value in databend, smallest in hive
+                let default_nulls_first =
+                    !self.ctx.get_settings().get_sql_dialect()?.is_null_biggest();
+                let order_by = window
+                    .order_by
+                    .iter()
+                    .map(|v| SortDesc {
+                        asc: v.asc.unwrap_or(true),
+                        nulls_first: v.nulls_first.unwrap_or(default_nulls_first),
+                        order_by: v.order_by_item.index,
+                    })
+                    .collect();
+
+                let window_frame = WindowFrame {
+                    units: match window.frame.units {
+                        WindowFuncFrameUnits::Rows => WindowFrameUnits::Rows,
+                        WindowFuncFrameUnits::Range => WindowFrameUnits::Range,
+                    },
+                    start_bound: Self::build_window_frame_bound(&window.frame.start)?,
+                    end_bound: Self::build_window_frame_bound(&window.frame.end)?,
+                };
+
+                Ok(PhysicalPlan::Window(Window {
+                    plan_id: self.next_plan_id(),
+                    index: window.aggregate_function.index,
+                    input: Box::new(input),
+                    agg_func,
+                    partition_by: window.partition_by.iter().map(|v| v.index).collect(),
+                    order_by,
+                    window_frame,
+
+                    stat_info: Some(stat_info),
+                }))
+            }
             RelOperator::Limit(limit) => Ok(PhysicalPlan::Limit(Limit {
                 plan_id: self.next_plan_id(),
                 input: Box::new(self.build(s_expr.child(0)?).await?),
@@ -815,12 +925,51 @@ impl PhysicalPlanBuilder {
                             .as_remote_expr(),
                     );
                 }
+                let mut scan_runtime_filters = BTreeMap::new();
+                for (id, probe_key) in op.left_runtime_filters.iter() {
+                    if let Some(target) = self.runtime_filter_scan_target(&left_side, probe_key) {
+                        scan_runtime_filters.insert(id.clone(), target);
+                    }
+                }
                 Ok(PhysicalPlan::RuntimeFilterSource(RuntimeFilterSource {
                     plan_id: self.next_plan_id(),
                     left_side,
                     right_side,
                     left_runtime_filters,
                     right_runtime_filters,
+                    scan_runtime_filters,
+                }))
+            }
+            RelOperator::MaterializedCte(op) => {
+                let left = self.build(s_expr.child(0)?).await?;
+                let right = self.build(s_expr.child(1)?).await?;
+                Ok(PhysicalPlan::MaterializedCte(MaterializedCte {
+                    plan_id: self.next_plan_id(),
+                    left: Box::new(left),
+                    right: Box::new(right),
+                    cte_idx: op.cte_idx,
+                    left_output_columns: op.left_output_columns.clone(),
+                    stat_info: Some(stat_info),
+                }))
+            }
+            RelOperator::CteScan(op) => {
+                let fields = op
+                    .columns
+                    .iter()
+                    .map(|index| match self.metadata.read().column(*index) {
+                        ColumnEntry::DerivedColumn(DerivedColumn { data_type, .. }) => {
+                            Ok(DataField::new(&index.to_string(), data_type.clone()))
+                        }
+                        _ => Err(ErrorCode::Internal(format!(
+                            "Column {index} of CteScan must be a derived column"
+                        ))),
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(PhysicalPlan::CteScan(CteScan {
+                    plan_id: self.next_plan_id(),
+                    cte_idx: op.cte_idx,
+                    output_schema: DataSchemaRefExt::create(fields),
+                    stat_info: Some(stat_info),
                 }))
             }
             _ => Err(ErrorCode::Internal(format!(
@@ -830,6 +979,78 @@ impl PhysicalPlanBuilder {
         }
     }
 
+    fn build_window_frame_bound(bound: &WindowFuncFrameBound) -> Result<WindowFrameBound> {
+        let offset = |offset: &Option<Box<ScalarExpr>>| match offset.as_deref() {
+            None => Ok(None),
+            Some(ScalarExpr::ConstantExpr(ConstantExpr {
+                value: Literal::UInt64(n),
+                ..
+            })) => Ok(Some(*n as usize)),
+            Some(_) => Err(ErrorCode::Internal(
+                "Window frame offset must be an unsigned integer constant".to_string(),
+            )),
+        };
+        Ok(match bound {
+            WindowFuncFrameBound::CurrentRow => WindowFrameBound::CurrentRow,
+            WindowFuncFrameBound::Preceding(n) => WindowFrameBound::Preceding(offset(n)?),
+            WindowFuncFrameBound::Following(n) => WindowFrameBound::Following(offset(n)?),
+        })
+    }
+
+    // Returns the table scan on the probe side which a runtime filter can be pushed down into.
+    //
+    // The probe key must be a column of the table, and the operators between the scan and the
+    // join must not change the rows other than filtering them.
+    fn runtime_filter_scan_target(
+        &self,
+        probe_side: &PhysicalPlan,
+        probe_key: &ScalarExpr,
+    ) -> Option<(IndexType, String)> {
+        let column_index = match probe_key {
+            ScalarExpr::BoundColumnRef(column_ref) => column_ref.column.index,
+            _ => return None,
+        };
+        let (table_index, column_name) = match self.metadata.read().column(column_index) {
+            ColumnEntry::BaseTableColumn(BaseTableColumn {
+                table_index,
+                column_name,
+                data_type,
+                path_indices: None,
+                ..
+            }) if RuntimeBloomFilter::supported_type(&DataType::from(data_type)) => {
+                (*table_index, column_name.clone())
+            }
+            _ => return None,
+        };
+
+        Self::can_push_down_runtime_filter(probe_side, table_index)
+            .then_some((table_index, column_name))
+    }
+
+    fn can_push_down_runtime_filter(plan: &PhysicalPlan, table_index: IndexType) -> bool {
+        match plan {
+            PhysicalPlan::TableScan(scan) => scan.table_index == table_index,
+            PhysicalPlan::Filter(filter) => {
+                Self::can_push_down_runtime_filter(&filter.input, table_index)
+            }
+            PhysicalPlan::EvalScalar(eval_scalar) => {
+                Self::can_push_down_runtime_filter(&eval_scalar.input, table_index)
+            }
+            PhysicalPlan::Project(project) => {
+                Self::can_push_down_runtime_filter(&project.input, table_index)
+            }
+            PhysicalPlan::RuntimeFilterSource(source) => {
+                Self::can_push_down_runtime_filter(&source.left_side, table_index)
+            }
+            // Both sides of an inner join only lose the rows without a match.
+            PhysicalPlan::HashJoin(join) if join.join_type == JoinType::Inner => {
+                Self::can_push_down_runtime_filter(&join.probe, table_index)
+                    || Self::can_push_down_runtime_filter(&join.build, table_index)
+            }
+            _ => false,
+        }
+    }
+
     fn push_downs(
         &self,
         scan: &Scan,
@@ -983,6 +1204,7 @@ impl PhysicalPlanBuilder {
             prewhere: prewhere_info,
             limit: scan.limit,
             order_by: order_by.unwrap_or_default(),
+            page_limit: scan.page_limit.clone(),
         })
     }
 
@@ -19,6 +19,7 @@ use common_functions::scalars::BUILTIN_FUNCTIONS;
 use itertools::Itertools;
 
 use super::AggregateExpand;
+use super::CteScan;
 use super::DistributedInsertSelect;
 use super::Unnest;
 use crate::executor::AggregateFinal;
@@ -30,12 +31,17 @@ use crate::executor::ExchangeSource;
 use crate::executor::Filter;
 use crate::executor::HashJoin;
 use crate::executor::Limit;
+use crate::executor::MaterializedCte;
 use crate::executor::PhysicalPlan;
 use crate::executor::Project;
 use crate::executor::RuntimeFilterSource;
 use crate::executor::Sort;
 use crate::executor::TableScan;
 use crate::executor::UnionAll;
+use crate::executor::Window;
+use crate::executor::WindowFrame;
+use crate::executor::WindowFrameBound;
+use crate::executor::WindowFrameUnits;
 use crate::plans::JoinType;
 
 impl PhysicalPlan {
@@ -62,6 +68,7 @@ impl<'a> Display for PhysicalPlanIndentFormatDisplay<'a> {
             PhysicalPlan::AggregatePartial(aggregate) => write!(f, "{}", aggregate)?,
             PhysicalPlan::AggregateFinal(aggregate) => write!(f, "{}", aggregate)?,
             PhysicalPlan::Sort(sort) => write!(f, "{}", sort)?,
+            PhysicalPlan::Window(window) => write!(f, "{}", window)?,
             PhysicalPlan::Limit(limit) => write!(f, "{}", limit)?,
             PhysicalPlan::HashJoin(join) => write!(f, "{}", join)?,
             PhysicalPlan::Exchange(exchange) => write!(f, "{}", exchange)?,
@@ -71,6 +78,8 @@ impl<'a> Display for PhysicalPlanIndentFormatDisplay<'a> {
             PhysicalPlan::DistributedInsertSelect(insert_select) => write!(f, "{}", insert_select)?,
             PhysicalPlan::Unnest(unnest) => write!(f, "{}", unnest)?,
             PhysicalPlan::RuntimeFilterSource(plan) => write!(f, "{}", plan)?,
+            PhysicalPlan::MaterializedCte(plan) => write!(f, "{}", plan)?,
+            PhysicalPlan::CteScan(plan) => write!(f, "{}", plan)?,
         }
 
         for node in self.node.children() {
@@ -136,6 +145,68 @@ impl Display for Sort {
     }
 }
 
+impl Display for Window {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let agg_func = format!(
+            "{}({})",
+            self.agg_func.sig.name,
+            self.agg_func
+                .arg_indices
+                .iter()
+                .map(|index| index.to_string())
+                .join(", ")
+        );
+        let partition_by = self
+            .partition_by
+            .iter()
+            .map(|index| index.to_string())
+            .join(", ");
+        let order_by = self
+            .order_by
+            .iter()
+            .map(|item| {
+                format!(
+                    "{} {}",
+                    item.order_by,
+                    if item.asc { "ASC" } else { "DESC" }
+                )
+            })
+            .join(", ");
+
+        write!(
+            f,
+            "Window: aggregate function: [{}], partition by: [{}], order by: [{}], frame: [{}]",
+            agg_func, partition_by, order_by, self.window_frame
+        )
+    }
+}
+
+impl Display for WindowFrame {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let units = match self.units {
+            WindowFrameUnits::Rows => "ROWS",
+            WindowFrameUnits::Range => "RANGE",
+        };
+        write!(
+            f,
+            "{} BETWEEN {} AND {}",
+            units, self.start_bound, self.end_bound
+        )
+    }
+}
+
+impl Display for WindowFrameBound {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WindowFrameBound::CurrentRow => write!(f, "CURRENT ROW"),
+            WindowFrameBound::Preceding(None) => write!(f, "UNBOUNDED PRECEDING"),
+            WindowFrameBound::Preceding(Some(n)) => write!(f, "{} PRECEDING", n),
+            WindowFrameBound::Following(None) => write!(f, "UNBOUNDED FOLLOWING"),
+            WindowFrameBound::Following(Some(n)) => write!(f, "{} FOLLOWING", n),
+        }
+    }
+}
+
 impl Display for EvalScalar {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let scalars = self
@@ -327,6 +398,18 @@ impl Display for RuntimeFilterSource {
     }
 }
 
+impl Display for MaterializedCte {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "MaterializedCte: cte index {}", self.cte_idx)
+    }
+}
+
+impl Display for CteScan {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CteScan: cte index {}", self.cte_idx)
+    }
+}
+
 impl Display for Unnest {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "Unnest: unnset num : {}", self.num_columns)
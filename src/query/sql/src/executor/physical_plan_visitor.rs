@@ -17,6 +17,7 @@ use common_exception::Result;
 use super::AggregateExpand;
 use super::AggregateFinal;
 use super::AggregatePartial;
+use super::CteScan;
 use super::DistributedInsertSelect;
 use super::EvalScalar;
 use super::Exchange;
@@ -25,11 +26,13 @@ use super::ExchangeSource;
 use super::Filter;
 use super::HashJoin;
 use super::Limit;
+use super::MaterializedCte;
 use super::PhysicalPlan;
 use super::Project;
 use super::Sort;
 use super::TableScan;
 use super::Unnest;
+use super::Window;
 use crate::executor::RuntimeFilterSource;
 use crate::executor::UnionAll;
 
@@ -44,6 +47,7 @@ pub trait PhysicalPlanReplacer {
             PhysicalPlan::AggregatePartial(plan) => self.replace_aggregate_partial(plan),
             PhysicalPlan::AggregateFinal(plan) => self.replace_aggregate_final(plan),
             PhysicalPlan::Sort(plan) => self.replace_sort(plan),
+            PhysicalPlan::Window(plan) => self.replace_window(plan),
             PhysicalPlan::Limit(plan) => self.replace_limit(plan),
             PhysicalPlan::HashJoin(plan) => self.replace_hash_join(plan),
             PhysicalPlan::Exchange(plan) => self.replace_exchange(plan),
@@ -53,6 +57,8 @@ pub trait PhysicalPlanReplacer {
             PhysicalPlan::DistributedInsertSelect(plan) => self.replace_insert_select(plan),
             PhysicalPlan::Unnest(plan) => self.replace_unnest(plan),
             PhysicalPlan::RuntimeFilterSource(plan) => self.replace_runtime_filter_source(plan),
+            PhysicalPlan::MaterializedCte(plan) => self.replace_materialized_cte(plan),
+            PhysicalPlan::CteScan(plan) => self.replace_cte_scan(plan),
         }
     }
 
@@ -67,6 +73,7 @@ pub trait PhysicalPlanReplacer {
             plan_id: plan.plan_id,
             input: Box::new(input),
             predicates: plan.predicates.clone(),
+            selectivities: plan.selectivities.clone(),
             stat_info: plan.stat_info.clone(),
         }))
     }
@@ -144,6 +151,7 @@ pub trait PhysicalPlanReplacer {
             build_keys: plan.build_keys.clone(),
             probe_keys: plan.probe_keys.clone(),
             non_equi_conditions: plan.non_equi_conditions.clone(),
+            is_null_equal: plan.is_null_equal.clone(),
             join_type: plan.join_type.clone(),
             marker_index: plan.marker_index,
             from_correlated_subquery: plan.from_correlated_subquery,
@@ -164,6 +172,21 @@ pub trait PhysicalPlanReplacer {
         }))
     }
 
+    fn replace_window(&mut self, plan: &Window) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+
+        Ok(PhysicalPlan::Window(Window {
+            plan_id: plan.plan_id,
+            index: plan.index,
+            input: Box::new(input),
+            agg_func: plan.agg_func.clone(),
+            partition_by: plan.partition_by.clone(),
+            order_by: plan.order_by.clone(),
+            window_frame: plan.window_frame.clone(),
+            stat_info: plan.stat_info.clone(),
+        }))
+    }
+
     fn replace_limit(&mut self, plan: &Limit) -> Result<PhysicalPlan> {
         let input = self.replace(&plan.input)?;
 
@@ -255,8 +278,26 @@ pub trait PhysicalPlanReplacer {
             right_side: Box::new(right_side),
             left_runtime_filters: plan.left_runtime_filters.clone(),
             right_runtime_filters: plan.right_runtime_filters.clone(),
+            scan_runtime_filters: plan.scan_runtime_filters.clone(),
         }))
     }
+
+    fn replace_materialized_cte(&mut self, plan: &MaterializedCte) -> Result<PhysicalPlan> {
+        let left = self.replace(&plan.left)?;
+        let right = self.replace(&plan.right)?;
+        Ok(PhysicalPlan::MaterializedCte(MaterializedCte {
+            plan_id: plan.plan_id,
+            left: Box::new(left),
+            right: Box::new(right),
+            cte_idx: plan.cte_idx,
+            left_output_columns: plan.left_output_columns.clone(),
+            stat_info: plan.stat_info.clone(),
+        }))
+    }
+
+    fn replace_cte_scan(&mut self, plan: &CteScan) -> Result<PhysicalPlan> {
+        Ok(PhysicalPlan::CteScan(plan.clone()))
+    }
 }
 
 impl PhysicalPlan {
@@ -291,6 +332,9 @@ impl PhysicalPlan {
                 PhysicalPlan::Sort(plan) => {
                     Self::traverse(&plan.input, pre_visit, visit, post_visit);
                 }
+                PhysicalPlan::Window(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit);
+                }
                 PhysicalPlan::Limit(plan) => {
                     Self::traverse(&plan.input, pre_visit, visit, post_visit);
                 }
@@ -319,6 +363,11 @@ impl PhysicalPlan {
                     Self::traverse(&plan.left_side, pre_visit, visit, post_visit);
                     Self::traverse(&plan.right_side, pre_visit, visit, post_visit);
                 }
+                PhysicalPlan::MaterializedCte(plan) => {
+                    Self::traverse(&plan.left, pre_visit, visit, post_visit);
+                    Self::traverse(&plan.right, pre_visit, visit, post_visit);
+                }
+                PhysicalPlan::CteScan(_) => {}
             }
             post_visit(plan);
         }
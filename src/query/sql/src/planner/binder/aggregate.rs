@@ -605,15 +605,15 @@ impl Binder {
         index: u64,
         select_list: &SelectList,
     ) -> Result<(ScalarExpr, String)> {
-        // Convert to zero-based index
-        let index = index as usize - 1;
-        if index >= select_list.items.len() {
+        if index == 0 || index as usize > select_list.items.len() {
             return Err(ErrorCode::SemanticError(format!(
                 "GROUP BY position {} is not in select list",
-                index + 1
+                index
             ))
             .set_span(expr.span()));
         }
+        // Convert to zero-based index
+        let index = index as usize - 1;
         let item = select_list
             .items
             .get(index)
@@ -142,6 +142,18 @@ pub struct BindContext {
 pub struct CteInfo {
     pub columns_alias: Vec<String>,
     pub query: Query,
+    /// Set if the CTE is `MATERIALIZED`, the query is bound only once in `bind_query`
+    /// and each reference reads its result with a `CteScan`.
+    pub materialized: Option<MaterializedCteInfo>,
+}
+
+#[derive(Clone, Debug)]
+pub struct MaterializedCteInfo {
+    pub cte_idx: IndexType,
+    /// Output columns of the CTE query.
+    pub columns: Vec<ColumnBinding>,
+    /// Estimated cardinality of the CTE query.
+    pub cardinality: u64,
 }
 
 impl BindContext {
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::collections::BTreeMap;
+use std::collections::HashMap;
 use std::str::FromStr;
 use std::sync::Arc;
 use std::time::Instant;
@@ -20,6 +21,7 @@ use std::time::Instant;
 use common_ast::ast::CopyStmt;
 use common_ast::ast::CopyUnit;
 use common_ast::ast::FileLocation;
+use common_ast::ast::Identifier;
 use common_ast::ast::Query;
 use common_ast::ast::SelectTarget;
 use common_ast::ast::SetExpr;
@@ -27,6 +29,7 @@ use common_ast::ast::Statement;
 use common_ast::ast::TableAlias;
 use common_ast::ast::TableReference;
 use common_ast::ast::UriLocation;
+use common_ast::parser::parse_expr;
 use common_ast::parser::parse_sql;
 use common_ast::parser::tokenize_sql;
 use common_ast::Dialect;
@@ -38,9 +41,20 @@ use common_catalog::table_context::TableContext;
 use common_config::GlobalConfig;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::Literal;
+use common_expression::TableField;
+use common_expression::TableSchemaRef;
+use common_expression::TableSchemaRefExt;
+use common_meta_app::principal::FileFormatOptions;
+use common_meta_app::principal::GrantObject;
 use common_meta_app::principal::OnErrorMode;
+use common_meta_app::principal::StageFileCompression;
+use common_meta_app::principal::StageFileFormatType;
 use common_meta_app::principal::StageInfo;
+use common_meta_app::principal::UserPrivilegeType;
 use common_storage::init_stage_operator;
+use common_storage::StageFileInfo;
 use common_storage::StageFileStatus;
 use common_storage::StageFilesInfo;
 use common_users::UserApiProvider;
@@ -49,10 +63,18 @@ use tracing::info;
 
 use crate::binder::location::parse_uri_location;
 use crate::binder::Binder;
+use crate::binder::ScalarBinder;
+use crate::normalize_identifier;
+use crate::plans::CastExpr;
+use crate::plans::ConstantExpr;
 use crate::plans::CopyPlan;
 use crate::plans::Plan;
+use crate::plans::ScalarExpr;
+use crate::plans::ScalarItem;
 use crate::plans::ValidationMode;
 use crate::BindContext;
+use crate::ColumnBinding;
+use crate::IndexType;
 
 impl<'a> Binder {
     pub(in crate::planner::binder) async fn bind_copy(
@@ -67,6 +89,7 @@ impl<'a> Binder {
                     catalog,
                     database,
                     table,
+                    columns,
                 },
             ) => {
                 let (catalog_name, database_name, table_name) =
@@ -79,6 +102,7 @@ impl<'a> Binder {
                     &catalog_name,
                     &database_name,
                     &table_name,
+                    columns,
                 )
                 .await
             }
@@ -88,6 +112,7 @@ impl<'a> Binder {
                     catalog,
                     database,
                     table,
+                    columns,
                 },
             ) => {
                 let (catalog_name, database_name, table_name) =
@@ -108,6 +133,7 @@ impl<'a> Binder {
                     &catalog_name,
                     &database_name,
                     &table_name,
+                    columns,
                 )
                 .await
             }
@@ -116,9 +142,10 @@ impl<'a> Binder {
                     catalog,
                     database,
                     table,
+                    columns,
                 },
                 CopyUnit::StageLocation(stage_location),
-            ) => {
+            ) if columns.is_empty() => {
                 let (catalog_name, database_name, table_name) =
                     self.normalize_object_identifier_triple(catalog, database, table);
 
@@ -138,9 +165,10 @@ impl<'a> Binder {
                     catalog,
                     database,
                     table,
+                    columns,
                 },
                 CopyUnit::UriLocation(uri_location),
-            ) => {
+            ) if columns.is_empty() => {
                 let (catalog_name, database_name, table) =
                     self.normalize_object_identifier_triple(catalog, database, table);
 
@@ -190,6 +218,7 @@ impl<'a> Binder {
                     catalog,
                     database,
                     table,
+                    columns,
                 },
             ) => {
                 let (catalog_name, database_name, table_name) =
@@ -201,6 +230,7 @@ impl<'a> Binder {
                     &catalog_name,
                     &database_name,
                     &table_name,
+                    columns,
                 )
                 .await
             }
@@ -223,6 +253,7 @@ impl<'a> Binder {
         dst_catalog_name: &str,
         dst_database_name: &str,
         dst_table_name: &str,
+        dst_columns: &[Identifier],
     ) -> Result<Plan> {
         let validation_mode = ValidationMode::from_str(stmt.validation_mode.as_str())
             .map_err(ErrorCode::SyntaxException)?;
@@ -231,31 +262,36 @@ impl<'a> Binder {
             .ctx
             .get_table(dst_catalog_name, dst_database_name, dst_table_name)
             .await?;
+        let schema = self.copy_into_table_schema(table.schema(), dst_columns)?;
 
         let (mut stage_info, path) =
-            parse_stage_location_v2(&self.ctx, src_stage, src_path).await?;
+            parse_stage_location_v2(&self.ctx, src_stage, src_path, UserPrivilegeType::Read)
+                .await?;
         self.apply_stage_options(stmt, &mut stage_info).await?;
         let files_info = StageFilesInfo {
             path,
             files: stmt.files.clone(),
             pattern: stmt.pattern.clone(),
         };
+        self.infer_file_format_of_stage(stmt, &mut stage_info, &files_info)
+            .await?;
 
         let from = DataSourcePlan {
             catalog: dst_catalog_name.to_string(),
             source_info: DataSourceInfo::StageSource(StageTableInfo {
-                schema: table.schema(),
+                schema: schema.clone(),
                 stage_info,
                 files_info,
                 files_to_copy: None,
             }),
-            output_schema: table.schema(),
+            output_schema: schema.clone(),
             parts: Partitions::default(),
             statistics: Default::default(),
             description: "".to_string(),
             tbl_args: None,
             push_downs: None,
             query_internal_columns: false,
+            table_index: None,
         };
 
         Ok(Plan::Copy(Box::new(CopyPlan::IntoTable {
@@ -263,7 +299,7 @@ impl<'a> Binder {
             database_name: dst_database_name.to_string(),
             table_name: dst_table_name.to_string(),
             table_id: table.get_id(),
-            schema: table.schema(),
+            schema,
             from: Box::new(from),
             validation_mode,
             force: stmt.force,
@@ -280,6 +316,7 @@ impl<'a> Binder {
         dst_catalog_name: &str,
         dst_database_name: &str,
         dst_table_name: &str,
+        dst_columns: &[Identifier],
     ) -> Result<Plan> {
         let validation_mode = ValidationMode::from_str(stmt.validation_mode.as_str())
             .map_err(ErrorCode::SyntaxException)?;
@@ -288,6 +325,7 @@ impl<'a> Binder {
             .ctx
             .get_table(dst_catalog_name, dst_database_name, dst_table_name)
             .await?;
+        let schema = self.copy_into_table_schema(table.schema(), dst_columns)?;
 
         let (storage_params, path) = parse_uri_location(src_uri_location)?;
         if !storage_params.is_secure() && !GlobalConfig::instance().storage.allow_insecure {
@@ -304,22 +342,25 @@ impl<'a> Binder {
             files: stmt.files.clone(),
             pattern: stmt.pattern.clone(),
         };
+        self.infer_file_format_of_stage(stmt, &mut stage_info, &files_info)
+            .await?;
 
         let from = DataSourcePlan {
             catalog: dst_catalog_name.to_string(),
             source_info: DataSourceInfo::StageSource(StageTableInfo {
-                schema: table.schema(),
+                schema: schema.clone(),
                 stage_info,
                 files_info,
                 files_to_copy: None,
             }),
-            output_schema: table.schema(),
+            output_schema: schema.clone(),
             parts: Partitions::default(),
             statistics: Default::default(),
             description: "".to_string(),
             tbl_args: None,
             push_downs: None,
             query_internal_columns: false,
+            table_index: None,
         };
 
         Ok(Plan::Copy(Box::new(CopyPlan::IntoTable {
@@ -327,7 +368,7 @@ impl<'a> Binder {
             database_name: dst_database_name.to_string(),
             table_name: dst_table_name.to_string(),
             table_id: table.get_id(),
-            schema: table.schema(),
+            schema,
             from: Box::new(from),
             validation_mode,
             force: stmt.force,
@@ -368,7 +409,8 @@ impl<'a> Binder {
             .map_err(ErrorCode::SyntaxException)?;
 
         let (mut stage_info, path) =
-            parse_stage_location_v2(&self.ctx, dst_stage, dst_path).await?;
+            parse_stage_location_v2(&self.ctx, dst_stage, dst_path, UserPrivilegeType::Write)
+                .await?;
         self.apply_stage_options(stmt, &mut stage_info).await?;
 
         Ok(Plan::Copy(Box::new(CopyPlan::IntoStage {
@@ -447,7 +489,8 @@ impl<'a> Binder {
             .map_err(ErrorCode::SyntaxException)?;
 
         let (mut stage_info, path) =
-            parse_stage_location_v2(&self.ctx, dst_stage, dst_path).await?;
+            parse_stage_location_v2(&self.ctx, dst_stage, dst_path, UserPrivilegeType::Write)
+                .await?;
         self.apply_stage_options(stmt, &mut stage_info).await?;
 
         Ok(Plan::Copy(Box::new(CopyPlan::IntoStage {
@@ -502,6 +545,7 @@ impl<'a> Binder {
         dst_catalog_name: &str,
         dst_database_name: &str,
         dst_table_name: &str,
+        dst_columns: &[Identifier],
     ) -> Result<Plan> {
         // Validation mode.
         let validation_mode = ValidationMode::from_str(stmt.validation_mode.as_str())
@@ -514,16 +558,9 @@ impl<'a> Binder {
             .await?;
 
         // src
-        let (select_list, location, alias) = check_transform_query(src_query)?;
-        if matches!(location, FileLocation::Uri(_)) {
-            // todo!(youngsofun): need to refactor parser
-            return Err(ErrorCode::SyntaxException(
-                "copy into table from uri with transform not supported yet",
-            ));
-        }
-
+        let (select_list, location, connection, alias) = check_transform_query(src_query)?;
         let (mut stage_info, path) =
-            parse_file_location(&self.ctx, location, BTreeMap::new()).await?;
+            parse_file_location(&self.ctx, location, connection.clone()).await?;
         self.apply_stage_options(stmt, &mut stage_info).await?;
         let files_info = StageFilesInfo {
             path,
@@ -547,6 +584,10 @@ impl<'a> Binder {
 
         info!("end to list files: {}", files.len());
 
+        if need_infer_file_format(stmt, &stage_info) {
+            infer_file_format(&mut stage_info, &files)?;
+        }
+
         if !stmt.force {
             // Status.
             {
@@ -595,7 +636,29 @@ impl<'a> Binder {
         let select_list = self
             .normalize_select_list(&mut from_context, select_list)
             .await?;
-        let (scalar_items, projections) = self.analyze_projection(&select_list)?;
+        let (mut scalar_items, mut projections) = self.analyze_projection(&select_list)?;
+        if !dst_columns.is_empty() {
+            // Name the columns of the query after the given columns of the table,
+            // so they are mapped to the columns of the table by name.
+            let schema = self.copy_into_table_schema(dst_table.schema(), dst_columns)?;
+            if projections.len() != schema.num_fields() {
+                return Err(ErrorCode::BadArguments(format!(
+                    "the query of COPY has {} columns, but {} columns of the table are given",
+                    projections.len(),
+                    schema.num_fields()
+                )));
+            }
+            for (column, field) in projections.iter_mut().zip(schema.fields()) {
+                column.column_name = field.name().clone();
+            }
+        }
+        self.fill_default_columns(
+            &from_context,
+            dst_table.schema(),
+            &mut scalar_items,
+            &mut projections,
+        )
+        .await?;
         let s_expr =
             self.bind_projection(&mut from_context, &projections, &scalar_items, s_expr)?;
         let mut output_context = BindContext::new();
@@ -625,6 +688,30 @@ impl<'a> Binder {
         })))
     }
 
+    /// The schema of the columns loaded by `COPY INTO <table> (<columns>)`, in the given order.
+    /// All the columns of the table are loaded if no column is given.
+    fn copy_into_table_schema(
+        &self,
+        schema: TableSchemaRef,
+        columns: &[Identifier],
+    ) -> Result<TableSchemaRef> {
+        if columns.is_empty() {
+            return Ok(schema);
+        }
+
+        let mut fields = Vec::with_capacity(columns.len());
+        for ident in columns {
+            let name = normalize_identifier(ident, &self.name_resolution_ctx).name;
+            if fields.iter().any(|f: &TableField| f.name() == &name) {
+                return Err(ErrorCode::BadArguments(format!(
+                    "column `{name}` is given more than once in COPY"
+                )));
+            }
+            fields.push(schema.field_with_name(&name)?.clone());
+        }
+        Ok(TableSchemaRefExt::create(fields))
+    }
+
     async fn apply_stage_options(&mut self, stmt: &CopyStmt, stage: &mut StageInfo) -> Result<()> {
         if !stmt.file_format.is_empty() {
             stage.file_format_options = self.try_resolve_file_format(&stmt.file_format).await?;
@@ -652,6 +739,142 @@ impl<'a> Binder {
 
         Ok(())
     }
+
+    /// The columns of the query are mapped to the columns of the table by name if all of them
+    /// are named after the columns of the table, by position otherwise. The columns of the table
+    /// not given by the query are filled with their default values.
+    async fn fill_default_columns(
+        &mut self,
+        bind_context: &BindContext,
+        schema: TableSchemaRef,
+        scalar_items: &mut HashMap<IndexType, ScalarItem>,
+        projections: &mut Vec<ColumnBinding>,
+    ) -> Result<()> {
+        let fields = schema.fields();
+        if projections.len() >= fields.len() {
+            return Ok(());
+        }
+
+        let by_name = projections
+            .iter()
+            .all(|column| schema.field_with_name(&column.column_name).is_ok());
+        let mut columns = Vec::with_capacity(fields.len());
+        for (i, field) in fields.iter().enumerate() {
+            let column = if by_name {
+                projections
+                    .iter()
+                    .find(|column| &column.column_name == field.name())
+            } else {
+                projections.get(i)
+            };
+            if let Some(column) = column {
+                columns.push(column.clone());
+                continue;
+            }
+
+            let data_type = DataType::from(field.data_type());
+            let scalar = match field.default_expr() {
+                Some(default_expr) => {
+                    let sql_dialect = self.ctx.get_settings().get_sql_dialect()?;
+                    let tokens = tokenize_sql(default_expr)?;
+                    let expr = parse_expr(&tokens, sql_dialect)?;
+                    let mut scalar_binder = ScalarBinder::new(
+                        bind_context,
+                        self.ctx.clone(),
+                        &self.name_resolution_ctx,
+                        self.metadata.clone(),
+                        &[],
+                    );
+                    let (scalar, _) = scalar_binder.bind(&expr).await?;
+                    ScalarExpr::CastExpr(CastExpr {
+                        span: None,
+                        is_try: data_type.is_nullable(),
+                        argument: Box::new(scalar),
+                        target_type: Box::new(data_type.clone()),
+                    })
+                }
+                None if data_type.is_nullable() => ScalarExpr::ConstantExpr(ConstantExpr {
+                    span: None,
+                    value: Literal::Null,
+                    data_type: Box::new(data_type.clone()),
+                }),
+                None => {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "column `{}` is not nullable and has no default value, it must be given in the COPY query",
+                        field.name()
+                    )));
+                }
+            };
+
+            let column = self.create_column_binding(None, None, field.name().clone(), data_type);
+            scalar_items.insert(column.index, ScalarItem {
+                scalar,
+                index: column.index,
+            });
+            columns.push(column);
+        }
+
+        *projections = columns;
+        Ok(())
+    }
+
+    /// Infer the file format from the extension of the files to copy, if neither
+    /// the statement nor the stage specifies the file format.
+    async fn infer_file_format_of_stage(
+        &self,
+        stmt: &CopyStmt,
+        stage: &mut StageInfo,
+        files_info: &StageFilesInfo,
+    ) -> Result<()> {
+        if !need_infer_file_format(stmt, stage) {
+            return Ok(());
+        }
+
+        let operator = init_stage_operator(stage)?;
+        let files = if operator.info().can_blocking() {
+            files_info.blocking_list(&operator, false)
+        } else {
+            files_info.list(&operator, false).await
+        }?;
+        infer_file_format(stage, &files)
+    }
+}
+
+fn need_infer_file_format(stmt: &CopyStmt, stage: &StageInfo) -> bool {
+    stmt.file_format.is_empty() && stage.file_format_options == FileFormatOptions::default()
+}
+
+/// All the files must have the same known extension, the stage is left unchanged if there is no file.
+fn infer_file_format(stage: &mut StageInfo, files: &[StageFileInfo]) -> Result<()> {
+    let mut inferred: Option<(&str, StageFileFormatType)> = None;
+    for file in files {
+        let format = StageFileFormatType::from_path(&file.path).ok_or_else(|| {
+            ErrorCode::BadArguments(format!(
+                "cannot infer the file format of '{}' from its extension, please specify FILE_FORMAT",
+                file.path
+            ))
+        })?;
+        match &inferred {
+            Some((path, inferred_format)) if *inferred_format != format => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "cannot infer the file format, '{}' is {:?} but '{}' is {:?}, please specify FILE_FORMAT",
+                    path, inferred_format, file.path, format
+                )));
+            }
+            Some(_) => {}
+            None => inferred = Some((&file.path, format)),
+        }
+    }
+
+    if let Some((_, format)) = inferred {
+        // The other options are left empty to use the defaults of the format.
+        stage.file_format_options = FileFormatOptions {
+            format,
+            compression: StageFileCompression::Auto,
+            ..FileFormatOptions::new()
+        };
+    }
+    Ok(())
 }
 
 // we can avoid this by specializing the parser.
@@ -659,7 +882,12 @@ impl<'a> Binder {
 // also check_query here may give a more friendly error msg.
 fn check_transform_query(
     query: &Query,
-) -> Result<(&Vec<SelectTarget>, &FileLocation, &Option<TableAlias>)> {
+) -> Result<(
+    &Vec<SelectTarget>,
+    &FileLocation,
+    &BTreeMap<String, String>,
+    &Option<TableAlias>,
+)> {
     if query.offset.is_none()
         && query.limit.is_empty()
         && query.order_by.is_empty()
@@ -678,11 +906,18 @@ fn check_transform_query(
                     alias,
                 } = &select.from[0]
                 {
-                    if options.is_empty() {
-                        return Ok((&select.select_list, location, alias));
+                    // The connection of an uri location can only be given inside the query,
+                    // other options should be applied in the outer copy stmt.
+                    let only_uri_connection = options.files.is_none()
+                        && options.pattern.is_none()
+                        && options.file_format.is_none()
+                        && (options.connection.is_empty()
+                            || matches!(location, FileLocation::Uri(_)));
+                    if only_uri_connection {
+                        return Ok((&select.select_list, location, &options.connection, alias));
                     } else {
                         return Err(ErrorCode::SyntaxException(
-                            "stage table function inside copy not allow options, apply them in the outer copy stmt instead.",
+                            "stage table function inside copy not allow options except the connection of uri, apply them in the outer copy stmt instead.",
                         ));
                     }
                 }
@@ -718,21 +953,19 @@ fn check_transform_query(
 /// For internal stage, we will also add prefix `/stage/<stage>/`
 ///
 /// - @internal/abc => (internal, "/stage/internal/abc")
+///
+/// The current user must have the `privilege` on the named stage: READ to read
+/// the files of the stage, WRITE to write or remove them.
 pub async fn parse_stage_location(
     ctx: &Arc<dyn TableContext>,
     location: &str,
+    privilege: UserPrivilegeType,
 ) -> Result<(StageInfo, String)> {
     let s: Vec<&str> = location.split('@').collect();
     // @my_ext_stage/abc/
     let names: Vec<&str> = s[1].splitn(2, '/').filter(|v| !v.is_empty()).collect();
 
-    let stage = if names[0] == "~" {
-        StageInfo::new_user_stage(&ctx.get_current_user()?.name)
-    } else {
-        UserApiProvider::instance()
-            .get_stage(&ctx.get_tenant(), names[0])
-            .await?
-    };
+    let stage = get_stage(ctx, names[0], privilege).await?;
 
     let path = names.get(1).unwrap_or(&"").trim_start_matches('/');
     let path = if path.is_empty() { "/" } else { path };
@@ -751,14 +984,9 @@ pub async fn parse_stage_location_v2(
     ctx: &Arc<dyn TableContext>,
     name: &str,
     path: &str,
+    privilege: UserPrivilegeType,
 ) -> Result<(StageInfo, String)> {
-    let stage = if name == "~" {
-        StageInfo::new_user_stage(&ctx.get_current_user()?.name)
-    } else {
-        UserApiProvider::instance()
-            .get_stage(&ctx.get_tenant(), name)
-            .await?
-    };
+    let stage = get_stage(ctx, name, privilege).await?;
 
     // prefix must be endswith `/`, so we should trim path here.
     let relative_path = path.trim_start_matches('/').to_string();
@@ -767,6 +995,26 @@ pub async fn parse_stage_location_v2(
     Ok((stage, relative_path))
 }
 
+/// Gets the stage by name, the user stage `~` is always accessible to the current user.
+async fn get_stage(
+    ctx: &Arc<dyn TableContext>,
+    name: &str,
+    privilege: UserPrivilegeType,
+) -> Result<StageInfo> {
+    if name == "~" {
+        return Ok(StageInfo::new_user_stage(&ctx.get_current_user()?.name));
+    }
+
+    let stage = UserApiProvider::instance()
+        .get_stage(&ctx.get_tenant(), name)
+        .await?;
+    ctx.validate_privilege(&GrantObject::Stage(stage.stage_name.clone()), vec![
+        privilege,
+    ])
+    .await?;
+    Ok(stage)
+}
+
 pub async fn parse_file_location(
     ctx: &Arc<dyn TableContext>,
     location: &FileLocation,
@@ -774,7 +1022,8 @@ pub async fn parse_file_location(
 ) -> Result<(StageInfo, String)> {
     match location.clone() {
         FileLocation::Stage(location) => {
-            parse_stage_location_v2(ctx, &location.name, &location.path).await
+            parse_stage_location_v2(ctx, &location.name, &location.path, UserPrivilegeType::Read)
+                .await
         }
         FileLocation::Uri(uri) => {
             let mut location = UriLocation::from_uri(uri, "".to_string(), connection)?;
@@ -22,6 +22,7 @@ use common_exception::Result;
 use common_meta_app::principal::FileFormatOptions;
 use common_meta_app::principal::OnErrorMode;
 use common_meta_app::principal::StageInfo;
+use common_meta_app::principal::UserPrivilegeType;
 
 use super::super::copy::parse_stage_location;
 use crate::binder::location::parse_uri_location;
@@ -38,7 +39,8 @@ impl Binder {
         pattern: &str,
     ) -> Result<Plan> {
         let stage_name = format!("@{location}");
-        let (stage, path) = parse_stage_location(&self.ctx, stage_name.as_str()).await?;
+        let (stage, path) =
+            parse_stage_location(&self.ctx, stage_name.as_str(), UserPrivilegeType::Read).await?;
         let plan_node = ListPlan {
             path,
             stage,
@@ -54,7 +56,8 @@ impl Binder {
         pattern: &str,
     ) -> Result<Plan> {
         let stage_name = format!("@{location}");
-        let (stage, path) = parse_stage_location(&self.ctx, stage_name.as_str()).await?;
+        let (stage, path) =
+            parse_stage_location(&self.ctx, stage_name.as_str(), UserPrivilegeType::Write).await?;
         let plan_node = RemoveStagePlan {
             path,
             stage,
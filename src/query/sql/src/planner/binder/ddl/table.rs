@@ -853,6 +853,7 @@ impl Binder {
             self.metadata.clone(),
             &[],
         );
+        let strict_default_expr = self.ctx.get_settings().get_enable_strict_default_expr()?;
         let mut fields = Vec::with_capacity(columns.len());
         let mut fields_default_expr = Vec::with_capacity(columns.len());
         let mut fields_comments = Vec::with_capacity(columns.len());
@@ -864,6 +865,12 @@ impl Binder {
             fields_default_expr.push({
                 if let Some(default_expr) = &column.default_expr {
                     let (expr, _) = scalar_binder.bind(default_expr).await?;
+                    if strict_default_expr && !expr.as_expr_with_col_index()?.is_deterministic() {
+                        return Err(ErrorCode::SemanticError(format!(
+                            "default expression `{default_expr}` of column `{name}` is not deterministic",
+                        ))
+                        .set_span(default_expr.span()));
+                    }
                     let is_try = schema_data_type.is_nullable();
                     let cast_expr_to_field_type = ScalarExpr::CastExpr(CastExpr {
                         span: expr.span(),
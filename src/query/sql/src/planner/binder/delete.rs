@@ -69,7 +69,7 @@ impl<'a> Binder {
                     is_having: false,
                 };
                 let filter_expr = SExpr::create_unary(filter.into(), table_expr);
-                let mut rewriter = SubqueryRewriter::new(self.metadata.clone());
+                let mut rewriter = SubqueryRewriter::new(self.ctx.clone(), self.metadata.clone());
                 let filter_expr = rewriter.rewrite(&filter_expr)?;
                 (None, Some(filter_expr))
             } else {
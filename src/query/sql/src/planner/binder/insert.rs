@@ -16,12 +16,23 @@ use std::sync::Arc;
 
 use common_ast::ast::InsertSource;
 use common_ast::ast::InsertStmt;
+use common_ast::ast::SelectTarget;
 use common_ast::ast::Statement;
+use common_catalog::table_context::StageAttachment;
+use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::DataField;
+use common_expression::DataSchemaRef;
+use common_expression::DataSchemaRefExt;
+use common_expression::TableSchemaRef;
 use common_expression::TableSchemaRefExt;
 use common_meta_app::principal::FileFormatOptions;
 
+use crate::binder::contain_aggregate_window_or_subquery;
 use crate::binder::Binder;
+use crate::binder::ColumnBinding;
+use crate::binder::Visibility;
 use crate::normalize_identifier;
 use crate::optimizer::optimize;
 use crate::optimizer::OptimizerConfig;
@@ -29,6 +40,7 @@ use crate::optimizer::OptimizerContext;
 use crate::plans::Insert;
 use crate::plans::InsertInputSource;
 use crate::plans::Plan;
+use crate::plans::ScalarExpr;
 use crate::BindContext;
 
 impl Binder {
@@ -44,6 +56,7 @@ impl Binder {
             columns,
             source,
             overwrite,
+            returning,
         } = stmt;
         let (catalog_name, database_name, table_name) =
             self.normalize_object_identifier_triple(catalog, database, table);
@@ -108,8 +121,27 @@ impl Binder {
                 let optimized_plan = optimize(self.ctx.clone(), opt_ctx, select_plan)?;
                 Ok(InsertInputSource::SelectPlan(Box::new(optimized_plan)))
             }
+            InsertSource::Stage {
+                location,
+                file_format,
+            } => Ok(InsertInputSource::Stage(Arc::new(StageAttachment {
+                location: format!("@{location}"),
+                file_format_options: (!file_format.is_empty()).then_some(file_format),
+                copy_options: None,
+                values_str: "".to_string(),
+            }))),
         };
 
+        let input_source = input_source?;
+        let (returning, returning_schema) = self
+            .bind_insert_returning(&database_name, &table_name, table.schema(), returning)
+            .await?;
+        if !returning.is_empty() && matches!(input_source, InsertInputSource::Stage(_)) {
+            return Err(ErrorCode::Unimplemented(
+                "RETURNING is not supported when inserting from a stage attachment",
+            ));
+        }
+
         let plan = Insert {
             catalog: catalog_name.to_string(),
             database: database_name.to_string(),
@@ -117,9 +149,53 @@ impl Binder {
             table_id,
             schema,
             overwrite: *overwrite,
-            source: input_source?,
+            source: input_source,
+            returning,
+            returning_schema,
         };
 
         Ok(Plan::Insert(Box::new(plan)))
     }
+
+    /// Binds the `RETURNING` clause against the columns of the table, the index of a column is
+    /// its position in the table schema, so the expressions can be evaluated on the inserted
+    /// rows after the missing columns are filled with default values.
+    async fn bind_insert_returning(
+        &mut self,
+        database_name: &str,
+        table_name: &str,
+        table_schema: TableSchemaRef,
+        returning: &[SelectTarget],
+    ) -> Result<(Vec<ScalarExpr>, DataSchemaRef)> {
+        if returning.is_empty() {
+            return Ok((vec![], DataSchemaRefExt::create(vec![])));
+        }
+
+        let mut context = BindContext::new();
+        for (index, field) in table_schema.fields().iter().enumerate() {
+            context.add_column_binding(ColumnBinding {
+                database_name: Some(database_name.to_string()),
+                table_name: Some(table_name.to_string()),
+                column_name: field.name().clone(),
+                index,
+                data_type: Box::new(DataType::from(field.data_type())),
+                visibility: Visibility::Visible,
+            });
+        }
+
+        let select_list = self.normalize_select_list(&mut context, returning).await?;
+        let mut scalars = Vec::with_capacity(select_list.items.len());
+        let mut fields = Vec::with_capacity(select_list.items.len());
+        for item in select_list.items {
+            if contain_aggregate_window_or_subquery(&item.scalar)? {
+                return Err(ErrorCode::SemanticError(
+                    "RETURNING clause can only contain expressions of the inserted columns",
+                )
+                .set_span(item.scalar.span()));
+            }
+            fields.push(DataField::new(&item.alias, item.scalar.data_type()?));
+            scalars.push(item.scalar);
+        }
+        Ok((scalars, DataSchemaRefExt::create(fields)))
+    }
 }
@@ -27,7 +27,8 @@ use common_exception::Span;
 use common_expression::type_check::common_super_type;
 use common_functions::scalars::BUILTIN_FUNCTIONS;
 
-use crate::binder::JoinPredicate;
+use crate::binder::split_join_predicates;
+use crate::binder::SplitJoinPredicates;
 use crate::binder::Visibility;
 use crate::normalize_identifier;
 use crate::optimizer::ColumnSet;
@@ -50,6 +51,8 @@ pub struct JoinConditions {
     pub(crate) right_conditions: Vec<ScalarExpr>,
     pub(crate) non_equi_conditions: Vec<ScalarExpr>,
     pub(crate) other_conditions: Vec<ScalarExpr>,
+    /// The indexes of the equi-conditions of `<=>`, whose NULL keys match each other.
+    pub(crate) is_null_equal: Vec<usize>,
 }
 
 impl Binder {
@@ -87,6 +90,7 @@ impl Binder {
         let mut right_join_conditions: Vec<ScalarExpr> = vec![];
         let mut non_equi_conditions: Vec<ScalarExpr> = vec![];
         let mut other_conditions: Vec<ScalarExpr> = vec![];
+        let mut is_null_equal: Vec<usize> = vec![];
         let mut join_condition_resolver = JoinConditionResolver::new(
             self.ctx.clone(),
             &self.name_resolution_ctx,
@@ -103,6 +107,7 @@ impl Binder {
                 &mut right_join_conditions,
                 &mut non_equi_conditions,
                 &mut other_conditions,
+                &mut is_null_equal,
                 &join.op,
             )
             .await?;
@@ -112,6 +117,7 @@ impl Binder {
             right_conditions: right_join_conditions,
             non_equi_conditions,
             other_conditions,
+            is_null_equal,
         };
         let s_expr = match &join.op {
             JoinOperator::Inner => {
@@ -172,31 +178,24 @@ impl Binder {
     pub fn bind_join_with_type(
         &mut self,
         join_type: JoinType,
-        join_conditions: JoinConditions,
+        mut join_conditions: JoinConditions,
         mut left_child: SExpr,
         mut right_child: SExpr,
     ) -> Result<SExpr> {
-        let left_conditions = join_conditions.left_conditions;
-        let right_conditions = join_conditions.right_conditions;
-        let mut non_equi_conditions = join_conditions.non_equi_conditions;
-        let other_conditions = join_conditions.other_conditions;
         if join_type == JoinType::Cross
-            && (!left_conditions.is_empty() || !right_conditions.is_empty())
+            && (!join_conditions.left_conditions.is_empty()
+                || !join_conditions.right_conditions.is_empty())
         {
             return Err(ErrorCode::SemanticError(
                 "Join conditions should be empty in cross join",
             ));
         }
-        self.push_down_other_conditions(
-            &mut left_child,
-            &mut right_child,
-            other_conditions,
-            &mut non_equi_conditions,
-        )?;
+        self.push_down_other_conditions(&mut left_child, &mut right_child, &mut join_conditions)?;
         let logical_join = Join {
-            left_conditions,
-            right_conditions,
-            non_equi_conditions,
+            left_conditions: join_conditions.left_conditions,
+            right_conditions: join_conditions.right_conditions,
+            non_equi_conditions: join_conditions.non_equi_conditions,
+            is_null_equal: join_conditions.is_null_equal,
             join_type,
             marker_index: None,
             from_correlated_subquery: false,
@@ -213,44 +212,29 @@ impl Binder {
         &self,
         left_child: &mut SExpr,
         right_child: &mut SExpr,
-        other_conditions: Vec<ScalarExpr>,
-        non_equi_conditions: &mut Vec<ScalarExpr>,
+        join_conditions: &mut JoinConditions,
     ) -> Result<()> {
-        if other_conditions.is_empty() {
+        if join_conditions.other_conditions.is_empty() {
             return Ok(());
         }
         let left_prop = RelExpr::with_s_expr(left_child).derive_relational_prop()?;
         let right_prop = RelExpr::with_s_expr(right_child).derive_relational_prop()?;
 
-        let mut left_push_down = vec![];
-        let mut right_push_down = vec![];
-        let mut need_push_down = false;
-
-        for predicate in other_conditions.iter() {
-            let pred = JoinPredicate::new(predicate, &left_prop, &right_prop);
-            match pred {
-                JoinPredicate::Left(_) => {
-                    need_push_down = true;
-                    left_push_down.push(predicate.clone());
-                }
-                JoinPredicate::Right(_) => {
-                    need_push_down = true;
-                    right_push_down.push(predicate.clone());
-                }
-                _ => {
-                    non_equi_conditions.push(predicate.clone());
-                }
-            }
-        }
-
-        if !need_push_down {
-            return Ok(());
-        }
+        let SplitJoinPredicates {
+            left_filters,
+            right_filters,
+            left_conditions,
+            right_conditions,
+            other_conditions,
+        } = split_join_predicates(&join_conditions.other_conditions, &left_prop, &right_prop)?;
+        join_conditions.left_conditions.extend(left_conditions);
+        join_conditions.right_conditions.extend(right_conditions);
+        join_conditions.non_equi_conditions.extend(other_conditions);
 
-        if !left_push_down.is_empty() {
+        if !left_filters.is_empty() {
             *left_child = SExpr::create_unary(
                 Filter {
-                    predicates: left_push_down,
+                    predicates: left_filters,
                     is_having: false,
                 }
                 .into(),
@@ -258,10 +242,10 @@ impl Binder {
             );
         }
 
-        if !right_push_down.is_empty() {
+        if !right_filters.is_empty() {
             *right_child = SExpr::create_unary(
                 Filter {
-                    predicates: right_push_down,
+                    predicates: right_filters,
                     is_having: false,
                 }
                 .into(),
@@ -398,6 +382,7 @@ impl<'a> JoinConditionResolver<'a> {
         right_join_conditions: &mut Vec<ScalarExpr>,
         non_equi_conditions: &mut Vec<ScalarExpr>,
         other_join_conditions: &mut Vec<ScalarExpr>,
+        is_null_equal: &mut Vec<usize>,
         join_op: &JoinOperator,
     ) -> Result<()> {
         match &self.join_condition {
@@ -408,6 +393,7 @@ impl<'a> JoinConditionResolver<'a> {
                     right_join_conditions,
                     non_equi_conditions,
                     other_join_conditions,
+                    is_null_equal,
                 )
                 .await?;
             }
@@ -463,6 +449,7 @@ impl<'a> JoinConditionResolver<'a> {
         right_join_conditions: &mut Vec<ScalarExpr>,
         non_equi_conditions: &mut Vec<ScalarExpr>,
         other_join_conditions: &mut Vec<ScalarExpr>,
+        is_null_equal: &mut Vec<usize>,
     ) -> Result<()> {
         let conjunctions = split_conjunctions_expr(condition);
         for expr in conjunctions.iter() {
@@ -472,6 +459,7 @@ impl<'a> JoinConditionResolver<'a> {
                 right_join_conditions,
                 non_equi_conditions,
                 other_join_conditions,
+                is_null_equal,
             )
             .await?;
         }
@@ -491,6 +479,7 @@ impl<'a> JoinConditionResolver<'a> {
         right_join_conditions: &mut Vec<ScalarExpr>,
         non_equi_conditions: &mut Vec<ScalarExpr>,
         other_join_conditions: &mut Vec<ScalarExpr>,
+        is_null_equal: &mut Vec<usize>,
     ) -> Result<()> {
         let mut join_context = (*self.join_context).clone();
         wrap_nullable_for_column(
@@ -509,7 +498,8 @@ impl<'a> JoinConditionResolver<'a> {
         // Given two tables: t1(a, b), t2(a, b)
         // A predicate can be regarded as an equi-predicate iff:
         //
-        //   - The predicate is literally an equivalence expression, e.g. `t1.a = t2.a`
+        //   - The predicate is literally an equivalence expression, e.g. `t1.a = t2.a`, or a
+        //     null-safe one, e.g. `t1.a <=> t2.a`
         //   - Each side of `=` only contains columns from one table and the both sides are disjoint.
         //     For example, `t1.a + t1.b = t2.a` is a valid one while `t1.a + t2.a = t2.b` isn't.
         //
@@ -519,6 +509,21 @@ impl<'a> JoinConditionResolver<'a> {
             let (left, _) = scalar_binder.bind(&left).await?;
             let (right, _) = scalar_binder.bind(&right).await?;
             self.add_equi_conditions(left, right, left_join_conditions, right_join_conditions)?
+        } else if let Expr::IsDistinctFrom {
+            left,
+            right,
+            not: true,
+            ..
+        } = predicate
+        {
+            let (left, _) = scalar_binder.bind(left).await?;
+            let (right, _) = scalar_binder.bind(right).await?;
+            let added =
+                self.add_equi_conditions(left, right, left_join_conditions, right_join_conditions)?;
+            if added {
+                is_null_equal.push(left_join_conditions.len() - 1);
+            }
+            added
         } else {
             false
         };
@@ -16,6 +16,7 @@ use common_ast::ast::PresignAction as AstPresignAction;
 use common_ast::ast::PresignLocation;
 use common_ast::ast::PresignStmt;
 use common_exception::Result;
+use common_meta_app::principal::UserPrivilegeType;
 use time::Duration;
 
 use super::copy::parse_stage_location_v2;
@@ -33,17 +34,24 @@ impl Binder {
     ) -> Result<Plan> {
         match &stmt.location {
             PresignLocation::StageLocation(stage_location) => {
-                let (stage_info, path) =
-                    parse_stage_location_v2(&self.ctx, &stage_location.name, &stage_location.path)
-                        .await?;
+                let (action, privilege) = match stmt.action {
+                    AstPresignAction::Download => {
+                        (PresignAction::Download, UserPrivilegeType::Read)
+                    }
+                    AstPresignAction::Upload => (PresignAction::Upload, UserPrivilegeType::Write),
+                };
+                let (stage_info, path) = parse_stage_location_v2(
+                    &self.ctx,
+                    &stage_location.name,
+                    &stage_location.path,
+                    privilege,
+                )
+                .await?;
 
                 Ok(Plan::Presign(Box::new(PresignPlan {
                     stage: Box::new(stage_info),
                     path,
-                    action: match stmt.action {
-                        AstPresignAction::Download => PresignAction::Download,
-                        AstPresignAction::Upload => PresignAction::Upload,
-                    },
+                    action,
                     expire: Duration::seconds(stmt.expire.as_secs() as i64),
                     content_type: stmt.content_type.clone(),
                 })))
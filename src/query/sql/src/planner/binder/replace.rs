@@ -17,6 +17,7 @@ use std::sync::Arc;
 use common_ast::ast::InsertSource;
 use common_ast::ast::ReplaceStmt;
 use common_ast::ast::Statement;
+use common_catalog::table_context::StageAttachment;
 use common_exception::Result;
 use common_expression::TableSchemaRefExt;
 use common_meta_app::principal::FileFormatOptions;
@@ -126,6 +127,15 @@ impl Binder {
                 let optimized_plan = optimize(self.ctx.clone(), opt_ctx, select_plan)?;
                 Ok(InsertInputSource::SelectPlan(Box::new(optimized_plan)))
             }
+            InsertSource::Stage {
+                location,
+                file_format,
+            } => Ok(InsertInputSource::Stage(Arc::new(StageAttachment {
+                location: format!("@{location}"),
+                file_format_options: (!file_format.is_empty()).then_some(file_format),
+                copy_options: None,
+                values_str: "".to_string(),
+            }))),
         };
 
         let plan = Replace {
@@ -14,8 +14,11 @@
 
 use std::collections::HashSet;
 
+use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::type_check::common_super_type;
 use common_expression::types::DataType;
+use common_functions::scalars::BUILTIN_FUNCTIONS;
 
 use crate::binder::scalar_visitor::Recursion;
 use crate::binder::scalar_visitor::ScalarVisitor;
@@ -42,7 +45,6 @@ impl<'a, F> Finder<'a, F>
 where F: Fn(&ScalarExpr) -> bool
 {
     /// Create a new finder with the `test_fn`
-    #[allow(dead_code)]
     fn new(find_fn: &'a F) -> Self {
         Self {
             find_fn,
@@ -67,6 +69,21 @@ where F: Fn(&ScalarExpr) -> bool
     }
 }
 
+/// Check if the scalar contains an aggregate function, a window function or a subquery,
+/// which can't be evaluated on the rows one by one.
+pub fn contain_aggregate_window_or_subquery(scalar: &ScalarExpr) -> Result<bool> {
+    let find_fn = |scalar: &ScalarExpr| {
+        matches!(
+            scalar,
+            ScalarExpr::AggregateFunction(_)
+                | ScalarExpr::WindowFunction(_)
+                | ScalarExpr::SubqueryExpr(_)
+        )
+    };
+    let finder = Finder::new(&find_fn).visit(scalar)?;
+    Ok(!finder.scalars.is_empty())
+}
+
 pub fn split_conjunctions(scalar: &ScalarExpr) -> Vec<ScalarExpr> {
     match scalar {
         ScalarExpr::AndExpr(AndExpr { left, right, .. }) => {
@@ -149,6 +166,61 @@ impl<'a> JoinPredicate<'a> {
     }
 }
 
+/// Predicates of a join classified with [`JoinPredicate`].
+#[derive(Clone, Debug, Default)]
+pub struct SplitJoinPredicates {
+    /// Predicates only referencing the left side
+    pub left_filters: Vec<ScalarExpr>,
+    /// Predicates only referencing the right side
+    pub right_filters: Vec<ScalarExpr>,
+    /// Left sides of the equi-conditions, casted to the common type of both sides
+    pub left_conditions: Vec<ScalarExpr>,
+    /// Right sides of the equi-conditions, casted to the common type of both sides
+    pub right_conditions: Vec<ScalarExpr>,
+    /// Predicates can't be classified as above
+    pub other_conditions: Vec<ScalarExpr>,
+}
+
+/// Classify `predicates` by the relational properties of the two sides of a join,
+/// the two sides of an equi-condition are casted to their common super type if they
+/// are of different types.
+pub fn split_join_predicates(
+    predicates: &[ScalarExpr],
+    left_prop: &RelationalProperty,
+    right_prop: &RelationalProperty,
+) -> Result<SplitJoinPredicates> {
+    let mut split = SplitJoinPredicates::default();
+    for predicate in predicates {
+        match JoinPredicate::new(predicate, left_prop, right_prop) {
+            JoinPredicate::Left(filter) => split.left_filters.push(filter.clone()),
+            JoinPredicate::Right(filter) => split.right_filters.push(filter.clone()),
+            JoinPredicate::Other(pred) => split.other_conditions.push(pred.clone()),
+            JoinPredicate::Both { left, right } => {
+                let left_type = left.data_type()?;
+                let right_type = right.data_type()?;
+                if left_type == right_type {
+                    split.left_conditions.push(left.clone());
+                    split.right_conditions.push(right.clone());
+                    continue;
+                }
+                let join_type = common_super_type(
+                    left_type.clone(),
+                    right_type.clone(),
+                    &BUILTIN_FUNCTIONS.default_cast_rules,
+                )
+                .ok_or_else(|| {
+                    ErrorCode::Internal(format!(
+                        "Left type {left_type} and right type {right_type} cannot be matched"
+                    ))
+                })?;
+                split.left_conditions.push(wrap_cast(left, &join_type));
+                split.right_conditions.push(wrap_cast(right, &join_type));
+            }
+        }
+    }
+    Ok(split)
+}
+
 pub fn contain_subquery(scalar: &ScalarExpr) -> bool {
     match scalar {
         ScalarExpr::BoundColumnRef(BoundColumnRef { column, .. }) => {
@@ -46,6 +46,7 @@ use crate::plans::CastExpr;
 use crate::plans::EvalScalar;
 use crate::plans::Filter;
 use crate::plans::JoinType;
+use crate::plans::MaterializedCte;
 use crate::plans::ScalarExpr;
 use crate::plans::ScalarItem;
 use crate::plans::UnionAll;
@@ -97,8 +98,6 @@ impl Binder {
             s_expr = self.bind_where(&mut from_context, expr, s_expr).await?;
         }
 
-        let window_order_by_exprs = self.fetch_window_order_by_expr(&stmt.select_list).await;
-
         // Generate a analyzed select list with from context
         let mut select_list = self
             .normalize_select_list(&mut from_context, &stmt.select_list)
@@ -114,6 +113,16 @@ impl Binder {
 
         self.analyze_aggregate_select(&mut from_context, &mut select_list)?;
 
+        if !from_context.windows.is_empty()
+            && (!from_context.aggregate_info.aggregate_functions.is_empty()
+                || !from_context.aggregate_info.group_items.is_empty())
+        {
+            return Err(ErrorCode::Unimplemented(
+                "Window functions with aggregation are not supported yet",
+            )
+            .set_span(stmt.span));
+        }
+
         // `analyze_projection` should behind `analyze_aggregate_select` because `analyze_aggregate_select` will rewrite `grouping`.
         let (mut scalar_items, projections) = self.analyze_projection(&select_list)?;
 
@@ -126,20 +135,6 @@ impl Binder {
             None
         };
 
-        let mut window_order_by_items = vec![];
-
-        for order_by_expr in window_order_by_exprs.iter() {
-            window_order_by_items.push(
-                self.fetch_window_order_items(
-                    &from_context,
-                    &mut scalar_items,
-                    &projections,
-                    order_by_expr,
-                )
-                .await?,
-            );
-        }
-
         let order_items = self
             .analyze_order_items(
                 &from_context,
@@ -162,22 +157,9 @@ impl Binder {
                 .await?;
         }
 
-        // bind window order by
-        for window_order_items in window_order_by_items {
-            s_expr = self
-                .bind_window_order_by(
-                    &from_context,
-                    window_order_items,
-                    &select_list,
-                    &mut scalar_items,
-                    s_expr,
-                )
-                .await?;
-        }
-
         // bind window
         // window run after the HAVING clause but before the ORDER BY clause.
-        for window_info in bind_context.windows.iter() {
+        for window_info in from_context.windows.iter() {
             s_expr = self.bind_window_function(window_info, s_expr).await?;
         }
 
@@ -245,6 +227,7 @@ impl Binder {
         bind_context: &BindContext,
         query: &Query,
     ) -> Result<(SExpr, BindContext)> {
+        let mut materialized_ctes = vec![];
         if let Some(with) = &query.with {
             for cte in with.ctes.iter() {
                 let table_name = cte.alias.name.name.clone();
@@ -253,9 +236,18 @@ impl Binder {
                         "duplicate cte {table_name}"
                     )));
                 }
+                let materialized = if cte.materialized {
+                    let (cte_s_expr, materialized_info) =
+                        self.bind_materialized_cte(bind_context, &cte.query).await?;
+                    materialized_ctes.push((cte_s_expr, materialized_info.clone()));
+                    Some(materialized_info)
+                } else {
+                    None
+                };
                 let cte_info = CteInfo {
                     columns_alias: cte.alias.columns.iter().map(|c| c.name.clone()).collect(),
                     query: cte.query.clone(),
+                    materialized,
                 };
                 bind_context.ctes_map.insert(table_name, cte_info);
             }
@@ -298,6 +290,20 @@ impl Binder {
                 .await?;
         }
 
+        // Materialized CTEs are computed before the query reading them, a CTE
+        // may also read the CTEs defined before it.
+        for (cte_s_expr, materialized_info) in materialized_ctes.into_iter().rev() {
+            let materialized_cte = MaterializedCte {
+                cte_idx: materialized_info.cte_idx,
+                left_output_columns: materialized_info
+                    .columns
+                    .iter()
+                    .map(|column| column.index)
+                    .collect(),
+            };
+            s_expr = SExpr::create_binary(materialized_cte.into(), cte_s_expr, s_expr);
+        }
+
         Ok((s_expr, bind_context))
     }
 
@@ -535,6 +541,7 @@ impl Binder {
             right_conditions,
             non_equi_conditions: vec![],
             other_conditions: vec![],
+            is_null_equal: vec![],
         };
         let s_expr = self.bind_join_with_type(join_type, join_conditions, left_expr, right_expr)?;
         Ok((s_expr, left_context))
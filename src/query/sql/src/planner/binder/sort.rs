@@ -20,12 +20,14 @@ use common_ast::ast::Literal;
 use common_ast::ast::OrderByExpr;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_functions::aggregates::AggregateFunctionFactory;
 
 use super::bind_context::NameResolutionResult;
 use crate::binder::scalar::ScalarBinder;
 use crate::binder::select::SelectList;
 use crate::binder::Binder;
 use crate::binder::ColumnBinding;
+use crate::column_name_of;
 use crate::normalize_identifier;
 use crate::optimizer::SExpr;
 use crate::planner::semantic::GroupingChecker;
@@ -85,13 +87,27 @@ impl Binder {
                         .map(|ident| normalize_identifier(ident, &self.name_resolution_ctx).name);
                     let column = normalize_identifier(ident, &self.name_resolution_ctx).name;
 
+                    // An unqualified name refers to the output names of the select list
+                    // first, so an alias shadows a column of the from clause with the same
+                    // name. A qualified name always refers to a table column, it only matches
+                    // the select items projecting that very column, whatever their aliases.
                     for item in projections.iter() {
-                        if BindContext::match_column_binding(
-                            database.as_deref(),
-                            table.as_deref(),
-                            column.as_str(),
-                            item,
-                        ) {
+                        let matched = if table.is_none() {
+                            BindContext::match_column_binding(None, None, column.as_str(), item)
+                        } else {
+                            match scalar_items.get(&item.index).map(|item| &item.scalar) {
+                                Some(ScalarExpr::BoundColumnRef(column_ref)) => {
+                                    BindContext::match_column_binding(
+                                        database.as_deref(),
+                                        table.as_deref(),
+                                        column.as_str(),
+                                        &column_ref.column,
+                                    )
+                                }
+                                _ => false,
+                            }
+                        };
+                        if matched {
                             order_items.push(OrderItem {
                                 expr: order.clone(),
                                 index: item.index,
@@ -148,7 +164,8 @@ impl Binder {
                             });
                         }
                         NameResolutionResult::Alias { .. } => {
-                            return Err(ErrorCode::Internal("Invalid name resolution result"));
+                            return Err(ErrorCode::Internal("Invalid name resolution result")
+                                .set_span(order.expr.span()));
                         }
                     }
                 }
@@ -156,14 +173,14 @@ impl Binder {
                     lit: Literal::UInt64(index),
                     ..
                 } => {
-                    let index = *index as usize - 1;
-                    if index >= projections.len() {
+                    if *index == 0 || *index as usize > projections.len() {
                         return Err(ErrorCode::SemanticError(format!(
                             "ORDER BY position {} is not in select list",
-                            index + 1
+                            index
                         ))
                         .set_span(order.expr.span()));
                     }
+                    let index = *index as usize - 1;
 
                     order_items.push(OrderItem {
                         expr: order.clone(),
@@ -204,7 +221,10 @@ impl Binder {
                             }
                             Ok(None)
                         })
-                        .map_err(|e| ErrorCode::SemanticError(e.message()))?;
+                        .map_err(|e| {
+                            let span = e.span().or_else(|| order.expr.span());
+                            ErrorCode::SemanticError(e.message()).set_span(span)
+                        })?;
                     let column_binding = self.create_column_binding(
                         None,
                         None,
@@ -248,6 +268,18 @@ impl Binder {
                     .find(|item| item.alias == order.name)
                 {
                     group_checker.resolve(&scalar_item.scalar, None)?;
+                } else if !order.need_eval_scalar
+                    && !scalar_items.contains_key(&order.index)
+                    && !Self::is_aggregate_output(from_context, order.index)
+                {
+                    // A bare column from the from clause, which is neither a group item
+                    // nor an aggregate function.
+                    let metadata = self.metadata.read();
+                    return Err(ErrorCode::SemanticError(format!(
+                        "ORDER BY column `{}` must appear in GROUP BY or be used in an aggregate",
+                        column_name_of(metadata.column(order.index))
+                    ))
+                    .set_span(order.expr.expr.span()));
                 }
             }
             if let Expr::ColumnRef {
@@ -316,6 +348,16 @@ impl Binder {
         Ok(new_expr)
     }
 
+    /// Returns true if the column `index` is produced by the aggregation of `bind_context`.
+    fn is_aggregate_output(bind_context: &BindContext, index: IndexType) -> bool {
+        let agg_info = &bind_context.aggregate_info;
+        agg_info
+            .group_items
+            .iter()
+            .chain(agg_info.aggregate_functions.iter())
+            .any(|item| item.index == index)
+    }
+
     pub(crate) async fn bind_order_by_for_set_operation(
         &mut self,
         bind_context: &BindContext,
@@ -344,16 +386,16 @@ impl Binder {
                             order_by_items.push(order_by_item);
                         }
                         _ => {
-                            return Err(ErrorCode::Internal("scalar should be BoundColumnRef")
-                                .set_span(order.expr.span()));
+                            return Err(ErrorCode::UnsupportedOrderBy(format!(
+                                "ORDER BY of set operation must reference an output column, but `{:#}` is not",
+                                order.expr
+                            ))
+                            .set_span(order.expr.span()));
                         }
                     }
                 }
                 _ => {
-                    return Err(
-                        ErrorCode::SemanticError("can only order by column".to_string())
-                            .set_span(order.expr.span()),
-                    );
+                    return Err(unsupported_set_operation_order_by(&order.expr));
                 }
             }
         }
@@ -465,3 +507,30 @@ impl Binder {
         }
     }
 }
+
+/// Describes why `expr` can't be used in the ORDER BY of a set operation, only output
+/// columns can be referenced there.
+fn unsupported_set_operation_order_by(expr: &Expr) -> ErrorCode {
+    let feature = match expr {
+        Expr::FunctionCall {
+            window: Some(_), ..
+        } => "window function",
+        Expr::CountAll { .. } => "aggregate function",
+        Expr::FunctionCall { name, .. }
+            if AggregateFunctionFactory::instance().contains(&name.name) =>
+        {
+            "aggregate function"
+        }
+        Expr::Literal {
+            lit: Literal::UInt64(_),
+            ..
+        } => "position",
+        Expr::Subquery { .. } => "subquery",
+        _ => "expression",
+    };
+    ErrorCode::UnsupportedOrderBy(format!(
+        "{} `{:#}` is not supported in ORDER BY of set operation, only output columns can be referenced",
+        feature, expr
+    ))
+    .set_span(expr.span())
+}
@@ -22,6 +22,7 @@ use chrono::TimeZone;
 use chrono::Utc;
 use common_ast::ast::Indirection;
 use common_ast::ast::Join;
+use common_ast::ast::Query;
 use common_ast::ast::SelectStmt;
 use common_ast::ast::SelectTarget;
 use common_ast::ast::Statement;
@@ -65,10 +66,13 @@ use crate::binder::table_args::bind_table_args;
 use crate::binder::Binder;
 use crate::binder::ColumnBinding;
 use crate::binder::CteInfo;
+use crate::binder::MaterializedCteInfo;
 use crate::binder::Visibility;
+use crate::optimizer::RelExpr;
 use crate::optimizer::SExpr;
 use crate::planner::semantic::normalize_identifier;
 use crate::planner::semantic::TypeChecker;
+use crate::plans::CteScan;
 use crate::plans::Scan;
 use crate::plans::Statistics;
 use crate::BaseTableColumn;
@@ -521,18 +525,22 @@ impl Binder {
         alias: &Option<TableAlias>,
         cte_info: &CteInfo,
     ) -> Result<(SExpr, BindContext)> {
-        let new_bind_context = BindContext {
-            parent: Some(Box::new(bind_context.clone())),
-            bound_internal_columns: BTreeMap::new(),
-            columns: vec![],
-            aggregate_info: Default::default(),
-            windows: vec![],
-            in_grouping: false,
-            ctes_map: Box::new(DashMap::new()),
-            is_view: false,
+        let (s_expr, mut new_bind_context) = match &cte_info.materialized {
+            Some(materialized_info) => self.bind_cte_scan(bind_context, materialized_info),
+            None => {
+                let new_bind_context = BindContext {
+                    parent: Some(Box::new(bind_context.clone())),
+                    bound_internal_columns: BTreeMap::new(),
+                    columns: vec![],
+                    aggregate_info: Default::default(),
+                    windows: vec![],
+                    in_grouping: false,
+                    ctes_map: Box::new(DashMap::new()),
+                    is_view: false,
+                };
+                self.bind_query(&new_bind_context, &cte_info.query).await?
+            }
         };
-        let (s_expr, mut new_bind_context) =
-            self.bind_query(&new_bind_context, &cte_info.query).await?;
         let mut cols_alias = cte_info.columns_alias.clone();
         if let Some(alias) = alias {
             for (idx, col_alias) in alias.columns.iter().enumerate() {
@@ -566,6 +574,53 @@ impl Binder {
         Ok((s_expr, new_bind_context))
     }
 
+    /// Bind the query of a materialized CTE, the result is computed once and shared
+    /// by all the references to the CTE.
+    pub(crate) async fn bind_materialized_cte(
+        &mut self,
+        bind_context: &BindContext,
+        query: &Query,
+    ) -> Result<(SExpr, MaterializedCteInfo)> {
+        let new_bind_context = BindContext::with_parent(Box::new(bind_context.clone()));
+        let (s_expr, new_bind_context) = self.bind_query(&new_bind_context, query).await?;
+        let cardinality = RelExpr::with_s_expr(&s_expr)
+            .derive_relational_prop()?
+            .cardinality as u64;
+        let materialized_info = MaterializedCteInfo {
+            cte_idx: self.metadata.write().add_materialized_cte(),
+            columns: new_bind_context.columns,
+            cardinality,
+        };
+        Ok((s_expr, materialized_info))
+    }
+
+    /// Bind a reference to a materialized CTE, each reference has its own columns.
+    fn bind_cte_scan(
+        &mut self,
+        bind_context: &BindContext,
+        materialized_info: &MaterializedCteInfo,
+    ) -> (SExpr, BindContext) {
+        let mut new_bind_context = BindContext::with_parent(Box::new(bind_context.clone()));
+        let mut columns = Vec::with_capacity(materialized_info.columns.len());
+        for column in materialized_info.columns.iter() {
+            let index = self
+                .metadata
+                .write()
+                .add_derived_column(column.column_name.clone(), *column.data_type.clone());
+            new_bind_context.columns.push(ColumnBinding {
+                index,
+                ..column.clone()
+            });
+            columns.push(index);
+        }
+        let cte_scan = CteScan {
+            cte_idx: materialized_info.cte_idx,
+            columns,
+            cardinality: materialized_info.cardinality,
+        };
+        (SExpr::create_leaf(cte_scan.into()), new_bind_context)
+    }
+
     async fn bind_base_table(
         &mut self,
         bind_context: &BindContext,
@@ -645,6 +700,7 @@ impl Binder {
                         is_accurate,
                     },
                     prewhere: None,
+                    page_limit: None,
                 }
                 .into(),
             ),
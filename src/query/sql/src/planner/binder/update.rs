@@ -13,19 +13,31 @@
 // limitations under the License.
 
 use std::collections::HashMap;
+use std::sync::Arc;
 
+use common_ast::ast::Expr;
+use common_ast::ast::Identifier;
+use common_ast::ast::Query;
+use common_ast::ast::SelectStmt;
+use common_ast::ast::SelectTarget;
+use common_ast::ast::SetExpr;
 use common_ast::ast::TableReference;
 use common_ast::ast::UpdateStmt;
+use common_catalog::plan::BLOCK_NAME;
+use common_catalog::plan::ROW_ID;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use parking_lot::RwLock;
 
 use crate::binder::Binder;
 use crate::binder::ScalarBinder;
 use crate::normalize_identifier;
+use crate::plans::BoundColumnRef;
 use crate::plans::Plan;
 use crate::plans::ScalarExpr;
 use crate::plans::UpdatePlan;
 use crate::BindContext;
+use crate::Metadata;
 
 impl Binder {
     pub(in crate::planner::binder) async fn bind_update(
@@ -36,6 +48,7 @@ impl Binder {
         let UpdateStmt {
             table,
             update_list,
+            from,
             selection,
         } = stmt;
 
@@ -62,6 +75,12 @@ impl Binder {
             ));
         };
 
+        if !from.is_empty() {
+            return self
+                .bind_update_from(bind_context, stmt, catalog_name, database_name, table_name)
+                .await;
+        }
+
         let (_, context) = self.bind_table_reference(bind_context, table).await?;
 
         let table = self
@@ -117,7 +136,151 @@ impl Binder {
             update_list: update_columns,
             selection: push_downs,
             bind_context: Box::new(context.clone()),
+            from: None,
+        };
+        Ok(Plan::Update(Box::new(plan)))
+    }
+
+    /// Binds `UPDATE t SET c = expr FROM s WHERE pred`.
+    ///
+    /// The matched rows of `t` and their new values are selected by joining `t` and `s`:
+    ///
+    /// ```sql
+    /// SELECT t._block_name, t._row_id, expr FROM t, s WHERE pred
+    /// ```
+    ///
+    /// As in PostgreSQL, if a row of `t` matches several rows of `s`, it's updated with one of them.
+    async fn bind_update_from(
+        &mut self,
+        bind_context: &BindContext,
+        stmt: &UpdateStmt,
+        catalog_name: String,
+        database_name: String,
+        table_name: String,
+    ) -> Result<Plan> {
+        let UpdateStmt {
+            table,
+            update_list,
+            from,
+            selection,
+        } = stmt;
+
+        let schema = self
+            .ctx
+            .get_table(&catalog_name, &database_name, &table_name)
+            .await?
+            .schema();
+
+        // The internal columns of `t`, qualified as `t` is written in the statement.
+        let (database, table_identifier) = match table {
+            TableReference::Table {
+                database, table, ..
+            } => (
+                database
+                    .clone()
+                    .unwrap_or_else(|| quoted_identifier(&database_name)),
+                table.clone(),
+            ),
+            _ => {
+                return Err(ErrorCode::Internal(
+                    "should not happen, parser should have report error already",
+                ));
+            }
+        };
+        let internal_column = |name: &str| SelectTarget::AliasedExpr {
+            expr: Box::new(Expr::ColumnRef {
+                span: None,
+                database: Some(database.clone()),
+                table: Some(table_identifier.clone()),
+                column: quoted_identifier(name),
+            }),
+            alias: None,
+        };
+        let mut select_list = vec![internal_column(BLOCK_NAME), internal_column(ROW_ID)];
+        let mut update_columns = HashMap::with_capacity(update_list.len());
+        for update_expr in update_list {
+            let col_name = normalize_identifier(&update_expr.name, &self.name_resolution_ctx).name;
+            let index = schema.index_of(&col_name)?;
+            if update_columns.contains_key(&index) {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Multiple assignments in the single statement to column `{}`",
+                    col_name
+                )));
+            }
+
+            // The new values follow the internal columns.
+            let offset = select_list.len();
+            update_columns.insert(index, offset);
+            select_list.push(SelectTarget::AliasedExpr {
+                expr: Box::new(update_expr.expr.clone()),
+                alias: Some(quoted_identifier(&format!("_update_{}", offset - 2))),
+            });
+        }
+
+        let query = Query {
+            span: None,
+            with: None,
+            body: SetExpr::Select(Box::new(SelectStmt {
+                span: None,
+                distinct: false,
+                select_list,
+                from: std::iter::once(table.clone())
+                    .chain(from.iter().cloned())
+                    .collect(),
+                selection: selection.clone(),
+                group_by: None,
+                having: None,
+            })),
+            order_by: vec![],
+            limit: vec![],
+            offset: None,
+            ignore_result: false,
+        };
+        let metadata = Arc::new(RwLock::new(Metadata::default()));
+        let mut binder = Binder::new(
+            self.ctx.clone(),
+            self.catalogs.clone(),
+            self.name_resolution_ctx.clone(),
+            metadata.clone(),
+        );
+        let (s_expr, from_context) = binder.bind_query(bind_context, &query).await?;
+        let update_list = update_columns
+            .into_iter()
+            .map(|(index, offset)| {
+                let column = from_context.columns[offset].clone();
+                (
+                    index,
+                    ScalarExpr::BoundColumnRef(BoundColumnRef { span: None, column }),
+                )
+            })
+            .collect();
+        let from = Plan::Query {
+            s_expr: Box::new(s_expr),
+            metadata,
+            bind_context: Box::new(from_context),
+            rewrite_kind: None,
+            formatted_ast: None,
+            ignore_result: false,
+        };
+
+        let (_, context) = self.bind_table_reference(bind_context, table).await?;
+        let plan = UpdatePlan {
+            catalog: catalog_name,
+            database: database_name,
+            table: table_name,
+            update_list,
+            selection: None,
+            bind_context: Box::new(context),
+            from: Some(Box::new(from)),
         };
         Ok(Plan::Update(Box::new(plan)))
     }
 }
+
+fn quoted_identifier(name: &str) -> Identifier {
+    Identifier {
+        name: name.to_string(),
+        quote: Some('"'),
+        span: None,
+    }
+}
@@ -17,6 +17,7 @@ use std::fmt::Display;
 use common_ast::ast::FormatTreeNode;
 use itertools::Itertools;
 
+use crate::column_name_of;
 use crate::optimizer::SExpr;
 use crate::plans::Aggregate;
 use crate::plans::AggregateMode;
@@ -33,11 +34,11 @@ use crate::plans::RelOperator;
 use crate::plans::ScalarExpr;
 use crate::plans::Scan;
 use crate::plans::Sort;
-use crate::BaseTableColumn;
-use crate::ColumnEntry;
-use crate::DerivedColumn;
+use crate::plans::Window;
+use crate::plans::WindowFuncFrame;
+use crate::plans::WindowFuncFrameBound;
+use crate::plans::WindowFuncFrameUnits;
 use crate::MetadataRef;
-use crate::TableInternalColumn;
 
 #[derive(Clone)]
 pub enum FormatContext {
@@ -80,6 +81,8 @@ impl Display for FormatContext {
                 RelOperator::DummyTableScan(_) => write!(f, "DummyTableScan"),
                 RelOperator::RuntimeFilterSource(_) => write!(f, "RuntimeFilterSource"),
                 RelOperator::Window(_) => write!(f, "WindowFunc"),
+                RelOperator::MaterializedCte(op) => write!(f, "MaterializedCte: {}", op.cte_idx),
+                RelOperator::CteScan(op) => write!(f, "CteScan: {}", op.cte_idx),
             },
             Self::Text(text) => write!(f, "{}", text),
         }
@@ -87,6 +90,17 @@ impl Display for FormatContext {
 }
 
 pub fn format_scalar(_metadata: &MetadataRef, scalar: &ScalarExpr) -> String {
+    format_scalar_with_options(_metadata, scalar, false)
+}
+
+/// Same as `format_scalar`, but with `simplify_casts` the redundant casts, which cast an
+/// expression to its own data type (e.g. the outer cast of `CAST(CAST(x AS A) AS A)`), are
+/// omitted.
+pub fn format_scalar_with_options(
+    _metadata: &MetadataRef,
+    scalar: &ScalarExpr,
+    simplify_casts: bool,
+) -> String {
     match scalar {
         ScalarExpr::BoundColumnRef(column_ref) => {
             if let Some(table_name) = &column_ref.column.table_name {
@@ -120,20 +134,23 @@ pub fn format_scalar(_metadata: &MetadataRef, scalar: &ScalarExpr) -> String {
         ScalarExpr::ConstantExpr(constant) => constant.value.to_string(),
         ScalarExpr::AndExpr(and) => format!(
             "({}) AND ({})",
-            format_scalar(_metadata, &and.left),
-            format_scalar(_metadata, &and.right)
+            format_scalar_with_options(_metadata, &and.left, simplify_casts),
+            format_scalar_with_options(_metadata, &and.right, simplify_casts)
         ),
         ScalarExpr::OrExpr(or) => format!(
             "({}) OR ({})",
-            format_scalar(_metadata, &or.left),
-            format_scalar(_metadata, &or.right)
+            format_scalar_with_options(_metadata, &or.left, simplify_casts),
+            format_scalar_with_options(_metadata, &or.right, simplify_casts)
+        ),
+        ScalarExpr::NotExpr(not) => format!(
+            "NOT ({})",
+            format_scalar_with_options(_metadata, &not.argument, simplify_casts),
         ),
-        ScalarExpr::NotExpr(not) => format!("NOT ({})", format_scalar(_metadata, &not.argument),),
         ScalarExpr::ComparisonExpr(comp) => format!(
             "{} {} {}",
-            format_scalar(_metadata, &comp.left),
-            comp.op.to_func_name(),
-            format_scalar(_metadata, &comp.right)
+            format_scalar_with_options(_metadata, &comp.left, simplify_casts),
+            comp.op.to_sql_op(),
+            format_scalar_with_options(_metadata, &comp.right, simplify_casts)
         ),
         ScalarExpr::WindowFunction(win) => win.agg_func.display_name.clone(),
         ScalarExpr::AggregateFunction(agg) => agg.display_name.clone(),
@@ -143,20 +160,29 @@ pub fn format_scalar(_metadata: &MetadataRef, scalar: &ScalarExpr) -> String {
                 &func.func_name,
                 func.arguments
                     .iter()
-                    .map(|arg| { format_scalar(_metadata, arg) })
+                    .map(|arg| { format_scalar_with_options(_metadata, arg, simplify_casts) })
                     .collect::<Vec<String>>()
                     .join(", ")
             )
         }
+        ScalarExpr::CastExpr(cast)
+            if simplify_casts
+                && matches!(cast.argument.data_type(), Ok(ty) if ty == *cast.target_type) =>
+        {
+            format_scalar_with_options(_metadata, &cast.argument, simplify_casts)
+        }
         ScalarExpr::CastExpr(cast) => {
             format!(
                 "CAST({} AS {})",
-                format_scalar(_metadata, &cast.argument),
+                format_scalar_with_options(_metadata, &cast.argument, simplify_casts),
                 cast.target_type
             )
         }
         ScalarExpr::Unnest(unnest) => {
-            format!("UNNEST({})", format_scalar(_metadata, &unnest.argument),)
+            format!(
+                "UNNEST({})",
+                format_scalar_with_options(_metadata, &unnest.argument, simplify_casts),
+            )
         }
         ScalarExpr::SubqueryExpr(_) => "SUBQUERY".to_string(),
     }
@@ -226,6 +252,7 @@ fn to_format_tree(
         RelOperator::Sort(op) => sort_to_format_tree(op, metadata, children),
         RelOperator::Limit(op) => limit_to_format_tree(op, metadata, children),
         RelOperator::Exchange(op) => exchange_to_format_tree(op, metadata, children),
+        RelOperator::Window(op) => window_to_format_tree(op, metadata, children),
 
         _ => FormatTreeNode::with_children(
             FormatContext::RelOp {
@@ -277,18 +304,7 @@ fn scan_to_format_tree(
                             .iter()
                             .map(|item| format!(
                                 "{} (#{}) {}",
-                                match metadata.read().column(item.index) {
-                                    ColumnEntry::BaseTableColumn(BaseTableColumn {
-                                        column_name,
-                                        ..
-                                    }) => column_name,
-                                    ColumnEntry::DerivedColumn(DerivedColumn { alias, .. }) =>
-                                        alias,
-                                    ColumnEntry::InternalColumn(TableInternalColumn {
-                                        internal_column,
-                                        ..
-                                    }) => internal_column.column_name(),
-                                },
+                                column_name_of(metadata.read().column(item.index)),
                                 item.index,
                                 if item.asc { "ASC" } else { "DESC" }
                             ))
@@ -346,18 +362,7 @@ fn logical_get_to_format_tree(
                             .iter()
                             .map(|item| format!(
                                 "{} (#{}) {}",
-                                match metadata.read().column(item.index) {
-                                    ColumnEntry::BaseTableColumn(BaseTableColumn {
-                                        column_name,
-                                        ..
-                                    }) => column_name,
-                                    ColumnEntry::DerivedColumn(DerivedColumn { alias, .. }) =>
-                                        alias,
-                                    ColumnEntry::InternalColumn(TableInternalColumn {
-                                        internal_column,
-                                        ..
-                                    }) => internal_column.column_name(),
-                                },
+                                column_name_of(metadata.read().column(item.index)),
                                 item.index,
                                 if item.asc { "ASC" } else { "DESC" }
                             ))
@@ -583,13 +588,7 @@ fn sort_to_format_tree(
         .iter()
         .map(|item| {
             let metadata = metadata.read();
-            let name = match metadata.column(item.index) {
-                ColumnEntry::BaseTableColumn(BaseTableColumn { column_name, .. }) => column_name,
-                ColumnEntry::DerivedColumn(DerivedColumn { alias, .. }) => alias,
-                ColumnEntry::InternalColumn(TableInternalColumn {
-                    internal_column, ..
-                }) => internal_column.column_name(),
-            };
+            let name = column_name_of(metadata.column(item.index));
             format!(
                 "{} (#{}) {}",
                 name,
@@ -671,3 +670,86 @@ fn exchange_to_format_tree(
         ),
     }
 }
+
+/// The order by items of a window are not part of the operator, they are displayed by the
+/// `Sort` below it.
+fn window_to_format_tree(
+    op: &Window,
+    metadata: MetadataRef,
+    children: Vec<FormatTreeNode<FormatContext>>,
+) -> FormatTreeNode<FormatContext> {
+    let partition_by = op
+        .partition_by
+        .iter()
+        .map(|item| format_scalar(&metadata, &item.scalar))
+        .collect::<Vec<String>>()
+        .join(", ");
+    let order_by = op
+        .order_by
+        .iter()
+        .map(|item| {
+            format!(
+                "{} {}",
+                format_scalar(&metadata, &item.order_by_item.scalar),
+                if item.asc.unwrap_or(true) {
+                    "ASC"
+                } else {
+                    "DESC"
+                }
+            )
+        })
+        .collect::<Vec<String>>()
+        .join(", ");
+    let agg_func = format_scalar(&metadata, &op.aggregate_function.scalar);
+    let frame = format_window_frame(&metadata, &op.frame);
+
+    FormatTreeNode::with_children(
+        FormatContext::RelOp {
+            metadata,
+            rel_operator: Box::new(op.clone().into()),
+        },
+        vec![
+            vec![
+                FormatTreeNode::new(FormatContext::Text(format!(
+                    "aggregate function: [{}]",
+                    agg_func
+                ))),
+                FormatTreeNode::new(FormatContext::Text(format!(
+                    "partition by: [{}]",
+                    partition_by
+                ))),
+                FormatTreeNode::new(FormatContext::Text(format!("order by: [{}]", order_by))),
+                FormatTreeNode::new(FormatContext::Text(format!("frame: [{}]", frame))),
+            ],
+            children,
+        ]
+        .concat(),
+    )
+}
+
+fn format_window_frame(metadata: &MetadataRef, frame: &WindowFuncFrame) -> String {
+    let units = match frame.units {
+        WindowFuncFrameUnits::Rows => "ROWS",
+        WindowFuncFrameUnits::Range => "RANGE",
+    };
+    format!(
+        "{} BETWEEN {} AND {}",
+        units,
+        format_window_frame_bound(metadata, &frame.start),
+        format_window_frame_bound(metadata, &frame.end)
+    )
+}
+
+fn format_window_frame_bound(metadata: &MetadataRef, bound: &WindowFuncFrameBound) -> String {
+    match bound {
+        WindowFuncFrameBound::CurrentRow => "CURRENT ROW".to_string(),
+        WindowFuncFrameBound::Preceding(None) => "UNBOUNDED PRECEDING".to_string(),
+        WindowFuncFrameBound::Preceding(Some(offset)) => {
+            format!("{} PRECEDING", format_scalar(metadata, offset))
+        }
+        WindowFuncFrameBound::Following(None) => "UNBOUNDED FOLLOWING".to_string(),
+        WindowFuncFrameBound::Following(Some(offset)) => {
+            format!("{} FOLLOWING", format_scalar(metadata, offset))
+        }
+    }
+}
@@ -14,3 +14,6 @@
 
 mod display_plan;
 mod display_rel_operator;
+
+pub use display_rel_operator::format_scalar;
+pub use display_rel_operator::format_scalar_with_options;
@@ -51,6 +51,11 @@ pub type MetadataRef = Arc<RwLock<Metadata>>;
 pub struct Metadata {
     tables: Vec<TableEntry>,
     columns: Vec<ColumnEntry>,
+    /// Derived columns created by decorrelating subqueries, for debugging the bloat of
+    /// decorrelated plans.
+    decorrelated_columns: Vec<(IndexType, DecorrelatedColumnOrigin)>,
+    /// Number of materialized CTEs, used to allocate the index of a materialized CTE.
+    materialized_ctes: usize,
 }
 
 impl Metadata {
@@ -145,6 +150,29 @@ impl Metadata {
         column_index
     }
 
+    /// Add a derived column created by decorrelating a subquery, it's tracked by
+    /// [`Metadata::decorrelated_columns`].
+    pub fn add_decorrelated_column(
+        &mut self,
+        alias: String,
+        data_type: DataType,
+        origin: DecorrelatedColumnOrigin,
+    ) -> IndexType {
+        let column_index = self.add_derived_column(alias, data_type);
+        self.decorrelated_columns.push((column_index, origin));
+        column_index
+    }
+
+    pub fn add_materialized_cte(&mut self) -> IndexType {
+        let cte_idx = self.materialized_ctes;
+        self.materialized_ctes += 1;
+        cte_idx
+    }
+
+    pub fn decorrelated_columns(&self) -> &[(IndexType, DecorrelatedColumnOrigin)] {
+        &self.decorrelated_columns
+    }
+
     pub fn add_internal_column(
         &mut self,
         table_index: IndexType,
@@ -345,6 +373,17 @@ pub struct TableInternalColumn {
     pub internal_column: InternalColumn,
 }
 
+/// Where a derived column created by decorrelating a subquery comes from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DecorrelatedColumnOrigin {
+    /// The column flattening the correlated column of the given index into the subquery.
+    CorrelatedColumn(IndexType),
+    /// The marker of a mark join.
+    Marker,
+    /// The row number in each group of the correlated columns, to limit a subquery to one row.
+    RowNumber,
+}
+
 #[derive(Clone, Debug)]
 pub enum ColumnEntry {
     /// Column from base table, for example `SELECT t.a, t.b FROM t`.
@@ -367,6 +406,18 @@ impl ColumnEntry {
     }
 }
 
+/// Get the user-facing name of a column entry, i.e. the column name of a base table column
+/// or the alias of a derived column.
+pub fn column_name_of(entry: &ColumnEntry) -> &str {
+    match entry {
+        ColumnEntry::BaseTableColumn(BaseTableColumn { column_name, .. }) => column_name,
+        ColumnEntry::DerivedColumn(DerivedColumn { alias, .. }) => alias,
+        ColumnEntry::InternalColumn(TableInternalColumn {
+            internal_column, ..
+        }) => internal_column.column_name(),
+    }
+}
+
 pub fn optimize_remove_count_args(name: &str, distinct: bool, args: &[&Expr]) -> bool {
     name.eq_ignore_ascii_case("count")
         && !distinct
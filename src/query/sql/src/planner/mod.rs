@@ -32,6 +32,8 @@ pub use binder::ScalarBinder;
 pub use binder::SelectBuilder;
 pub use binder::Visibility;
 pub use expression_parser::*;
+pub use format::format_scalar;
+pub use format::format_scalar_with_options;
 pub use metadata::*;
 pub use planner::PlanExtras;
 pub use planner::Planner;
@@ -42,6 +42,7 @@ pub struct CascadesOptimizer {
     /// group index -> best cost context
     pub(crate) best_cost_map: HashMap<IndexType, CostContext>,
     pub(crate) explore_rule_set: RuleSet,
+    pub(crate) ctx: Arc<dyn TableContext>,
     pub(crate) metadata: MetadataRef,
 }
 
@@ -58,6 +59,7 @@ impl CascadesOptimizer {
             cost_model: Box::new(DefaultCostModel),
             best_cost_map: HashMap::new(),
             explore_rule_set,
+            ctx,
             metadata,
         })
     }
@@ -40,13 +40,18 @@ fn compute_cost_impl(memo: &Memo, m_expr: &MExpr) -> Result<Cost> {
     match &m_expr.plan {
         RelOperator::Scan(plan) => compute_cost_scan(memo, m_expr, plan),
         RelOperator::DummyTableScan(_) => Ok(Cost(0.0)),
+        RelOperator::CteScan(plan) => {
+            Ok(Cost(plan.cardinality as f64 * COST_FACTOR_COMPUTE_PER_ROW))
+        }
         RelOperator::Join(plan) => compute_cost_join(memo, m_expr, plan),
         RelOperator::UnionAll(_) => compute_cost_union_all(memo, m_expr),
+        RelOperator::MaterializedCte(_) => compute_cost_materialized_cte(memo, m_expr),
 
         RelOperator::EvalScalar(_)
         | RelOperator::Filter(_)
         | RelOperator::Aggregate(_)
         | RelOperator::Sort(_)
+        | RelOperator::Window(_)
         | RelOperator::Limit(_) => compute_cost_unary_common_operator(memo, m_expr),
 
         _ => Err(ErrorCode::Internal("Cannot compute cost from logical plan")),
@@ -90,6 +95,13 @@ fn compute_cost_unary_common_operator(memo: &Memo, m_expr: &MExpr) -> Result<Cos
     Ok(Cost(cost))
 }
 
+fn compute_cost_materialized_cte(memo: &Memo, m_expr: &MExpr) -> Result<Cost> {
+    // Only the cost of materializing the CTE, reading it is the cost of `CteScan`s.
+    let left_group = m_expr.child_group(memo, 0)?;
+    let cost = left_group.relational_prop.cardinality * COST_FACTOR_COMPUTE_PER_ROW;
+    Ok(Cost(cost))
+}
+
 fn compute_cost_union_all(memo: &Memo, m_expr: &MExpr) -> Result<Cost> {
     let left_group = m_expr.child_group(memo, 0)?;
     let right_group = m_expr.child_group(memo, 0)?;
@@ -21,8 +21,10 @@ use super::cost::CostContext;
 use crate::optimizer::group::Group;
 use crate::optimizer::MExpr;
 use crate::optimizer::Memo;
+use crate::optimizer::SExpr;
 use crate::plans::RelOperator;
 use crate::IndexType;
+use crate::MetadataRef;
 
 pub fn display_memo(memo: &Memo, cost_map: &HashMap<IndexType, CostContext>) -> Result<String> {
     Ok(memo
@@ -33,6 +35,22 @@ pub fn display_memo(memo: &Memo, cost_map: &HashMap<IndexType, CostContext>) ->
         .join("\n"))
 }
 
+pub fn display_optimize_traces(
+    traces: &[(String, SExpr)],
+    metadata: &MetadataRef,
+) -> Result<String> {
+    Ok(traces
+        .iter()
+        .map(|(step, s_expr)| {
+            Ok(format!(
+                "{step}:\n{}",
+                s_expr.to_format_tree(metadata).format_pretty()?
+            ))
+        })
+        .collect::<Result<Vec<_>>>()?
+        .concat())
+}
+
 pub fn display_rel_op(rel_op: &RelOperator) -> String {
     match rel_op {
         RelOperator::Scan(_) => "Scan".to_string(),
@@ -48,6 +66,8 @@ pub fn display_rel_op(rel_op: &RelOperator) -> String {
         RelOperator::DummyTableScan(_) => "DummyTableScan".to_string(),
         RelOperator::RuntimeFilterSource(_) => "RuntimeFilterSource".to_string(),
         RelOperator::Window(_) => "WindowFunc".to_string(),
+        RelOperator::MaterializedCte(_) => "MaterializedCte".to_string(),
+        RelOperator::CteScan(_) => "CteScan".to_string(),
     }
 }
 
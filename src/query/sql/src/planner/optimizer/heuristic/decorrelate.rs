@@ -14,15 +14,18 @@
 
 use std::collections::HashMap;
 use std::collections::HashSet;
+use std::sync::Arc;
 
+use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_exception::Span;
-use common_expression::type_check::common_super_type;
 use common_expression::types::DataType;
-use common_functions::scalars::BUILTIN_FUNCTIONS;
+use common_expression::types::NumberDataType;
+use common_expression::Literal;
 
-use crate::binder::JoinPredicate;
+use crate::binder::split_join_predicates;
+use crate::binder::SplitJoinPredicates;
 use crate::binder::Visibility;
 use crate::optimizer::heuristic::subquery_rewriter::FlattenInfo;
 use crate::optimizer::heuristic::subquery_rewriter::SubqueryRewriter;
@@ -39,6 +42,7 @@ use crate::plans::BoundColumnRef;
 use crate::plans::CastExpr;
 use crate::plans::ComparisonExpr;
 use crate::plans::ComparisonOp;
+use crate::plans::ConstantExpr;
 use crate::plans::EvalScalar;
 use crate::plans::Filter;
 use crate::plans::FunctionCall;
@@ -52,12 +56,19 @@ use crate::plans::RelOperator;
 use crate::plans::ScalarExpr;
 use crate::plans::ScalarItem;
 use crate::plans::Scan;
+use crate::plans::SortItem;
 use crate::plans::Statistics;
 use crate::plans::SubqueryExpr;
 use crate::plans::SubqueryType;
+use crate::plans::Window;
+use crate::plans::WindowFuncFrame;
+use crate::plans::WindowFuncFrameBound;
+use crate::plans::WindowFuncFrameUnits;
+use crate::plans::WindowOrderByInfo;
 use crate::BaseTableColumn;
 use crate::ColumnBinding;
 use crate::ColumnEntry;
+use crate::DecorrelatedColumnOrigin;
 use crate::DerivedColumn;
 use crate::IndexType;
 use crate::MetadataRef;
@@ -74,8 +85,12 @@ use crate::TableInternalColumn;
 /// Correlated exists subquery -> Marker join
 ///
 /// More information can be found in the paper: Unnesting Arbitrary Queries
-pub fn decorrelate_subquery(metadata: MetadataRef, s_expr: SExpr) -> Result<SExpr> {
-    let mut rewriter = SubqueryRewriter::new(metadata);
+pub fn decorrelate_subquery(
+    ctx: Arc<dyn TableContext>,
+    metadata: MetadataRef,
+    s_expr: SExpr,
+) -> Result<SExpr> {
+    let mut rewriter = SubqueryRewriter::new(ctx, metadata);
     rewriter.rewrite(&s_expr)
 }
 
@@ -83,11 +98,15 @@ impl SubqueryRewriter {
     // Try to decorrelate a `CrossApply` into `SemiJoin` or `AntiJoin`.
     // We only do simple decorrelation here, the scheme is:
     // 1. If the subquery is correlated, we will try to decorrelate it into `SemiJoin`
+    // 2. If the subquery isn't a conjunctive predicate, e.g. `EXISTS(...) OR a > 5`, the rows
+    //    of `input` can't be filtered by it, so we decorrelate it into a `RightMark` join whose
+    //    marker feeds the predicate instead.
     pub fn try_decorrelate_simple_subquery(
         &self,
         input: &SExpr,
         subquery: &SubqueryExpr,
-    ) -> Result<Option<SExpr>> {
+        is_conjunctive_predicate: bool,
+    ) -> Result<Option<(SExpr, UnnestResult)>> {
         if subquery.outer_columns.is_empty() {
             return Ok(None);
         }
@@ -146,63 +165,61 @@ impl SubqueryRewriter {
 
         // Second, we will check if the filter only contains equi-predicates.
         // This is not necessary, but it is a good heuristic for most cases.
-        let mut left_conditions = vec![];
-        let mut right_conditions = vec![];
-        let mut non_equi_conditions = vec![];
-        let mut left_filters = vec![];
-        let mut right_filters = vec![];
-        for pred in filter.predicates.iter() {
-            let join_condition = JoinPredicate::new(pred, &input_prop, &filter_prop);
-            match join_condition {
-                JoinPredicate::Left(filter) => {
-                    left_filters.push(filter.clone());
-                }
-                JoinPredicate::Right(filter) => {
-                    right_filters.push(filter.clone());
-                }
-
-                JoinPredicate::Other(pred) => {
-                    non_equi_conditions.push(pred.clone());
-                }
+        let SplitJoinPredicates {
+            mut left_filters,
+            right_filters,
+            left_conditions,
+            right_conditions,
+            other_conditions: mut non_equi_conditions,
+        } = split_join_predicates(&filter.predicates, &input_prop, &filter_prop)?;
 
-                JoinPredicate::Both { left, right } => {
-                    if left.data_type()?.eq(&right.data_type()?) {
-                        left_conditions.push(left.clone());
-                        right_conditions.push(right.clone());
-                        continue;
-                    }
-                    let join_type = common_super_type(
-                        left.data_type()?,
-                        right.data_type()?,
-                        &BUILTIN_FUNCTIONS.default_cast_rules,
-                    )
-                    .ok_or_else(|| ErrorCode::Internal("Cannot find common type"))?;
-                    let left = wrap_cast(left, &join_type);
-                    let right = wrap_cast(right, &join_type);
-                    left_conditions.push(left);
-                    right_conditions.push(right);
-                }
-            }
+        if matches!(
+            subquery.typ,
+            SubqueryType::Any | SubqueryType::All | SubqueryType::Scalar
+        ) {
+            return Ok(None);
         }
 
-        let join = Join {
-            left_conditions,
-            right_conditions,
-            non_equi_conditions,
-            join_type: match &subquery.typ {
-                SubqueryType::Any | SubqueryType::All | SubqueryType::Scalar => {
-                    return Ok(None);
-                }
-                SubqueryType::Exists => JoinType::LeftSemi,
-                SubqueryType::NotExists => JoinType::LeftAnti,
-            },
-            marker_index: None,
-            from_correlated_subquery: true,
-            contain_runtime_filter: false,
+        let mut left_child = input.clone();
+        let (join, result) = if is_conjunctive_predicate {
+            // Rewrite plan to semi-join.
+            let join = Join {
+                left_conditions,
+                right_conditions,
+                non_equi_conditions,
+                is_null_equal: vec![],
+                join_type: match &subquery.typ {
+                    SubqueryType::Exists => JoinType::LeftSemi,
+                    _ => JoinType::LeftAnti,
+                },
+                marker_index: None,
+                from_correlated_subquery: true,
+                contain_runtime_filter: false,
+            };
+            (join, UnnestResult::SimpleJoin)
+        } else {
+            // Rewrite plan to mark join, every row of `input` is kept, so the predicates
+            // only referencing `input` become join conditions instead of filtering it.
+            // The marker is negated for `NOT EXISTS` by the caller.
+            // A mark join without equi-conditions isn't supported, leave it to flattening.
+            if left_conditions.is_empty() {
+                return Ok(None);
+            }
+            non_equi_conditions.append(&mut left_filters);
+            let marker_index = self.marker_index(subquery);
+            let join = Join {
+                left_conditions,
+                right_conditions,
+                non_equi_conditions,
+                is_null_equal: vec![],
+                join_type: JoinType::RightMark,
+                marker_index: Some(marker_index),
+                from_correlated_subquery: true,
+                contain_runtime_filter: false,
+            };
+            (join, UnnestResult::MarkJoin { marker_index })
         };
 
-        // Rewrite plan to semi-join.
-        let mut left_child = input.clone();
         if !left_filters.is_empty() {
             left_child = SExpr::create_unary(
                 Filter {
@@ -233,9 +250,9 @@ impl SubqueryRewriter {
             );
         }
 
-        let result = SExpr::create_binary(join.into(), left_child, right_child);
+        let s_expr = SExpr::create_binary(join.into(), left_child, right_child);
 
-        Ok(Some(result))
+        Ok(Some((s_expr, result)))
     }
 
     pub fn try_decorrelate_subquery(
@@ -263,6 +280,7 @@ impl SubqueryRewriter {
                     left_conditions,
                     right_conditions,
                     non_equi_conditions: vec![],
+                    is_null_equal: vec![],
                     join_type: JoinType::Single,
                     marker_index: None,
                     from_correlated_subquery: true,
@@ -272,10 +290,10 @@ impl SubqueryRewriter {
                 Ok((s_expr, UnnestResult::SingleJoin))
             }
             SubqueryType::Exists | SubqueryType::NotExists => {
-                if is_conjunctive_predicate {
-                    if let Some(result) = self.try_decorrelate_simple_subquery(left, subquery)? {
-                        return Ok((result, UnnestResult::SimpleJoin));
-                    }
+                if let Some(result) =
+                    self.try_decorrelate_simple_subquery(left, subquery, is_conjunctive_predicate)?
+                {
+                    return Ok(result);
                 }
                 let correlated_columns = subquery.outer_columns.clone();
                 let flatten_plan =
@@ -290,18 +308,12 @@ impl SubqueryRewriter {
                     &mut right_conditions,
                 )?;
 
-                let marker_index = if let Some(idx) = subquery.projection_index {
-                    idx
-                } else {
-                    self.metadata.write().add_derived_column(
-                        "marker".to_string(),
-                        DataType::Nullable(Box::new(DataType::Boolean)),
-                    )
-                };
+                let marker_index = self.marker_index(subquery);
                 let join_plan = Join {
                     left_conditions: right_conditions,
                     right_conditions: left_conditions,
                     non_equi_conditions: vec![],
+                    is_null_equal: vec![],
                     join_type: JoinType::RightMark,
                     marker_index: Some(marker_index),
                     from_correlated_subquery: true,
@@ -347,18 +359,12 @@ impl SubqueryRewriter {
                     left: Box::new(child_expr),
                     right: Box::new(right_condition),
                 })];
-                let marker_index = if let Some(idx) = subquery.projection_index {
-                    idx
-                } else {
-                    self.metadata.write().add_derived_column(
-                        "marker".to_string(),
-                        DataType::Nullable(Box::new(DataType::Boolean)),
-                    )
-                };
+                let marker_index = self.marker_index(subquery);
                 let mark_join = Join {
                     left_conditions: right_conditions,
                     right_conditions: left_conditions,
                     non_equi_conditions,
+                    is_null_equal: vec![],
                     join_type: JoinType::RightMark,
                     marker_index: Some(marker_index),
                     from_correlated_subquery: true,
@@ -374,7 +380,30 @@ impl SubqueryRewriter {
         }
     }
 
+    // Flattening recurses through the whole plan of the subquery, which can be arbitrarily
+    // deep with nested subqueries, so the depth is bounded to avoid overflowing the stack.
     fn flatten(
+        &mut self,
+        plan: &SExpr,
+        correlated_columns: &ColumnSet,
+        flatten_info: &mut FlattenInfo,
+        need_cross_join: bool,
+    ) -> Result<SExpr> {
+        let max_depth = self.ctx.get_settings().get_max_decorrelation_depth()?;
+        if self.flatten_depth >= max_depth {
+            return Err(ErrorCode::Unimplemented(format!(
+                "Correlated subquery is nested too deeply to be decorrelated, the depth exceeds max_decorrelation_depth({})",
+                max_depth
+            )));
+        }
+
+        self.flatten_depth += 1;
+        let result = self.flatten_plan(plan, correlated_columns, flatten_info, need_cross_join);
+        self.flatten_depth -= 1;
+        result
+    }
+
+    fn flatten_plan(
         &mut self,
         plan: &SExpr,
         correlated_columns: &ColumnSet,
@@ -392,9 +421,14 @@ impl SubqueryRewriter {
             let mut metadata = self.metadata.write();
             // Currently, we don't support left plan's from clause contains subquery.
             // Such as: select t2.a from (select a + 1 as a from t) as t2 where (select sum(a) from t as t1 where t1.a < t2.a) = 1;
-            let table_index = metadata
-                .table_index_by_column_indexes(correlated_columns)
-                .unwrap();
+            let table_index = match metadata.table_index_by_column_indexes(correlated_columns) {
+                Some(table_index) => table_index,
+                None => {
+                    return Err(ErrorCode::Unimplemented(
+                        "Correlated subquery referencing columns of a derived table is not supported",
+                    ));
+                }
+            };
             for correlated_column in correlated_columns.iter() {
                 let column_entry = metadata.column(*correlated_column).clone();
                 let (name, data_type) = match &column_entry {
@@ -412,7 +446,11 @@ impl SubqueryRewriter {
                 };
                 self.derived_columns.insert(
                     *correlated_column,
-                    metadata.add_derived_column(name.to_string(), data_type.wrap_nullable()),
+                    metadata.add_decorrelated_column(
+                        name.to_string(),
+                        data_type.wrap_nullable(),
+                        DecorrelatedColumnOrigin::CorrelatedColumn(*correlated_column),
+                    ),
                 );
             }
             let logical_get = SExpr::create_leaf(
@@ -428,6 +466,7 @@ impl SubqueryRewriter {
                         is_accurate: false,
                     },
                     prewhere: None,
+                    page_limit: None,
                 }
                 .into(),
             );
@@ -436,6 +475,7 @@ impl SubqueryRewriter {
                 left_conditions: vec![],
                 right_conditions: vec![],
                 non_equi_conditions: vec![],
+                is_null_equal: vec![],
                 join_type: JoinType::Cross,
                 marker_index: None,
                 from_correlated_subquery: false,
@@ -555,6 +595,7 @@ impl SubqueryRewriter {
                         left_conditions: join.left_conditions.clone(),
                         right_conditions: join.right_conditions.clone(),
                         non_equi_conditions: join.non_equi_conditions.clone(),
+                        is_null_equal: join.is_null_equal.clone(),
                         join_type: join.join_type.clone(),
                         marker_index: join.marker_index,
                         from_correlated_subquery: false,
@@ -626,9 +667,13 @@ impl SubqueryRewriter {
                     if let ScalarExpr::AggregateFunction(AggregateFunction { func_name, .. }) =
                         &scalar
                     {
-                        if func_name.eq_ignore_ascii_case("count") || func_name.eq("count_distinct")
+                        // The count over an empty set is 0, but there is no group for the
+                        // empty set after flattening, see `wrap_count_aggregates`.
+                        if aggregate.group_items.is_empty()
+                            && (func_name.eq_ignore_ascii_case("count")
+                                || func_name.eq("count_distinct"))
                         {
-                            flatten_info.from_count_func = true;
+                            flatten_info.count_aggregates.insert(item.index);
                         }
                     }
                     agg_items.push(ScalarItem {
@@ -661,15 +706,30 @@ impl SubqueryRewriter {
                 Ok(SExpr::create_unary(plan.plan().clone(), flatten_plan))
             }
 
-            RelOperator::Limit(_) => {
-                // Currently, we don't support limit contain subquery.
-                let flatten_plan = self.flatten(
-                    plan.child(0)?,
-                    correlated_columns,
-                    flatten_info,
-                    need_cross_join,
-                )?;
-                Ok(SExpr::create_unary(plan.plan().clone(), flatten_plan))
+            RelOperator::Limit(limit) => {
+                // A correlated subquery limited to one row, optionally ordered by a single
+                // column, is flattened to one row for each group of the correlated columns.
+                // Other limits can't be flattened, they apply to each group of rows.
+                if limit.limit != Some(1) || limit.offset != 0 {
+                    return Err(ErrorCode::Unimplemented(
+                        "Correlated subquery with LIMIT other than 1 or with OFFSET is not supported",
+                    ));
+                }
+                let child = plan.child(0)?;
+                let (sort_item, input) = match child.plan() {
+                    RelOperator::Sort(sort) if sort.items.len() == 1 => {
+                        (Some(sort.items[0].clone()), child.child(0)?)
+                    }
+                    RelOperator::Sort(_) => {
+                        return Err(ErrorCode::Unimplemented(
+                            "Correlated subquery with LIMIT ordered by more than one column is not supported",
+                        ));
+                    }
+                    _ => (None, child),
+                };
+                let flatten_plan =
+                    self.flatten(input, correlated_columns, flatten_info, need_cross_join)?;
+                self.limit_to_one_row(flatten_plan, sort_item)
             }
 
             RelOperator::UnionAll(op) => {
@@ -802,6 +862,105 @@ impl SubqueryRewriter {
         }
     }
 
+    // Keep one row for each group of the correlated columns: the first row ordered by the
+    // sort key, or any row if there is no sort key. The rows of each group are numbered by
+    // `count()` over a window of the preceding rows, and the row numbered 1 is kept, so the
+    // nulls are ordered as the sort item says and all the columns come from the same row.
+    fn limit_to_one_row(&self, input: SExpr, sort_item: Option<SortItem>) -> Result<SExpr> {
+        let mut metadata = self.metadata.write();
+        let column_ref = |index: IndexType, data_type: DataType| {
+            ScalarExpr::BoundColumnRef(BoundColumnRef {
+                span: None,
+                column: ColumnBinding {
+                    database_name: None,
+                    table_name: None,
+                    column_name: format!("subquery_{}", index),
+                    index,
+                    data_type: Box::new(data_type),
+                    visibility: Visibility::Visible,
+                },
+            })
+        };
+
+        let mut partition_by = Vec::with_capacity(self.derived_columns.len());
+        for derived_column in self.derived_columns.values() {
+            let data_type = column_data_type(metadata.column(*derived_column));
+            partition_by.push(ScalarItem {
+                scalar: column_ref(*derived_column, data_type),
+                index: *derived_column,
+            });
+        }
+
+        let order_by = sort_item
+            .iter()
+            .map(|item| WindowOrderByInfo {
+                order_by_item: ScalarItem {
+                    scalar: column_ref(item.index, column_data_type(metadata.column(item.index))),
+                    index: item.index,
+                },
+                asc: Some(item.asc),
+                nulls_first: Some(item.nulls_first),
+            })
+            .collect();
+
+        let row_number_type = DataType::Number(NumberDataType::UInt64);
+        let row_number = metadata.add_decorrelated_column(
+            "row_number".to_string(),
+            row_number_type.clone(),
+            DecorrelatedColumnOrigin::RowNumber,
+        );
+        let window = Window {
+            aggregate_function: ScalarItem {
+                scalar: ScalarExpr::AggregateFunction(AggregateFunction {
+                    display_name: "count()".to_string(),
+                    func_name: "count".to_string(),
+                    distinct: false,
+                    params: vec![],
+                    args: vec![],
+                    return_type: Box::new(row_number_type.clone()),
+                }),
+                index: row_number,
+            },
+            partition_by,
+            order_by,
+            frame: WindowFuncFrame {
+                units: WindowFuncFrameUnits::Rows,
+                start: WindowFuncFrameBound::Preceding(None),
+                end: WindowFuncFrameBound::CurrentRow,
+            },
+        };
+
+        let filter = Filter {
+            predicates: vec![ScalarExpr::ComparisonExpr(ComparisonExpr {
+                op: ComparisonOp::Equal,
+                left: Box::new(column_ref(row_number, row_number_type.clone())),
+                right: Box::new(ScalarExpr::ConstantExpr(ConstantExpr {
+                    span: None,
+                    value: Literal::UInt64(1),
+                    data_type: Box::new(row_number_type),
+                })),
+            })],
+            is_having: false,
+        };
+
+        Ok(SExpr::create_unary(
+            filter.into(),
+            SExpr::create_unary(window.into(), input),
+        ))
+    }
+
+    fn marker_index(&self, subquery: &SubqueryExpr) -> IndexType {
+        if let Some(idx) = subquery.projection_index {
+            idx
+        } else {
+            self.metadata.write().add_decorrelated_column(
+                "marker".to_string(),
+                DataType::Nullable(Box::new(DataType::Boolean)),
+                DecorrelatedColumnOrigin::Marker,
+            )
+        }
+    }
+
     fn add_equi_conditions(
         &self,
         span: Span,
@@ -811,17 +970,10 @@ impl SubqueryRewriter {
     ) -> Result<()> {
         for correlated_column in correlated_columns.iter() {
             let metadata = self.metadata.read();
-            let column_entry = metadata.column(*correlated_column);
-            let data_type = match column_entry {
-                ColumnEntry::BaseTableColumn(BaseTableColumn { data_type, .. }) => {
-                    DataType::from(data_type)
-                }
-                ColumnEntry::DerivedColumn(DerivedColumn { data_type, .. }) => data_type.clone(),
-                ColumnEntry::InternalColumn(TableInternalColumn {
-                    internal_column, ..
-                }) => internal_column.data_type(),
-            };
-            let right_column = ScalarExpr::BoundColumnRef(BoundColumnRef {
+            let data_type = column_data_type(metadata.column(*correlated_column));
+            let derive_column = self.derived_columns.get(correlated_column).unwrap();
+            let derive_data_type = column_data_type(metadata.column(*derive_column));
+            let mut right_column = ScalarExpr::BoundColumnRef(BoundColumnRef {
                 span,
                 column: ColumnBinding {
                     database_name: None,
@@ -832,18 +984,28 @@ impl SubqueryRewriter {
                     visibility: Visibility::Visible,
                 },
             });
-            let derive_column = self.derived_columns.get(correlated_column).unwrap();
-            let left_column = ScalarExpr::BoundColumnRef(BoundColumnRef {
+            let mut left_column = ScalarExpr::BoundColumnRef(BoundColumnRef {
                 span,
                 column: ColumnBinding {
                     database_name: None,
                     table_name: None,
                     column_name: format!("subquery_{}", derive_column),
                     index: *derive_column,
-                    data_type: Box::from(data_type.clone()),
+                    data_type: Box::from(derive_data_type.clone()),
                     visibility: Visibility::Visible,
                 },
             });
+            // The derived column may have been wrapped nullable during flatten,
+            // so make both sides of the join key nullable if either of them is.
+            if data_type != derive_data_type {
+                let target_type = data_type.wrap_nullable();
+                if data_type != target_type {
+                    right_column = wrap_cast(&right_column, &target_type);
+                }
+                if derive_data_type != target_type {
+                    left_column = wrap_cast(&left_column, &target_type);
+                }
+            }
             left_conditions.push(left_column);
             right_conditions.push(right_column);
         }
@@ -869,6 +1031,9 @@ impl SubqueryRewriter {
                 }) = predicate
                 {
                     if op == &ComparisonOp::Equal {
+                        // A correlated column referenced inside a cast, such as `t1.a = CAST(t.b AS INT)`,
+                        // can't be replaced by the inner column because the cast may be lossy. The outer
+                        // table is joined instead, and the cast is evaluated over the derived column.
                         if let (
                             ScalarExpr::BoundColumnRef(left),
                             ScalarExpr::BoundColumnRef(right),
@@ -896,3 +1061,15 @@ impl SubqueryRewriter {
         }))
     }
 }
+
+fn column_data_type(column_entry: &ColumnEntry) -> DataType {
+    match column_entry {
+        ColumnEntry::BaseTableColumn(BaseTableColumn { data_type, .. }) => {
+            DataType::from(data_type)
+        }
+        ColumnEntry::DerivedColumn(DerivedColumn { data_type, .. }) => data_type.clone(),
+        ColumnEntry::InternalColumn(TableInternalColumn {
+            internal_column, ..
+        }) => internal_column.data_type(),
+    }
+}
@@ -34,6 +34,7 @@ pub static DEFAULT_REWRITE_RULES: Lazy<Vec<RuleID>> = Lazy::new(|| {
         RuleID::NormalizeScalarFilter,
         RuleID::EliminateFilter,
         RuleID::EliminateEvalScalar,
+        RuleID::EliminateIdentityEvalScalar,
         RuleID::MergeFilter,
         RuleID::MergeEvalScalar,
         RuleID::PushDownFilterUnion,
@@ -48,19 +49,23 @@ pub static DEFAULT_REWRITE_RULES: Lazy<Vec<RuleID>> = Lazy::new(|| {
         RuleID::PushDownFilterEvalScalar,
         RuleID::PushDownFilterJoin,
         RuleID::FoldCountAggregate,
+        RuleID::FoldMinMaxAggregate,
         RuleID::SplitAggregate,
         RuleID::PushDownFilterScan,
         RuleID::PushDownPrewhere, /* PushDownPrwhere should be after all rules except PushDownFilterScan */
         RuleID::PushDownSortScan, // PushDownFilterScan should be after PushDownPrewhere
+        RuleID::PushDownPageLimitScan,
     ]
 });
 
 /// A heuristic query optimizer. It will apply specific transformation rules in order and
 /// implement the logical plans with default implementation rules.
 pub struct HeuristicOptimizer {
-    _ctx: Arc<dyn TableContext>,
+    ctx: Arc<dyn TableContext>,
     bind_context: Box<BindContext>,
     metadata: MetadataRef,
+    /// The plans after each step of the optimization, only recorded for `EXPLAIN OPTIMIZE`.
+    traces: Option<Vec<(String, SExpr)>>,
 }
 
 impl HeuristicOptimizer {
@@ -70,28 +75,59 @@ impl HeuristicOptimizer {
         metadata: MetadataRef,
     ) -> Self {
         HeuristicOptimizer {
-            _ctx: ctx,
+            ctx,
             bind_context,
             metadata,
+            traces: None,
+        }
+    }
+
+    /// Record the plan after each step of the optimization.
+    pub fn with_traces(mut self) -> Self {
+        self.traces = Some(vec![]);
+        self
+    }
+
+    /// The recorded steps and the plans after them. The plans of the rules are the
+    /// expressions rewritten by them, instead of the whole plan.
+    pub fn traces(&self) -> &[(String, SExpr)] {
+        self.traces.as_deref().unwrap_or_default()
+    }
+
+    fn trace(&mut self, step: impl FnOnce() -> String, s_expr: &SExpr) {
+        if let Some(traces) = &mut self.traces {
+            traces.push((step(), s_expr.clone()));
         }
     }
 
     fn pre_optimize(&mut self, s_expr: SExpr) -> Result<SExpr> {
         let mut s_expr = s_expr;
-        if s_expr.contain_subquery() {
-            s_expr = decorrelate_subquery(self.metadata.clone(), s_expr)?;
+        // Skipping decorrelation leaves the subqueries in the plan, which can't be executed,
+        // it's only for comparing plans when debugging.
+        if s_expr.contain_subquery()
+            && self
+                .ctx
+                .get_settings()
+                .get_enable_subquery_decorrelation()?
+        {
+            s_expr = decorrelate_subquery(self.ctx.clone(), self.metadata.clone(), s_expr)?;
+            self.trace(|| "Decorrelate subqueries".to_string(), &s_expr);
         }
 
         // always pruner the unused columns before and after optimization
         let pruner = UnusedColumnPruner::new(self.metadata.clone());
         let require_columns: ColumnSet = self.bind_context.column_set();
-        pruner.remove_unused_columns(&s_expr, require_columns)
+        let s_expr = pruner.remove_unused_columns(&s_expr, require_columns)?;
+        self.trace(|| "Prune unused columns".to_string(), &s_expr);
+        Ok(s_expr)
     }
 
     fn post_optimize(&mut self, s_expr: SExpr) -> Result<SExpr> {
         let pruner = UnusedColumnPruner::new(self.metadata.clone());
         let require_columns: ColumnSet = self.bind_context.column_set();
-        pruner.remove_unused_columns(&s_expr, require_columns)
+        let s_expr = pruner.remove_unused_columns(&s_expr, require_columns)?;
+        self.trace(|| "Prune unused columns".to_string(), &s_expr);
+        Ok(s_expr)
     }
 
     pub fn optimize(&mut self, s_expr: SExpr) -> Result<SExpr> {
@@ -102,7 +138,7 @@ impl HeuristicOptimizer {
         Ok(post_optimized)
     }
 
-    fn optimize_expression(&self, s_expr: &SExpr) -> Result<SExpr> {
+    fn optimize_expression(&mut self, s_expr: &SExpr) -> Result<SExpr> {
         let mut optimized_children = Vec::with_capacity(s_expr.arity());
         for expr in s_expr.children() {
             optimized_children.push(self.optimize_expression(expr)?);
@@ -115,11 +151,11 @@ impl HeuristicOptimizer {
 
     /// Try to apply the rules to the expression.
     /// Return the final result that no rule can be applied.
-    fn apply_transform_rules(&self, s_expr: &SExpr) -> Result<SExpr> {
+    fn apply_transform_rules(&mut self, s_expr: &SExpr) -> Result<SExpr> {
         let mut s_expr = s_expr.clone();
 
         for rule_id in DEFAULT_REWRITE_RULES.iter() {
-            let rule = RuleFactory::create_rule(*rule_id, self.metadata.clone())?;
+            let rule = RuleFactory::create_rule(*rule_id, self.ctx.clone(), self.metadata.clone())?;
             let mut state = TransformResult::new();
             if s_expr.match_pattern(rule.pattern()) && !s_expr.applied_rule(&rule.id()) {
                 s_expr.set_applied_rule(&rule.id());
@@ -127,6 +163,7 @@ impl HeuristicOptimizer {
                 if !state.results().is_empty() {
                     // Recursive optimize the result
                     let result = &state.results()[0];
+                    self.trace(|| format!("Apply rule {}", rule.id()), result);
                     let optimized_result = self.optimize_expression(result)?;
                     return Ok(optimized_result);
                 }
@@ -156,6 +156,22 @@ impl UnusedColumnPruner {
                     Self::keep_required_columns(expr.child(0)?, required)?,
                 ))
             }
+            RelOperator::Window(p) => {
+                // The window operator is kept, so all of its inputs are required.
+                for c in p.aggregate_function.scalar.used_columns() {
+                    required.insert(c);
+                }
+                p.partition_by.iter().for_each(|i| {
+                    required.insert(i.index);
+                });
+                p.order_by.iter().for_each(|i| {
+                    required.insert(i.order_by_item.index);
+                });
+                Ok(SExpr::create_unary(
+                    RelOperator::Window(p.clone()),
+                    Self::keep_required_columns(expr.child(0)?, required)?,
+                ))
+            }
             RelOperator::Sort(p) => {
                 p.items.iter().for_each(|s| {
                     required.insert(s.index);
@@ -186,7 +202,17 @@ impl UnusedColumnPruner {
                 ))
             }
 
-            RelOperator::DummyTableScan(_) => Ok(expr.clone()),
+            RelOperator::MaterializedCte(p) => {
+                // `CteScan`s read the materialized columns by position, so all of them are required.
+                let left_used = p.used_columns()?;
+                Ok(SExpr::create_binary(
+                    RelOperator::MaterializedCte(p.clone()),
+                    Self::keep_required_columns(expr.child(0)?, left_used)?,
+                    Self::keep_required_columns(expr.child(1)?, required)?,
+                ))
+            }
+
+            RelOperator::DummyTableScan(_) | RelOperator::CteScan(_) => Ok(expr.clone()),
 
             _ => Err(ErrorCode::Internal(
                 "Attempting to prune columns of a physical plan is not allowed",
@@ -13,9 +13,12 @@
 // limitations under the License.
 
 use std::collections::HashMap;
+use std::sync::Arc;
 
+use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_exception::Span;
 use common_expression::types::number::UInt64Type;
 use common_expression::types::ArgType;
 use common_expression::types::DataType;
@@ -26,6 +29,7 @@ use common_functions::aggregates::AggregateCountFunction;
 use crate::binder::wrap_cast;
 use crate::binder::ColumnBinding;
 use crate::binder::Visibility;
+use crate::optimizer::ColumnSet;
 use crate::optimizer::RelExpr;
 use crate::optimizer::SExpr;
 use crate::plans::Aggregate;
@@ -62,20 +66,27 @@ pub enum UnnestResult {
 }
 
 pub struct FlattenInfo {
-    pub from_count_func: bool,
+    /// The count aggregates without GROUP BY in the subquery, which are NULL rather than 0
+    /// for the outer rows not matched by the decorrelated join.
+    pub count_aggregates: ColumnSet,
 }
 
 /// Rewrite subquery into `Apply` operator
 pub struct SubqueryRewriter {
+    pub(crate) ctx: Arc<dyn TableContext>,
     pub(crate) metadata: MetadataRef,
     pub(crate) derived_columns: HashMap<IndexType, IndexType>,
+    /// Depth of the plan being flattened, bounded by the `max_decorrelation_depth` setting.
+    pub(crate) flatten_depth: usize,
 }
 
 impl SubqueryRewriter {
-    pub fn new(metadata: MetadataRef) -> Self {
+    pub fn new(ctx: Arc<dyn TableContext>, metadata: MetadataRef) -> Self {
         Self {
+            ctx,
             metadata,
             derived_columns: Default::default(),
+            flatten_depth: 0,
         }
     }
 
@@ -120,18 +131,21 @@ impl SubqueryRewriter {
                 Ok(SExpr::create_unary(plan.into(), input))
             }
 
-            RelOperator::Join(_) | RelOperator::UnionAll(_) => Ok(SExpr::create_binary(
-                s_expr.plan().clone(),
-                self.rewrite(s_expr.child(0)?)?,
-                self.rewrite(s_expr.child(1)?)?,
-            )),
+            RelOperator::Join(_) | RelOperator::UnionAll(_) | RelOperator::MaterializedCte(_) => {
+                Ok(SExpr::create_binary(
+                    s_expr.plan().clone(),
+                    self.rewrite(s_expr.child(0)?)?,
+                    self.rewrite(s_expr.child(1)?)?,
+                ))
+            }
 
-            RelOperator::Limit(_) | RelOperator::Sort(_) => Ok(SExpr::create_unary(
-                s_expr.plan().clone(),
-                self.rewrite(s_expr.child(0)?)?,
-            )),
+            RelOperator::Limit(_) | RelOperator::Sort(_) | RelOperator::Window(_) => Ok(
+                SExpr::create_unary(s_expr.plan().clone(), self.rewrite(s_expr.child(0)?)?),
+            ),
 
-            RelOperator::DummyTableScan(_) | RelOperator::Scan(_) => Ok(s_expr.clone()),
+            RelOperator::DummyTableScan(_) | RelOperator::Scan(_) | RelOperator::CteScan(_) => {
+                Ok(s_expr.clone())
+            }
 
             _ => Err(ErrorCode::Internal("Invalid plan type")),
         }
@@ -266,7 +280,7 @@ impl SubqueryRewriter {
                 let rel_expr = RelExpr::with_s_expr(&subquery.subquery);
                 let prop = rel_expr.derive_relational_prop()?;
                 let mut flatten_info = FlattenInfo {
-                    from_count_func: false,
+                    count_aggregates: ColumnSet::new(),
                 };
                 let (s_expr, result) = if prop.outer_columns.is_empty() {
                     self.try_rewrite_uncorrelated_subquery(s_expr, &subquery)?
@@ -327,42 +341,8 @@ impl SubqueryRewriter {
                     },
                 });
 
-                let scalar = if flatten_info.from_count_func {
-                    // convert count aggregate function to `if(count() is not null, count(), 0)`
-                    let is_not_null = ScalarExpr::FunctionCall(FunctionCall {
-                        span: subquery.span,
-                        func_name: "is_not_null".to_string(),
-                        params: vec![],
-                        arguments: vec![column_ref.clone()],
-                    });
-                    let cast_column_ref_to_uint64 = ScalarExpr::CastExpr(CastExpr {
-                        span: subquery.span,
-                        is_try: true,
-                        argument: Box::new(column_ref),
-                        target_type: Box::new(
-                            DataType::Number(NumberDataType::UInt64).wrap_nullable(),
-                        ),
-                    });
-                    let zero = ScalarExpr::ConstantExpr(ConstantExpr {
-                        span: subquery.span,
-                        value: Literal::Int64(0),
-                        data_type: Box::new(
-                            DataType::Number(NumberDataType::Int64).wrap_nullable(),
-                        ),
-                    });
-                    ScalarExpr::CastExpr(CastExpr {
-                        span: subquery.span,
-                        is_try: true,
-                        argument: Box::new(ScalarExpr::FunctionCall(FunctionCall {
-                            span: subquery.span,
-                            params: vec![],
-                            arguments: vec![is_not_null, cast_column_ref_to_uint64, zero],
-                            func_name: "if".to_string(),
-                        })),
-                        target_type: Box::new(
-                            DataType::Number(NumberDataType::UInt64).wrap_nullable(),
-                        ),
-                    })
+                let scalar = if !flatten_info.count_aggregates.is_empty() {
+                    wrap_count_aggregates(&subquery, column_ref, &flatten_info.count_aggregates)
                 } else if subquery.typ == SubqueryType::NotExists {
                     ScalarExpr::NotExpr(NotExpr {
                         argument: Box::new(column_ref),
@@ -387,6 +367,7 @@ impl SubqueryRewriter {
                     left_conditions: vec![],
                     right_conditions: vec![],
                     non_equi_conditions: vec![],
+                    is_null_equal: vec![],
                     join_type: JoinType::Single,
                     marker_index: None,
                     from_correlated_subquery: false,
@@ -480,6 +461,7 @@ impl SubqueryRewriter {
                     left_conditions: vec![],
                     right_conditions: vec![],
                     non_equi_conditions: vec![],
+                    is_null_equal: vec![],
                     join_type: JoinType::Cross,
                     marker_index: None,
                     from_correlated_subquery: false,
@@ -543,6 +525,7 @@ impl SubqueryRewriter {
                     left_conditions: right_conditions,
                     right_conditions: left_conditions,
                     non_equi_conditions,
+                    is_null_equal: vec![],
                     join_type: JoinType::RightMark,
                     marker_index: Some(marker_index),
                     from_correlated_subquery: false,
@@ -576,3 +559,117 @@ pub fn check_child_expr_in_subquery(
         ))),
     }
 }
+
+/// Handle the count bug of the correlated scalar subquery, e.g.
+/// `SELECT (SELECT COUNT(*) FROM t2 WHERE t2.a = t1.a) FROM t1`.
+///
+/// The aggregates of the outer rows not matched by the single join are NULL, while the
+/// counts over an empty set must be 0, so the counts are wrapped with `COALESCE(count, 0)`.
+/// If the output of the subquery is an expression of the aggregates, e.g. `COUNT(*) + 1`,
+/// the expression is evaluated after the join instead, with the counts wrapped.
+fn wrap_count_aggregates(
+    subquery: &SubqueryExpr,
+    column_ref: ScalarExpr,
+    count_aggregates: &ColumnSet,
+) -> ScalarExpr {
+    let output_index = subquery.output_column.index;
+    if count_aggregates.contains(&output_index) {
+        return coalesce_count(subquery.span, column_ref);
+    }
+
+    let eval_scalar = match subquery.subquery.plan() {
+        RelOperator::EvalScalar(eval_scalar) => eval_scalar,
+        _ => return column_ref,
+    };
+    // The expression can only be moved above the join if it's computed from the aggregates
+    // directly, e.g. a HAVING clause may filter out the aggregates of an empty set.
+    match subquery.subquery.child(0).map(|child| child.plan()) {
+        Ok(RelOperator::Aggregate(aggregate)) if aggregate.group_items.is_empty() => {}
+        _ => return column_ref,
+    }
+    let mut scalar = match eval_scalar
+        .items
+        .iter()
+        .find(|item| item.index == output_index)
+    {
+        Some(item) => item.scalar.clone(),
+        None => return column_ref,
+    };
+    if !wrap_count_columns(subquery.span, &mut scalar, count_aggregates) {
+        return column_ref;
+    }
+
+    ScalarExpr::CastExpr(CastExpr {
+        span: subquery.span,
+        is_try: false,
+        argument: Box::new(scalar),
+        target_type: Box::new(subquery.data_type.wrap_nullable()),
+    })
+}
+
+/// Replace the references of the count aggregates in the scalar with `COALESCE(count, 0)`,
+/// returns true if any reference is replaced.
+fn wrap_count_columns(span: Span, scalar: &mut ScalarExpr, count_aggregates: &ColumnSet) -> bool {
+    match scalar {
+        ScalarExpr::BoundColumnRef(column) if count_aggregates.contains(&column.column.index) => {
+            let column_ref = ScalarExpr::BoundColumnRef(column.clone());
+            *scalar = coalesce_count(span, column_ref);
+            true
+        }
+        ScalarExpr::AndExpr(expr) => {
+            let left = wrap_count_columns(span, &mut expr.left, count_aggregates);
+            let right = wrap_count_columns(span, &mut expr.right, count_aggregates);
+            left || right
+        }
+        ScalarExpr::OrExpr(expr) => {
+            let left = wrap_count_columns(span, &mut expr.left, count_aggregates);
+            let right = wrap_count_columns(span, &mut expr.right, count_aggregates);
+            left || right
+        }
+        ScalarExpr::NotExpr(expr) => wrap_count_columns(span, &mut expr.argument, count_aggregates),
+        ScalarExpr::ComparisonExpr(expr) => {
+            let left = wrap_count_columns(span, &mut expr.left, count_aggregates);
+            let right = wrap_count_columns(span, &mut expr.right, count_aggregates);
+            left || right
+        }
+        ScalarExpr::FunctionCall(expr) => expr.arguments.iter_mut().fold(false, |acc, arg| {
+            wrap_count_columns(span, arg, count_aggregates) || acc
+        }),
+        ScalarExpr::CastExpr(expr) => {
+            wrap_count_columns(span, &mut expr.argument, count_aggregates)
+        }
+        _ => false,
+    }
+}
+
+/// Convert the count aggregate to `if(count is not null, count, 0)`.
+fn coalesce_count(span: Span, column_ref: ScalarExpr) -> ScalarExpr {
+    let is_not_null = ScalarExpr::FunctionCall(FunctionCall {
+        span,
+        func_name: "is_not_null".to_string(),
+        params: vec![],
+        arguments: vec![column_ref.clone()],
+    });
+    let cast_column_ref_to_uint64 = ScalarExpr::CastExpr(CastExpr {
+        span,
+        is_try: true,
+        argument: Box::new(column_ref),
+        target_type: Box::new(DataType::Number(NumberDataType::UInt64).wrap_nullable()),
+    });
+    let zero = ScalarExpr::ConstantExpr(ConstantExpr {
+        span,
+        value: Literal::Int64(0),
+        data_type: Box::new(DataType::Number(NumberDataType::Int64).wrap_nullable()),
+    });
+    ScalarExpr::CastExpr(CastExpr {
+        span,
+        is_try: true,
+        argument: Box::new(ScalarExpr::FunctionCall(FunctionCall {
+            span,
+            params: vec![],
+            arguments: vec![is_not_null, cast_column_ref_to_uint64, zero],
+            func_name: "if".to_string(),
+        })),
+        target_type: Box::new(DataType::Number(NumberDataType::UInt64).wrap_nullable()),
+    })
+}
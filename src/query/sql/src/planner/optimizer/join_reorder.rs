@@ -0,0 +1,236 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+
+use crate::optimizer::ColumnSet;
+use crate::optimizer::RelExpr;
+use crate::optimizer::SExpr;
+use crate::plans::Join;
+use crate::plans::JoinType;
+use crate::plans::RelOperator;
+use crate::ScalarExpr;
+
+/// Reorder the inner joins of more than two relations with a greedy algorithm.
+///
+/// The inner and cross joins which are directly connected are flattened into a set of
+/// relations and join conditions. Then the two relations whose join has the lowest
+/// estimated cardinality are joined repeatedly, until there is only one relation left.
+/// Cross joins are only introduced if there is no join condition between the relations.
+///
+/// The cardinalities are estimated from the statistics of the scans, so the smaller
+/// relation of each join is placed on the right side to be the build side.
+pub fn reorder_joins(s_expr: &SExpr) -> Result<SExpr> {
+    if is_reorderable_join(s_expr) {
+        let mut relations = vec![];
+        let mut conditions = vec![];
+        flatten_joins(s_expr, &mut relations, &mut conditions)?;
+        if relations.len() > 2 {
+            let relations = relations
+                .iter()
+                .map(reorder_joins)
+                .collect::<Result<Vec<_>>>()?;
+            if let Some(result) = GreedyJoinReorder::create(relations, conditions)?.reorder()? {
+                return Ok(result);
+            }
+        }
+    }
+
+    let children = s_expr
+        .children()
+        .iter()
+        .map(reorder_joins)
+        .collect::<Result<Vec<_>>>()?;
+    Ok(s_expr.replace_children(children))
+}
+
+fn is_reorderable_join(s_expr: &SExpr) -> bool {
+    match s_expr.plan() {
+        RelOperator::Join(join) => {
+            matches!(join.join_type, JoinType::Inner | JoinType::Cross)
+                && join.marker_index.is_none()
+                && !join.from_correlated_subquery
+                && !join.contain_runtime_filter
+                && join.is_null_equal.is_empty()
+        }
+        _ => false,
+    }
+}
+
+#[derive(Clone)]
+enum JoinCondition {
+    Equi(ScalarExpr, ScalarExpr),
+    NonEqui(ScalarExpr),
+}
+
+fn flatten_joins(
+    s_expr: &SExpr,
+    relations: &mut Vec<SExpr>,
+    conditions: &mut Vec<JoinCondition>,
+) -> Result<()> {
+    if !is_reorderable_join(s_expr) {
+        relations.push(s_expr.clone());
+        return Ok(());
+    }
+
+    let join: Join = s_expr.plan().clone().try_into()?;
+    for (left, right) in join
+        .left_conditions
+        .into_iter()
+        .zip(join.right_conditions.into_iter())
+    {
+        conditions.push(JoinCondition::Equi(left, right));
+    }
+    conditions.extend(
+        join.non_equi_conditions
+            .into_iter()
+            .map(JoinCondition::NonEqui),
+    );
+
+    flatten_joins(s_expr.child(0)?, relations, conditions)?;
+    flatten_joins(s_expr.child(1)?, relations, conditions)
+}
+
+struct JoinRelation {
+    s_expr: SExpr,
+    output_columns: ColumnSet,
+    cardinality: f64,
+}
+
+impl JoinRelation {
+    fn create(s_expr: SExpr) -> Result<Self> {
+        let prop = RelExpr::with_s_expr(&s_expr).derive_relational_prop()?;
+        Ok(JoinRelation {
+            s_expr,
+            output_columns: prop.output_columns,
+            cardinality: prop.cardinality,
+        })
+    }
+}
+
+struct GreedyJoinReorder {
+    relations: Vec<JoinRelation>,
+    conditions: Vec<JoinCondition>,
+}
+
+impl GreedyJoinReorder {
+    fn create(relations: Vec<SExpr>, conditions: Vec<JoinCondition>) -> Result<Self> {
+        let relations = relations
+            .into_iter()
+            .map(JoinRelation::create)
+            .collect::<Result<Vec<_>>>()?;
+        Ok(GreedyJoinReorder {
+            relations,
+            conditions,
+        })
+    }
+
+    /// Returns `None` if the join conditions can't be placed into the reordered joins.
+    fn reorder(mut self) -> Result<Option<SExpr>> {
+        while self.relations.len() > 1 {
+            let mut best: Option<(usize, usize, JoinRelation, Vec<usize>)> = None;
+            // Prefer the joins with equi conditions, to avoid cross joins.
+            let mut best_is_equi = false;
+            for i in 0..self.relations.len() {
+                for j in i + 1..self.relations.len() {
+                    let (join, placed, is_equi) = self.join(i, j)?;
+                    let better = match &best {
+                        None => true,
+                        Some((_, _, best_join, _)) => {
+                            (is_equi && !best_is_equi)
+                                || (is_equi == best_is_equi
+                                    && join.cardinality < best_join.cardinality)
+                        }
+                    };
+                    if better {
+                        best = Some((i, j, join, placed));
+                        best_is_equi = is_equi;
+                    }
+                }
+            }
+
+            let (i, j, join, placed) = best.unwrap();
+            // `j` is always greater than `i`, remove it first.
+            self.relations.remove(j);
+            self.relations.remove(i);
+            self.relations.push(join);
+            for idx in placed.into_iter().rev() {
+                self.conditions.remove(idx);
+            }
+        }
+
+        if !self.conditions.is_empty() {
+            return Ok(None);
+        }
+        Ok(self.relations.pop().map(|relation| relation.s_expr))
+    }
+
+    /// Join the `i`th and `j`th relations with the conditions which can be evaluated on them.
+    /// Returns the join, the indexes of the placed conditions, and whether the join has any
+    /// equi condition.
+    fn join(&self, i: usize, j: usize) -> Result<(JoinRelation, Vec<usize>, bool)> {
+        // The smaller relation is the build side.
+        let (left, right) = if self.relations[i].cardinality >= self.relations[j].cardinality {
+            (&self.relations[i], &self.relations[j])
+        } else {
+            (&self.relations[j], &self.relations[i])
+        };
+        let output_columns: ColumnSet = left
+            .output_columns
+            .union(&right.output_columns)
+            .cloned()
+            .collect();
+
+        let mut join = Join {
+            join_type: JoinType::Inner,
+            ..Default::default()
+        };
+        let mut placed = vec![];
+        for (idx, condition) in self.conditions.iter().enumerate() {
+            match condition {
+                JoinCondition::Equi(l, r) => {
+                    let l_columns = l.used_columns();
+                    let r_columns = r.used_columns();
+                    if l_columns.is_subset(&left.output_columns)
+                        && r_columns.is_subset(&right.output_columns)
+                    {
+                        join.left_conditions.push(l.clone());
+                        join.right_conditions.push(r.clone());
+                    } else if l_columns.is_subset(&right.output_columns)
+                        && r_columns.is_subset(&left.output_columns)
+                    {
+                        join.left_conditions.push(r.clone());
+                        join.right_conditions.push(l.clone());
+                    } else {
+                        continue;
+                    }
+                }
+                JoinCondition::NonEqui(cond) => {
+                    if !cond.used_columns().is_subset(&output_columns) {
+                        continue;
+                    }
+                    join.non_equi_conditions.push(cond.clone());
+                }
+            }
+            placed.push(idx);
+        }
+
+        let is_equi = !join.left_conditions.is_empty();
+        if !is_equi && join.non_equi_conditions.is_empty() {
+            join.join_type = JoinType::Cross;
+        }
+        let s_expr = SExpr::create_binary(join.into(), left.s_expr.clone(), right.s_expr.clone());
+        Ok((JoinRelation::create(s_expr)?, placed, is_equi))
+    }
+}
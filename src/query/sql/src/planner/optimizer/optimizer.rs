@@ -22,9 +22,11 @@ use common_exception::Result;
 
 use super::cost::CostContext;
 use super::format::display_memo;
+use super::format::display_optimize_traces;
 use super::Memo;
 use crate::optimizer::cascades::CascadesOptimizer;
 use crate::optimizer::distributed::optimize_distributed_query;
+use crate::optimizer::join_reorder::reorder_joins;
 use crate::optimizer::runtime_filter::try_add_runtime_filter_nodes;
 use crate::optimizer::util::contains_local_table_scan;
 use crate::optimizer::HeuristicOptimizer;
@@ -106,6 +108,30 @@ pub fn optimize(
                     ))
                 }
             }
+            ExplainKind::Optimize(_) => {
+                if let box Plan::Query {
+                    ref s_expr,
+                    ref metadata,
+                    ref bind_context,
+                    ..
+                } = plan
+                {
+                    let traces = get_optimize_traces(
+                        ctx,
+                        *s_expr.clone(),
+                        metadata.clone(),
+                        bind_context.clone(),
+                    )?;
+                    Ok(Plan::Explain {
+                        kind: ExplainKind::Optimize(display_optimize_traces(&traces, metadata)?),
+                        plan,
+                    })
+                } else {
+                    Err(ErrorCode::BadArguments(
+                        "Cannot use EXPLAIN OPTIMIZE with a non-query statement",
+                    ))
+                }
+            }
             _ => Ok(Plan::Explain {
                 kind,
                 plan: Box::new(optimize(ctx, opt_ctx, *plan)?),
@@ -133,6 +159,13 @@ pub fn optimize(
                 into_table => into_table,
             })))
         }
+        Plan::Update(mut plan) => {
+            if let Some(from) = plan.from.take() {
+                // Make sure the query of the matched rows has been optimized.
+                plan.from = Some(Box::new(optimize(ctx, opt_ctx, *from)?));
+            }
+            Ok(Plan::Update(plan))
+        }
         // Passthrough statements
         _ => Ok(plan),
     }
@@ -149,6 +182,9 @@ pub fn optimize_query(
 
     let mut heuristic = HeuristicOptimizer::new(ctx.clone(), bind_context, metadata.clone());
     let mut result = heuristic.optimize(s_expr)?;
+    if ctx.get_settings().get_enable_join_reorder()? {
+        result = reorder_joins(&result)?;
+    }
     let mut cascades = CascadesOptimizer::create(ctx.clone(), metadata)?;
     result = cascades.optimize(result)?;
     // So far, we don't have ability to execute distributed query
@@ -177,9 +213,28 @@ fn get_optimized_memo(
     bind_context: Box<BindContext>,
 ) -> Result<(Memo, HashMap<IndexType, CostContext>)> {
     let mut heuristic = HeuristicOptimizer::new(ctx.clone(), bind_context, metadata.clone());
-    let result = heuristic.optimize(s_expr)?;
+    let mut result = heuristic.optimize(s_expr)?;
+    if ctx.get_settings().get_enable_join_reorder()? {
+        result = reorder_joins(&result)?;
+    }
 
     let mut cascades = CascadesOptimizer::create(ctx, metadata)?;
     cascades.optimize(result)?;
     Ok((cascades.memo, cascades.best_cost_map))
 }
+
+/// Run the heuristic optimization and the join reorder, recording the plan after each step.
+fn get_optimize_traces(
+    ctx: Arc<dyn TableContext>,
+    s_expr: SExpr,
+    metadata: MetadataRef,
+    bind_context: Box<BindContext>,
+) -> Result<Vec<(String, SExpr)>> {
+    let mut heuristic = HeuristicOptimizer::new(ctx.clone(), bind_context, metadata).with_traces();
+    let result = heuristic.optimize(s_expr)?;
+    let mut traces = heuristic.traces().to_vec();
+    if ctx.get_settings().get_enable_join_reorder()? {
+        traces.push(("Reorder joins".to_string(), reorder_joins(&result)?));
+    }
+    Ok(traces)
+}
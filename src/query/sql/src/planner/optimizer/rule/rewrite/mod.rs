@@ -15,7 +15,9 @@
 mod filter_join;
 mod rule_eliminate_eval_scalar;
 mod rule_eliminate_filter;
+mod rule_eliminate_identity_eval_scalar;
 mod rule_fold_count_aggregate;
+mod rule_fold_min_max_aggregate;
 mod rule_merge_eval_scalar;
 mod rule_merge_filter;
 mod rule_normalize_disjunctive_filter;
@@ -32,13 +34,16 @@ mod rule_push_down_limit_join;
 mod rule_push_down_limit_scan;
 mod rule_push_down_limit_sort;
 mod rule_push_down_limit_union;
+mod rule_push_down_page_limit_scan;
 mod rule_push_down_prewhere;
 mod rule_push_down_sort_scan;
 mod rule_split_aggregate;
 
 pub use rule_eliminate_eval_scalar::RuleEliminateEvalScalar;
 pub use rule_eliminate_filter::RuleEliminateFilter;
+pub use rule_eliminate_identity_eval_scalar::RuleEliminateIdentityEvalScalar;
 pub use rule_fold_count_aggregate::RuleFoldCountAggregate;
+pub use rule_fold_min_max_aggregate::RuleFoldMinMaxAggregate;
 pub use rule_merge_eval_scalar::RuleMergeEvalScalar;
 pub use rule_merge_filter::RuleMergeFilter;
 pub use rule_normalize_disjunctive_filter::RuleNormalizeDisjunctiveFilter;
@@ -56,6 +61,7 @@ pub use rule_push_down_limit_join::RulePushDownLimitOuterJoin;
 pub use rule_push_down_limit_scan::RulePushDownLimitScan;
 pub use rule_push_down_limit_sort::RulePushDownLimitSort;
 pub use rule_push_down_limit_union::RulePushDownLimitUnion;
+pub use rule_push_down_page_limit_scan::RulePushDownPageLimitScan;
 pub use rule_push_down_prewhere::RulePushDownPrewhere;
 pub use rule_push_down_sort_scan::RulePushDownSortScan;
 pub use rule_split_aggregate::RuleSplitAggregate;
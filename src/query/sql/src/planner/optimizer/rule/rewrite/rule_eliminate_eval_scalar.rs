@@ -21,9 +21,8 @@ use crate::optimizer::SExpr;
 use crate::plans::EvalScalar;
 use crate::plans::PatternPlan;
 use crate::plans::RelOp;
-use crate::plans::ScalarExpr;
-use crate::plans::ScalarItem;
 
+/// Eliminate empty `EvalScalar`.
 pub struct RuleEliminateEvalScalar {
     id: RuleID,
     pattern: SExpr,
@@ -63,25 +62,6 @@ impl Rule for RuleEliminateEvalScalar {
         // Eliminate empty EvalScalar
         if eval_scalar.items.is_empty() {
             state.add_result(s_expr.child(0)?.clone());
-            return Ok(());
-        }
-
-        // TODO(leiysky): Use another rule to do this.
-        // Remove trivial column reference scalar.
-        if eval_scalar.items.iter().any(|item| matches!(&item.scalar, ScalarExpr::BoundColumnRef(column) if column.column.index == item.index)) {
-            let new_items = eval_scalar
-                .items
-                .into_iter()
-                .filter(|item| !matches!(&item.scalar, ScalarExpr::BoundColumnRef(column) if column.column.index == item.index))
-                .collect::<Vec<ScalarItem>>();
-            if new_items.is_empty() {
-                state.add_result(s_expr.child(0)?.clone());
-            } else {
-                state.add_result(SExpr::create_unary(
-                    EvalScalar { items: new_items }.into(),
-                    s_expr.child(0)?.clone(),
-                ));
-            }
         }
         Ok(())
     }
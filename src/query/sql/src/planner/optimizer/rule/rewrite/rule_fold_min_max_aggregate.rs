@@ -0,0 +1,158 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::iter::once;
+
+use common_exception::Result;
+use common_expression::Literal;
+use storages_common_index::RangeIndex;
+
+use crate::optimizer::rule::Rule;
+use crate::optimizer::rule::RuleID;
+use crate::optimizer::rule::TransformResult;
+use crate::optimizer::SExpr;
+use crate::plans::Aggregate;
+use crate::plans::AggregateMode;
+use crate::plans::ConstantExpr;
+use crate::plans::DummyTableScan;
+use crate::plans::EvalScalar;
+use crate::plans::PatternPlan;
+use crate::plans::RelOp;
+use crate::plans::ScalarExpr;
+use crate::plans::Scan;
+
+/// Fold simple `MIN(col)`/`MAX(col)` aggregate over a table scan with the min/max statistics
+/// of the column, so no data rows are read.
+pub struct RuleFoldMinMaxAggregate {
+    id: RuleID,
+    pattern: SExpr,
+}
+
+impl RuleFoldMinMaxAggregate {
+    pub fn new() -> Self {
+        Self {
+            id: RuleID::FoldMinMaxAggregate,
+            //  Aggregate
+            //  \
+            //   Scan
+            pattern: SExpr::create_unary(
+                PatternPlan {
+                    plan_type: RelOp::Aggregate,
+                }
+                .into(),
+                SExpr::create_leaf(
+                    PatternPlan {
+                        plan_type: RelOp::Scan,
+                    }
+                    .into(),
+                ),
+            ),
+        }
+    }
+}
+
+impl Rule for RuleFoldMinMaxAggregate {
+    fn id(&self) -> RuleID {
+        self.id
+    }
+
+    fn apply(&self, s_expr: &SExpr, state: &mut TransformResult) -> Result<()> {
+        let agg: Aggregate = s_expr.plan().clone().try_into()?;
+        let scan: Scan = s_expr.child(0)?.plan().clone().try_into()?;
+
+        if agg.mode == AggregateMode::Final
+            || agg.mode == AggregateMode::Partial
+            || !agg.group_items.is_empty()
+            || agg.aggregate_functions.is_empty()
+        {
+            return Ok(());
+        }
+
+        // The statistics describe the whole table, they can only be used if
+        // every row is visible to the aggregate.
+        if !scan.statistics.is_accurate
+            || scan.push_down_predicates.is_some()
+            || scan.prewhere.is_some()
+            || scan.limit.is_some()
+        {
+            return Ok(());
+        }
+        let num_rows = scan
+            .statistics
+            .statistics
+            .as_ref()
+            .and_then(|stat| stat.num_rows);
+        if !matches!(num_rows, Some(num_rows) if num_rows > 0) {
+            return Ok(());
+        }
+
+        let mut scalars = agg.aggregate_functions;
+        for item in scalars.iter_mut() {
+            let agg_func = match &item.scalar {
+                ScalarExpr::AggregateFunction(agg_func)
+                    if (agg_func.func_name == "min" || agg_func.func_name == "max")
+                        && !agg_func.distinct
+                        && agg_func.args.len() == 1 =>
+                {
+                    agg_func
+                }
+                _ => return Ok(()),
+            };
+            let column = match &agg_func.args[0] {
+                ScalarExpr::BoundColumnRef(column_ref) => &column_ref.column,
+                _ => return Ok(()),
+            };
+            let col_stat = match scan.statistics.col_stats.get(&column.index) {
+                Some(Some(col_stat)) => col_stat,
+                _ => return Ok(()),
+            };
+
+            // NULL-only columns have NULL min/max, and are left to the aggregate.
+            let (min, max) = match RangeIndex::global_min_max(
+                once((&col_stat.min, &col_stat.max)),
+                &column.data_type,
+            ) {
+                Some(min_max) => min_max,
+                None => return Ok(()),
+            };
+            let value = if agg_func.func_name == "min" {
+                min
+            } else {
+                max
+            };
+            let value = match Literal::try_from(value) {
+                Ok(value) => value,
+                Err(_) => return Ok(()),
+            };
+
+            item.scalar = ScalarExpr::ConstantExpr(ConstantExpr {
+                span: item.scalar.span(),
+                value,
+                data_type: Box::new(item.scalar.data_type()?),
+            });
+        }
+
+        let eval_scalar = EvalScalar { items: scalars };
+        let dummy_table_scan = DummyTableScan;
+        state.add_result(SExpr::create_unary(
+            eval_scalar.into(),
+            SExpr::create_leaf(dummy_table_scan.into()),
+        ));
+        Ok(())
+    }
+
+    fn pattern(&self) -> &SExpr {
+        &self.pattern
+    }
+}
@@ -0,0 +1,146 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::plan::PageLimit;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::RemoteExpr;
+
+use crate::optimizer::rule::Rule;
+use crate::optimizer::rule::TransformResult;
+use crate::optimizer::RuleID;
+use crate::optimizer::SExpr;
+use crate::plans::PatternPlan;
+use crate::plans::RelOp;
+use crate::plans::RelOperator;
+use crate::plans::Scan;
+use crate::plans::Sort;
+use crate::BaseTableColumn;
+use crate::ColumnEntry;
+use crate::MetadataRef;
+
+/// Input:  Sort(limit)
+///           \
+///          LogicalGet
+///
+/// Output:
+///         Sort(limit)
+///           \
+///           LogicalGet(padding page_limit)
+///
+/// The page limit is only pushed down if the sort items are the prefix of the cluster keys
+/// of the table, and in the same order as the blocks are sorted, i.e. `ASC NULLS LAST` or
+/// `DESC NULLS FIRST`, so that the fuse table only reads the leading or trailing pages.
+pub struct RulePushDownPageLimitScan {
+    id: RuleID,
+    ctx: Arc<dyn TableContext>,
+    metadata: MetadataRef,
+    pattern: SExpr,
+}
+
+impl RulePushDownPageLimitScan {
+    pub fn new(ctx: Arc<dyn TableContext>, metadata: MetadataRef) -> Self {
+        Self {
+            id: RuleID::PushDownPageLimitScan,
+            ctx,
+            metadata,
+            pattern: SExpr::create_unary(
+                PatternPlan {
+                    plan_type: RelOp::Sort,
+                }
+                .into(),
+                SExpr::create_leaf(
+                    PatternPlan {
+                        plan_type: RelOp::Scan,
+                    }
+                    .into(),
+                ),
+            ),
+        }
+    }
+
+    fn page_limit(&self, sort: &Sort, get: &Scan) -> Option<PageLimit> {
+        let limit = sort.limit?;
+        // The rows may be filtered after reading, the first rows of the blocks are not enough.
+        if get.push_down_predicates.is_some() || get.prewhere.is_some() {
+            return None;
+        }
+
+        let asc = sort.items.first()?.asc;
+        if sort
+            .items
+            .iter()
+            .any(|item| item.asc != asc || item.nulls_first == asc)
+        {
+            return None;
+        }
+
+        let table = self.metadata.read().table(get.table_index).table();
+        let cluster_keys = table.cluster_keys(self.ctx.clone());
+        if sort.items.len() > cluster_keys.len() {
+            return None;
+        }
+
+        let metadata = self.metadata.read();
+        for (item, cluster_key) in sort.items.iter().zip(cluster_keys.iter()) {
+            let cluster_key = match cluster_key {
+                RemoteExpr::ColumnRef { id, .. } => id,
+                _ => return None,
+            };
+            match metadata.column(item.index) {
+                ColumnEntry::BaseTableColumn(BaseTableColumn {
+                    table_index,
+                    column_name,
+                    path_indices: None,
+                    ..
+                }) if *table_index == get.table_index && column_name == cluster_key => {}
+                _ => return None,
+            }
+        }
+
+        Some(PageLimit { limit, asc })
+    }
+}
+
+impl Rule for RulePushDownPageLimitScan {
+    fn id(&self) -> RuleID {
+        self.id
+    }
+
+    fn apply(&self, s_expr: &SExpr, state: &mut TransformResult) -> Result<()> {
+        let sort: Sort = s_expr.plan().clone().try_into()?;
+        let child = s_expr.child(0)?;
+        let mut get: Scan = child.plan().clone().try_into()?;
+        if get.page_limit.is_some() {
+            return Ok(());
+        }
+
+        get.page_limit = self.page_limit(&sort, &get);
+        if get.page_limit.is_none() {
+            return Ok(());
+        }
+        let get = SExpr::create_leaf(RelOperator::Scan(get));
+
+        let mut result = s_expr.replace_children(vec![get]);
+        result.set_applied_rule(&self.id);
+        state.add_result(result);
+        Ok(())
+    }
+
+    fn pattern(&self) -> &SExpr {
+        &self.pattern
+    }
+}
@@ -56,12 +56,15 @@ pub enum RuleID {
     PushDownLimitAggregate,
     PushDownLimitScan,
     PushDownSortScan,
+    PushDownPageLimitScan,
     EliminateEvalScalar,
+    EliminateIdentityEvalScalar,
     EliminateFilter,
     MergeEvalScalar,
     MergeFilter,
     SplitAggregate,
     FoldCountAggregate,
+    FoldMinMaxAggregate,
     PushDownPrewhere,
 
     // Exploration rules
@@ -90,7 +93,9 @@ impl Display for RuleID {
             RuleID::PushDownFilterAggregate => write!(f, "PushDownFilterAggregate"),
             RuleID::PushDownLimitScan => write!(f, "PushDownLimitScan"),
             RuleID::PushDownSortScan => write!(f, "PushDownSortScan"),
+            RuleID::PushDownPageLimitScan => write!(f, "PushDownPageLimitScan"),
             RuleID::EliminateEvalScalar => write!(f, "EliminateEvalScalar"),
+            RuleID::EliminateIdentityEvalScalar => write!(f, "EliminateIdentityEvalScalar"),
             RuleID::EliminateFilter => write!(f, "EliminateFilter"),
             RuleID::MergeEvalScalar => write!(f, "MergeEvalScalar"),
             RuleID::MergeFilter => write!(f, "MergeFilter"),
@@ -98,6 +103,7 @@ impl Display for RuleID {
             RuleID::SplitAggregate => write!(f, "SplitAggregate"),
             RuleID::NormalizeDisjunctiveFilter => write!(f, "NormalizeDisjunctiveFilter"),
             RuleID::FoldCountAggregate => write!(f, "FoldCountAggregate"),
+            RuleID::FoldMinMaxAggregate => write!(f, "FoldMinMaxAggregate"),
             RuleID::PushDownPrewhere => write!(f, "PushDownPrewhere"),
 
             RuleID::CommuteJoin => write!(f, "CommuteJoin"),
@@ -16,15 +16,27 @@ use common_exception::Result;
 
 use crate::plans::ComparisonExpr;
 use crate::plans::ComparisonOp;
+use crate::plans::FunctionCall;
 use crate::plans::Join;
 use crate::plans::ScalarExpr;
 
+/// The null-safe equi conditions are returned as `is_not_distinct_from` function calls,
+/// which are only evaluated as non-equi conditions of the transformed joins.
 pub fn get_join_predicates(join: &Join) -> Result<Vec<ScalarExpr>> {
     Ok(join
         .left_conditions
         .iter()
         .zip(join.right_conditions.iter())
-        .map(|(left_cond, right_cond)| {
+        .enumerate()
+        .map(|(idx, (left_cond, right_cond))| {
+            if join.is_null_equal.contains(&idx) {
+                return Ok(ScalarExpr::FunctionCall(FunctionCall {
+                    span: None,
+                    func_name: "is_not_distinct_from".to_string(),
+                    params: vec![],
+                    arguments: vec![left_cond.clone(), right_cond.clone()],
+                }));
+            }
             Ok(ScalarExpr::ComparisonExpr(ComparisonExpr {
                 left: Box::new(left_cond.clone()),
                 right: Box::new(right_cond.clone()),
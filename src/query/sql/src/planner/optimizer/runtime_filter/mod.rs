@@ -89,7 +89,8 @@ pub fn try_add_runtime_filter_nodes(expr: &SExpr) -> Result<SExpr> {
 fn add_runtime_filter_nodes(expr: &SExpr) -> Result<SExpr> {
     assert_eq!(expr.plan.rel_op(), RelOp::Join);
     let join: Join = expr.plan().clone().try_into()?;
-    if join.join_type != JoinType::Inner {
+    // The runtime filters never match the NULL keys, which are matched by `<=>`.
+    if join.join_type != JoinType::Inner || !join.is_null_equal.is_empty() {
         return Ok(expr.clone());
     }
     let runtime_filter_result = create_runtime_filters(&join)?;
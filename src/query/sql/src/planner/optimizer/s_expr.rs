@@ -175,6 +175,11 @@ impl SExpr {
         self.applied_rules.get(rule_id)
     }
 
+    /// Check if the plan of current SExpr contains subquery, the children are not checked
+    pub(crate) fn plan_contain_subquery(&self) -> bool {
+        find_subquery(&self.plan)
+    }
+
     /// Check if contain subquery
     pub(crate) fn contain_subquery(&self) -> bool {
         if !find_subquery(&self.plan) {
@@ -231,6 +236,8 @@ fn find_subquery(rel_op: &RelOperator) -> bool {
         | RelOperator::UnionAll(_)
         | RelOperator::Sort(_)
         | RelOperator::DummyTableScan(_)
+        | RelOperator::MaterializedCte(_)
+        | RelOperator::CteScan(_)
         | RelOperator::RuntimeFilterSource(_)
         | RelOperator::Pattern(_) => false,
         RelOperator::Join(op) => {
@@ -256,6 +263,10 @@ fn find_subquery(rel_op: &RelOperator) -> bool {
             op.partition_by
                 .iter()
                 .any(|expr| find_subquery_in_expr(&expr.scalar))
+                || op
+                    .order_by
+                    .iter()
+                    .any(|expr| find_subquery_in_expr(&expr.order_by_item.scalar))
                 || find_subquery_in_expr(&op.aggregate_function.scalar)
         }
     }
@@ -15,6 +15,8 @@
 use std::sync::Arc;
 
 use common_ast::ast::Expr;
+use common_ast::ast::InsertSource;
+use common_ast::ast::InsertStmt;
 use common_ast::ast::Literal;
 use common_ast::ast::Statement;
 use common_ast::parser::parse_sql;
@@ -25,6 +27,7 @@ use common_ast::walk_statement_mut;
 use common_ast::Dialect;
 use common_catalog::catalog::CatalogManager;
 use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use parking_lot::RwLock;
 
@@ -51,6 +54,9 @@ pub struct PlanExtras {
     pub metadata: MetadataRef,
     pub format: Option<String>,
     pub stament: Statement,
+    /// Diff between the parsed statement and the statement rewritten before binding, in the
+    /// form of `- <original>\n+ <rewritten>` with credentials masked. Empty if no rewrite applies.
+    pub rewrite_diff: String,
 }
 
 impl Planner {
@@ -60,7 +66,21 @@ impl Planner {
 
     pub async fn plan_sql(&mut self, sql: &str) -> Result<(Plan, PlanExtras)> {
         let settings = self.ctx.get_settings();
-        let sql_dialect = settings.get_sql_dialect()?;
+        let mut sql_dialect = settings.get_sql_dialect()?;
+        if settings.get_enable_dialect_hint()? {
+            if let Some(dialect) = parse_dialect_hint(sql) {
+                sql_dialect = dialect;
+            }
+        }
+
+        let max_statement_bytes = settings.get_max_statement_bytes()? as usize;
+        if max_statement_bytes != 0 && sql.len() > max_statement_bytes {
+            return Err(ErrorCode::BadArguments(format!(
+                "Statement of {} bytes exceeds the limit of {} bytes (max_statement_bytes)",
+                sql.len(),
+                max_statement_bytes
+            )));
+        }
 
         // Step 1: Tokenize the SQL.
         let mut tokenizer = Tokenizer::new(sql).peekable();
@@ -82,14 +102,44 @@ impl Planner {
                 .collect::<Result<_>>()
                 .unwrap()
         } else {
-            (&mut tokenizer).collect::<Result<_>>()?
+            let tokens: Vec<Token> = (&mut tokenizer).collect::<Result<_>>()?;
+            let max_statement_tokens = settings.get_max_statement_tokens()? as usize;
+            // The tokens are ended with EOI.
+            if max_statement_tokens != 0 && tokens.len() - 1 > max_statement_tokens {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Statement of {} tokens exceeds the limit of {} tokens (max_statement_tokens)",
+                    tokens.len() - 1,
+                    max_statement_tokens
+                )));
+            }
+            if let Some(next) = tokens
+                .iter()
+                .position(|token| token.kind == TokenKind::SemiColon)
+                .map(|pos| &tokens[pos + 1])
+                .filter(|next| next.kind != TokenKind::EOI)
+            {
+                return Err(ErrorCode::SyntaxException(
+                    "multiple statements are not supported in this endpoint",
+                )
+                .set_span(Some(next.span)));
+            }
+            tokens
         };
 
+        let mut probed = false;
         loop {
+            let has_more_tokens = matches!(tokenizer.peek(), Some(Ok(_)));
             let res = async {
                 // Step 2: Parse the SQL.
                 let (mut stmt, format) = parse_sql(&tokens, sql_dialect)?;
-                self.replace_stmt(&mut stmt, sql_dialect);
+                if has_more_tokens && values_contain_returning(&stmt) {
+                    // The `RETURNING` clause is beyond the probed tokens, so it's taken as a
+                    // part of the VALUES, tokenize more and parse again.
+                    return Err(ErrorCode::SyntaxException(
+                        "RETURNING clause of INSERT is not fully tokenized",
+                    ));
+                }
+                let rewrite_diff = self.replace_stmt(&mut stmt, sql_dialect);
 
                 // Step 3: Bind AST with catalog, and generate a pure logical SExpr
                 let metadata = Arc::new(RwLock::new(Metadata::default()));
@@ -112,11 +162,13 @@ impl Planner {
                     metadata,
                     format,
                     stament: stmt,
+                    rewrite_diff,
                 }))
             }
             .await;
 
-            if res.is_err() && matches!(tokenizer.peek(), Some(Ok(_))) {
+            if res.is_err() && has_more_tokens {
+                probed = true;
                 // Remove the previous EOI.
                 tokens.pop();
                 // Tokenize more and try again.
@@ -137,12 +189,34 @@ impl Planner {
                         .chain(std::iter::once(Token::new_eoi(sql)));
                     tokens.extend(iter);
                 };
+            } else if probed {
+                return res.map_err(|err| {
+                    if err.code() != ErrorCode::SYNTAX_EXCEPTION {
+                        return err;
+                    }
+                    err.add_message_back(format!(
+                        "\n(the INSERT statement was parsed incrementally and failed after {} tokens were probed, the error is likely in the VALUES payload)",
+                        tokens.len()
+                    ))
+                });
             } else {
                 return res;
             }
         }
     }
 
+    /// Plans each of the semicolon separated statements of `sql` in order.
+    ///
+    /// Unlike `plan_sql`, the whole SQL is tokenized at once, so the VALUES of INSERT statements
+    /// must be valid tokens.
+    pub async fn plan_sqls(&mut self, sql: &str) -> Result<Vec<(Plan, PlanExtras)>> {
+        let mut plans = vec![];
+        for stmt in split_statements(sql)? {
+            plans.push(self.plan_sql(stmt).await?);
+        }
+        Ok(plans)
+    }
+
     fn add_max_rows_limit(&self, statement: &mut Statement) {
         let max_rows = self.ctx.get_settings().get_max_result_rows().unwrap();
         if max_rows == 0 {
@@ -159,10 +233,92 @@ impl Planner {
         }
     }
 
-    fn replace_stmt(&self, stmt: &mut Statement, sql_dialect: Dialect) {
+    /// Rewrites the statement before binding, and returns the diff of the rewrite for auditing.
+    fn replace_stmt(&self, stmt: &mut Statement, sql_dialect: Dialect) -> String {
+        let original = stmt.clone();
+
         walk_statement_mut(&mut DistinctToGroupBy::default(), stmt);
         walk_statement_mut(&mut AggregateRewriter { sql_dialect }, stmt);
 
         self.add_max_rows_limit(stmt);
+
+        if *stmt == original {
+            String::new()
+        } else {
+            format!("- {}\n+ {}", original.to_mask_sql(), stmt.to_mask_sql())
+        }
+    }
+}
+
+/// Parses the dialect hint of a statement, which is a leading comment like `-- dialect: mysql`.
+fn parse_dialect_hint(sql: &str) -> Option<Dialect> {
+    let hint = sql.trim_start().strip_prefix("--")?.lines().next()?;
+    let (key, value) = hint.split_once(':')?;
+    if !key.trim().eq_ignore_ascii_case("dialect") {
+        return None;
+    }
+
+    match &*value.trim().to_lowercase() {
+        "mysql" => Some(Dialect::MySQL),
+        "hive" => Some(Dialect::Hive),
+        "postgresql" | "postgres" => Some(Dialect::PostgreSQL),
+        _ => None,
+    }
+}
+
+/// Splits a SQL string into its semicolon separated statements, every statement keeps its
+/// ending semicolon.
+fn split_statements(sql: &str) -> Result<Vec<&str>> {
+    let mut stmts = vec![];
+    let mut start = None;
+    for token in Tokenizer::new(sql) {
+        let token = token?;
+        match token.kind {
+            TokenKind::EOI => {
+                if let Some(start) = start {
+                    stmts.push(&sql[start..]);
+                }
+                break;
+            }
+            TokenKind::SemiColon => {
+                if let Some(start) = start.take() {
+                    stmts.push(&sql[start..token.span.end]);
+                }
+            }
+            _ => {
+                start.get_or_insert(token.span.start);
+            }
+        }
+    }
+    Ok(stmts)
+}
+
+/// Checks if the VALUES of an INSERT statement contain a `RETURNING` clause, which happens
+/// when the statement is parsed from the probed tokens and the clause is beyond them.
+fn values_contain_returning(stmt: &Statement) -> bool {
+    let rest_str = match stmt {
+        Statement::Insert(InsertStmt {
+            source: InsertSource::Values { rest_str },
+            returning,
+            ..
+        }) if returning.is_empty() => rest_str,
+        _ => return false,
+    };
+
+    let mut depth = 0;
+    for token in Tokenizer::new(rest_str) {
+        // The VALUES may be not valid for the tokenizer, there's no `RETURNING` clause
+        // that can be parsed then.
+        let token = match token {
+            Ok(token) => token,
+            Err(_) => return false,
+        };
+        match token.kind {
+            TokenKind::LParen => depth += 1,
+            TokenKind::RParen => depth -= 1,
+            TokenKind::RETURNING if depth == 0 => return true,
+            _ => {}
+        }
     }
+    false
 }
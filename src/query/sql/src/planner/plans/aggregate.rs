@@ -108,9 +108,16 @@ impl Operator for Aggregate {
                     // Scalar aggregation
                     required.distribution = Distribution::Any;
                 } else {
-                    // Group aggregation, enforce `Hash` distribution
-                    required.distribution =
-                        Distribution::Hash(vec![self.group_items[0].scalar.clone()]);
+                    // Group aggregation, enforce `Hash` distribution.
+                    // The grouping id is generated by the expand of grouping sets,
+                    // so it can't be used as the key of the exchange.
+                    let key = self.group_items.iter().find(|item| {
+                        self.grouping_sets.is_empty() || item.index != self.grouping_id_index
+                    });
+                    required.distribution = match key {
+                        Some(item) => Distribution::Hash(vec![item.scalar.clone()]),
+                        None => Distribution::Serial,
+                    };
                 }
             }
 
@@ -15,9 +15,23 @@
 use std::fmt::Debug;
 use std::fmt::Formatter;
 use std::str::FromStr;
+use std::sync::Arc;
 
+use common_catalog::plan::DataSourceInfo;
 use common_catalog::plan::DataSourcePlan;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
+use common_expression::types::UInt64Type;
+use common_expression::DataBlock;
+use common_expression::DataField;
+use common_expression::DataSchema;
+use common_expression::DataSchemaRef;
+use common_expression::DataSchemaRefExt;
+use common_expression::FromData;
+use common_expression::FromOptData;
 use common_expression::TableSchemaRef;
+use common_meta_app::principal::OnErrorMode;
 use common_meta_app::principal::StageInfo;
 use common_meta_types::MetaId;
 use common_storage::StageFileInfo;
@@ -39,16 +53,14 @@ impl FromStr for ValidationMode {
             "" => Ok(ValidationMode::None),
             "RETURN_ERRORS" => Ok(ValidationMode::ReturnErrors),
             "RETURN_ALL_ERRORS" => Ok(ValidationMode::ReturnAllErrors),
-            v => {
-                let rows_str = v.replace("RETURN_", "").replace("_ROWS", "");
-                let rows = rows_str.parse::<u64>();
-                match rows {
-                    Ok(v) => Ok(ValidationMode::ReturnNRows(v)),
-                    Err(_) => Err(format!(
-                        "Unknown validation mode:{v:?}, must one of {{ RETURN_<n>_ROWS | RETURN_ERRORS | RETURN_ALL_ERRORS}}"
-                    )),
-                }
-            }
+            v => v
+                .strip_prefix("RETURN_")
+                .and_then(|v| v.strip_suffix("_ROWS"))
+                .and_then(|rows| rows.parse::<u64>().ok())
+                .map(ValidationMode::ReturnNRows)
+                .ok_or_else(|| format!(
+                    "Unknown validation mode:{v:?}, must one of {{ RETURN_<n>_ROWS | RETURN_ERRORS | RETURN_ALL_ERRORS}}"
+                )),
         }
     }
 }
@@ -86,6 +98,122 @@ pub enum CopyPlan {
     },
 }
 
+impl CopyPlan {
+    /// Copy returns a [`CopySummary`], except when only validating the files copied into a
+    /// table: `RETURN_<n>_ROWS` returns the first n rows as they would be loaded, `RETURN_ERRORS`
+    /// and `RETURN_ALL_ERRORS` return the error found in each file. Copying files into a table
+    /// with `ON_ERROR = CONTINUE` returns a [`CopyFileSummary`] for each file.
+    pub fn schema(&self) -> DataSchemaRef {
+        match self {
+            CopyPlan::IntoTable {
+                schema,
+                validation_mode,
+                from,
+                ..
+            } => match validation_mode {
+                ValidationMode::None => match &from.source_info {
+                    DataSourceInfo::StageSource(info)
+                        if info.stage_info.copy_options.on_error == OnErrorMode::Continue =>
+                    {
+                        CopyFileSummary::schema()
+                    }
+                    _ => CopySummary::schema(),
+                },
+                ValidationMode::ReturnNRows(_) => Arc::new(DataSchema::from(schema)),
+                ValidationMode::ReturnErrors | ValidationMode::ReturnAllErrors => {
+                    DataSchemaRefExt::create(vec![
+                        DataField::new("file", DataType::String),
+                        DataField::new("error", DataType::String),
+                    ])
+                }
+            },
+            CopyPlan::IntoTableWithTransform { stage_info, .. }
+                if stage_info.copy_options.on_error == OnErrorMode::Continue =>
+            {
+                CopyFileSummary::schema()
+            }
+            CopyPlan::IntoTableWithTransform { .. } | CopyPlan::IntoStage { .. } => {
+                CopySummary::schema()
+            }
+        }
+    }
+}
+
+/// The summary returned by a copy, the same for loading files into a table and for
+/// unloading data into a stage.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CopySummary {
+    /// Files loaded into the table, or unloaded into the stage.
+    pub files_processed: u64,
+    pub rows_loaded: u64,
+    /// Rows skipped because of errors, see `ON_ERROR`.
+    pub rows_failed: u64,
+    /// Size of the files loaded, or of the data unloaded.
+    pub bytes: u64,
+}
+
+impl CopySummary {
+    pub fn schema() -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![
+            DataField::new("files_processed", DataType::Number(NumberDataType::UInt64)),
+            DataField::new("rows_loaded", DataType::Number(NumberDataType::UInt64)),
+            DataField::new("rows_failed", DataType::Number(NumberDataType::UInt64)),
+            DataField::new("bytes", DataType::Number(NumberDataType::UInt64)),
+        ])
+    }
+
+    pub fn to_block(&self) -> DataBlock {
+        DataBlock::new_from_columns(vec![
+            UInt64Type::from_data(vec![self.files_processed]),
+            UInt64Type::from_data(vec![self.rows_loaded]),
+            UInt64Type::from_data(vec![self.rows_failed]),
+            UInt64Type::from_data(vec![self.bytes]),
+        ])
+    }
+}
+
+/// The summary of a file loaded into a table with `ON_ERROR = CONTINUE`, which may be
+/// loaded partially.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CopyFileSummary {
+    pub file: String,
+    pub rows_loaded: u64,
+    /// Rows skipped because of errors.
+    pub errors_seen: u64,
+    /// The most frequent error of the skipped rows.
+    pub error: Option<String>,
+}
+
+impl CopyFileSummary {
+    pub fn schema() -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![
+            DataField::new("file", DataType::String),
+            DataField::new("rows_loaded", DataType::Number(NumberDataType::UInt64)),
+            DataField::new("errors_seen", DataType::Number(NumberDataType::UInt64)),
+            DataField::new("error", DataType::String.wrap_nullable()),
+        ])
+    }
+
+    pub fn to_block(summaries: &[CopyFileSummary]) -> DataBlock {
+        DataBlock::new_from_columns(vec![
+            StringType::from_data(
+                summaries
+                    .iter()
+                    .map(|s| s.file.as_str())
+                    .collect::<Vec<_>>(),
+            ),
+            UInt64Type::from_data(summaries.iter().map(|s| s.rows_loaded).collect::<Vec<_>>()),
+            UInt64Type::from_data(summaries.iter().map(|s| s.errors_seen).collect::<Vec<_>>()),
+            StringType::from_opt_data(
+                summaries
+                    .iter()
+                    .map(|s| s.error.as_ref().map(|e| e.as_bytes().to_vec()))
+                    .collect::<Vec<_>>(),
+            ),
+        ])
+    }
+}
+
 impl Debug for CopyPlan {
     // Ignore the schema.
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
@@ -23,6 +23,7 @@ use common_meta_types::MetaId;
 use common_pipeline_sources::input_formats::InputContext;
 
 use super::Plan;
+use super::ScalarExpr;
 
 #[derive(Clone)]
 pub enum InsertInputSource {
@@ -52,6 +53,9 @@ pub struct Insert {
     pub schema: TableSchemaRef,
     pub overwrite: bool,
     pub source: InsertInputSource,
+    /// Expressions of the `RETURNING` clause, bound to the columns of the table.
+    pub returning: Vec<ScalarExpr>,
+    pub returning_schema: DataSchemaRef,
 }
 
 impl PartialEq for Insert {
@@ -71,6 +75,10 @@ impl Insert {
     pub fn has_select_plan(&self) -> bool {
         matches!(&self.source, InsertInputSource::SelectPlan(_))
     }
+
+    pub fn has_returning(&self) -> bool {
+        !self.returning.is_empty()
+    }
 }
 
 impl std::fmt::Debug for Insert {
@@ -82,6 +90,7 @@ impl std::fmt::Debug for Insert {
             .field("table_id", &self.table_id)
             .field("schema", &self.schema)
             .field("overwrite", &self.overwrite)
+            .field("returning", &self.returning)
             .finish()
     }
 }
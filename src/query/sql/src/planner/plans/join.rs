@@ -134,6 +134,8 @@ pub struct Join {
     pub left_conditions: Vec<ScalarExpr>,
     pub right_conditions: Vec<ScalarExpr>,
     pub non_equi_conditions: Vec<ScalarExpr>,
+    // The indexes of the equi conditions which are null-safe equal, i.e. `<=>`.
+    pub is_null_equal: Vec<usize>,
     pub join_type: JoinType,
     // marker_index is for MarkJoin only.
     pub marker_index: Option<IndexType>,
@@ -148,6 +150,7 @@ impl Default for Join {
             left_conditions: Default::default(),
             right_conditions: Default::default(),
             non_equi_conditions: Default::default(),
+            is_null_equal: Default::default(),
             join_type: JoinType::Cross,
             marker_index: Default::default(),
             from_correlated_subquery: Default::default(),
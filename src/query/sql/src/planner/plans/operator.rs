@@ -33,7 +33,9 @@ use crate::optimizer::RelExpr;
 use crate::optimizer::RelationalProperty;
 use crate::optimizer::RequiredProperty;
 use crate::plans::runtime_filter_source::RuntimeFilterSource;
+use crate::plans::CteScan;
 use crate::plans::Exchange;
+use crate::plans::MaterializedCte;
 use crate::plans::Window;
 
 pub trait Operator {
@@ -71,6 +73,8 @@ pub enum RelOp {
     DummyTableScan,
     RuntimeFilterSource,
     Window,
+    MaterializedCte,
+    CteScan,
 
     // Pattern
     Pattern,
@@ -91,6 +95,8 @@ pub enum RelOperator {
     DummyTableScan(DummyTableScan),
     RuntimeFilterSource(RuntimeFilterSource),
     Window(Window),
+    MaterializedCte(MaterializedCte),
+    CteScan(CteScan),
 
     Pattern(PatternPlan),
 }
@@ -111,6 +117,8 @@ impl Operator for RelOperator {
             RelOperator::DummyTableScan(rel_op) => rel_op.rel_op(),
             RelOperator::RuntimeFilterSource(rel_op) => rel_op.rel_op(),
             RelOperator::Window(rel_op) => rel_op.rel_op(),
+            RelOperator::MaterializedCte(rel_op) => rel_op.rel_op(),
+            RelOperator::CteScan(rel_op) => rel_op.rel_op(),
         }
     }
 
@@ -129,6 +137,8 @@ impl Operator for RelOperator {
             RelOperator::DummyTableScan(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::RuntimeFilterSource(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::Window(rel_op) => rel_op.derive_relational_prop(rel_expr),
+            RelOperator::MaterializedCte(rel_op) => rel_op.derive_relational_prop(rel_expr),
+            RelOperator::CteScan(rel_op) => rel_op.derive_relational_prop(rel_expr),
         }
     }
 
@@ -147,6 +157,8 @@ impl Operator for RelOperator {
             RelOperator::DummyTableScan(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::RuntimeFilterSource(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::Window(rel_op) => rel_op.derive_physical_prop(rel_expr),
+            RelOperator::MaterializedCte(rel_op) => rel_op.derive_physical_prop(rel_expr),
+            RelOperator::CteScan(rel_op) => rel_op.derive_physical_prop(rel_expr),
         }
     }
 
@@ -197,6 +209,12 @@ impl Operator for RelOperator {
             RelOperator::Window(rel_op) => {
                 rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
             }
+            RelOperator::MaterializedCte(rel_op) => {
+                rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
+            }
+            RelOperator::CteScan(rel_op) => {
+                rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
+            }
         }
     }
 }
@@ -312,6 +330,44 @@ impl TryFrom<RelOperator> for Window {
     }
 }
 
+impl From<MaterializedCte> for RelOperator {
+    fn from(v: MaterializedCte) -> Self {
+        Self::MaterializedCte(v)
+    }
+}
+
+impl TryFrom<RelOperator> for MaterializedCte {
+    type Error = ErrorCode;
+    fn try_from(value: RelOperator) -> Result<Self> {
+        if let RelOperator::MaterializedCte(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::Internal(
+                "Cannot downcast RelOperator to MaterializedCte",
+            ))
+        }
+    }
+}
+
+impl From<CteScan> for RelOperator {
+    fn from(v: CteScan) -> Self {
+        Self::CteScan(v)
+    }
+}
+
+impl TryFrom<RelOperator> for CteScan {
+    type Error = ErrorCode;
+    fn try_from(value: RelOperator) -> Result<Self> {
+        if let RelOperator::CteScan(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::Internal(
+                "Cannot downcast RelOperator to CteScan",
+            ))
+        }
+    }
+}
+
 impl From<Sort> for RelOperator {
     fn from(v: Sort) -> Self {
         Self::Sort(v)
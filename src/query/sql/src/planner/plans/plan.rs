@@ -341,7 +341,7 @@ impl Plan {
             Plan::ExplainAnalyze { .. } => {
                 DataSchemaRefExt::create(vec![DataField::new("explain", DataType::String)])
             }
-            Plan::Copy(_) => Arc::new(DataSchema::empty()),
+            Plan::Copy(plan) => plan.schema(),
             Plan::ShowCreateCatalog(plan) => plan.schema(),
             Plan::CreateCatalog(plan) => plan.schema(),
             Plan::DropCatalog(plan) => plan.schema(),
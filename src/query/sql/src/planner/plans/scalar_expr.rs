@@ -79,6 +79,12 @@ impl ScalarExpr {
                 for scalar in &scalar.agg_func.args {
                     result = result.union(&scalar.used_columns()).cloned().collect();
                 }
+                for scalar in &scalar.partition_by {
+                    result = result.union(&scalar.used_columns()).cloned().collect();
+                }
+                for order in &scalar.order_by {
+                    result = result.union(&order.expr.used_columns()).cloned().collect();
+                }
                 result
             }
             ScalarExpr::AggregateFunction(scalar) => {
@@ -446,6 +452,18 @@ impl ComparisonOp {
             ComparisonOp::LTE => "lte",
         }
     }
+
+    /// The SQL operator of the comparison, as displayed by `Expr::sql_display`.
+    pub fn to_sql_op(&self) -> &'static str {
+        match &self {
+            ComparisonOp::Equal => "=",
+            ComparisonOp::NotEqual => "<>",
+            ComparisonOp::GT => ">",
+            ComparisonOp::LT => "<",
+            ComparisonOp::GTE => ">=",
+            ComparisonOp::LTE => "<=",
+        }
+    }
 }
 
 impl<'a> TryFrom<&'a BinaryOperator> for ComparisonOp {
@@ -478,6 +496,7 @@ pub struct AggregateFunction {
 pub struct WindowFunc {
     pub agg_func: AggregateFunction,
     pub partition_by: Vec<ScalarExpr>,
+    pub order_by: Vec<WindowOrderBy>,
     pub frame: WindowFuncFrame,
 }
 
@@ -487,6 +506,15 @@ impl WindowFunc {
     }
 }
 
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct WindowOrderBy {
+    pub expr: ScalarExpr,
+    // Optional `ASC` or `DESC`
+    pub asc: Option<bool>,
+    // Optional `NULLS FIRST` or `NULLS LAST`
+    pub nulls_first: Option<bool>,
+}
+
 #[derive(Clone, PartialEq, Eq, Hash, Debug)]
 pub struct WindowFuncFrame {
     pub units: WindowFuncFrameUnits,
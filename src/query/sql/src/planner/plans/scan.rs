@@ -15,6 +15,7 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use common_catalog::plan::PageLimit;
 use common_catalog::table::ColumnStatistics;
 use common_catalog::table::TableStatistics;
 use common_catalog::table_context::TableContext;
@@ -66,6 +67,7 @@ pub struct Scan {
     pub limit: Option<usize>,
     pub order_by: Option<Vec<SortItem>>,
     pub prewhere: Option<Prewhere>,
+    pub page_limit: Option<PageLimit>,
 
     pub statistics: Statistics,
 }
@@ -86,6 +88,7 @@ impl Scan {
             push_down_predicates: self.push_down_predicates.clone(),
             limit: self.limit,
             order_by: self.order_by.clone(),
+            page_limit: self.page_limit.clone(),
             statistics: Statistics {
                 statistics: self.statistics.statistics,
                 col_stats,
@@ -172,8 +175,9 @@ impl Operator for Scan {
             }
         }
 
-        // If prewhere is not none, we can't get precise cardinality
-        let precise_cardinality = if self.prewhere.is_none() {
+        // If there are pushed down filters or prewhere, we can't get precise cardinality
+        let has_filter = self.push_down_predicates.is_some() || self.prewhere.is_some();
+        let precise_cardinality = if !has_filter {
             self.statistics
                 .statistics
                 .as_ref()
@@ -19,6 +19,7 @@ use common_expression::DataSchema;
 use common_expression::DataSchemaRef;
 use common_expression::FieldIndex;
 
+use crate::plans::Plan;
 use crate::plans::ScalarExpr;
 use crate::BindContext;
 
@@ -30,6 +31,8 @@ pub struct UpdatePlan {
     pub update_list: HashMap<FieldIndex, ScalarExpr>,
     pub selection: Option<ScalarExpr>,
     pub bind_context: Box<BindContext>,
+    // The case: UPDATE ... FROM, the query of the matched rows and their new values
+    pub from: Option<Box<Plan>>,
 }
 
 impl UpdatePlan {
@@ -35,10 +35,21 @@ pub struct Window {
     pub aggregate_function: ScalarItem,
     // partition by scalar expressions
     pub partition_by: Vec<ScalarItem>,
+    // order by scalar expressions
+    pub order_by: Vec<WindowOrderByInfo>,
     // window frames
     pub frame: WindowFuncFrame,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct WindowOrderByInfo {
+    pub order_by_item: ScalarItem,
+    // Optional `ASC` or `DESC`
+    pub asc: Option<bool>,
+    // Optional `NULLS FIRST` or `NULLS LAST`
+    pub nulls_first: Option<bool>,
+}
+
 impl Window {
     pub fn used_columns(&self) -> Result<ColumnSet> {
         let mut used_columns = ColumnSet::new();
@@ -51,6 +62,11 @@ impl Window {
             used_columns.extend(part.scalar.used_columns())
         }
 
+        for order in self.order_by.iter() {
+            used_columns.insert(order.order_by_item.index);
+            used_columns.extend(order.order_by_item.scalar.used_columns())
+        }
+
         Ok(used_columns)
     }
 }
@@ -79,10 +95,10 @@ impl Operator for Window {
         }
 
         if self.partition_by.is_empty() {
-            // Scalar aggregation
-            required.distribution = Distribution::Any;
+            // The whole input is a single partition, enforce `Serial` distribution
+            required.distribution = Distribution::Serial;
         } else {
-            // Partition aggregation, enforce `Hash` distribution
+            // Partition window, enforce `Hash` distribution
             required.distribution = Distribution::Hash(vec![self.partition_by[0].scalar.clone()]);
         }
 
@@ -93,7 +109,8 @@ impl Operator for Window {
         let input_prop = rel_expr.derive_relational_prop_child(0)?;
 
         // Derive output columns
-        let output_columns = ColumnSet::from([self.aggregate_function.index]);
+        let mut output_columns = input_prop.output_columns.clone();
+        output_columns.insert(self.aggregate_function.index);
 
         // Derive outer columns
         let outer_columns = input_prop
@@ -102,32 +119,9 @@ impl Operator for Window {
             .cloned()
             .collect();
 
-        let cardinality = if self.partition_by.is_empty() {
-            // Scalar aggregation
-            1.0
-        } else if self.partition_by.iter().any(|item| {
-            input_prop
-                .statistics
-                .column_stats
-                .get(&item.index)
-                .is_none()
-        }) {
-            input_prop.cardinality
-        } else {
-            // A upper bound
-            let res = self.partition_by.iter().fold(1.0, |acc, item| {
-                let item_stat = input_prop.statistics.column_stats.get(&item.index).unwrap();
-                acc * item_stat.ndv
-            });
-            // To avoid res is very large
-            f64::min(res, input_prop.cardinality)
-        };
-
-        let precise_cardinality = if self.partition_by.is_empty() {
-            Some(1)
-        } else {
-            None
-        };
+        // Window functions don't change the cardinality of input
+        let cardinality = input_prop.cardinality;
+        let precise_cardinality = input_prop.statistics.precise_cardinality;
 
         // Derive used columns
         let mut used_columns = self.used_columns()?;
@@ -77,7 +77,7 @@ impl<'a> GroupingChecker<'a> {
                 Err(ErrorCode::SemanticError(format!(
                     "column \"{}\" must appear in the GROUP BY clause or be used in an aggregate function",
                     &column.column.column_name
-                )).set_span(span))
+                )).set_span(column.span.or(span)))
             }
             ScalarExpr::BoundInternalColumnRef(column) => {
                 // If this is a group item, then it should have been replaced with `group_items_map`
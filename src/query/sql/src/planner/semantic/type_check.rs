@@ -31,6 +31,7 @@ use common_ast::ast::UnaryOperator;
 use common_ast::ast::WindowFrame;
 use common_ast::ast::WindowFrameBound;
 use common_ast::ast::WindowFrameUnits;
+use common_ast::ast::WindowSpec;
 use common_ast::parser::parse_expr;
 use common_ast::parser::tokenize_sql;
 use common_catalog::catalog::CatalogManager;
@@ -56,6 +57,8 @@ use common_functions::aggregates::AggregateCountFunction;
 use common_functions::aggregates::AggregateFunctionFactory;
 use common_functions::is_builtin_function;
 use common_functions::scalars::BUILTIN_FUNCTIONS;
+use common_meta_app::principal::GrantObject;
+use common_meta_app::principal::UserPrivilegeType;
 use common_users::UserApiProvider;
 
 use super::name_resolution::NameResolutionContext;
@@ -84,6 +87,7 @@ use crate::plans::WindowFunc;
 use crate::plans::WindowFuncFrame;
 use crate::plans::WindowFuncFrameBound;
 use crate::plans::WindowFuncFrameUnits;
+use crate::plans::WindowOrderBy;
 use crate::BaseTableColumn;
 use crate::BindContext;
 use crate::ColumnBinding;
@@ -233,65 +237,14 @@ impl<'a> TypeChecker<'a> {
                 right,
                 not,
             } => {
-                let left_null_expr = Box::new(Expr::IsNull {
-                    span: *span,
-                    expr: left.clone(),
-                    not: false,
-                });
-                let right_null_expr = Box::new(Expr::IsNull {
-                    span: *span,
-                    expr: right.clone(),
-                    not: false,
-                });
-                let op = if *not {
-                    BinaryOperator::Eq
+                let name = if *not {
+                    "is_not_distinct_from"
                 } else {
-                    BinaryOperator::NotEq
+                    "is_distinct_from"
                 };
-                let (scalar, _) = *self
-                    .resolve_function(
-                        *span,
-                        "if",
-                        vec![],
-                        &[
-                            &Expr::BinaryOp {
-                                span: *span,
-                                op: BinaryOperator::And,
-                                left: left_null_expr.clone(),
-                                right: right_null_expr.clone(),
-                            },
-                            &Expr::Literal {
-                                span: *span,
-                                lit: Literal::Boolean(*not),
-                            },
-                            &Expr::BinaryOp {
-                                span: *span,
-                                op: BinaryOperator::Or,
-                                left: left_null_expr.clone(),
-                                right: right_null_expr.clone(),
-                            },
-                            &Expr::Literal {
-                                span: *span,
-                                lit: Literal::Boolean(!*not),
-                            },
-                            &Expr::BinaryOp {
-                                span: *span,
-                                op,
-                                left: left.clone(),
-                                right: right.clone(),
-                            },
-                        ],
-                        None,
-                    )
-                    .await?;
-                self.resolve_scalar_function_call(
-                    *span,
-                    "assume_not_null",
-                    vec![],
-                    vec![scalar],
-                    required_type,
-                )
-                .await?
+                let args = &[left.as_ref(), right.as_ref()];
+                self.resolve_function(*span, name, vec![], args, required_type)
+                    .await?
             }
 
             Expr::InList {
@@ -703,6 +656,7 @@ impl<'a> TypeChecker<'a> {
                     self.in_aggregate_function = false;
 
                     // Rewrite `xxx(distinct)` to `xxx_distinct(...)`
+                    let is_distinct = *distinct;
                     let (func_name, distinct) =
                         if func_name.eq_ignore_ascii_case("count") && *distinct {
                             ("count_distinct", false)
@@ -730,7 +684,7 @@ impl<'a> TypeChecker<'a> {
                     let new_agg_func = AggregateFunction {
                         display_name: format!("{:#}", expr),
                         func_name,
-                        distinct: false,
+                        distinct: is_distinct,
                         params,
                         args,
                         return_type: Box::new(agg_func.return_type()?),
@@ -740,19 +694,8 @@ impl<'a> TypeChecker<'a> {
 
                     if let Some(window) = window {
                         // window function
-                        let mut partitions = vec![];
-                        for p in window.partition_by.iter() {
-                            let box (part, _part_type) = self.resolve(p, None).await?;
-                            partitions.push(part);
-                        }
-                        self.resolve_window(
-                            *span,
-                            new_agg_func.clone(),
-                            partitions,
-                            window.window_frame.clone(),
-                            data_type.clone(),
-                        )
-                        .await?
+                        self.resolve_window(*span, new_agg_func, window, data_type)
+                            .await?
                     } else {
                         Box::new((new_agg_func.into(), data_type))
                     }
@@ -985,64 +928,129 @@ impl<'a> TypeChecker<'a> {
     #[async_recursion::async_recursion]
     pub async fn resolve_window(
         &mut self,
-        _span: Span,
+        span: Span,
         agg_func: AggregateFunction,
-        partitions: Vec<ScalarExpr>,
-        window_frame: Option<WindowFrame>,
+        window: &WindowSpec,
         return_type: DataType,
     ) -> Result<Box<(ScalarExpr, DataType)>> {
-        let frame = window_frame.unwrap();
-        let units = match frame.units.clone() {
-            WindowFrameUnits::Rows => WindowFuncFrameUnits::Rows,
-            WindowFrameUnits::Range => WindowFuncFrameUnits::Range,
+        let mut partitions = Vec::with_capacity(window.partition_by.len());
+        for p in window.partition_by.iter() {
+            let box (part, _part_type) = self.resolve(p, None).await?;
+            partitions.push(part);
+        }
+
+        let mut order_by = Vec::with_capacity(window.order_by.len());
+        for o in window.order_by.iter() {
+            let box (expr, _expr_type) = self.resolve(&o.expr, None).await?;
+            order_by.push(WindowOrderBy {
+                expr,
+                asc: o.asc,
+                nulls_first: o.nulls_first,
+            });
+        }
+
+        let frame = self.resolve_window_frame(span, &order_by, &window.window_frame)?;
+
+        let window_func = WindowFunc {
+            agg_func,
+            partition_by: partitions,
+            order_by,
+            frame,
         };
-        let start = match frame.start_bound {
-            WindowFrameBound::CurrentRow => WindowFuncFrameBound::CurrentRow,
-            WindowFrameBound::Preceding(f) => {
-                if let Some(box expr) = f {
-                    let box (result_expr, _) = self.resolve(&expr, None).await?;
-                    WindowFuncFrameBound::Preceding(Some(Box::new(result_expr)))
-                } else {
-                    WindowFuncFrameBound::Preceding(None)
-                }
+
+        Ok(Box::new((window_func.into(), return_type)))
+    }
+
+    // If no frame is specified, the default frame is
+    // `RANGE BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW` when the window is ordered,
+    // otherwise the whole partition.
+    fn resolve_window_frame(
+        &self,
+        span: Span,
+        order_by: &[WindowOrderBy],
+        window_frame: &Option<WindowFrame>,
+    ) -> Result<WindowFuncFrame> {
+        let frame = match window_frame {
+            Some(frame) => frame,
+            None if order_by.is_empty() => {
+                return Ok(WindowFuncFrame {
+                    units: WindowFuncFrameUnits::Rows,
+                    start: WindowFuncFrameBound::Preceding(None),
+                    end: WindowFuncFrameBound::Following(None),
+                });
             }
-            WindowFrameBound::Following(f) => {
-                if let Some(box expr) = f {
-                    let box (result_expr, _) = self.resolve(&expr, None).await?;
-                    WindowFuncFrameBound::Following(Some(Box::new(result_expr)))
-                } else {
-                    WindowFuncFrameBound::Following(None)
-                }
+            None => {
+                return Ok(WindowFuncFrame {
+                    units: WindowFuncFrameUnits::Range,
+                    start: WindowFuncFrameBound::Preceding(None),
+                    end: WindowFuncFrameBound::CurrentRow,
+                });
             }
         };
 
-        let end = match frame.end_bound {
-            WindowFrameBound::CurrentRow => WindowFuncFrameBound::CurrentRow,
-            WindowFrameBound::Preceding(f) => {
-                if let Some(box expr) = f {
-                    let box (result_expr, _) = self.resolve(&expr, None).await?;
-                    WindowFuncFrameBound::Preceding(Some(Box::new(result_expr)))
-                } else {
-                    WindowFuncFrameBound::Preceding(None)
-                }
-            }
-            WindowFrameBound::Following(f) => {
-                if let Some(box expr) = f {
-                    let box (result_expr, _) = self.resolve(&expr, None).await?;
-                    WindowFuncFrameBound::Following(Some(Box::new(result_expr)))
-                } else {
-                    WindowFuncFrameBound::Following(None)
-                }
-            }
+        let units = match frame.units {
+            WindowFrameUnits::Rows => WindowFuncFrameUnits::Rows,
+            WindowFrameUnits::Range => WindowFuncFrameUnits::Range,
         };
+        let start = self.resolve_window_frame_bound(span, &units, &frame.start_bound)?;
+        let end = self.resolve_window_frame_bound(span, &units, &frame.end_bound)?;
 
-        let window_func = WindowFunc {
-            agg_func,
-            partition_by: partitions,
-            frame: WindowFuncFrame { units, start, end },
+        if matches!(start, WindowFuncFrameBound::Following(None))
+            || matches!(end, WindowFuncFrameBound::Preceding(None))
+        {
+            return Err(ErrorCode::SemanticError(
+                "Frame start cannot be UNBOUNDED FOLLOWING and frame end cannot be UNBOUNDED PRECEDING",
+            )
+            .set_span(span));
+        }
+
+        Ok(WindowFuncFrame { units, start, end })
+    }
+
+    fn resolve_window_frame_bound(
+        &self,
+        span: Span,
+        units: &WindowFuncFrameUnits,
+        bound: &WindowFrameBound,
+    ) -> Result<WindowFuncFrameBound> {
+        let offset = match bound {
+            WindowFrameBound::CurrentRow => return Ok(WindowFuncFrameBound::CurrentRow),
+            WindowFrameBound::Preceding(None) => return Ok(WindowFuncFrameBound::Preceding(None)),
+            WindowFrameBound::Following(None) => return Ok(WindowFuncFrameBound::Following(None)),
+            WindowFrameBound::Preceding(Some(offset))
+            | WindowFrameBound::Following(Some(offset)) => offset.as_ref(),
         };
 
-        Ok(Box::new((window_func.into(), return_type)))
+        if *units == WindowFuncFrameUnits::Range {
+            return Err(ErrorCode::Unimplemented(
+                "RANGE frame with an offset is not supported yet, use ROWS instead",
+            )
+            .set_span(offset.span()));
+        }
+
+        let offset = match offset {
+            Expr::Literal {
+                span,
+                lit: Literal::UInt64(n),
+            } => ScalarExpr::ConstantExpr(ConstantExpr {
+                span: *span,
+                value: common_expression::Literal::UInt64(*n),
+                data_type: Box::new(DataType::Number(NumberDataType::UInt64)),
+            }),
+            _ => {
+                return Err(ErrorCode::SemanticError(
+                    "Frame offset of ROWS must be a non-negative integer constant",
+                )
+                .set_span(offset.span()));
+            }
+        };
+
+        Ok(match bound {
+            WindowFrameBound::Preceding(_) => {
+                WindowFuncFrameBound::Preceding(Some(Box::new(offset)))
+            }
+            _ => WindowFuncFrameBound::Following(Some(Box::new(offset))),
+        })
     }
 
     /// Resolve function call.
@@ -2042,6 +2050,11 @@ impl<'a> TypeChecker<'a> {
             .get_udf(self.ctx.get_tenant().as_str(), func_name)
             .await;
         if let Ok(udf) = udf {
+            self.ctx
+                .validate_privilege(&GrantObject::UDF(udf.name.clone()), vec![
+                    UserPrivilegeType::Usage,
+                ])
+                .await?;
             let parameters = udf.parameters;
             if parameters.len() != arguments.len() {
                 return Err(ErrorCode::SyntaxException(format!(
@@ -0,0 +1,264 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::types::number::F64;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::DataSchemaRefExt;
+use common_expression::Literal;
+use common_expression::RemoteExpr;
+use common_profile::ProfSpan;
+use common_profile::ProfSpanSetRef;
+use common_sql::executor::AggregateFunctionDesc;
+use common_sql::executor::AggregateFunctionSignature;
+use common_sql::executor::AggregatePartial;
+use common_sql::executor::EvalScalar;
+use common_sql::executor::ExchangeSource;
+use common_sql::executor::Filter;
+use common_sql::executor::Limit;
+use common_sql::executor::PhysicalPlan;
+use common_sql::Metadata;
+use parking_lot::RwLock;
+
+fn exchange_source_plan() -> PhysicalPlan {
+    PhysicalPlan::ExchangeSource(ExchangeSource {
+        schema: DataSchemaRefExt::create(vec![]),
+        source_fragment_id: 1,
+        query_id: String::from("query_id"),
+    })
+}
+
+fn limit_plan() -> PhysicalPlan {
+    PhysicalPlan::Limit(Limit {
+        plan_id: 0,
+        input: Box::new(exchange_source_plan()),
+        limit: Some(3),
+        offset: 0,
+        stat_info: None,
+    })
+}
+
+#[test]
+fn test_format_physical_plan_profiling() -> Result<()> {
+    let plan = limit_plan();
+    let metadata = Arc::new(RwLock::new(Metadata::default()));
+    let prof_span_set = ProfSpanSetRef::default();
+    prof_span_set.lock().unwrap().update(0, ProfSpan {
+        process_time: 2_000_000,
+        output_rows: 3,
+        output_bytes: 24,
+        peak_memory_usage: 1024,
+    });
+
+    // EXPLAIN
+    let explain = plan
+        .format(metadata.clone(), prof_span_set.clone(), false)?
+        .format_pretty()?;
+    assert_eq!(
+        explain,
+        "Limit\n├── limit: 3\n├── offset: 0\n└── ExchangeSource\n    └── source fragment: [1]\n"
+    );
+
+    // EXPLAIN ANALYZE
+    let explain_analyze = plan
+        .format(metadata, prof_span_set, true)?
+        .format_pretty()?;
+    assert_eq!(
+        explain_analyze,
+        "Limit\n├── limit: 3\n├── offset: 0\n├── total process time: 2ms\n├── output rows: 3\n├── output bytes: 24\n├── peak memory usage: 1024\n└── ExchangeSource\n    └── source fragment: [1]\n"
+    );
+
+    Ok(())
+}
+
+fn boolean_column(id: usize, name: &str) -> RemoteExpr {
+    RemoteExpr::ColumnRef {
+        span: None,
+        id,
+        data_type: DataType::Boolean,
+        display_name: name.to_string(),
+    }
+}
+
+#[test]
+fn test_format_filter_selectivities() -> Result<()> {
+    let plan = PhysicalPlan::Filter(Filter {
+        plan_id: 0,
+        input: Box::new(exchange_source_plan()),
+        predicates: vec![boolean_column(0, "a"), boolean_column(1, "b")],
+        selectivities: vec![0.5, 0.25],
+        stat_info: None,
+    });
+    let metadata = Arc::new(RwLock::new(Metadata::default()));
+
+    let explain = plan
+        .format(metadata, ProfSpanSetRef::default(), false)?
+        .format_pretty()?;
+    assert_eq!(
+        explain,
+        "Filter\n├── filters: [a, b]\n├── estimated selectivities: [a: 0.50, b: 0.25]\n└── ExchangeSource\n    └── source fragment: [1]\n"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_format_json() -> Result<()> {
+    let plan = PhysicalPlan::Filter(Filter {
+        plan_id: 0,
+        input: Box::new(exchange_source_plan()),
+        predicates: vec![boolean_column(0, "a"), boolean_column(1, "b")],
+        selectivities: vec![0.5, 0.25],
+        stat_info: None,
+    });
+    let metadata = Arc::new(RwLock::new(Metadata::default()));
+
+    let explain = plan.format_json(metadata, ProfSpanSetRef::default(), false)?;
+    assert_eq!(
+        explain,
+        serde_json::json!({
+            "name": "Filter",
+            "properties": {
+                "filters": "[a, b]",
+                "estimated selectivities": "[a: 0.50, b: 0.25]",
+            },
+            "children": [{
+                "name": "ExchangeSource",
+                "properties": {
+                    "source fragment": "[1]",
+                },
+                "children": [],
+            }],
+        })
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_format_eval_scalar_nullable_outputs() -> Result<()> {
+    let column = |id: usize, name: &str, data_type: DataType| RemoteExpr::ColumnRef {
+        span: None,
+        id,
+        data_type,
+        display_name: name.to_string(),
+    };
+    let plan = PhysicalPlan::EvalScalar(EvalScalar {
+        plan_id: 0,
+        input: Box::new(exchange_source_plan()),
+        exprs: vec![
+            (column(0, "a", DataType::String), 1),
+            (
+                column(1, "b", DataType::Nullable(Box::new(DataType::String))),
+                2,
+            ),
+        ],
+        stat_info: None,
+    });
+    let metadata = Arc::new(RwLock::new(Metadata::default()));
+
+    let explain = plan
+        .format(metadata, ProfSpanSetRef::default(), false)?
+        .format_pretty()?;
+    assert_eq!(
+        explain,
+        "EvalScalar\n├── expressions: [a, b: String NULL]\n└── ExchangeSource\n    └── source fragment: [1]\n"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_format_distinct_aggregate() -> Result<()> {
+    let metadata = Arc::new(RwLock::new(Metadata::default()));
+    let number = DataType::Number(NumberDataType::UInt64);
+    let a = metadata
+        .write()
+        .add_derived_column("a".to_string(), number.clone());
+
+    let agg_func = |name: &str, distinct: bool, output_column: usize| AggregateFunctionDesc {
+        sig: AggregateFunctionSignature {
+            name: name.to_string(),
+            args: vec![number.clone()],
+            params: vec![],
+            return_type: number.clone(),
+            distinct,
+        },
+        output_column,
+        args: vec![0],
+        arg_indices: vec![a],
+    };
+    let plan = PhysicalPlan::AggregatePartial(AggregatePartial {
+        plan_id: 0,
+        input: Box::new(exchange_source_plan()),
+        group_by: vec![],
+        agg_funcs: vec![
+            agg_func("count", false, 1),
+            agg_func("count_distinct", true, 2),
+            agg_func("approx_count_distinct", false, 3),
+        ],
+        stat_info: None,
+    });
+
+    let explain = plan
+        .format(metadata, ProfSpanSetRef::default(), false)?
+        .format_pretty()?;
+    assert_eq!(
+        explain,
+        "AggregatePartial\n├── group by: []\n├── aggregate functions: [count(a), count(DISTINCT a), approx_count_distinct(a)]\n└── ExchangeSource\n    └── source fragment: [1]\n"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_format_parameterized_aggregate() -> Result<()> {
+    let metadata = Arc::new(RwLock::new(Metadata::default()));
+    let number = DataType::Number(NumberDataType::Float64);
+    let a = metadata
+        .write()
+        .add_derived_column("a".to_string(), number.clone());
+
+    let plan = PhysicalPlan::AggregatePartial(AggregatePartial {
+        plan_id: 0,
+        input: Box::new(exchange_source_plan()),
+        group_by: vec![],
+        agg_funcs: vec![AggregateFunctionDesc {
+            sig: AggregateFunctionSignature {
+                name: "quantile".to_string(),
+                args: vec![number.clone()],
+                params: vec![Literal::Float64(F64::from(0.9))],
+                return_type: number,
+                distinct: false,
+            },
+            output_column: 1,
+            args: vec![0],
+            arg_indices: vec![a],
+        }],
+        stat_info: None,
+    });
+
+    let explain = plan
+        .format(metadata, ProfSpanSetRef::default(), false)?
+        .format_pretty()?;
+    assert_eq!(
+        explain,
+        "AggregatePartial\n├── group by: []\n├── aggregate functions: [quantile(0.9)(a)]\n└── ExchangeSource\n    └── source fragment: [1]\n"
+    );
+
+    Ok(())
+}
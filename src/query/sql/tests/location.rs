@@ -14,7 +14,9 @@
 
 #![allow(clippy::uninlined_format_args)]
 
+mod executor;
 mod optimizer;
+mod planner;
 
 use std::collections::BTreeMap;
 
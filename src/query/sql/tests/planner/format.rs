@@ -0,0 +1,156 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::Literal;
+use common_sql::format_scalar;
+use common_sql::format_scalar_with_options;
+use common_sql::optimizer::SExpr;
+use common_sql::plans::AggregateFunction;
+use common_sql::plans::BoundColumnRef;
+use common_sql::plans::CastExpr;
+use common_sql::plans::ComparisonExpr;
+use common_sql::plans::ComparisonOp;
+use common_sql::plans::ConstantExpr;
+use common_sql::plans::DummyTableScan;
+use common_sql::plans::ScalarItem;
+use common_sql::plans::Window;
+use common_sql::plans::WindowFunc;
+use common_sql::plans::WindowFuncFrame;
+use common_sql::plans::WindowFuncFrameBound;
+use common_sql::plans::WindowFuncFrameUnits;
+use common_sql::ColumnBinding;
+use common_sql::Metadata;
+use common_sql::ScalarExpr;
+use common_sql::Visibility;
+use parking_lot::RwLock;
+
+fn column_ref(index: usize, name: &str) -> ScalarExpr {
+    ScalarExpr::BoundColumnRef(BoundColumnRef {
+        span: None,
+        column: ColumnBinding {
+            database_name: None,
+            table_name: Some("t".to_string()),
+            column_name: name.to_string(),
+            index,
+            data_type: Box::new(DataType::Number(NumberDataType::UInt64)),
+            visibility: Visibility::Visible,
+        },
+    })
+}
+
+fn uint64(value: u64) -> Box<ScalarExpr> {
+    Box::new(ScalarExpr::ConstantExpr(ConstantExpr {
+        span: None,
+        value: Literal::UInt64(value),
+        data_type: Box::new(DataType::Number(NumberDataType::UInt64)),
+    }))
+}
+
+#[test]
+fn test_format_window_frame() -> Result<()> {
+    let frame = WindowFuncFrame {
+        units: WindowFuncFrameUnits::Rows,
+        start: WindowFuncFrameBound::Preceding(Some(uint64(2))),
+        end: WindowFuncFrameBound::Following(None),
+    };
+    let window = Window {
+        aggregate_function: ScalarItem {
+            scalar: ScalarExpr::WindowFunction(WindowFunc {
+                agg_func: AggregateFunction {
+                    func_name: "sum".to_string(),
+                    distinct: false,
+                    params: vec![],
+                    args: vec![column_ref(1, "b")],
+                    return_type: Box::new(DataType::Number(NumberDataType::UInt64)),
+                    display_name: "sum(b)".to_string(),
+                },
+                partition_by: vec![column_ref(0, "a")],
+                frame: frame.clone(),
+            }),
+            index: 2,
+        },
+        partition_by: vec![ScalarItem {
+            scalar: column_ref(0, "a"),
+            index: 0,
+        }],
+        frame,
+    };
+    let s_expr = SExpr::create_unary(window.into(), SExpr::create_leaf(DummyTableScan.into()));
+    let metadata = Arc::new(RwLock::new(Metadata::default()));
+
+    let explain = s_expr.to_format_tree(&metadata).format_pretty()?;
+    assert_eq!(
+        explain,
+        "WindowFunc\n├── aggregate function: [sum(b)]\n├── partition by: [t.a (#0)]\n├── frame: [ROWS BETWEEN 2_u64 PRECEDING AND UNBOUNDED FOLLOWING]\n└── DummyTableScan\n"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_format_simplified_casts() {
+    let int64 = DataType::Number(NumberDataType::Int64);
+    let cast = |argument: ScalarExpr| {
+        ScalarExpr::CastExpr(CastExpr {
+            span: None,
+            is_try: false,
+            argument: Box::new(argument),
+            target_type: Box::new(int64.clone()),
+        })
+    };
+    let metadata = Arc::new(RwLock::new(Metadata::default()));
+
+    // `t.a` is UInt64, only the outer casts are redundant.
+    let scalar = cast(cast(cast(column_ref(0, "a"))));
+    assert_eq!(
+        format_scalar(&metadata, &scalar),
+        "CAST(CAST(CAST(t.a (#0) AS Int64) AS Int64) AS Int64)"
+    );
+    assert_eq!(
+        format_scalar_with_options(&metadata, &scalar, true),
+        "CAST(t.a (#0) AS Int64)"
+    );
+}
+
+#[test]
+fn test_format_comparison_as_sql_display() -> Result<()> {
+    let metadata = Arc::new(RwLock::new(Metadata::default()));
+
+    for op in [
+        ComparisonOp::Equal,
+        ComparisonOp::NotEqual,
+        ComparisonOp::GT,
+        ComparisonOp::LT,
+        ComparisonOp::GTE,
+        ComparisonOp::LTE,
+    ] {
+        let scalar = ScalarExpr::ComparisonExpr(ComparisonExpr {
+            op: op.clone(),
+            left: Box::new(column_ref(0, "a")),
+            right: Box::new(column_ref(1, "b")),
+        });
+
+        // The logical plan is displayed like the physical plan.
+        let physical = scalar.as_expr_with_col_index()?.sql_display();
+        assert_eq!(format_scalar(&metadata, &scalar), physical);
+        assert_eq!(physical, format!("t.a (#0) {} t.b (#1)", op.to_sql_op()));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,117 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::Literal;
+use common_sql::binder::split_join_predicates;
+use common_sql::optimizer::RelationalProperty;
+use common_sql::plans::BoundColumnRef;
+use common_sql::plans::CastExpr;
+use common_sql::plans::ComparisonExpr;
+use common_sql::plans::ComparisonOp;
+use common_sql::plans::ConstantExpr;
+use common_sql::ColumnBinding;
+use common_sql::ScalarExpr;
+use common_sql::Visibility;
+
+fn column(index: usize, name: &str, data_type: NumberDataType) -> ScalarExpr {
+    ScalarExpr::BoundColumnRef(BoundColumnRef {
+        span: None,
+        column: ColumnBinding {
+            database_name: None,
+            table_name: None,
+            column_name: name.to_string(),
+            index,
+            data_type: Box::new(DataType::Number(data_type)),
+            visibility: Visibility::Visible,
+        },
+    })
+}
+
+fn constant(value: u8) -> ScalarExpr {
+    ScalarExpr::ConstantExpr(ConstantExpr {
+        span: None,
+        value: Literal::UInt8(value),
+        data_type: Box::new(DataType::Number(NumberDataType::UInt8)),
+    })
+}
+
+fn compare(op: ComparisonOp, left: ScalarExpr, right: ScalarExpr) -> ScalarExpr {
+    ScalarExpr::ComparisonExpr(ComparisonExpr {
+        op,
+        left: Box::new(left),
+        right: Box::new(right),
+    })
+}
+
+fn prop(columns: &[usize]) -> RelationalProperty {
+    RelationalProperty {
+        output_columns: columns.iter().cloned().collect(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_split_join_predicates() -> Result<()> {
+    // left side: a UInt8 (#0), right side: b UInt8 (#1), c UInt16 (#2)
+    let a = column(0, "a", NumberDataType::UInt8);
+    let b = column(1, "b", NumberDataType::UInt8);
+
+    let left_filter = compare(ComparisonOp::Equal, a.clone(), constant(1));
+    let right_filter = compare(ComparisonOp::Equal, b.clone(), constant(1));
+    let equi_condition = compare(ComparisonOp::Equal, b.clone(), a.clone());
+    let other_condition = compare(ComparisonOp::LT, a.clone(), b.clone());
+    let predicates = [
+        left_filter.clone(),
+        right_filter.clone(),
+        equi_condition,
+        other_condition.clone(),
+    ];
+
+    let split = split_join_predicates(&predicates, &prop(&[0]), &prop(&[1, 2]))?;
+    assert_eq!(split.left_filters, vec![left_filter]);
+    assert_eq!(split.right_filters, vec![right_filter]);
+    // the sides of `b = a` are swapped
+    assert_eq!(split.left_conditions, vec![a]);
+    assert_eq!(split.right_conditions, vec![b]);
+    assert_eq!(split.other_conditions, vec![other_condition]);
+
+    Ok(())
+}
+
+#[test]
+fn test_split_join_predicates_coercion() -> Result<()> {
+    let a = column(0, "a", NumberDataType::UInt8);
+    let c = column(2, "c", NumberDataType::UInt16);
+    let predicates = [compare(ComparisonOp::Equal, a.clone(), c.clone())];
+
+    let split = split_join_predicates(&predicates, &prop(&[0]), &prop(&[1, 2]))?;
+    let cast = |argument: ScalarExpr| {
+        ScalarExpr::CastExpr(CastExpr {
+            span: None,
+            is_try: false,
+            argument: Box::new(argument),
+            target_type: Box::new(DataType::Number(NumberDataType::UInt16)),
+        })
+    };
+    assert_eq!(split.left_conditions, vec![cast(a)]);
+    assert_eq!(split.right_conditions, vec![cast(c)]);
+    assert!(split.left_filters.is_empty());
+    assert!(split.right_filters.is_empty());
+    assert!(split.other_conditions.is_empty());
+
+    Ok(())
+}
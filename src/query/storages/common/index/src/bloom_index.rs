@@ -51,7 +51,9 @@ use crate::filters::FilterBuilder;
 use crate::filters::V2BloomBlock;
 use crate::filters::Xor8Builder;
 use crate::filters::Xor8Filter;
+use crate::filters::XOR8_FALSE_POSITIVE_RATE;
 use crate::Index;
+use crate::IndexStats;
 
 /// BlockFilter represents multiple per-column filters(bloom filter or xor filter etc) for data block.
 ///
@@ -102,6 +104,9 @@ pub struct BloomIndex {
 pub enum FilterEvalResult {
     MustFalse,
     Uncertain,
+    /// The filters of the indexed columns can not rule out the block, and the expression
+    /// also refers to columns without filters, so a residual predicate remains to be applied.
+    Partial,
 }
 
 impl BloomIndex {
@@ -127,11 +132,15 @@ impl BloomIndex {
     /// Create a filter block from source data.
     ///
     /// All input blocks should belong to a Parquet file, e.g. the block array represents the parquet file in memory.
+    ///
+    /// Only the columns in `bloom_columns` get a filter if it is specified, otherwise every
+    /// column of a supported type does.
     pub fn try_create(
         func_ctx: FunctionContext,
         source_schema: TableSchemaRef,
         version: u64,
         data_blocks_tobe_indexed: &[&DataBlock],
+        bloom_columns: Option<&[String]>,
     ) -> Result<Option<Self>> {
         if data_blocks_tobe_indexed.is_empty() {
             return Err(ErrorCode::BadArguments("block is empty"));
@@ -145,6 +154,13 @@ impl BloomIndex {
         let mut fields = Vec::new();
         let mut columns = Vec::new();
         for i in 0..num_columns {
+            if let Some(bloom_columns) = bloom_columns {
+                let column_name = source_schema.field(i).name();
+                if !bloom_columns.iter().any(|name| name == column_name) {
+                    continue;
+                }
+            }
+
             let data_type = &data_blocks_tobe_indexed[0].get_by_offset(i).data_type;
             match data_type {
                 DataType::Map(box inner_ty) => {
@@ -278,13 +294,15 @@ impl BloomIndex {
     /// If we are sure of skipping the scan, return false, e.g. the expression must be false.
     /// This happens when the data doesn't show up in the filter.
     ///
-    /// Otherwise return `Uncertain`.
+    /// Otherwise return `Partial` if the expression also refers to columns without filters,
+    /// or `Uncertain`.
     #[tracing::instrument(level = "debug", name = "block_filter_index_eval", skip_all)]
     pub fn apply(
         &self,
         mut expr: Expr<String>,
         scalar_map: &HashMap<Scalar, u64>,
     ) -> Result<FilterEvalResult> {
+        let column_refs = expr.column_refs();
         visit_expr_column_eq_constant(
             &mut expr,
             &mut |span, col_name, scalar, ty, return_type| {
@@ -308,10 +326,46 @@ impl BloomIndex {
                 scalar: Scalar::Boolean(false),
                 ..
             } => Ok(FilterEvalResult::MustFalse),
-            _ => Ok(FilterEvalResult::Uncertain),
+            _ => {
+                let (indexed, residual): (Vec<_>, Vec<_>) = column_refs
+                    .into_keys()
+                    .partition(|column_name| self.has_filter(column_name));
+                if !indexed.is_empty() && !residual.is_empty() {
+                    Ok(FilterEvalResult::Partial)
+                } else {
+                    Ok(FilterEvalResult::Uncertain)
+                }
+            }
         }
     }
 
+    /// Returns false if none of the digests is found in the filter of the column.
+    ///
+    /// The filters of the old version are built from the values instead of the digests,
+    /// they can't be probed and are always considered to contain the digests.
+    pub fn may_contain_digests(&self, column_name: &str, digests: &[u64]) -> Result<bool> {
+        if self.version == V2BloomBlock::VERSION || !self.has_filter(column_name) {
+            return Ok(true);
+        }
+
+        let filter_column = Self::build_filter_column_name(
+            self.version,
+            self.source_schema.field_with_name(column_name)?,
+        )?;
+        let filter = &self.filters[self.filter_schema.index_of(&filter_column)?];
+        Ok(digests.iter().any(|digest| filter.contains_digest(*digest)))
+    }
+
+    /// Returns true if the filter of the column is loaded in this index.
+    fn has_filter(&self, column_name: &str) -> bool {
+        self.source_schema
+            .field_with_name(column_name)
+            .and_then(|field| Self::build_filter_column_name(self.version, field))
+            .map_or(false, |filter_column| {
+                self.filter_schema.has_field(&filter_column)
+            })
+    }
+
     /// calculate digest for column
     pub fn calculate_column_digest(
         func_ctx: FunctionContext,
@@ -418,6 +472,27 @@ impl BloomIndex {
     }
 }
 
+impl Index for BloomIndex {
+    fn supported_type(data_type: &DataType) -> bool {
+        Xor8Filter::supported_type(data_type)
+    }
+
+    fn stats(&self) -> Result<IndexStats> {
+        let mut serialized_size = 0;
+        let mut num_entries = 0;
+        for filter in &self.filters {
+            let stats = filter.stats()?;
+            serialized_size += stats.serialized_size;
+            num_entries += stats.num_entries;
+        }
+        Ok(IndexStats {
+            serialized_size,
+            num_entries,
+            false_positive_rate: Some(XOR8_FALSE_POSITIVE_RATE),
+        })
+    }
+}
+
 fn visit_expr_column_eq_constant(
     expr: &mut Expr<String>,
     visitor: &mut impl FnMut(Span, &str, &Scalar, &DataType, &DataType) -> Result<Option<Expr<String>>>,
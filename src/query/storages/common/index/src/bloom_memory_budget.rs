@@ -0,0 +1,89 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+/// Limits the memory of the bloom filters that are loaded at the same time.
+///
+/// A bloom filter should only be loaded after the size of it is reserved by
+/// [`BloomMemoryBudget::try_reserve`], the reservation is released once the returned
+/// guard is dropped, i.e. the filter is evicted. If the budget is exhausted, the caller
+/// should not load the filter and fall back to the other indexes.
+#[derive(Debug)]
+pub struct BloomMemoryBudget {
+    // 0 means no limit
+    limit: u64,
+    used: AtomicU64,
+    peak: AtomicU64,
+}
+
+impl BloomMemoryBudget {
+    pub fn create(limit: u64) -> Arc<Self> {
+        Arc::new(BloomMemoryBudget {
+            limit,
+            used: AtomicU64::new(0),
+            peak: AtomicU64::new(0),
+        })
+    }
+
+    /// Reserves `size` bytes, returns `None` if it would exceed the limit.
+    pub fn try_reserve(self: &Arc<Self>, size: u64) -> Option<BloomMemoryReservation> {
+        let reserved = self
+            .used
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |used| {
+                let new_used = used.checked_add(size)?;
+                (self.limit == 0 || new_used <= self.limit).then_some(new_used)
+            });
+
+        match reserved {
+            Ok(used) => {
+                self.peak.fetch_max(used + size, Ordering::Relaxed);
+                Some(BloomMemoryReservation {
+                    budget: self.clone(),
+                    size,
+                })
+            }
+            Err(_) => None,
+        }
+    }
+
+    pub fn limit(&self) -> u64 {
+        self.limit
+    }
+
+    /// Bytes currently reserved.
+    pub fn used(&self) -> u64 {
+        self.used.load(Ordering::Acquire)
+    }
+
+    /// The maximum bytes ever reserved at the same time.
+    pub fn peak(&self) -> u64 {
+        self.peak.load(Ordering::Relaxed)
+    }
+}
+
+/// Memory reserved for a loaded bloom filter, released on drop.
+#[derive(Debug)]
+pub struct BloomMemoryReservation {
+    budget: Arc<BloomMemoryBudget>,
+    size: u64,
+}
+
+impl Drop for BloomMemoryReservation {
+    fn drop(&mut self) {
+        self.budget.used.fetch_sub(self.size, Ordering::AcqRel);
+    }
+}
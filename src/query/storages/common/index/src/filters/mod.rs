@@ -26,3 +26,4 @@ pub use xor8::Xor8Builder;
 pub use xor8::Xor8BuildingError;
 pub use xor8::Xor8CodecError;
 pub use xor8::Xor8Filter;
+pub use xor8::XOR8_FALSE_POSITIVE_RATE;
@@ -24,3 +24,4 @@ pub use xor8_filter::Xor8Builder;
 pub use xor8_filter::Xor8BuildingError;
 pub use xor8_filter::Xor8CodecError;
 pub use xor8_filter::Xor8Filter;
+pub use xor8_filter::XOR8_FALSE_POSITIVE_RATE;
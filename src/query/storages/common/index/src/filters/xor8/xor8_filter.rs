@@ -20,12 +20,17 @@ use cbordata::Cbor;
 use cbordata::FromCbor;
 use cbordata::IntoCbor;
 use common_exception::ErrorCode;
+use common_exception::Result;
 use common_expression::types::DataType;
 use xorfilter::Xor8;
 
 use crate::filters::Filter;
 use crate::filters::FilterBuilder;
 use crate::Index;
+use crate::IndexStats;
+
+/// False positive rate of a xor filter with 8-bit fingerprints, i.e. 2^-8.
+pub const XOR8_FALSE_POSITIVE_RATE: f64 = 1.0 / 256.0;
 
 /// A builder that builds a xor8 filter.
 ///
@@ -134,6 +139,14 @@ impl Index for Xor8Filter {
             DataType::Number(_) | DataType::String | DataType::Timestamp | DataType::Date
         )
     }
+
+    fn stats(&self) -> Result<IndexStats> {
+        Ok(IndexStats {
+            serialized_size: self.to_bytes()?.len() as u64,
+            num_entries: self.len().unwrap_or(0) as u64,
+            false_positive_rate: Some(XOR8_FALSE_POSITIVE_RATE),
+        })
+    }
 }
 
 impl Xor8CodecError {
@@ -12,8 +12,20 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_exception::Result;
 use common_expression::types::DataType;
 
+/// Build-time statistics of an index, used to report the overhead of the index.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct IndexStats {
+    /// Size in bytes of the serialized index, zero if nothing is persisted.
+    pub serialized_size: u64,
+    /// Number of entries, e.g. distinct keys of a filter or columns of a range expression.
+    pub num_entries: u64,
+    /// False positive rate of a probabilistic index, `None` if the index is exact.
+    pub false_positive_rate: Option<f64>,
+}
+
 pub trait Index {
     fn supported_type(data_type: &DataType) -> bool {
         // we support nullable column but Nulls are not added into the bloom filter.
@@ -27,4 +39,7 @@ pub trait Index {
                 | DataType::Decimal(_)
         )
     }
+
+    /// Returns the build-time size and selectivity hints of the index.
+    fn stats(&self) -> Result<IndexStats>;
 }
@@ -16,13 +16,20 @@
 #![feature(box_patterns)]
 
 mod bloom_index;
+mod bloom_memory_budget;
 pub mod filters;
 mod index;
 mod page_index;
 mod range_index;
+mod runtime_filter;
 
 pub use bloom_index::BloomIndex;
 pub use bloom_index::FilterEvalResult;
+pub use bloom_memory_budget::BloomMemoryBudget;
+pub use bloom_memory_budget::BloomMemoryReservation;
 pub use index::Index;
+pub use index::IndexStats;
 pub use page_index::PageIndex;
 pub use range_index::RangeIndex;
+pub use runtime_filter::RuntimeBloomFilter;
+pub use runtime_filter::RuntimeBloomFilterBuilder;
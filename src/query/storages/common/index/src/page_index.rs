@@ -32,6 +32,8 @@ use storages_common_table_meta::meta::ClusterStatistics;
 use storages_common_table_meta::meta::ColumnStatistics;
 
 use crate::range_index::statistics_to_domain;
+use crate::Index;
+use crate::IndexStats;
 
 #[derive(Clone)]
 pub struct PageIndex {
@@ -146,6 +148,82 @@ impl PageIndex {
         }
     }
 
+    /// Returns the ranges of the pages which may match the expression, adjacent pages are
+    /// coalesced into a single range, so that each range can be read from storage at once.
+    ///
+    /// Returns `None` if the pages can't be evaluated, e.g. the block has no page statistics
+    /// or is clustered by another cluster key, in which case the whole block should be read.
+    pub fn matching_page_ranges(
+        &self,
+        stats: &Option<ClusterStatistics>,
+    ) -> Result<Option<Vec<Range<usize>>>> {
+        let stats = match stats {
+            Some(stats) if stats.cluster_key_id == self.cluster_key_id => stats,
+            _ => return Ok(None),
+        };
+        let min_values = match stats.pages {
+            Some(ref pages) => pages,
+            None => return Ok(None),
+        };
+
+        let max_value = Scalar::Tuple(stats.max.clone());
+        let mut ranges: Vec<Range<usize>> = vec![];
+        for (idx, min_value) in min_values.iter().enumerate() {
+            let max_value = min_values.get(idx + 1).unwrap_or(&max_value);
+            if !self.eval_single_page(min_value, max_value)? {
+                continue;
+            }
+            match ranges.last_mut() {
+                Some(range) if range.end == idx => range.end = idx + 1,
+                _ => ranges.push(idx..idx + 1),
+            }
+        }
+        Ok(Some(ranges))
+    }
+
+    /// Returns the range of the leading (or trailing if `asc` is false) pages which hold at
+    /// least `limit` rows, as the block is sorted by the cluster key.
+    ///
+    /// Returns `None` if the block is not sorted by the cluster key, or all the pages are needed.
+    pub fn limit_page_range(
+        stats: &Option<ClusterStatistics>,
+        cluster_key_id: u32,
+        page_rows: &[u64],
+        limit: usize,
+        asc: bool,
+    ) -> Option<Range<usize>> {
+        let pages = match stats {
+            Some(stats) if stats.cluster_key_id == cluster_key_id => stats.pages.as_ref()?,
+            _ => return None,
+        };
+        // The pages of the cluster statistics must be the pages of the columns.
+        if pages.len() != page_rows.len() {
+            return None;
+        }
+
+        let total = page_rows.len();
+        let mut rows = 0;
+        let mut num_pages = 0;
+        while num_pages < total && (num_pages == 0 || rows < limit) {
+            let idx = if asc {
+                num_pages
+            } else {
+                total - num_pages - 1
+            };
+            rows += page_rows[idx] as usize;
+            num_pages += 1;
+        }
+
+        if num_pages == total {
+            return None;
+        }
+        if asc {
+            Some(0..num_pages)
+        } else {
+            Some(total - num_pages..total)
+        }
+    }
+
     fn eval_single_page(&self, min_value: &Scalar, max_value: &Scalar) -> Result<bool> {
         let min_value = min_value
             .as_tuple()
@@ -204,3 +282,14 @@ impl PageIndex {
         }))
     }
 }
+
+impl Index for PageIndex {
+    fn stats(&self) -> Result<IndexStats> {
+        // Evaluated against the page boundaries of the cluster statistics, nothing is persisted.
+        Ok(IndexStats {
+            serialized_size: 0,
+            num_entries: self.cluster_key_fields.len() as u64,
+            false_positive_rate: None,
+        })
+    }
+}
@@ -35,6 +35,7 @@ use storages_common_table_meta::meta::ColumnStatistics;
 use storages_common_table_meta::meta::StatisticsOfColumns;
 
 use crate::Index;
+use crate::IndexStats;
 
 #[derive(Clone)]
 pub struct RangeIndex {
@@ -95,6 +96,35 @@ impl RangeIndex {
             ..
         }))
     }
+
+    /// Returns the global `(min, max)` of a column from the `(min, max)` statistics of its blocks.
+    ///
+    /// Blocks with only NULL values have NULL min/max and are skipped. Returns `None` if there is
+    /// no non-NULL value, or if the statistics of the column type are not exact, e.g. the min/max
+    /// of strings are truncated prefixes.
+    pub fn global_min_max<'a>(
+        stats: impl IntoIterator<Item = (&'a Scalar, &'a Scalar)>,
+        data_type: &DataType,
+    ) -> Option<(Scalar, Scalar)> {
+        if !matches!(
+            data_type.remove_nullable(),
+            DataType::Number(_) | DataType::Boolean
+        ) {
+            return None;
+        }
+
+        let mut global: Option<(&Scalar, &Scalar)> = None;
+        for (min, max) in stats {
+            if min.is_null() || max.is_null() {
+                continue;
+            }
+            global = match global {
+                Some((global_min, global_max)) => Some((global_min.min(min), global_max.max(max))),
+                None => Some((min, max)),
+            };
+        }
+        global.map(|(min, max)| (min.clone(), max.clone()))
+    }
 }
 
 pub fn statistics_to_domain(mut stats: Vec<&ColumnStatistics>, data_type: &DataType) -> Domain {
@@ -185,4 +215,13 @@ pub fn statistics_to_domain(mut stats: Vec<&ColumnStatistics>, data_type: &DataT
     }
 }
 
-impl Index for RangeIndex {}
+impl Index for RangeIndex {
+    fn stats(&self) -> Result<IndexStats> {
+        // Evaluated against the min/max of the block meta, nothing is persisted.
+        Ok(IndexStats {
+            serialized_size: 0,
+            num_entries: self.expr.column_refs().len() as u64,
+            false_positive_rate: None,
+        })
+    }
+}
@@ -0,0 +1,155 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::ops::Deref;
+
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::types::UInt64Type;
+use common_expression::types::ValueType;
+use common_expression::Column;
+use common_expression::FunctionContext;
+use common_expression::Scalar;
+use storages_common_table_meta::meta::ColumnStatistics;
+
+use crate::filters::Xor8Filter;
+use crate::BloomIndex;
+use crate::Index;
+use crate::RangeIndex;
+
+/// A filter built at runtime from the keys of the build side of a join, which is pushed
+/// down into the scan of the probe side to prune the blocks that can't match any key.
+///
+/// A block is pruned if the min/max statistics of the column don't overlap the range of
+/// the keys, or if none of the keys is found in the bloom filter of the column.
+#[derive(Clone, Debug)]
+pub struct RuntimeBloomFilter {
+    /// The name of the column of the probe side table.
+    pub column_name: String,
+    /// The type of the keys, without nullable.
+    pub data_type: DataType,
+    /// The min and max of the keys, `None` if there is no key at all.
+    pub range: Option<(Scalar, Scalar)>,
+    /// The digests of the keys, `None` if there are too many keys to probe the bloom filters.
+    pub digests: Option<Vec<u64>>,
+}
+
+impl RuntimeBloomFilter {
+    /// The keys must have the min/max statistics of the range index.
+    pub fn supported_type(data_type: &DataType) -> bool {
+        RangeIndex::supported_type(data_type)
+    }
+
+    /// Returns false if the values described by the statistics can't match any key.
+    pub fn may_match_range(&self, stat: &ColumnStatistics) -> bool {
+        match &self.range {
+            None => false,
+            Some((min, max)) => !(stat.max < *min || stat.min > *max),
+        }
+    }
+
+    /// Returns false if none of the keys is found in the bloom filter of the column.
+    pub fn may_match_bloom(&self, index: &BloomIndex) -> Result<bool> {
+        match &self.digests {
+            None => Ok(true),
+            Some(digests) => index.may_contain_digests(&self.column_name, digests),
+        }
+    }
+}
+
+/// Build a `RuntimeBloomFilter` from the key columns of the build side.
+///
+/// The keys are dropped once there are more than `max_keys` of them, then only the
+/// range is left to prune the blocks.
+pub struct RuntimeBloomFilterBuilder {
+    column_name: String,
+    data_type: DataType,
+    max_keys: usize,
+
+    range: Option<(Scalar, Scalar)>,
+    num_keys: usize,
+    keys: Option<Vec<Column>>,
+}
+
+impl RuntimeBloomFilterBuilder {
+    pub fn create(column_name: String, data_type: &DataType, max_keys: usize) -> Self {
+        let data_type = data_type.remove_nullable();
+        let keys = Xor8Filter::supported_type(&data_type).then(Vec::new);
+        RuntimeBloomFilterBuilder {
+            column_name,
+            data_type,
+            max_keys,
+            range: None,
+            num_keys: 0,
+            keys,
+        }
+    }
+
+    pub fn add_column(&mut self, column: &Column) {
+        // Nulls never match an equi join key.
+        let column = match column {
+            Column::Nullable(box nullable) => nullable.column.filter(&nullable.validity),
+            column => column.clone(),
+        };
+
+        for value in column.iter() {
+            let value = value.to_owned();
+            self.range = match self.range.take() {
+                None => Some((value.clone(), value)),
+                Some((min, max)) if value < min => Some((value, max)),
+                Some((min, max)) if value > max => Some((min, value)),
+                range => range,
+            };
+        }
+
+        self.num_keys += column.len();
+        if self.num_keys > self.max_keys {
+            self.keys = None;
+        }
+        if let Some(keys) = &mut self.keys {
+            keys.push(column);
+        }
+    }
+
+    pub fn build(self, func_ctx: FunctionContext) -> Result<RuntimeBloomFilter> {
+        let digests = match self.keys {
+            Some(keys) if self.num_keys > 0 => {
+                let column = Column::concat(&keys);
+                let column = BloomIndex::calculate_column_digest(
+                    func_ctx,
+                    &column,
+                    &self.data_type,
+                    &DataType::Number(NumberDataType::UInt64),
+                )?;
+                let mut digests = UInt64Type::try_downcast_column(&column)
+                    .unwrap()
+                    .deref()
+                    .to_vec();
+                digests.sort_unstable();
+                digests.dedup();
+                Some(digests)
+            }
+            Some(_) => Some(vec![]),
+            None => None,
+        };
+
+        Ok(RuntimeBloomFilter {
+            column_name: self.column_name,
+            data_type: self.data_type,
+            range: self.range,
+            digests,
+        })
+    }
+}
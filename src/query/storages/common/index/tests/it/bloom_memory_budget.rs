@@ -0,0 +1,51 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use storages_common_index::BloomMemoryBudget;
+
+#[test]
+fn test_bloom_memory_budget() {
+    let budget = BloomMemoryBudget::create(100);
+
+    let first = budget.try_reserve(60);
+    assert!(first.is_some());
+    // Exceeds the limit, the filter should not be loaded.
+    assert!(budget.try_reserve(50).is_none());
+    let second = budget.try_reserve(40);
+    assert!(second.is_some());
+    assert_eq!(budget.used(), 100);
+
+    // Evicting a filter frees its memory.
+    drop(first);
+    assert_eq!(budget.used(), 40);
+    let third = budget.try_reserve(50);
+    assert!(third.is_some());
+    assert_eq!(budget.used(), 90);
+
+    drop(second);
+    drop(third);
+    assert_eq!(budget.used(), 0);
+    assert_eq!(budget.peak(), 100);
+    assert!(budget.peak() <= budget.limit());
+}
+
+#[test]
+fn test_bloom_memory_budget_unlimited() {
+    let budget = BloomMemoryBudget::create(0);
+    let reservations = (0..10)
+        .map(|_| budget.try_reserve(u32::MAX as u64))
+        .collect::<Vec<_>>();
+    assert!(reservations.iter().all(|r| r.is_some()));
+    assert_eq!(budget.used(), 10 * u32::MAX as u64);
+}
@@ -25,6 +25,7 @@ use common_expression::types::map::KvPair;
 use common_expression::types::number::NumberScalar;
 use common_expression::types::number::UInt8Type;
 use common_expression::types::AnyType;
+use common_expression::types::BooleanType;
 use common_expression::types::DataType;
 use common_expression::types::NumberDataType;
 use common_expression::types::StringType;
@@ -41,8 +42,10 @@ use common_expression::TableSchema;
 use common_expression::Value;
 use common_functions::scalars::BUILTIN_FUNCTIONS;
 use storages_common_index::filters::BlockFilter as LatestBloom;
+use storages_common_index::filters::XOR8_FALSE_POSITIVE_RATE;
 use storages_common_index::BloomIndex;
 use storages_common_index::FilterEvalResult;
+use storages_common_index::Index;
 use storages_common_table_meta::meta::Versioned;
 
 #[test]
@@ -111,9 +114,16 @@ fn test_bloom_filter() -> Result<()> {
         schema,
         LatestBloom::VERSION,
         &blocks_ref,
+        None,
     )?
     .unwrap();
 
+    let stats = index.stats()?;
+    assert!(stats.serialized_size > 0);
+    // 3 distinct keys in each of the two scalar columns, 4 distinct map values.
+    assert!(stats.num_entries >= 3 && stats.num_entries <= 10);
+    assert_eq!(stats.false_positive_rate, Some(XOR8_FALSE_POSITIVE_RATE));
+
     assert_eq!(
         FilterEvalResult::MustFalse,
         eval_index(
@@ -193,6 +203,157 @@ fn test_bloom_filter() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_bloom_filter_partial() -> Result<()> {
+    // Column `b` is boolean, which is not indexed by the bloom filter.
+    let schema = Arc::new(TableSchema::new(vec![
+        TableField::new("a", TableDataType::Number(NumberDataType::UInt8)),
+        TableField::new("b", TableDataType::Boolean),
+    ]));
+    let blocks = vec![DataBlock::new_from_columns(vec![
+        UInt8Type::from_data(vec![1, 2]),
+        BooleanType::from_data(vec![true, false]),
+    ])];
+    let blocks_ref = blocks.iter().collect::<Vec<_>>();
+
+    let index = BloomIndex::try_create(
+        FunctionContext::default(),
+        schema,
+        LatestBloom::VERSION,
+        &blocks_ref,
+        None,
+    )?
+    .unwrap();
+
+    // a = 1 and b = true
+    assert_eq!(FilterEvalResult::Partial, eval_two_columns_index(&index, 1));
+    // a = 3 and b = true
+    assert_eq!(
+        FilterEvalResult::MustFalse,
+        eval_two_columns_index(&index, 3)
+    );
+    // a = 1
+    assert_eq!(
+        FilterEvalResult::Uncertain,
+        eval_index(
+            &index,
+            "a",
+            Scalar::Number(NumberScalar::UInt8(1)),
+            DataType::Number(NumberDataType::UInt8)
+        )
+    );
+    Ok(())
+}
+
+#[test]
+fn test_bloom_filter_selected_columns() -> Result<()> {
+    let schema = Arc::new(TableSchema::new(vec![
+        TableField::new("a", TableDataType::Number(NumberDataType::UInt8)),
+        TableField::new("b", TableDataType::String),
+    ]));
+    let blocks = vec![DataBlock::new_from_columns(vec![
+        UInt8Type::from_data(vec![1, 2]),
+        StringType::from_data(vec!["x", "y"]),
+    ])];
+    let blocks_ref = blocks.iter().collect::<Vec<_>>();
+
+    // Only build the filter of column `a`.
+    let bloom_columns = ["a".to_string()];
+    let index = BloomIndex::try_create(
+        FunctionContext::default(),
+        schema,
+        LatestBloom::VERSION,
+        &blocks_ref,
+        Some(bloom_columns.as_slice()),
+    )?
+    .unwrap();
+    assert_eq!(index.filter_schema.num_fields(), 1);
+    assert_eq!(index.column_distinct_count.len(), 1);
+
+    // The filter of `a` rules out absent values.
+    assert_eq!(
+        FilterEvalResult::MustFalse,
+        eval_index(
+            &index,
+            "a",
+            Scalar::Number(NumberScalar::UInt8(3)),
+            DataType::Number(NumberDataType::UInt8)
+        )
+    );
+    // `b` has no filter, so nothing can be ruled out.
+    assert_eq!(
+        FilterEvalResult::Uncertain,
+        eval_index(&index, "b", Scalar::String(b"z".to_vec()), DataType::String)
+    );
+
+    // No filter at all if none of the selected columns exists.
+    let schema = Arc::new(TableSchema::new(vec![TableField::new(
+        "a",
+        TableDataType::Number(NumberDataType::UInt8),
+    )]));
+    let block = DataBlock::new_from_columns(vec![UInt8Type::from_data(vec![1, 2])]);
+    let bloom_columns = ["c".to_string()];
+    let index = BloomIndex::try_create(
+        FunctionContext::default(),
+        schema,
+        LatestBloom::VERSION,
+        &[&block],
+        Some(bloom_columns.as_slice()),
+    )?;
+    assert!(index.is_none());
+
+    Ok(())
+}
+
+fn eval_two_columns_index(index: &BloomIndex, a: u8) -> FilterEvalResult {
+    let eq = |name: &str, scalar: Scalar, ty: DataType| {
+        check_function(
+            None,
+            "eq",
+            &[],
+            &[
+                Expr::ColumnRef {
+                    span: None,
+                    id: name.to_string(),
+                    data_type: ty.clone(),
+                    display_name: name.to_string(),
+                },
+                Expr::Constant {
+                    span: None,
+                    scalar,
+                    data_type: ty,
+                },
+            ],
+            &BUILTIN_FUNCTIONS,
+        )
+        .unwrap()
+    };
+    let expr = check_function(
+        None,
+        "and",
+        &[],
+        &[
+            eq(
+                "a",
+                Scalar::Number(NumberScalar::UInt8(a)),
+                DataType::Number(NumberDataType::UInt8),
+            ),
+            eq("b", Scalar::Boolean(true), DataType::Boolean),
+        ],
+        &BUILTIN_FUNCTIONS,
+    )
+    .unwrap();
+
+    let mut scalar_map = HashMap::<Scalar, u64>::new();
+    let func_ctx = FunctionContext::default();
+    for (_, scalar, ty) in BloomIndex::find_eq_columns(&expr).unwrap().iter() {
+        let digest = BloomIndex::calculate_scalar_digest(func_ctx, scalar, ty).unwrap();
+        scalar_map.insert(scalar.clone(), digest);
+    }
+
+    index.apply(expr, &scalar_map).unwrap()
+}
+
 fn eval_index(index: &BloomIndex, col_name: &str, val: Scalar, ty: DataType) -> FilterEvalResult {
     let expr = check_function(
         None,
@@ -14,4 +14,8 @@
 
 #![allow(clippy::uninlined_format_args)]
 
+mod bloom_memory_budget;
 mod filters;
+mod page_index;
+mod range_index;
+mod runtime_filter;
@@ -0,0 +1,153 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_expression::type_check::check_function;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::types::NumberScalar;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_functions::scalars::BUILTIN_FUNCTIONS;
+use storages_common_index::PageIndex;
+use storages_common_table_meta::meta::ClusterStatistics;
+
+fn int64(value: i64) -> Expr<String> {
+    Expr::Constant {
+        span: None,
+        scalar: Scalar::Number(NumberScalar::Int64(value)),
+        data_type: DataType::Number(NumberDataType::Int64),
+    }
+}
+
+fn tuple(value: i64) -> Scalar {
+    Scalar::Tuple(vec![Scalar::Number(NumberScalar::Int64(value))])
+}
+
+// `a = v1 or a = v2 or ...`
+fn eq_any(values: &[i64]) -> Expr<String> {
+    let column = Expr::ColumnRef {
+        span: None,
+        id: "a".to_string(),
+        data_type: DataType::Number(NumberDataType::Int64),
+        display_name: "a".to_string(),
+    };
+    values
+        .iter()
+        .map(|v| {
+            check_function(
+                None,
+                "eq",
+                &[],
+                &[column.clone(), int64(*v)],
+                &BUILTIN_FUNCTIONS,
+            )
+            .unwrap()
+        })
+        .reduce(|acc, eq| check_function(None, "or", &[], &[acc, eq], &BUILTIN_FUNCTIONS).unwrap())
+        .unwrap()
+}
+
+#[test]
+fn test_matching_page_ranges() {
+    let schema = Arc::new(TableSchema::new(vec![TableField::new(
+        "a",
+        TableDataType::Number(NumberDataType::Int64),
+    )]));
+    // Six pages: [0, 2], [2, 4], [4, 6], [6, 8], [8, 10], [10, 12].
+    let stats = Some(ClusterStatistics {
+        cluster_key_id: 0,
+        min: vec![Scalar::Number(NumberScalar::Int64(0))],
+        max: vec![Scalar::Number(NumberScalar::Int64(12))],
+        level: 0,
+        pages: Some((0..6).map(|i| tuple(i * 2)).collect()),
+    });
+    let page_ranges = |values: &[i64]| {
+        let index = PageIndex::try_create(
+            FunctionContext::default(),
+            0,
+            vec!["a".to_string()],
+            &eq_any(values),
+            schema.clone(),
+        )
+        .unwrap();
+        index.matching_page_ranges(&stats).unwrap()
+    };
+
+    // Alternating pages are kept apart.
+    assert_eq!(page_ranges(&[1, 5, 9]), Some(vec![0..1, 2..3, 4..5]));
+    // Adjacent pages are coalesced.
+    assert_eq!(page_ranges(&[1, 3, 9, 11]), Some(vec![0..2, 4..6]));
+    assert_eq!(page_ranges(&[100]), Some(vec![]));
+
+    // Pages can't be evaluated against the stats of another cluster key.
+    let index = PageIndex::try_create(
+        FunctionContext::default(),
+        1,
+        vec!["a".to_string()],
+        &eq_any(&[1]),
+        schema,
+    )
+    .unwrap();
+    assert_eq!(index.matching_page_ranges(&stats).unwrap(), None);
+}
+
+#[test]
+fn test_limit_page_range() {
+    let stats = Some(ClusterStatistics {
+        cluster_key_id: 0,
+        min: vec![Scalar::Number(NumberScalar::Int64(0))],
+        max: vec![Scalar::Number(NumberScalar::Int64(12))],
+        level: 0,
+        pages: Some((0..4).map(|i| tuple(i * 3)).collect()),
+    });
+    let page_rows = [10, 10, 10, 5];
+
+    assert_eq!(
+        PageIndex::limit_page_range(&stats, 0, &page_rows, 0, true),
+        Some(0..1)
+    );
+    assert_eq!(
+        PageIndex::limit_page_range(&stats, 0, &page_rows, 10, true),
+        Some(0..1)
+    );
+    assert_eq!(
+        PageIndex::limit_page_range(&stats, 0, &page_rows, 11, true),
+        Some(0..2)
+    );
+    assert_eq!(
+        PageIndex::limit_page_range(&stats, 0, &page_rows, 15, false),
+        Some(2..4)
+    );
+    // All the pages are needed.
+    assert_eq!(
+        PageIndex::limit_page_range(&stats, 0, &page_rows, 31, true),
+        None
+    );
+    // The block is sorted by another cluster key.
+    assert_eq!(
+        PageIndex::limit_page_range(&stats, 1, &page_rows, 10, true),
+        None
+    );
+    // The pages of the statistics don't match the pages of the columns.
+    assert_eq!(
+        PageIndex::limit_page_range(&stats, 0, &[20, 20], 10, true),
+        None
+    );
+}
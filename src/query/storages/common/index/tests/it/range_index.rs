@@ -0,0 +1,61 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_expression::type_check::check_function;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::types::NumberScalar;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_functions::scalars::BUILTIN_FUNCTIONS;
+use storages_common_index::Index;
+use storages_common_index::RangeIndex;
+
+#[test]
+fn test_range_index_stats() {
+    let schema = Arc::new(TableSchema::new(vec![
+        TableField::new("a", TableDataType::Number(NumberDataType::Int64)),
+        TableField::new("b", TableDataType::Number(NumberDataType::Int64)),
+    ]));
+    let column = |name: &str| Expr::ColumnRef {
+        span: None,
+        id: name.to_string(),
+        data_type: DataType::Number(NumberDataType::Int64),
+        display_name: name.to_string(),
+    };
+    let expr = check_function(
+        None,
+        "gt",
+        &[],
+        &[column("a"), Expr::Constant {
+            span: None,
+            scalar: Scalar::Number(NumberScalar::Int64(1)),
+            data_type: DataType::Number(NumberDataType::Int64),
+        }],
+        &BUILTIN_FUNCTIONS,
+    )
+    .unwrap();
+
+    let index = RangeIndex::try_create(FunctionContext::default(), &expr, schema).unwrap();
+    let stats = index.stats().unwrap();
+    assert_eq!(stats.serialized_size, 0);
+    assert_eq!(stats.num_entries, 1);
+    assert_eq!(stats.false_positive_rate, None);
+}
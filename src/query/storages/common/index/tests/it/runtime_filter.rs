@@ -0,0 +1,121 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::types::number::Int32Type;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_expression::FunctionContext;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use storages_common_index::filters::BlockFilter as LatestBloom;
+use storages_common_index::BloomIndex;
+use storages_common_index::RuntimeBloomFilterBuilder;
+use storages_common_table_meta::meta::ColumnStatistics;
+use storages_common_table_meta::meta::Versioned;
+
+fn column_statistics(min: i32, max: i32) -> ColumnStatistics {
+    ColumnStatistics {
+        min: Scalar::from(min),
+        max: Scalar::from(max),
+        null_count: 0,
+        in_memory_size: 0,
+        distinct_of_values: None,
+    }
+}
+
+#[test]
+fn test_runtime_filter_range() -> Result<()> {
+    let data_type = DataType::Nullable(Box::new(DataType::Number(NumberDataType::Int32)));
+    let mut builder = RuntimeBloomFilterBuilder::create("a".to_string(), &data_type, 1024);
+    builder.add_column(&Int32Type::from_data_with_validity(vec![10, 0, 20], vec![
+        true, false, true,
+    ]));
+    builder.add_column(&Int32Type::from_data(vec![15, 30]));
+    let filter = builder.build(FunctionContext::default())?;
+
+    // The null is not a key.
+    assert_eq!(
+        filter.range,
+        Some((Scalar::from(10i32), Scalar::from(30i32)))
+    );
+    assert_eq!(
+        filter.digests.as_ref().map(|digests| digests.len()),
+        Some(4)
+    );
+
+    assert!(!filter.may_match_range(&column_statistics(0, 9)));
+    assert!(filter.may_match_range(&column_statistics(0, 10)));
+    assert!(filter.may_match_range(&column_statistics(12, 13)));
+    assert!(filter.may_match_range(&column_statistics(30, 40)));
+    assert!(!filter.may_match_range(&column_statistics(31, 40)));
+
+    // No key matches nothing.
+    let builder = RuntimeBloomFilterBuilder::create("a".to_string(), &data_type, 1024);
+    let filter = builder.build(FunctionContext::default())?;
+    assert_eq!(filter.range, None);
+    assert!(!filter.may_match_range(&column_statistics(0, 100)));
+
+    // Too many keys to keep the digests.
+    let mut builder = RuntimeBloomFilterBuilder::create("a".to_string(), &data_type, 2);
+    builder.add_column(&Int32Type::from_data(vec![1, 2, 3]));
+    let filter = builder.build(FunctionContext::default())?;
+    assert_eq!(filter.range, Some((Scalar::from(1i32), Scalar::from(3i32))));
+    assert_eq!(filter.digests, None);
+
+    Ok(())
+}
+
+#[test]
+fn test_runtime_filter_bloom() -> Result<()> {
+    let schema = Arc::new(TableSchema::new(vec![TableField::new(
+        "a",
+        TableDataType::Number(NumberDataType::Int32),
+    )]));
+    let blocks = vec![DataBlock::new_from_columns(vec![Int32Type::from_data(
+        vec![1, 3, 5, 7],
+    )])];
+    let blocks_ref = blocks.iter().collect::<Vec<_>>();
+    let index = BloomIndex::try_create(
+        FunctionContext::default(),
+        schema,
+        LatestBloom::VERSION,
+        &blocks_ref,
+        None,
+    )?
+    .unwrap();
+
+    let data_type = DataType::Number(NumberDataType::Int32);
+    let build = |keys: Vec<i32>| {
+        let mut builder = RuntimeBloomFilterBuilder::create("a".to_string(), &data_type, 1024);
+        builder.add_column(&Int32Type::from_data(keys));
+        builder.build(FunctionContext::default())
+    };
+
+    assert!(build(vec![2, 5])?.may_match_bloom(&index)?);
+    assert!(!build(vec![2, 4, 6])?.may_match_bloom(&index)?);
+
+    // Without the digests, the block can't be pruned by the bloom filter.
+    let mut filter = build(vec![2, 4, 6])?;
+    filter.digests = None;
+    assert!(filter.may_match_bloom(&index)?);
+
+    Ok(())
+}
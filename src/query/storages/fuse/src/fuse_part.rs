@@ -28,7 +28,9 @@ use common_expression::ColumnId;
 use common_expression::Scalar;
 use storages_common_pruner::BlockMetaIndex;
 use storages_common_table_meta::meta::ColumnMeta;
+use storages_common_table_meta::meta::ColumnStatistics;
 use storages_common_table_meta::meta::Compression;
+use storages_common_table_meta::meta::Location;
 
 #[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Debug)]
 pub struct FusePartInfo {
@@ -42,6 +44,12 @@ pub struct FusePartInfo {
 
     pub sort_min_max: Option<(Scalar, Scalar)>,
     pub block_meta_index: Option<BlockMetaIndex>,
+
+    /// The statistics and the bloom filter index of the columns, used to prune the part
+    /// by the runtime filters while scanning.
+    pub columns_stat: HashMap<ColumnId, ColumnStatistics>,
+    pub bloom_index_location: Option<Location>,
+    pub bloom_index_size: u64,
 }
 
 #[typetag::serde(name = "fuse")]
@@ -65,6 +73,7 @@ impl PartInfo for FusePartInfo {
 }
 
 impl FusePartInfo {
+    #[allow(clippy::too_many_arguments)]
     pub fn create(
         location: String,
         format_version: u64,
@@ -73,6 +82,9 @@ impl FusePartInfo {
         compression: Compression,
         sort_min_max: Option<(Scalar, Scalar)>,
         block_meta_index: Option<BlockMetaIndex>,
+        columns_stat: HashMap<ColumnId, ColumnStatistics>,
+        bloom_index_location: Option<Location>,
+        bloom_index_size: u64,
     ) -> Arc<Box<dyn PartInfo>> {
         Arc::new(Box::new(FusePartInfo {
             location,
@@ -82,6 +94,9 @@ impl FusePartInfo {
             compression,
             sort_min_max,
             block_meta_index,
+            columns_stat,
+            bloom_index_location,
+            bloom_index_size,
         }))
     }
 
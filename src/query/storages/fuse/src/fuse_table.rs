@@ -21,6 +21,7 @@ use std::sync::Arc;
 
 use common_catalog::catalog::StorageDescription;
 use common_catalog::plan::DataSourcePlan;
+use common_catalog::plan::MatchedRows;
 use common_catalog::plan::PartStatistics;
 use common_catalog::plan::Partitions;
 use common_catalog::plan::PushDownInfo;
@@ -81,6 +82,7 @@ use crate::DEFAULT_ROW_PER_PAGE;
 use crate::DEFAULT_ROW_PER_PAGE_FOR_BLOCKING;
 use crate::FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD;
 use crate::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
+use crate::FUSE_OPT_KEY_BLOOM_INDEX_COLUMNS;
 use crate::FUSE_OPT_KEY_ROW_PER_BLOCK;
 use crate::FUSE_OPT_KEY_ROW_PER_PAGE;
 use crate::FUSE_TBL_LAST_SNAPSHOT_HINT;
@@ -186,9 +188,25 @@ impl FuseTable {
             table_compression: self.table_compression,
             max_page_size,
             block_per_seg,
+            bloom_index_columns: self.bloom_index_columns(),
         }
     }
 
+    /// Get the columns to build bloom filters for, from the comma separated
+    /// `bloom_index_columns` option. None if the option is not set.
+    pub fn bloom_index_columns(&self) -> Option<Vec<String>> {
+        self.table_info
+            .options()
+            .get(FUSE_OPT_KEY_BLOOM_INDEX_COLUMNS)
+            .map(|columns| {
+                columns
+                    .split(',')
+                    .map(|column| column.trim().to_string())
+                    .filter(|column| !column.is_empty())
+                    .collect()
+            })
+    }
+
     /// Get max page size.
     /// For native storage format.
     pub fn get_max_page_size(&self) -> Option<usize> {
@@ -593,10 +611,18 @@ impl Table for FuseTable {
         filter: Option<RemoteExpr<String>>,
         col_indices: Vec<FieldIndex>,
         update_list: Vec<(FieldIndex, RemoteExpr<String>)>,
+        matched_rows: Option<MatchedRows>,
         pipeline: &mut Pipeline,
     ) -> Result<()> {
-        self.do_update(ctx, filter, col_indices, update_list, pipeline)
-            .await
+        self.do_update(
+            ctx,
+            filter,
+            col_indices,
+            update_list,
+            matched_rows,
+            pipeline,
+        )
+        .await
     }
 
     fn get_block_compact_thresholds(&self) -> BlockThresholds {
@@ -114,6 +114,7 @@ impl BlockBuilder {
             self.source_schema.clone(),
             &data_block,
             bloom_index_location,
+            self.write_settings.bloom_index_columns.as_deref(),
         )?;
         let column_distinct_count = bloom_index_state
             .as_ref()
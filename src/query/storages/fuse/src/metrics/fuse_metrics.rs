@@ -173,6 +173,14 @@ pub fn metrics_inc_bytes_block_bloom_pruning_after(c: u64) {
     increment_gauge!(key!("bytes_block_bloom_pruning_after"), c as f64);
 }
 
+pub fn metrics_inc_blocks_runtime_filter_pruning_before(c: u64) {
+    increment_gauge!(key!("blocks_runtime_filter_pruning_before"), c as f64);
+}
+
+pub fn metrics_inc_blocks_runtime_filter_pruning_after(c: u64) {
+    increment_gauge!(key!("blocks_runtime_filter_pruning_after"), c as f64);
+}
+
 pub fn metrics_inc_pruning_prewhere_nums(c: u64) {
     increment_gauge!(key!("pruning_prewhere_nums"), c as f64);
 }
@@ -222,6 +230,8 @@ pub fn metrics_reset() {
     gauge!(key!("blocks_range_pruning_after"), c);
     gauge!(key!("blocks_bloom_pruning_before"), c);
     gauge!(key!("blocks_bloom_pruning_after"), c);
+    gauge!(key!("blocks_runtime_filter_pruning_before"), c);
+    gauge!(key!("blocks_runtime_filter_pruning_after"), c);
     gauge!(key!("bytes_segment_range_pruning_before"), c);
     gauge!(key!("bytes_segment_range_pruning_after"), c);
     gauge!(key!("bytes_block_bloom_pruning_before"), c);
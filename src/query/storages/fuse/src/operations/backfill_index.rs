@@ -0,0 +1,190 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::plan::Projection;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use storages_common_cache::LoadParams;
+use storages_common_table_meta::meta::BlockMeta;
+use tracing::info;
+use uuid::Uuid;
+
+use crate::io::write_data;
+use crate::io::BlockReader;
+use crate::io::MetaReaders;
+use crate::io::ReadSettings;
+use crate::io::WriteSettings;
+use crate::operations::mutation::AbortOperation;
+use crate::operations::mutation::BaseMutator;
+use crate::operations::BloomIndexState;
+use crate::FuseTable;
+
+impl FuseTable {
+    /// Rebuilds the bloom filter index of every existing block, e.g. after the
+    /// `bloom_index_columns` option is changed, and commits them in a new snapshot.
+    /// The range index needs no backfill, it is derived from the column statistics
+    /// kept in the block meta.
+    ///
+    /// Returns the number of blocks whose index is rebuilt.
+    ///
+    /// The indexes are written to new locations, the files referenced by the current
+    /// snapshot are never overwritten. If it fails half way, the files written so far are
+    /// removed and the table is left untouched, so it is safe to run it again.
+    pub async fn backfill_bloom_index(&self, ctx: Arc<dyn TableContext>) -> Result<usize> {
+        let snapshot = match self.read_table_snapshot().await? {
+            Some(snapshot) => snapshot,
+            None => return Ok(0),
+        };
+
+        let mut mutator = BaseMutator::try_create(
+            ctx.clone(),
+            self.operator.clone(),
+            self.meta_location_generator.clone(),
+            snapshot.clone(),
+            self.get_block_compact_thresholds(),
+        )?;
+
+        let mut abort_operation = AbortOperation::default();
+        let backfilled = match self
+            .backfill_segments(&ctx, &mut mutator, &mut abort_operation)
+            .await
+        {
+            Ok(backfilled) => backfilled,
+            Err(e) => {
+                abort_operation.abort(ctx, self.operator.clone()).await?;
+                return Err(e);
+            }
+        };
+
+        let (segments, summary, mut segments_abort_operation) =
+            match mutator.generate_segments().await {
+                Ok(generated) => generated,
+                Err(e) => {
+                    abort_operation.abort(ctx, self.operator.clone()).await?;
+                    return Err(e);
+                }
+            };
+        // The blocks keep their locations, they are still referenced by the current
+        // snapshot and must not be removed if the commit is aborted.
+        segments_abort_operation.blocks.clear();
+        abort_operation.merge(&segments_abort_operation);
+
+        self.commit_mutation(&ctx, snapshot, segments, summary, abort_operation)
+            .await?;
+        Ok(backfilled)
+    }
+
+    async fn backfill_segments(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        mutator: &mut BaseMutator,
+        abort_operation: &mut AbortOperation,
+    ) -> Result<usize> {
+        let schema = self.schema();
+        let write_settings = self.get_write_settings();
+        let read_settings = ReadSettings::from_ctx(ctx)?;
+        let block_reader = self.create_block_reader(
+            Projection::Columns(self.all_column_indices()),
+            false,
+            ctx.clone(),
+        )?;
+        let segment_reader = MetaReaders::segment_info_reader(self.operator.clone(), schema);
+
+        let base_snapshot = mutator.base_snapshot.clone();
+        let total = base_snapshot.summary.block_count;
+        let mut backfilled = 0;
+        for (segment_idx, (location, version)) in base_snapshot.segments.iter().enumerate() {
+            let load_params = LoadParams {
+                location: location.clone(),
+                len_hint: None,
+                ver: *version,
+                put_cache: false,
+            };
+            let segment = segment_reader.read(&load_params).await?;
+
+            for block_meta in segment.blocks.iter() {
+                let new_block_meta = self
+                    .backfill_block(
+                        ctx,
+                        &block_reader,
+                        &read_settings,
+                        &write_settings,
+                        block_meta,
+                        abort_operation,
+                    )
+                    .await?;
+                mutator.add_mutation(
+                    segment_idx,
+                    block_meta.location.clone(),
+                    Some(new_block_meta),
+                );
+
+                backfilled += 1;
+                let status = format!(
+                    "backfill bloom index: {} of {} blocks done",
+                    backfilled, total
+                );
+                ctx.set_status_info(&status);
+                info!(status);
+            }
+        }
+
+        Ok(backfilled)
+    }
+
+    async fn backfill_block(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        block_reader: &BlockReader,
+        read_settings: &ReadSettings,
+        write_settings: &WriteSettings,
+        block_meta: &BlockMeta,
+        abort_operation: &mut AbortOperation,
+    ) -> Result<BlockMeta> {
+        let block = block_reader
+            .read_by_meta(read_settings, block_meta, &write_settings.storage_format)
+            .await?;
+
+        let location = self
+            .meta_location_generator
+            .block_bloom_index_location(&Uuid::new_v4());
+        let bloom_index_state = BloomIndexState::try_create(
+            ctx.clone(),
+            self.schema(),
+            &block,
+            location,
+            write_settings.bloom_index_columns.as_deref(),
+        )?;
+
+        let mut new_block_meta = block_meta.clone();
+        match bloom_index_state {
+            Some(state) => {
+                abort_operation
+                    .bloom_filter_indexes
+                    .push(state.location.0.clone());
+                write_data(state.data, &self.operator, &state.location.0).await?;
+                new_block_meta.bloom_filter_index_location = Some(state.location);
+                new_block_meta.bloom_filter_index_size = state.size;
+            }
+            None => {
+                new_block_meta.bloom_filter_index_location = None;
+                new_block_meta.bloom_filter_index_size = 0;
+            }
+        }
+        Ok(new_block_meta)
+    }
+}
@@ -71,12 +71,16 @@ impl BloomIndexState {
         source_schema: TableSchemaRef,
         block: &DataBlock,
         location: Location,
+        bloom_columns: Option<&[String]>,
     ) -> Result<Option<Self>> {
         // write index
-        let maybe_bloom_index =
-            BloomIndex::try_create(ctx.get_function_context()?, source_schema, location.1, &[
-                block,
-            ])?;
+        let maybe_bloom_index = BloomIndex::try_create(
+            ctx.get_function_context()?,
+            source_schema,
+            location.1,
+            &[block],
+            bloom_columns,
+        )?;
         if let Some(bloom_index) = maybe_bloom_index {
             let index_block = bloom_index.serialize_to_data_block()?;
             let filter_schema = bloom_index.filter_schema;
@@ -227,6 +231,7 @@ impl Processor for FuseTableSink {
                     self.source_schema.clone(),
                     &block,
                     location,
+                    self.write_settings.bloom_index_columns.as_deref(),
                 )?;
                 let column_distinct_count = bloom_index_state
                     .as_ref()
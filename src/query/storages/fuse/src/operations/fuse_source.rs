@@ -24,7 +24,9 @@ use crate::fuse_table::FuseStorageFormat;
 use crate::io::BlockReader;
 use crate::operations::read::build_fuse_parquet_source_pipeline;
 use crate::operations::read::fuse_source::build_fuse_native_source_pipeline;
+use crate::pruning::RuntimeFilterPruner;
 
+#[allow(clippy::too_many_arguments)]
 pub fn build_fuse_source_pipeline(
     ctx: Arc<dyn TableContext>,
     pipeline: &mut Pipeline,
@@ -33,6 +35,7 @@ pub fn build_fuse_source_pipeline(
     plan: &DataSourcePlan,
     top_k: Option<TopK>,
     max_io_requests: usize,
+    runtime_filter_pruner: Option<Arc<RuntimeFilterPruner>>,
 ) -> Result<()> {
     let max_threads = ctx.get_settings().get_max_threads()? as usize;
 
@@ -45,6 +48,7 @@ pub fn build_fuse_source_pipeline(
             plan,
             top_k,
             max_io_requests,
+            runtime_filter_pruner,
         ),
         FuseStorageFormat::Parquet => build_fuse_parquet_source_pipeline(
             ctx,
@@ -53,6 +57,7 @@ pub fn build_fuse_source_pipeline(
             plan,
             max_threads,
             max_io_requests,
+            runtime_filter_pruner,
         ),
     }
 }
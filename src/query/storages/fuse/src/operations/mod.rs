@@ -14,6 +14,7 @@
 
 mod analyze;
 mod append;
+mod backfill_index;
 mod commit;
 mod compact;
 mod delete;
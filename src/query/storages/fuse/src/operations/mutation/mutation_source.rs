@@ -18,13 +18,21 @@ use std::sync::Arc;
 
 use common_base::base::Progress;
 use common_base::base::ProgressValues;
+use common_catalog::plan::InternalColumn;
+use common_catalog::plan::InternalColumnMeta;
+use common_catalog::plan::InternalColumnType;
+use common_catalog::plan::MatchedRows;
 use common_catalog::plan::PartInfoPtr;
+use common_catalog::plan::ROW_ID;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::types::BooleanType;
 use common_expression::types::DataType;
+use common_expression::types::UInt64Type;
+use common_expression::types::ValueType;
 use common_expression::BlockEntry;
+use common_expression::Column;
 use common_expression::DataBlock;
 use common_expression::Evaluator;
 use common_expression::Expr;
@@ -49,6 +57,8 @@ use crate::MergeIOReadResult;
 pub enum MutationAction {
     Deletion,
     Update,
+    /// Update the rows matched by the `FROM` clause of an UPDATE.
+    UpdateFrom(Arc<MatchedRows>),
 }
 
 enum State {
@@ -115,6 +125,23 @@ impl MutationSource {
             storage_format,
         })))
     }
+
+    // The `_row_id` of the rows in the current block, the same as the internal column.
+    fn row_ids(&self, num_rows: usize) -> Vec<u64> {
+        let meta = InternalColumnMeta {
+            segment_id: self.index.segment_id,
+            block_id: self.index.block_id,
+            block_location: self.index.block_location.clone(),
+            segment_location: self.index.segment_location.clone(),
+            snapshot_location: self.index.snapshot_location.clone().unwrap_or_default(),
+        };
+        let entry = InternalColumn::new(ROW_ID, InternalColumnType::RowId)
+            .generate_column_values(&meta, num_rows);
+        let column = entry
+            .value
+            .convert_to_full_column(&entry.data_type, num_rows);
+        UInt64Type::try_downcast_column(&column).unwrap().to_vec()
+    }
 }
 
 #[async_trait::async_trait]
@@ -180,7 +207,32 @@ impl Processor for MutationSource {
                 )?;
                 let num_rows = data_block.num_rows();
 
-                if let Some(filter) = self.filter.as_ref() {
+                if let MutationAction::UpdateFrom(matched_rows) = &self.action {
+                    let row_ids = self.row_ids(num_rows);
+                    match matched_rows.take_block_rows(&self.index.block_location, &row_ids)? {
+                        Some((predicates, values)) => {
+                            let progress_values = ProgressValues {
+                                rows: predicates.len() - predicates.unset_bits(),
+                                bytes: 0,
+                            };
+                            self.scan_progress.incr(&progress_values);
+
+                            data_block.add_column(BlockEntry {
+                                data_type: DataType::Boolean,
+                                value: Value::Column(Column::Boolean(predicates)),
+                            });
+                            for col in values.columns() {
+                                data_block.add_column(col.clone());
+                            }
+                            self.state = State::PerformOperator(data_block);
+                        }
+                        None => {
+                            // Do nothing.
+                            self.state =
+                                State::Output(self.ctx.get_partition(), DataBlock::empty());
+                        }
+                    }
+                } else if let Some(filter) = self.filter.as_ref() {
                     assert_eq!(filter.data_type(), &DataType::Boolean);
 
                     let func_ctx = self.ctx.get_function_context()?;
@@ -237,7 +289,7 @@ impl Processor for MutationSource {
                                     }
                                 }
                             }
-                            MutationAction::Update => {
+                            MutationAction::Update | MutationAction::UpdateFrom(_) => {
                                 if self.remain_reader.is_none() {
                                     data_block.add_column(BlockEntry {
                                         data_type: DataType::Boolean,
@@ -284,7 +336,7 @@ impl Processor for MutationSource {
                                 data_block.add_column(col.clone());
                             }
                         }
-                        MutationAction::Update => {
+                        MutationAction::Update | MutationAction::UpdateFrom(_) => {
                             for col in remain_block.columns() {
                                 data_block.add_column(col.clone());
                             }
@@ -321,6 +373,13 @@ impl Processor for MutationSource {
                 let part = MutationPartInfo::from_part(&part)?;
                 self.index = part.index.clone();
                 self.origin_stats = part.cluster_stats.clone();
+                if let MutationAction::UpdateFrom(matched_rows) = &self.action {
+                    if !matched_rows.contains_block(&self.index.block_location) {
+                        // None of the rows in the block is matched, skip reading it.
+                        self.state = State::Output(self.ctx.get_partition(), DataBlock::empty());
+                        return Ok(());
+                    }
+                }
                 let inner_part = part.inner_part.clone();
                 let fuse_part = FusePartInfo::from_part(&inner_part)?;
 
@@ -180,6 +180,7 @@ impl Processor for SerializeDataTransform {
                     self.schema.clone(),
                     &block,
                     location,
+                    self.write_settings.bloom_index_columns.as_deref(),
                 )?;
                 let column_distinct_count = bloom_index_state
                     .as_ref()
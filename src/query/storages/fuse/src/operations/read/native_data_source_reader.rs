@@ -31,6 +31,7 @@ use common_pipeline_sources::SyncSourcer;
 use crate::io::BlockReader;
 use crate::operations::read::native_data_source::DataChunks;
 use crate::operations::read::native_data_source::NativeDataSourceMeta;
+use crate::pruning::RuntimeFilterPruner;
 
 pub struct ReadNativeDataSource<const BLOCKING_IO: bool> {
     id: usize,
@@ -41,6 +42,7 @@ pub struct ReadNativeDataSource<const BLOCKING_IO: bool> {
     output: Arc<OutputPort>,
     output_data: Option<(Vec<PartInfoPtr>, Vec<DataChunks>)>,
     partitions: StealablePartitions,
+    runtime_filter_pruner: Option<Arc<RuntimeFilterPruner>>,
 }
 
 impl ReadNativeDataSource<true> {
@@ -60,6 +62,7 @@ impl ReadNativeDataSource<true> {
             finished: false,
             output_data: None,
             partitions,
+            runtime_filter_pruner: None,
         })
     }
 }
@@ -71,6 +74,7 @@ impl ReadNativeDataSource<false> {
         output: Arc<OutputPort>,
         block_reader: Arc<BlockReader>,
         partitions: StealablePartitions,
+        runtime_filter_pruner: Option<Arc<RuntimeFilterPruner>>,
     ) -> Result<ProcessorPtr> {
         let batch_size = ctx.get_settings().get_storage_fetch_part_num()? as usize;
         Ok(ProcessorPtr::create(Box::new(ReadNativeDataSource::<
@@ -83,6 +87,7 @@ impl ReadNativeDataSource<false> {
             finished: false,
             output_data: None,
             partitions,
+            runtime_filter_pruner,
         })))
     }
 }
@@ -136,7 +141,14 @@ impl Processor for ReadNativeDataSource<false> {
     }
 
     async fn async_process(&mut self) -> Result<()> {
-        let parts = self.partitions.steal(self.id, self.batch_size);
+        let parts = match &self.runtime_filter_pruner {
+            Some(pruner) => {
+                pruner
+                    .steal_parts(&self.partitions, self.id, self.batch_size)
+                    .await?
+            }
+            None => self.partitions.steal(self.id, self.batch_size),
+        };
 
         if !parts.is_empty() {
             let mut chunks = Vec::with_capacity(parts.len());
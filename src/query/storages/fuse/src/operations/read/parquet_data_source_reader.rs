@@ -32,6 +32,7 @@ use crate::fuse_part::FusePartInfo;
 use crate::io::BlockReader;
 use crate::io::ReadSettings;
 use crate::operations::read::parquet_data_source::DataSourceMeta;
+use crate::pruning::RuntimeFilterPruner;
 use crate::MergeIOReadResult;
 
 pub struct ReadParquetDataSource<const BLOCKING_IO: bool> {
@@ -43,6 +44,7 @@ pub struct ReadParquetDataSource<const BLOCKING_IO: bool> {
     output: Arc<OutputPort>,
     output_data: Option<(Vec<PartInfoPtr>, Vec<MergeIOReadResult>)>,
     partitions: StealablePartitions,
+    runtime_filter_pruner: Option<Arc<RuntimeFilterPruner>>,
 }
 
 impl<const BLOCKING_IO: bool> ReadParquetDataSource<BLOCKING_IO> {
@@ -52,6 +54,7 @@ impl<const BLOCKING_IO: bool> ReadParquetDataSource<BLOCKING_IO> {
         output: Arc<OutputPort>,
         block_reader: Arc<BlockReader>,
         partitions: StealablePartitions,
+        runtime_filter_pruner: Option<Arc<RuntimeFilterPruner>>,
     ) -> Result<ProcessorPtr> {
         let batch_size = ctx.get_settings().get_storage_fetch_part_num()? as usize;
 
@@ -64,6 +67,7 @@ impl<const BLOCKING_IO: bool> ReadParquetDataSource<BLOCKING_IO> {
                 finished: false,
                 output_data: None,
                 partitions,
+                runtime_filter_pruner,
             })
         } else {
             Ok(ProcessorPtr::create(Box::new(ReadParquetDataSource::<
@@ -76,6 +80,7 @@ impl<const BLOCKING_IO: bool> ReadParquetDataSource<BLOCKING_IO> {
                 finished: false,
                 output_data: None,
                 partitions,
+                runtime_filter_pruner,
             })))
         }
     }
@@ -133,7 +138,14 @@ impl Processor for ReadParquetDataSource<false> {
     }
 
     async fn async_process(&mut self) -> Result<()> {
-        let parts = self.partitions.steal(self.id, self.batch_size);
+        let parts = match &self.runtime_filter_pruner {
+            Some(pruner) => {
+                pruner
+                    .steal_parts(&self.partitions, self.id, self.batch_size)
+                    .await?
+            }
+            None => self.partitions.steal(self.id, self.batch_size),
+        };
 
         if !parts.is_empty() {
             let mut chunks = Vec::with_capacity(parts.len());
@@ -403,6 +403,9 @@ impl FuseTable {
             meta.compression(),
             sort_min_max,
             block_meta_index.to_owned(),
+            meta.col_stats.clone(),
+            meta.bloom_filter_index_location.clone(),
+            meta.bloom_filter_index_size,
         )
     }
 
@@ -445,6 +448,9 @@ impl FuseTable {
             meta.compression(),
             sort_min_max,
             block_meta_index.to_owned(),
+            meta.col_stats.clone(),
+            meta.bloom_filter_index_location.clone(),
+            meta.bloom_filter_index_size,
         )
     }
 }
@@ -132,6 +132,7 @@ impl FuseTable {
             tbl_args: self.table_args(),
             push_downs: None,
             query_internal_columns: false,
+            table_index: None,
         };
 
         ctx.set_partitions(plan.parts.clone())?;
@@ -15,6 +15,7 @@
 use std::collections::BTreeMap;
 use std::sync::Arc;
 
+use common_catalog::plan::MatchedRows;
 use common_catalog::plan::Projection;
 use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
@@ -39,12 +40,16 @@ use crate::FuseTable;
 impl FuseTable {
     /// UPDATE column = expression WHERE condition
     /// The flow of Pipeline is the same as that of deletion.
+    ///
+    /// For UPDATE ... FROM, the rows to update are given by `matched_rows` instead of `filter`,
+    /// and the update expressions refer to their new values.
     pub async fn do_update(
         &self,
         ctx: Arc<dyn TableContext>,
         filter: Option<RemoteExpr<String>>,
         col_indices: Vec<FieldIndex>,
         update_list: Vec<(usize, RemoteExpr<String>)>,
+        matched_rows: Option<MatchedRows>,
         pipeline: &mut Pipeline,
     ) -> Result<()> {
         let snapshot_opt = self.read_table_snapshot().await?;
@@ -78,6 +83,7 @@ impl FuseTable {
             filter,
             col_indices,
             update_list,
+            matched_rows.map(Arc::new),
             &snapshot,
             pipeline,
         )
@@ -108,6 +114,7 @@ impl FuseTable {
         filter: Option<RemoteExpr<String>>,
         col_indices: Vec<FieldIndex>,
         update_list: Vec<(FieldIndex, RemoteExpr<String>)>,
+        matched_rows: Option<Arc<MatchedRows>>,
         base_snapshot: &TableSnapshot,
         pipeline: &mut Pipeline,
     ) -> Result<()> {
@@ -117,7 +124,23 @@ impl FuseTable {
         let mut offset_map = BTreeMap::new();
         let mut remain_reader = None;
         let mut pos = 0;
-        let (projection, input_schema) = if col_indices.is_empty() {
+        let (projection, input_schema) = if let Some(matched_rows) = &matched_rows {
+            all_column_indices.iter().for_each(|&index| {
+                offset_map.insert(index, pos);
+                pos += 1;
+            });
+
+            // The source appends the predicate and the new values of the matched rows.
+            let mut fields = schema.fields().clone();
+            fields.push(TableField::new("_predicate", TableDataType::Boolean));
+            fields.extend_from_slice(matched_rows.schema().fields());
+            pos += 1 + matched_rows.schema().num_fields();
+
+            (
+                Projection::Columns(all_column_indices),
+                Arc::new(TableSchema::new(fields)),
+            )
+        } else if col_indices.is_empty() {
             all_column_indices.iter().for_each(|&index| {
                 offset_map.insert(index, pos);
                 pos += 1;
@@ -205,9 +228,13 @@ impl FuseTable {
         // Add source pipe.
         pipeline.add_source(
             |output| {
+                let action = match &matched_rows {
+                    Some(matched_rows) => MutationAction::UpdateFrom(matched_rows.clone()),
+                    None => MutationAction::Update,
+                };
                 MutationSource::try_create(
                     ctx.clone(),
-                    MutationAction::Update,
+                    action,
                     output,
                     filter_expr.clone(),
                     block_reader.clone(),
@@ -22,6 +22,8 @@ use common_base::base::tokio::sync::OwnedSemaphorePermit;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use futures_util::future;
+use storages_common_index::BloomMemoryBudget;
+use storages_common_index::FilterEvalResult;
 use storages_common_pruner::BlockMetaIndex;
 use storages_common_table_meta::meta::BlockMeta;
 use storages_common_table_meta::meta::SegmentInfo;
@@ -70,6 +72,7 @@ impl BlockPruner {
         let limit_pruner = self.pruning_ctx.limit_pruner.clone();
         let range_pruner = self.pruning_ctx.range_pruner.clone();
         let page_pruner = self.pruning_ctx.page_pruner.clone();
+        let bloom_budget = BloomMemoryBudget::create(self.pruning_ctx.bloom_memory_limit);
 
         let block_num = segment_info.blocks.len();
         let mut blocks = segment_info.blocks.iter().enumerate();
@@ -109,6 +112,7 @@ impl BlockPruner {
                     let bloom_pruner = bloom_pruner.clone();
                     let limit_pruner = limit_pruner.clone();
                     let page_pruner = page_pruner.clone();
+                    let bloom_budget = bloom_budget.clone();
                     let index_location = block_meta.bloom_filter_index_location.clone();
                     let index_size = block_meta.bloom_filter_index_size;
                     let column_ids = block_meta.col_metas.keys().cloned().collect::<Vec<_>>();
@@ -124,9 +128,23 @@ impl BlockPruner {
                             }
 
                             let _permit = permit;
-                            let keep = bloom_pruner
-                                .should_keep(&index_location, index_size, column_ids)
-                                .await
+                            // The size of the index file is taken as the memory of the filters,
+                            // if it is not affordable, the block is kept by range pruning only.
+                            let result = match bloom_budget.try_reserve(index_size) {
+                                Some(_reservation) => {
+                                    bloom_pruner
+                                        .evaluate(&index_location, index_size, column_ids)
+                                        .await
+                                }
+                                None => {
+                                    pruning_stats.set_blocks_bloom_pruning_skipped(1);
+                                    FilterEvalResult::Uncertain
+                                }
+                            };
+                            if result == FilterEvalResult::Partial {
+                                pruning_stats.set_blocks_bloom_pruning_partial(1);
+                            }
+                            let keep = result != FilterEvalResult::MustFalse
                                 && limit_pruner.within_limit(row_count);
 
                             if keep {
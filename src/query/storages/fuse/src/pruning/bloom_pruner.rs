@@ -32,13 +32,13 @@ use crate::io::BloomBlockFilterReader;
 
 #[async_trait::async_trait]
 pub trait BloomPruner {
-    // returns true, if target should NOT be pruned (false positive allowed)
-    async fn should_keep(
+    // returns `MustFalse`, if target should be pruned, otherwise it should be kept (false positive allowed)
+    async fn evaluate(
         &self,
         index_location: &Option<Location>,
         index_length: u64,
         column_ids: Vec<ColumnId>,
-    ) -> bool;
+    ) -> FilterEvalResult;
 }
 
 pub struct BloomPrunerCreator {
@@ -104,7 +104,7 @@ impl BloomPrunerCreator {
         index_location: &Location,
         index_length: u64,
         column_ids_of_indexed_block: Vec<ColumnId>,
-    ) -> Result<bool> {
+    ) -> Result<FilterEvalResult> {
         let version = index_location.1;
 
         // filter out columns that no longer exist in the indexed block
@@ -123,20 +123,19 @@ impl BloomPrunerCreator {
             .await;
 
         match maybe_filter {
-            Ok(filter) => Ok(BloomIndex::from_filter_block(
+            Ok(filter) => BloomIndex::from_filter_block(
                 self.func_ctx,
                 self.data_schema.clone(),
                 filter.filter_schema,
                 filter.filters,
                 version,
             )?
-            .apply(self.filter_expression.clone(), &self.scalar_map)?
-                != FilterEvalResult::MustFalse),
+            .apply(self.filter_expression.clone(), &self.scalar_map),
             Err(e) if e.code() == ErrorCode::DEPRECATED_INDEX_FORMAT => {
-                // In case that the index is no longer supported, just return true to indicate
+                // In case that the index is no longer supported, just return `Uncertain` to indicate
                 // that the block being pruned should be kept. (Although the caller of this method
-                // "FilterPruner::should_keep",  will ignore any exceptions returned)
-                Ok(true)
+                // "BloomPruner::evaluate",  will ignore any exceptions returned)
+                Ok(FilterEvalResult::Uncertain)
             }
             Err(e) => Err(e),
         }
@@ -145,24 +144,24 @@ impl BloomPrunerCreator {
 
 #[async_trait::async_trait]
 impl BloomPruner for BloomPrunerCreator {
-    async fn should_keep(
+    async fn evaluate(
         &self,
         index_location: &Option<Location>,
         index_length: u64,
         column_ids: Vec<ColumnId>,
-    ) -> bool {
+    ) -> FilterEvalResult {
         if let Some(loc) = index_location {
             // load filter, and try pruning according to filter expression
             match self.apply(loc, index_length, column_ids).await {
                 Ok(v) => v,
                 Err(e) => {
                     // swallow exceptions intentionally, corrupted index should not prevent execution
-                    tracing::warn!("failed to apply bloom pruner, returning true. {}", e);
-                    true
+                    tracing::warn!("failed to apply bloom pruner, returning uncertain. {}", e);
+                    FilterEvalResult::Uncertain
                 }
             }
         } else {
-            true
+            FilterEvalResult::Uncertain
         }
     }
 }
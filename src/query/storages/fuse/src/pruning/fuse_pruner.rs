@@ -24,6 +24,7 @@ use common_expression::RemoteExpr;
 use common_expression::TableSchemaRef;
 use common_functions::scalars::BUILTIN_FUNCTIONS;
 use opendal::Operator;
+use storages_common_index::PageIndex;
 use storages_common_pruner::BlockMetaIndex;
 use storages_common_pruner::Limiter;
 use storages_common_pruner::LimiterPrunerCreator;
@@ -34,6 +35,7 @@ use storages_common_pruner::RangePrunerCreator;
 use storages_common_pruner::TopNPrunner;
 use storages_common_table_meta::meta::BlockMeta;
 use storages_common_table_meta::meta::ClusterKey;
+use storages_common_table_meta::meta::ColumnMeta;
 use storages_common_table_meta::meta::Location;
 use tracing::warn;
 
@@ -48,6 +50,8 @@ pub struct PruningContext {
     pub dal: Operator,
     pub pruning_runtime: Arc<Runtime>,
     pub pruning_semaphore: Arc<Semaphore>,
+    /// Max memory of the bloom filters loaded at the same time for a segment, 0 means no limit.
+    pub bloom_memory_limit: u64,
 
     pub limit_pruner: Arc<dyn Limiter + Send + Sync>,
     pub range_pruner: Arc<dyn RangePruner + Send + Sync>,
@@ -61,6 +65,7 @@ pub struct FusePruner {
     pub table_schema: TableSchemaRef,
     pub pruning_ctx: Arc<PruningContext>,
     pub push_down: Option<PushDownInfo>,
+    pub cluster_key_id: Option<u32>,
 }
 
 impl FusePruner {
@@ -109,6 +114,7 @@ impl FusePruner {
             BloomPrunerCreator::create(func_ctx, &table_schema, dal.clone(), filter_expr.as_ref())?;
 
         // Page pruner, used in native format
+        let cluster_key_id = cluster_key_meta.as_ref().map(|(id, _)| *id);
         let page_pruner = PagePrunerCreator::try_create(
             func_ctx,
             &table_schema,
@@ -138,6 +144,9 @@ impl FusePruner {
             Some("pruning-worker".to_owned()),
         )?);
         let pruning_semaphore = Arc::new(Semaphore::new(max_concurrency));
+        let bloom_memory_limit = ctx
+            .get_settings()
+            .get_max_bloom_filter_memory_per_segment()?;
         let pruning_stats = Arc::new(FusePruningStatistics::default());
 
         let pruning_ctx = Arc::new(PruningContext {
@@ -145,6 +154,7 @@ impl FusePruner {
             dal,
             pruning_runtime,
             pruning_semaphore,
+            bloom_memory_limit,
             limit_pruner,
             range_pruner,
             bloom_pruner,
@@ -156,11 +166,12 @@ impl FusePruner {
             table_schema,
             push_down: push_down.clone(),
             pruning_ctx,
+            cluster_key_id,
         })
     }
 
     // Pruning chain:
-    // segment pruner -> block pruner -> topn pruner
+    // segment pruner -> block pruner -> topn pruner -> page limit pruner
     pub async fn pruning(
         &self,
         segment_locs: Vec<Location>,
@@ -175,7 +186,10 @@ impl FusePruner {
         let metas = segment_pruner.pruning(segment_locs).await?;
 
         // TopN pruner.
-        self.topn_pruning(metas)
+        let metas = self.topn_pruning(metas)?;
+
+        // Page limit pruner.
+        Ok(self.page_limit_pruning(metas))
     }
 
     // topn pruner:
@@ -200,6 +214,47 @@ impl FusePruner {
         Ok(metas)
     }
 
+    // page limit pruner:
+    // if the query is ordered by the cluster keys with a limit and no filters,
+    // only read the leading or trailing pages of the blocks sorted by the cluster keys
+    fn page_limit_pruning(
+        &self,
+        metas: Vec<(BlockMetaIndex, Arc<BlockMeta>)>,
+    ) -> Vec<(BlockMetaIndex, Arc<BlockMeta>)> {
+        let page_limit = self
+            .push_down
+            .as_ref()
+            .filter(|p| p.filter.is_none() && p.prewhere.is_none())
+            .and_then(|p| p.page_limit.as_ref());
+        let (cluster_key_id, page_limit) = match (self.cluster_key_id, page_limit) {
+            (Some(cluster_key_id), Some(page_limit)) => (cluster_key_id, page_limit),
+            _ => return metas,
+        };
+
+        metas
+            .into_iter()
+            .map(|(mut index, meta)| {
+                if index.range.is_none() {
+                    if let Some(ColumnMeta::Native(column_meta)) = meta.col_metas.values().next() {
+                        let page_rows = column_meta
+                            .pages
+                            .iter()
+                            .map(|page| page.num_values)
+                            .collect::<Vec<_>>();
+                        index.range = PageIndex::limit_page_range(
+                            &meta.cluster_stats,
+                            cluster_key_id,
+                            &page_rows,
+                            page_limit.limit,
+                            page_limit.asc,
+                        );
+                    }
+                }
+                (index, meta)
+            })
+            .collect()
+    }
+
     // Pruning stats.
     pub fn pruning_stats(&self) -> common_catalog::plan::PruningStatistics {
         let stats = self.pruning_ctx.pruning_stats.clone();
@@ -212,6 +267,8 @@ impl FusePruner {
 
         let blocks_bloom_pruning_before = stats.get_blocks_bloom_pruning_before() as usize;
         let blocks_bloom_pruning_after = stats.get_blocks_bloom_pruning_after() as usize;
+        let blocks_bloom_pruning_partial = stats.get_blocks_bloom_pruning_partial() as usize;
+        let blocks_bloom_pruning_skipped = stats.get_blocks_bloom_pruning_skipped() as usize;
 
         common_catalog::plan::PruningStatistics {
             segments_range_pruning_before,
@@ -220,6 +277,8 @@ impl FusePruner {
             blocks_range_pruning_after,
             blocks_bloom_pruning_before,
             blocks_bloom_pruning_after,
+            blocks_bloom_pruning_partial,
+            blocks_bloom_pruning_skipped,
         }
     }
 }
@@ -17,6 +17,7 @@ mod bloom_pruner;
 mod fuse_pruner;
 mod pruner_location;
 mod pruning_statistics;
+mod runtime_filter_pruner;
 mod segment_pruner;
 
 pub use block_pruner::BlockPruner;
@@ -27,4 +28,5 @@ pub use fuse_pruner::PruningContext;
 pub use pruner_location::create_segment_location_vector;
 pub use pruner_location::SegmentLocation;
 pub use pruning_statistics::FusePruningStatistics;
+pub use runtime_filter_pruner::RuntimeFilterPruner;
 pub use segment_pruner::SegmentPruner;
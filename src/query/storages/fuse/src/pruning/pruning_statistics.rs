@@ -28,6 +28,10 @@ pub struct FusePruningStatistics {
     /// Block bloom filter pruning stats.
     pub blocks_bloom_pruning_before: AtomicU64,
     pub blocks_bloom_pruning_after: AtomicU64,
+    /// Blocks kept by bloom filter with a residual predicate on unindexed columns.
+    pub blocks_bloom_pruning_partial: AtomicU64,
+    /// Blocks kept without loading the bloom filter, as the memory budget is exhausted.
+    pub blocks_bloom_pruning_skipped: AtomicU64,
 }
 
 impl FusePruningStatistics {
@@ -84,4 +88,22 @@ impl FusePruningStatistics {
     pub fn get_blocks_bloom_pruning_after(&self) -> u64 {
         self.blocks_bloom_pruning_after.load(Ordering::Relaxed)
     }
+
+    pub fn set_blocks_bloom_pruning_partial(&self, v: u64) {
+        self.blocks_bloom_pruning_partial
+            .fetch_add(v, Ordering::Relaxed);
+    }
+
+    pub fn get_blocks_bloom_pruning_partial(&self) -> u64 {
+        self.blocks_bloom_pruning_partial.load(Ordering::Relaxed)
+    }
+
+    pub fn set_blocks_bloom_pruning_skipped(&self, v: u64) {
+        self.blocks_bloom_pruning_skipped
+            .fetch_add(v, Ordering::Relaxed);
+    }
+
+    pub fn get_blocks_bloom_pruning_skipped(&self) -> u64 {
+        self.blocks_bloom_pruning_skipped.load(Ordering::Relaxed)
+    }
 }
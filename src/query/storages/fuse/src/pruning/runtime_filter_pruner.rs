@@ -0,0 +1,179 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::plan::DataSourcePlan;
+use common_catalog::plan::PartInfoPtr;
+use common_catalog::plan::StealablePartitions;
+use common_catalog::runtime_filter_info::RuntimeFilterInfo;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::FunctionContext;
+use common_expression::TableField;
+use common_expression::TableSchemaRef;
+use opendal::Operator;
+use storages_common_index::BloomIndex;
+use storages_common_index::RuntimeBloomFilter;
+use storages_common_table_meta::meta::Location;
+
+use crate::fuse_part::FusePartInfo;
+use crate::io::BloomBlockFilterReader;
+use crate::metrics::*;
+
+/// Prune the parts of a table scan by the runtime filters pushed down by the joins.
+///
+/// The runtime filters are only ready once the build sides of the joins are finished,
+/// so the pruner waits for them before returning any part.
+pub struct RuntimeFilterPruner {
+    func_ctx: FunctionContext,
+    dal: Operator,
+    schema: TableSchemaRef,
+    infos: Vec<Arc<RuntimeFilterInfo>>,
+}
+
+impl RuntimeFilterPruner {
+    pub fn try_create(
+        ctx: &Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
+        dal: Operator,
+        schema: TableSchemaRef,
+    ) -> Result<Option<Arc<RuntimeFilterPruner>>> {
+        let infos = match plan.table_index {
+            Some(table_index) => ctx.get_runtime_filters(table_index),
+            None => vec![],
+        };
+        if infos.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(Arc::new(RuntimeFilterPruner {
+            func_ctx: ctx.get_function_context()?,
+            dal,
+            schema,
+            infos,
+        })))
+    }
+
+    /// Steal the parts and prune them, until some parts are left or there are no more parts.
+    pub async fn steal_parts(
+        &self,
+        partitions: &StealablePartitions,
+        idx: usize,
+        max_size: usize,
+    ) -> Result<Vec<PartInfoPtr>> {
+        let mut filters = Vec::with_capacity(self.infos.len());
+        for info in &self.infos {
+            filters.push(info.wait_filters().await);
+        }
+
+        loop {
+            let parts = partitions.steal(idx, max_size);
+            if parts.is_empty() {
+                return Ok(parts);
+            }
+
+            metrics_inc_blocks_runtime_filter_pruning_before(parts.len() as u64);
+            let mut kept = Vec::with_capacity(parts.len());
+            for part in parts {
+                if self.should_keep(&part, &filters).await? {
+                    kept.push(part);
+                }
+            }
+            metrics_inc_blocks_runtime_filter_pruning_after(kept.len() as u64);
+
+            if !kept.is_empty() {
+                return Ok(kept);
+            }
+        }
+    }
+
+    async fn should_keep(
+        &self,
+        part: &PartInfoPtr,
+        filters: &[Arc<Vec<RuntimeBloomFilter>>],
+    ) -> Result<bool> {
+        let part = FusePartInfo::from_part(part)?;
+
+        // Prune by the statistics first, the bloom filters are only loaded if they can't.
+        let mut bloom_filters = vec![];
+        for filter in filters.iter().flat_map(|filters| filters.iter()) {
+            let field = match self.schema.field_with_name(&filter.column_name) {
+                Ok(field) => field,
+                Err(_) => continue,
+            };
+            if DataType::from(field.data_type()).remove_nullable() != filter.data_type {
+                continue;
+            }
+
+            // The column may be added after the block is written.
+            if let Some(stat) = part.columns_stat.get(&field.column_id()) {
+                if !filter.may_match_range(stat) {
+                    return Ok(false);
+                }
+                if filter.digests.is_some() {
+                    bloom_filters.push((field, filter));
+                }
+            }
+        }
+
+        match &part.bloom_index_location {
+            Some(location) if !bloom_filters.is_empty() => {
+                match self
+                    .may_match_bloom(location, part.bloom_index_size, &bloom_filters)
+                    .await
+                {
+                    Ok(keep) => Ok(keep),
+                    Err(e) => {
+                        // swallow exceptions intentionally, corrupted index should not prevent execution
+                        tracing::warn!("failed to apply runtime filter, keep the part. {}", e);
+                        Ok(true)
+                    }
+                }
+            }
+            _ => Ok(true),
+        }
+    }
+
+    async fn may_match_bloom(
+        &self,
+        location: &Location,
+        index_length: u64,
+        bloom_filters: &[(&TableField, &RuntimeBloomFilter)],
+    ) -> Result<bool> {
+        let version = location.1;
+        let columns = bloom_filters
+            .iter()
+            .map(|(field, _)| BloomIndex::build_filter_column_name(version, field))
+            .collect::<Result<Vec<_>>>()?;
+        let filter = location
+            .read_block_filter(self.dal.clone(), &columns, index_length)
+            .await?;
+        let index = BloomIndex::from_filter_block(
+            self.func_ctx,
+            self.schema.clone(),
+            filter.filter_schema,
+            filter.filters,
+            version,
+        )?;
+
+        for (_, filter) in bloom_filters {
+            if !filter.may_match_bloom(&index)? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+}
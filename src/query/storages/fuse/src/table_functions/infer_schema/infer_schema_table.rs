@@ -32,6 +32,7 @@ use common_expression::TableField;
 use common_expression::TableSchema;
 use common_expression::TableSchemaRefExt;
 use common_meta_app::principal::StageFileFormatType;
+use common_meta_app::principal::UserPrivilegeType;
 use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
@@ -174,8 +175,12 @@ impl AsyncSource for InferSchemaSource {
         }
         self.is_finished = true;
 
-        let (stage_info, path) =
-            parse_stage_location(&self.ctx, &self.args_parsed.location).await?;
+        let (stage_info, path) = parse_stage_location(
+            &self.ctx,
+            &self.args_parsed.location,
+            UserPrivilegeType::Read,
+        )
+        .await?;
         let files_info = StageFilesInfo {
             path,
             ..self.args_parsed.files_info.clone()
@@ -15,6 +15,7 @@
 use std::any::Any;
 use std::ops::Deref;
 use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
 use common_base::base::uuid;
@@ -45,7 +46,7 @@ use parking_lot::Mutex;
 
 use crate::stage_table_sink::StageTableSink;
 
-/// TODO: we need to track the data metrics in stage table.
+/// The rows and bytes unloaded into the stage are added to the write progress of the query.
 pub struct StageTable {
     table_info: StageTableInfo,
     // This is no used but a placeholder.
@@ -53,6 +54,8 @@ pub struct StageTable {
     // fn get_table_info(&self) -> &TableInfo).
     table_info_placeholder: TableInfo,
     block_compact_threshold: Mutex<Option<BlockThresholds>>,
+    // The number of files unloaded into the stage.
+    unloaded_files: Arc<AtomicUsize>,
 }
 
 impl StageTable {
@@ -63,9 +66,14 @@ impl StageTable {
             table_info,
             table_info_placeholder,
             block_compact_threshold: Default::default(),
+            unloaded_files: Default::default(),
         }))
     }
 
+    pub fn unloaded_files(&self) -> usize {
+        self.unloaded_files.load(Ordering::Relaxed)
+    }
+
     /// Get operator with correctly prefix.
     pub fn get_op(stage: &StageInfo) -> Result<Operator> {
         init_stage_operator(stage)
@@ -180,7 +188,12 @@ impl Table for StageTable {
         )?);
 
         input_ctx.format.exec_copy(input_ctx.clone(), pipeline)?;
-        ctx.set_on_error_map(input_ctx.get_maximum_error_per_file());
+        // The errors are only known once all the files are read.
+        pipeline.set_on_finished(move |_| {
+            ctx.set_file_load_status(input_ctx.get_file_load_status());
+            ctx.set_on_error_rows(input_ctx.get_error_rows());
+            Ok(())
+        });
         Ok(())
     }
 
@@ -200,7 +213,7 @@ impl Table for StageTable {
         // parallel compact unload, the partial block will flush into next operator
         if !single && pipeline.output_len() > 1 {
             pipeline.add_transform(|input, output| {
-                let gid = group_id.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                let gid = group_id.fetch_add(1, Ordering::Relaxed);
                 StageTableSink::try_create(
                     input,
                     ctx.clone(),
@@ -209,6 +222,7 @@ impl Table for StageTable {
                     Some(output),
                     uuid.clone(),
                     gid,
+                    self.unloaded_files.clone(),
                 )
             })?;
         }
@@ -218,7 +232,7 @@ impl Table for StageTable {
 
         // Add sink pipe.
         pipeline.add_sink(|input| {
-            let gid = group_id.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            let gid = group_id.fetch_add(1, Ordering::Relaxed);
             StageTableSink::try_create(
                 input,
                 ctx.clone(),
@@ -227,6 +241,7 @@ impl Table for StageTable {
                 None,
                 uuid.clone(),
                 gid,
+                self.unloaded_files.clone(),
             )
         })
     }
@@ -13,9 +13,12 @@
 // limitations under the License.
 
 use std::any::Any;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use common_base::base::ProgressValues;
 use common_catalog::plan::StageTableInfo;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
@@ -39,6 +42,7 @@ enum State {
 }
 
 pub struct StageTableSink {
+    ctx: Arc<dyn TableContext>,
     state: State,
     input: Arc<InputPort>,
     data_accessor: Operator,
@@ -47,6 +51,8 @@ pub struct StageTableSink {
     table_info: StageTableInfo,
     working_buffer: Vec<u8>,
     working_datablocks: Vec<DataBlock>,
+    // The rows serialized into the working buffer.
+    working_rows: usize,
     output_format: Box<dyn OutputFormat>,
     write_header: bool,
 
@@ -56,6 +62,7 @@ pub struct StageTableSink {
 
     single: bool,
     max_file_size: usize,
+    unloaded_files: Arc<AtomicUsize>,
 }
 
 impl StageTableSink {
@@ -69,6 +76,7 @@ impl StageTableSink {
 
         uuid: String,
         group_id: usize,
+        unloaded_files: Arc<AtomicUsize>,
     ) -> Result<ProcessorPtr> {
         let output_format = FileFormatOptionsExt::get_output_format_from_format_options(
             table_info.schema(),
@@ -80,6 +88,7 @@ impl StageTableSink {
         let single = table_info.stage_info.copy_options.single;
 
         Ok(ProcessorPtr::create(Box::new(StageTableSink {
+            ctx,
             input,
             data_accessor,
             table_info,
@@ -89,12 +98,14 @@ impl StageTableSink {
             output_format,
             working_buffer: Vec::with_capacity((max_file_size as f64 * 1.2) as usize),
             working_datablocks: vec![],
+            working_rows: 0,
             write_header: false,
 
             uuid,
             group_id,
             batch_id: 0,
             max_file_size,
+            unloaded_files,
         })))
     }
 
@@ -177,6 +188,8 @@ impl Processor for StageTableSink {
                 return Ok(Event::Async);
             }
 
+            // The held blocks are unloaded by the final sink.
+            self.working_rows = 0;
             match (&self.output, self.working_datablocks.is_empty()) {
                 (Some(output), false) => {
                     if output.can_push() {
@@ -220,6 +233,7 @@ impl Processor for StageTableSink {
 
                         let bs = self.output_format.serialize_block(&small_block)?;
                         self.working_buffer.extend_from_slice(bs.as_slice());
+                        self.working_rows += small_block.num_rows();
 
                         if self.working_buffer.len() + self.output_format.buffer_size()
                             >= self.max_file_size
@@ -241,6 +255,7 @@ impl Processor for StageTableSink {
                 } else {
                     let bs = self.output_format.serialize_block(&datablock)?;
                     self.working_buffer.extend_from_slice(bs.as_slice());
+                    self.working_rows += datablock.num_rows();
                 }
 
                 // hold this datablock
@@ -260,7 +275,13 @@ impl Processor for StageTableSink {
             State::NeedWrite(bytes, remainng_block) => {
                 let path = self.unload_path();
 
+                let progress_values = ProgressValues {
+                    rows: std::mem::take(&mut self.working_rows),
+                    bytes: bytes.len(),
+                };
                 self.data_accessor.write(&path, bytes).await?;
+                self.ctx.get_write_progress().incr(&progress_values);
+                self.unloaded_files.fetch_add(1, Ordering::Relaxed);
 
                 match remainng_block {
                     Some(block) => self.state = State::NeedSerialize(block),
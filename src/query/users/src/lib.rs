@@ -27,10 +27,12 @@ mod user_udf;
 
 pub mod file_format;
 pub mod idm_config;
+pub mod privilege_cache;
 pub mod role_cache_mgr;
 pub mod role_util;
 
 pub use jwt::*;
+pub use privilege_cache::PrivilegeCache;
 pub use role_cache_mgr::RoleCacheManager;
 pub use role_mgr::BUILTIN_ROLE_ACCOUNT_ADMIN;
 pub use role_mgr::BUILTIN_ROLE_PUBLIC;
@@ -0,0 +1,55 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_meta_app::principal::RoleInfo;
+use parking_lot::RwLock;
+
+struct CachedRelatedRoles {
+    generation: u64,
+    roles: Vec<String>,
+    related_roles: Vec<RoleInfo>,
+}
+
+/// A per session cache of the roles related to the session's roles, so that the role graph
+/// needn't be walked on each privilege check.
+///
+/// The cached roles are keyed by the generation of the tenant's roles in `RoleCacheManager`,
+/// which is bumped each time the roles are reloaded, e.g. on GRANT and REVOKE.
+#[derive(Default)]
+pub struct PrivilegeCache {
+    cached: RwLock<Option<CachedRelatedRoles>>,
+}
+
+impl PrivilegeCache {
+    /// Returns the cached related roles if they're found from the same roles, and the roles
+    /// of the tenant are not changed since.
+    pub fn get(&self, generation: u64, roles: &[String]) -> Option<Vec<RoleInfo>> {
+        let cached = self.cached.read();
+        match cached.as_ref() {
+            Some(cached) if cached.generation == generation && cached.roles == roles => {
+                Some(cached.related_roles.clone())
+            }
+            _ => None,
+        }
+    }
+
+    pub fn set(&self, generation: u64, roles: Vec<String>, related_roles: Vec<RoleInfo>) {
+        let mut cached = self.cached.write();
+        *cached = Some(CachedRelatedRoles {
+            generation,
+            roles,
+            related_roles,
+        });
+    }
+}
@@ -13,6 +13,9 @@
 // limitations under the License.
 
 use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::time::Duration;
 use std::time::Instant;
@@ -22,20 +25,65 @@ use common_base::base::tokio::task::JoinHandle;
 use common_base::base::GlobalInstance;
 use common_exception::Result;
 use common_meta_app::principal::RoleInfo;
+use common_metrics::label_counter_with_val_and_labels;
+use common_metrics::LABEL_KEY_TENANT;
+use futures::StreamExt;
+use parking_lot::Mutex;
 use parking_lot::RwLock;
 use tracing::warn;
 
+use crate::privilege_cache::PrivilegeCache;
 use crate::role_util::find_all_related_roles;
+use crate::role_util::find_role_cycles;
 use crate::UserApiProvider;
 
+const METRIC_ROLE_CACHE_HIT_COUNT: &str = "role_cache_hit_count";
+const METRIC_ROLE_CACHE_MISS_COUNT: &str = "role_cache_miss_count";
+
+// The generations of all the tenants share a counter, so that the roles loaded again after
+// being invalidated never get a generation of the previous ones.
+static NEXT_GENERATION: AtomicU64 = AtomicU64::new(0);
+
 struct CachedRoles {
     roles: HashMap<String, RoleInfo>,
     cached_at: Instant,
+    generation: u64,
+}
+
+/// Lookups of a tenant's roles in `find_related_roles`, a miss means the roles had to be
+/// (re)loaded from the meta service.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RoleCacheStats {
+    pub hits: u64,
+    pub misses: u64,
+}
+
+/// The counters of `RoleCacheStats`, which are updated under the read lock of the stats of
+/// all the tenants, so that the lookups of the sessions are not serialized.
+#[derive(Default)]
+struct RoleCacheCounters {
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl RoleCacheCounters {
+    fn record(&self, miss: bool) {
+        let counter = match miss {
+            true => &self.misses,
+            false => &self.hits,
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
 }
 
 pub struct RoleCacheManager {
     user_manager: Arc<UserApiProvider>,
     cache: Arc<RwLock<HashMap<String, CachedRoles>>>,
+    stats: RwLock<HashMap<String, RoleCacheCounters>>,
+    // tenants whose roles are being loaded by `warmup`
+    warming_up: Arc<Mutex<HashSet<String>>>,
+    // tenants whose role changes are being watched, see `watch_roles`
+    watching: Arc<Mutex<HashSet<String>>>,
     polling_interval: Duration,
     polling_join_handle: Option<JoinHandle<()>>,
 }
@@ -50,32 +98,49 @@ impl RoleCacheManager {
     }
 
     pub fn try_create(user_manager: Arc<UserApiProvider>) -> Result<Arc<RoleCacheManager>> {
-        let mut role_cache_manager = Self {
+        let mut role_cache_manager = Self::create(user_manager, Duration::new(15, 0));
+        role_cache_manager.background_polling();
+        Ok(Arc::new(role_cache_manager))
+    }
+
+    /// Creates the manager without starting the background polling.
+    pub fn create(user_manager: Arc<UserApiProvider>, polling_interval: Duration) -> Self {
+        Self {
             user_manager,
             polling_join_handle: None,
             cache: Arc::new(RwLock::new(HashMap::new())),
-            polling_interval: Duration::new(15, 0),
-        };
-
-        role_cache_manager.background_polling();
-        Ok(Arc::new(role_cache_manager))
+            stats: RwLock::new(HashMap::new()),
+            warming_up: Arc::new(Mutex::new(HashSet::new())),
+            watching: Arc::new(Mutex::new(HashSet::new())),
+            polling_interval,
+        }
     }
 
     pub fn instance() -> Arc<RoleCacheManager> {
         GlobalInstance::get()
     }
 
+    /// Polls the roles of the cached tenants, it's only a fallback of the tenants whose role
+    /// changes can't be watched, e.g. the meta store is embedded or the watching is broken.
+    /// The watching of them is retried on each polling.
     pub fn background_polling(&mut self) {
         let cache = self.cache.clone();
+        let watching = self.watching.clone();
         let polling_interval = self.polling_interval;
         let user_manager = self.user_manager.clone();
         self.polling_join_handle = Some(tokio::spawn(async move {
             loop {
                 let tenants: Vec<String> = {
                     let cached = cache.read();
-                    cached.keys().cloned().collect()
+                    let watching = watching.lock();
+                    cached
+                        .keys()
+                        .filter(|tenant| !watching.contains(*tenant))
+                        .cloned()
+                        .collect()
                 };
                 for tenant in tenants {
+                    watch_roles(&user_manager, &cache, &watching, &tenant);
                     match load_roles_data(&user_manager, &tenant).await {
                         Err(err) => {
                             warn!(
@@ -94,6 +159,43 @@ impl RoleCacheManager {
         }));
     }
 
+    /// Loads the roles of the tenant in background if they are not cached, e.g. on session
+    /// start, so that the first privilege check needn't wait for the meta service. Concurrent
+    /// warmups of a tenant load the roles only once.
+    ///
+    /// Returns true if the loading is started.
+    pub fn warmup(&self, tenant: &str) -> bool {
+        if self.cache.read().contains_key(tenant) {
+            return false;
+        }
+        if !self.warming_up.lock().insert(tenant.to_string()) {
+            return false;
+        }
+
+        let cache = self.cache.clone();
+        let warming_up = self.warming_up.clone();
+        let user_manager = self.user_manager.clone();
+        let tenant = tenant.to_string();
+        watch_roles(&user_manager, &cache, &self.watching, &tenant);
+        tokio::spawn(async move {
+            match load_roles_data(&user_manager, &tenant).await {
+                Err(err) => {
+                    warn!(
+                        "role_cache_mgr warmup roles data of tenant {} failed: {}",
+                        tenant, err,
+                    )
+                }
+                Ok(data) => {
+                    // keep the roles if they are loaded meanwhile, which are not older
+                    let mut cached = cache.write();
+                    cached.entry(tenant.clone()).or_insert(data);
+                }
+            }
+            warming_up.lock().remove(&tenant);
+        });
+        true
+    }
+
     pub fn invalidate_cache(&self, tenant: &str) {
         let mut cached = self.cache.write();
         cached.remove(tenant);
@@ -114,25 +216,128 @@ impl RoleCacheManager {
         tenant: &str,
         roles: &[String],
     ) -> Result<Vec<RoleInfo>> {
-        self.maybe_reload(tenant).await?;
+        self.find_related_roles_with_tolerance(tenant, roles, Duration::ZERO)
+            .await
+    }
+
+    /// Same as `find_related_roles`, but the cached roles are used without reloading until
+    /// they're older than `stale_tolerance` in addition to the regular threshold, which trades
+    /// freshness for the latency of the lookup.
+    pub async fn find_related_roles_with_tolerance(
+        &self,
+        tenant: &str,
+        roles: &[String],
+        stale_tolerance: Duration,
+    ) -> Result<Vec<RoleInfo>> {
+        self.find_related_roles_impl(tenant, roles, stale_tolerance, None)
+            .await
+    }
+
+    /// Same as `find_related_roles_with_tolerance`, but the related roles are kept in the
+    /// session's `PrivilegeCache`, they're found again only if the roles of the tenant are
+    /// reloaded since.
+    pub async fn find_related_roles_cached(
+        &self,
+        tenant: &str,
+        roles: &[String],
+        stale_tolerance: Duration,
+        privilege_cache: &PrivilegeCache,
+    ) -> Result<Vec<RoleInfo>> {
+        self.find_related_roles_impl(tenant, roles, stale_tolerance, Some(privilege_cache))
+            .await
+    }
+
+    async fn find_related_roles_impl(
+        &self,
+        tenant: &str,
+        roles: &[String],
+        stale_tolerance: Duration,
+        privilege_cache: Option<&PrivilegeCache>,
+    ) -> Result<Vec<RoleInfo>> {
+        let reloaded = self.maybe_reload(tenant, stale_tolerance).await?;
+        self.record_lookup(tenant, reloaded);
+
         let cached = self.cache.read();
         let cached_roles = match cached.get(tenant) {
             None => return Ok(vec![]),
             Some(cached_roles) => cached_roles,
         };
-        Ok(find_all_related_roles(&cached_roles.roles, roles))
+        let privilege_cache = match privilege_cache {
+            None => return Ok(find_all_related_roles(&cached_roles.roles, roles)),
+            Some(privilege_cache) => privilege_cache,
+        };
+        if let Some(related_roles) = privilege_cache.get(cached_roles.generation, roles) {
+            return Ok(related_roles);
+        }
+        let related_roles = find_all_related_roles(&cached_roles.roles, roles);
+        privilege_cache.set(
+            cached_roles.generation,
+            roles.to_vec(),
+            related_roles.clone(),
+        );
+        Ok(related_roles)
+    }
+
+    /// The generation of the cached roles of the tenant, which is bumped each time the roles
+    /// are reloaded. Returns `None` if the roles are not cached.
+    pub fn generation(&self, tenant: &str) -> Option<u64> {
+        let cached = self.cache.read();
+        cached
+            .get(tenant)
+            .map(|cached_roles| cached_roles.generation)
     }
 
     pub async fn force_reload(&self, tenant: &str) -> Result<()> {
+        watch_roles(&self.user_manager, &self.cache, &self.watching, tenant);
         let data = load_roles_data(&self.user_manager, tenant).await?;
         let mut cached = self.cache.write();
         cached.insert(tenant.to_string(), data);
         Ok(())
     }
 
+    pub fn cache_stats(&self, tenant: &str) -> RoleCacheStats {
+        let stats = self.stats.read();
+        match stats.get(tenant) {
+            None => RoleCacheStats::default(),
+            Some(counters) => RoleCacheStats {
+                hits: counters.hits.load(Ordering::Relaxed),
+                misses: counters.misses.load(Ordering::Relaxed),
+            },
+        }
+    }
+
+    fn record_lookup(&self, tenant: &str, miss: bool) {
+        // the write lock is only taken on the first lookup of the tenant
+        let recorded = match self.stats.read().get(tenant) {
+            None => false,
+            Some(counters) => {
+                counters.record(miss);
+                true
+            }
+        };
+        if !recorded {
+            let mut stats = self.stats.write();
+            stats.entry(tenant.to_string()).or_default().record(miss);
+        }
+
+        let name = match miss {
+            true => METRIC_ROLE_CACHE_MISS_COUNT,
+            false => METRIC_ROLE_CACHE_HIT_COUNT,
+        };
+
+        let labels = vec![(LABEL_KEY_TENANT, tenant.to_string())];
+        label_counter_with_val_and_labels(name, &labels, 1);
+    }
+
+    /// Returns true if the role changes of the tenant are being watched, then the cached
+    /// roles are reloaded on each change instead of by the polling.
+    pub fn is_watching(&self, tenant: &str) -> bool {
+        self.watching.lock().contains(tenant)
+    }
+
     // Load roles data if not found in cache. Watch this tenant's role data in background if
-    // once it loads successfully.
-    async fn maybe_reload(&self, tenant: &str) -> Result<()> {
+    // once it loads successfully. Returns true if the data is reloaded.
+    async fn maybe_reload(&self, tenant: &str, stale_tolerance: Duration) -> Result<bool> {
         let need_reload = {
             let cached = self.cache.read();
             match cached.get(tenant) {
@@ -140,9 +345,13 @@ impl RoleCacheManager {
                 Some(cached_roles) => {
                     // force reload the data when:
                     // - if the cache is too old (the background polling task
-                    //   may got some network errors, leaves the cache outdated)
+                    //   may got some network errors, leaves the cache outdated),
+                    //   unless it is within the tolerance of the caller, or the
+                    //   role changes are watched, which keep the cache updated
                     // - if the cache is empty
-                    cached_roles.cached_at.elapsed() >= self.polling_interval * 2
+                    (!self.is_watching(tenant)
+                        && cached_roles.cached_at.elapsed()
+                            >= self.polling_interval * 2 + stale_tolerance)
                         || cached_roles.roles.is_empty()
                 }
             }
@@ -150,7 +359,7 @@ impl RoleCacheManager {
         if need_reload {
             self.force_reload(tenant).await?;
         }
-        Ok(())
+        Ok(need_reload)
     }
 }
 
@@ -160,8 +369,67 @@ async fn load_roles_data(user_api: &Arc<UserApiProvider>, tenant: &str) -> Resul
         .into_iter()
         .map(|r| (r.identity().to_string(), r))
         .collect::<HashMap<_, _>>();
+    for cycle in find_role_cycles(&roles_map) {
+        warn!(
+            "role_cache_mgr found cyclic role grants of tenant {}: {}",
+            tenant,
+            cycle.join(" -> "),
+        );
+    }
     Ok(CachedRoles {
         roles: roles_map,
         cached_at: Instant::now(),
+        generation: NEXT_GENERATION.fetch_add(1, Ordering::Relaxed),
     })
 }
+
+/// Watch the role changes of the tenant in background, the roles are reloaded on each change,
+/// so that e.g. a revoked privilege takes effect at once instead of on the next polling.
+/// Nothing is done if the tenant is already watched.
+fn watch_roles(
+    user_manager: &Arc<UserApiProvider>,
+    cache: &Arc<RwLock<HashMap<String, CachedRoles>>>,
+    watching: &Arc<Mutex<HashSet<String>>>,
+    tenant: &str,
+) {
+    if !watching.lock().insert(tenant.to_string()) {
+        return;
+    }
+
+    let user_manager = user_manager.clone();
+    let cache = cache.clone();
+    let watching = watching.clone();
+    let tenant = tenant.to_string();
+    tokio::spawn(async move {
+        if let Err(err) = reload_on_role_changes(&user_manager, &cache, &tenant).await {
+            warn!(
+                "role_cache_mgr watch roles of tenant {} failed, fallback to polling: {}",
+                tenant, err,
+            )
+        }
+        // the roles are polled again, and the watching is retried by the polling
+        watching.lock().remove(&tenant);
+    });
+}
+
+async fn reload_on_role_changes(
+    user_manager: &Arc<UserApiProvider>,
+    cache: &RwLock<HashMap<String, CachedRoles>>,
+    tenant: &str,
+) -> Result<()> {
+    let mut stream = match user_manager.watch_roles(tenant).await? {
+        None => return Ok(()),
+        Some(stream) => stream,
+    };
+
+    // the roles may be changed before the watching starts
+    let data = load_roles_data(user_manager, tenant).await?;
+    cache.write().insert(tenant.to_string(), data);
+
+    while let Some(resp) = stream.next().await {
+        resp?;
+        let data = load_roles_data(user_manager, tenant).await?;
+        cache.write().insert(tenant.to_string(), data);
+    }
+    Ok(())
+}
@@ -81,13 +81,31 @@ impl UserApiProvider {
     //    it also contains all roles. ACCOUNT_ADMIN can access the data objects which owned by any role.
     // 2. PUBLIC, on the other side only includes the public accessible privileges, but every role
     //    contains the PUBLIC role. The data objects which owned by PUBLIC can be accessed by any role.
+    //
+    // An existing ACCOUNT_ADMIN is granted the global privileges added after it was created.
     pub async fn ensure_builtin_roles(&self, tenant: &str) -> Result<u64> {
+        let global_privileges = UserPrivilegeSet::available_privileges_on_global();
         let mut account_admin = RoleInfo::new(BUILTIN_ROLE_ACCOUNT_ADMIN);
-        account_admin.grants.grant_privileges(
-            &GrantObject::Global,
-            UserPrivilegeSet::available_privileges_on_global(),
-        );
-        self.add_role(tenant, account_admin, true).await?;
+        account_admin
+            .grants
+            .grant_privileges(&GrantObject::Global, global_privileges);
+        if self.add_role(tenant, account_admin, true).await? == 0 {
+            let account_admin = self
+                .get_role(tenant, BUILTIN_ROLE_ACCOUNT_ADMIN.to_string())
+                .await?;
+            if !account_admin
+                .grants
+                .verify_privilege(&GrantObject::Global, global_privileges.iter().collect())
+            {
+                self.grant_privileges_to_role(
+                    tenant,
+                    &account_admin.name,
+                    GrantObject::Global,
+                    global_privileges,
+                )
+                .await?;
+            }
+        }
 
         let mut public = RoleInfo::new(BUILTIN_ROLE_PUBLIC);
         public.grants.grant_privileges(
@@ -54,3 +54,49 @@ pub fn find_all_related_roles(
     }
     result
 }
+
+/// Finds the cycles of role grants, e.g. `role1` is granted to `role2` and `role2` is granted
+/// to `role1`. Each cycle is returned as the roles along it, starting and ending with the same
+/// role. Cycles don't break `find_all_related_roles`, which visits each role once, but they're
+/// most likely granted by mistake.
+pub fn find_role_cycles(cache: &HashMap<String, RoleInfo>) -> Vec<Vec<String>> {
+    fn visit(
+        cache: &HashMap<String, RoleInfo>,
+        role_identity: &str,
+        path: &mut Vec<String>,
+        visited: &mut HashSet<String>,
+        cycles: &mut Vec<Vec<String>>,
+    ) {
+        if let Some(pos) = path.iter().position(|r| r == role_identity) {
+            let mut cycle = path[pos..].to_vec();
+            cycle.push(role_identity.to_string());
+            cycles.push(cycle);
+            return;
+        }
+        if !visited.insert(role_identity.to_string()) {
+            return;
+        }
+        let role = match cache.get(role_identity) {
+            None => return,
+            Some(role) => role,
+        };
+
+        path.push(role_identity.to_string());
+        let mut related_roles = role.grants.roles();
+        related_roles.sort();
+        for related_role in related_roles {
+            visit(cache, &related_role, path, visited, cycles);
+        }
+        path.pop();
+    }
+
+    let mut role_identities = cache.keys().collect::<Vec<_>>();
+    role_identities.sort();
+
+    let mut visited = HashSet::new();
+    let mut cycles = vec![];
+    for role_identity in role_identities {
+        visit(cache, role_identity, &mut vec![], &mut visited, &mut cycles);
+    }
+    cycles
+}
@@ -15,6 +15,7 @@
 use std::sync::Arc;
 
 use common_base::base::GlobalInstance;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_grpc::RpcClientConf;
 use common_management::FileFormatApi;
@@ -36,6 +37,9 @@ use common_meta_app::tenant::TenantQuota;
 use common_meta_kvapi::kvapi;
 use common_meta_store::MetaStore;
 use common_meta_store::MetaStoreProvider;
+use common_meta_store::WatchStream;
+use common_meta_types::protobuf::watch_request::FilterType;
+use common_meta_types::protobuf::WatchRequest;
 use common_meta_types::MatchSeq;
 use common_meta_types::MetaError;
 
@@ -119,6 +123,24 @@ impl UserApiProvider {
         Arc::new(self.meta.clone())
     }
 
+    /// Watch the changes of the roles of the tenant, an event is received once a role is
+    /// created, altered or dropped. Returns `None` if the meta store is embedded, which
+    /// can't be watched.
+    pub async fn watch_roles(&self, tenant: &str) -> Result<Option<WatchStream>> {
+        if self.meta.is_local() {
+            return Ok(None);
+        }
+
+        let (key, key_end) = kvapi::prefix_to_range(&RoleMgr::role_key_prefix(tenant))
+            .map_err(|e| ErrorCode::Internal(e.to_string()))?;
+        let request = WatchRequest {
+            key,
+            key_end: Some(key_end),
+            filter_type: FilterType::All.into(),
+        };
+        Ok(Some(self.meta.watch(request).await?))
+    }
+
     pub fn get_configured_user(&self, user_name: &str) -> Option<&AuthInfo> {
         self.idm_config.users.get(user_name)
     }
@@ -14,6 +14,7 @@
 
 use std::collections::HashMap;
 use std::collections::HashSet;
+use std::time::Duration;
 
 use common_base::base::tokio;
 use common_exception::Result;
@@ -21,7 +22,11 @@ use common_grpc::RpcClientConf;
 use common_meta_app::principal::GrantObject;
 use common_meta_app::principal::RoleInfo;
 use common_meta_app::principal::UserPrivilegeSet;
+use common_meta_app::principal::UserPrivilegeType;
+use common_users::role_cache_mgr::RoleCacheStats;
 use common_users::role_util::find_all_related_roles;
+use common_users::role_util::find_role_cycles;
+use common_users::PrivilegeCache;
 use common_users::RoleCacheManager;
 use common_users::UserApiProvider;
 
@@ -48,6 +53,209 @@ async fn test_role_cache_mgr() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_role_cache_mgr_stats() -> Result<()> {
+    let conf = RpcClientConf::default();
+    let user_manager = UserApiProvider::try_create_simple(conf).await?;
+    let role_cache_manager = RoleCacheManager::try_create(user_manager.clone())?;
+
+    user_manager
+        .add_role("tenant1", RoleInfo::new("role1"), false)
+        .await?;
+    assert_eq!(
+        role_cache_manager.cache_stats("tenant1"),
+        RoleCacheStats::default()
+    );
+
+    // The tenant is cold, its roles are loaded.
+    role_cache_manager
+        .find_related_roles("tenant1", &["role1".to_string()])
+        .await?;
+    assert_eq!(role_cache_manager.cache_stats("tenant1"), RoleCacheStats {
+        hits: 0,
+        misses: 1,
+    });
+
+    role_cache_manager
+        .find_related_roles("tenant1", &["role1".to_string()])
+        .await?;
+    assert_eq!(role_cache_manager.cache_stats("tenant1"), RoleCacheStats {
+        hits: 1,
+        misses: 1,
+    });
+
+    // Counters are per tenant.
+    assert_eq!(
+        role_cache_manager.cache_stats("tenant2"),
+        RoleCacheStats::default()
+    );
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_role_cache_mgr_warmup() -> Result<()> {
+    let conf = RpcClientConf::default();
+    let user_manager = UserApiProvider::try_create_simple(conf).await?;
+    let role_cache_manager = RoleCacheManager::try_create(user_manager.clone())?;
+
+    user_manager
+        .add_role("tenant1", RoleInfo::new("role1"), false)
+        .await?;
+
+    // The roles are loaded in background, only once.
+    assert!(role_cache_manager.warmup("tenant1"));
+    assert!(!role_cache_manager.warmup("tenant1"));
+
+    let mut cached = None;
+    for _ in 0..100 {
+        cached = role_cache_manager.find_role("tenant1", "role1").await?;
+        if cached.is_some() {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    assert_eq!(cached.map(|role| role.name), Some("role1".to_string()));
+
+    // Already cached, the first privilege check is a hit.
+    assert!(!role_cache_manager.warmup("tenant1"));
+    role_cache_manager
+        .find_related_roles("tenant1", &["role1".to_string()])
+        .await?;
+    assert_eq!(role_cache_manager.cache_stats("tenant1"), RoleCacheStats {
+        hits: 1,
+        misses: 0,
+    });
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_role_cache_mgr_stale_tolerance() -> Result<()> {
+    let conf = RpcClientConf::default();
+    let user_manager = UserApiProvider::try_create_simple(conf).await?;
+    // Without background polling, the cache gets stale.
+    let role_cache_manager =
+        RoleCacheManager::create(user_manager.clone(), Duration::from_millis(50));
+
+    user_manager
+        .add_role("tenant1", RoleInfo::new("role1"), false)
+        .await?;
+    let roles = ["role1".to_string()];
+    role_cache_manager
+        .find_related_roles("tenant1", &roles)
+        .await?;
+    assert_eq!(role_cache_manager.cache_stats("tenant1").misses, 1);
+
+    // Older than twice the polling interval, but within the tolerance, it's not reloaded.
+    tokio::time::sleep(Duration::from_millis(150)).await;
+    let found = role_cache_manager
+        .find_related_roles_with_tolerance("tenant1", &roles, Duration::from_secs(60))
+        .await?;
+    assert_eq!(found.len(), 1);
+    assert_eq!(role_cache_manager.cache_stats("tenant1"), RoleCacheStats {
+        hits: 1,
+        misses: 1,
+    });
+
+    // Reloaded without tolerance.
+    role_cache_manager
+        .find_related_roles("tenant1", &roles)
+        .await?;
+    assert_eq!(role_cache_manager.cache_stats("tenant1"), RoleCacheStats {
+        hits: 1,
+        misses: 2,
+    });
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_role_cache_mgr_watch_embedded_meta() -> Result<()> {
+    let conf = RpcClientConf::default();
+    let user_manager = UserApiProvider::try_create_simple(conf).await?;
+    // The embedded meta store can't be watched.
+    assert!(user_manager.watch_roles("tenant1").await?.is_none());
+
+    let role_cache_manager =
+        RoleCacheManager::create(user_manager.clone(), Duration::from_millis(50));
+    user_manager
+        .add_role("tenant1", RoleInfo::new("role1"), false)
+        .await?;
+    let roles = ["role1".to_string()];
+    role_cache_manager
+        .find_related_roles("tenant1", &roles)
+        .await?;
+
+    // The watching ends at once, the cache falls back to the polling.
+    let mut watching = true;
+    for _ in 0..100 {
+        watching = role_cache_manager.is_watching("tenant1");
+        if !watching {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    assert!(!watching);
+
+    // Without the watching, the stale cache is reloaded.
+    tokio::time::sleep(Duration::from_millis(150)).await;
+    role_cache_manager
+        .find_related_roles("tenant1", &roles)
+        .await?;
+    assert_eq!(role_cache_manager.cache_stats("tenant1"), RoleCacheStats {
+        hits: 0,
+        misses: 2,
+    });
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_role_cache_mgr_privilege_cache() -> Result<()> {
+    let conf = RpcClientConf::default();
+    let user_manager = UserApiProvider::try_create_simple(conf).await?;
+    let role_cache_manager =
+        RoleCacheManager::create(user_manager.clone(), Duration::from_secs(60));
+    let privilege_cache = PrivilegeCache::default();
+
+    user_manager
+        .add_role("tenant1", RoleInfo::new("role1"), false)
+        .await?;
+    let roles = ["role1".to_string()];
+    let found = role_cache_manager
+        .find_related_roles_cached("tenant1", &roles, Duration::ZERO, &privilege_cache)
+        .await?;
+    assert_eq!(found.len(), 1);
+
+    // The related roles are cached with the generation of the tenant's roles.
+    let generation = role_cache_manager.generation("tenant1").unwrap();
+    assert!(privilege_cache.get(generation, &roles).is_some());
+    assert!(privilege_cache
+        .get(generation, &["role2".to_string()])
+        .is_none());
+
+    // The generation is bumped once the roles are reloaded on GRANT.
+    let object = GrantObject::Database(CATALOG_DEFAULT.to_owned(), "db1".to_string());
+    user_manager
+        .grant_privileges_to_role(
+            "tenant1",
+            &"role1".to_string(),
+            object.clone(),
+            UserPrivilegeSet::available_privileges_on_database(),
+        )
+        .await?;
+    role_cache_manager.force_reload("tenant1").await?;
+    let new_generation = role_cache_manager.generation("tenant1").unwrap();
+    assert_ne!(generation, new_generation);
+    assert!(privilege_cache.get(new_generation, &roles).is_none());
+
+    let found = role_cache_manager
+        .find_related_roles_cached("tenant1", &roles, Duration::ZERO, &privilege_cache)
+        .await?;
+    assert_eq!(found.len(), 1);
+    assert!(found[0]
+        .grants
+        .verify_privilege(&object, vec![UserPrivilegeType::Select]));
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_find_all_related_roles() -> Result<()> {
     let roles = vec![
@@ -95,3 +303,55 @@ async fn test_find_all_related_roles() -> Result<()> {
     }
     Ok(())
 }
+
+#[test]
+fn test_find_role_cycles() {
+    // role1 -> role2 -> role3 -> role1, role3 -> role4, role4 -> role4
+    let role_grants = [
+        ("role1", "role2"),
+        ("role2", "role3"),
+        ("role3", "role1"),
+        ("role3", "role4"),
+        ("role4", "role4"),
+    ];
+    let mut cached: HashMap<String, RoleInfo> = ["role1", "role2", "role3", "role4", "role5"]
+        .into_iter()
+        .map(|r| (r.to_string(), RoleInfo::new(r)))
+        .collect();
+    for (lhs, rhs) in role_grants {
+        cached
+            .get_mut(lhs)
+            .unwrap()
+            .grants
+            .grant_role(rhs.to_string())
+    }
+
+    assert_eq!(find_role_cycles(&cached), vec![
+        vec!["role1", "role2", "role3", "role1"],
+        vec!["role4", "role4"],
+    ]);
+
+    // The traversal terminates on the cycles.
+    let got: HashSet<_> = find_all_related_roles(&cached, &["role2".to_string()])
+        .into_iter()
+        .map(|r| r.identity().to_string())
+        .collect();
+    let want: HashSet<_> = ["role1", "role2", "role3", "role4"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+    assert_eq!(got, want);
+
+    // No cycle after revoking.
+    cached
+        .get_mut("role3")
+        .unwrap()
+        .grants
+        .revoke_role(&"role1".to_string());
+    cached
+        .get_mut("role4")
+        .unwrap()
+        .grants
+        .revoke_role(&"role4".to_string());
+    assert!(find_role_cycles(&cached).is_empty());
+}
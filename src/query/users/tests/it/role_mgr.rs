@@ -21,6 +21,7 @@ use common_meta_app::principal::RoleInfo;
 use common_meta_app::principal::UserPrivilegeSet;
 use common_meta_app::principal::UserPrivilegeType;
 use common_users::UserApiProvider;
+use common_users::BUILTIN_ROLE_ACCOUNT_ADMIN;
 use pretty_assertions::assert_eq;
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
@@ -98,3 +99,33 @@ async fn test_role_manager() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_ensure_builtin_roles() -> Result<()> {
+    let conf = RpcClientConf::default();
+    let role_mgr = UserApiProvider::try_create_simple(conf).await?;
+
+    let tenant = "tenant2";
+
+    // an account_admin created before the stage and udf privileges
+    {
+        let mut role_info = RoleInfo::new(BUILTIN_ROLE_ACCOUNT_ADMIN);
+        role_info
+            .grants
+            .grant_privileges(&GrantObject::Global, UserPrivilegeType::Create.into());
+        role_mgr.add_role(tenant, role_info, false).await?;
+    }
+
+    role_mgr.ensure_builtin_roles(tenant).await?;
+
+    let role = role_mgr
+        .get_role(tenant, BUILTIN_ROLE_ACCOUNT_ADMIN.to_string())
+        .await?;
+    assert!(role.grants.verify_privilege(&GrantObject::Global, vec![
+        UserPrivilegeType::Read,
+        UserPrivilegeType::Write,
+        UserPrivilegeType::Usage,
+    ]));
+
+    Ok(())
+}
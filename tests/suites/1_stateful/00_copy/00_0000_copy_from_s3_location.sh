@@ -30,7 +30,7 @@ copy_from_location_cases=(
 )
 
 for i in "${copy_from_location_cases[@]}"; do
-  echo "$i" | $MYSQL_CLIENT_CONNECT
+  echo "$i" | $MYSQL_CLIENT_CONNECT > /dev/null
   echo "select count(1), avg(Year), sum(DayOfWeek)  from ontime200" | $MYSQL_CLIENT_CONNECT
   echo "truncate table ontime200" | $MYSQL_CLIENT_CONNECT
 done
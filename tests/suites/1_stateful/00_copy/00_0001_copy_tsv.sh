@@ -20,7 +20,7 @@ copy_from_tsv_cases=(
 counter=1
 for i in "${copy_from_tsv_cases[@]}"; do
   echo "---$counter"
-  echo "$i" | $MYSQL_CLIENT_CONNECT
+  echo "$i" | $MYSQL_CLIENT_CONNECT > /dev/null
   echo "select a, b, length(a) from test_tsv" | $MYSQL_CLIENT_CONNECT
   echo "truncate table test_tsv" | $MYSQL_CLIENT_CONNECT
   _=$((counter++))
@@ -31,7 +31,7 @@ copy_from_tsv_whitespace_cases=(
 )
 echo "---test tsv field with whitespace"
 for i in "${copy_from_tsv_whitespace_cases[@]}"; do
-  echo "$i" | $MYSQL_CLIENT_CONNECT
+  echo "$i" | $MYSQL_CLIENT_CONNECT > /dev/null
   echo "select a, b, length(b) from test_tsv_field_whitespace" | $MYSQL_CLIENT_CONNECT
   echo "truncate table test_tsv_field_whitespace" | $MYSQL_CLIENT_CONNECT
 done
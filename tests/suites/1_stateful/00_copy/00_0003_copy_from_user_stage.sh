@@ -39,9 +39,9 @@ copy_from_stage_cases=(
 
 ## Copy file twice but return the same result to test idempotent-copy
 for i in "${copy_from_stage_cases[@]}"; do
-  echo "$i" | $MYSQL_CLIENT_CONNECT
+  echo "$i" | $MYSQL_CLIENT_CONNECT > /dev/null
   echo "select count(1), avg(Year), sum(DayOfWeek)  from ontime200" | $MYSQL_CLIENT_CONNECT
-  echo "$i" | $MYSQL_CLIENT_CONNECT
+  echo "$i" | $MYSQL_CLIENT_CONNECT > /dev/null
   echo "select count(1), avg(Year), sum(DayOfWeek)  from ontime200" | $MYSQL_CLIENT_CONNECT
   echo "truncate table ontime200" | $MYSQL_CLIENT_CONNECT
 done
@@ -53,7 +53,7 @@ curl -s -u root: -XPOST "http://localhost:${QUERY_HTTP_HANDLER_PORT}/v1/query" -
 
 ## copy with purge
 cmd="copy into ontime200 from @~  PATTERN = 'ontime_200.csv.*$' FILE_FORMAT = (type = CSV field_delimiter = ',' compression = 'auto'  record_delimiter = '\n' skip_header = 1) purge = true;"
-echo $cmd | $MYSQL_CLIENT_CONNECT
+echo $cmd | $MYSQL_CLIENT_CONNECT > /dev/null
 
 ## list stage has metacache, so we just we aws client to ensure the data are purged
 aws --endpoint-url ${STORAGE_S3_ENDPOINT_URL} s3 ls s3://testbucket/admin/stage/user/root/ | grep -o ontime_200.csv  | wc -l
@@ -61,8 +61,8 @@ aws --endpoint-url ${STORAGE_S3_ENDPOINT_URL} s3 ls s3://testbucket/admin/stage/
 ## copy with force=true
 echo "truncate table ontime200" | $MYSQL_CLIENT_CONNECT
 cmd="copy into ontime200 from @~ PATTERN = 'ontime.*parquet$' FILE_FORMAT = (type = PARQUET) force=true;"
-echo $cmd | $MYSQL_CLIENT_CONNECT
-echo $cmd | $MYSQL_CLIENT_CONNECT
+echo $cmd | $MYSQL_CLIENT_CONNECT > /dev/null
+echo $cmd | $MYSQL_CLIENT_CONNECT > /dev/null
 echo "select count(1), avg(Year), sum(DayOfWeek)  from ontime200" | $MYSQL_CLIENT_CONNECT
 
 ## Drop table.
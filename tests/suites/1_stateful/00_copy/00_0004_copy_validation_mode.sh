@@ -0,0 +1,35 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "drop table if exists validation_csv;" | $MYSQL_CLIENT_CONNECT
+
+## Create table
+echo "create table validation_csv(Id int, City varchar, Score int);" | $MYSQL_CLIENT_CONNECT
+
+# Should be <root>/tests/data/
+DATADIR=$(realpath $CURDIR/../../../data/)
+
+CSV_FORMAT="FILE_FORMAT = (type = CSV field_delimiter = ','  record_delimiter = '\n' skip_header = 0)"
+
+# a clean file has no errors
+echo "COPY INTO validation_csv FROM 'fs://${DATADIR}/sample.csv' ${CSV_FORMAT} VALIDATION_MODE = 'RETURN_ERRORS'" | $MYSQL_CLIENT_CONNECT | wc -l
+
+# the errors of a file with type errors are returned
+echo "COPY INTO validation_csv FROM 'fs://${DATADIR}/wrong_sample.csv' ${CSV_FORMAT} VALIDATION_MODE = 'RETURN_ERRORS'" | $MYSQL_CLIENT_CONNECT | grep -c "fail to decode column"
+echo "COPY INTO validation_csv FROM 'fs://${DATADIR}/wrong_sample.csv' ${CSV_FORMAT} VALIDATION_MODE = 'RETURN_ALL_ERRORS'" | $MYSQL_CLIENT_CONNECT | grep -c "fail to decode column"
+
+# the first rows of a clean file are returned
+echo "COPY INTO validation_csv FROM 'fs://${DATADIR}/sample.csv' ${CSV_FORMAT} VALIDATION_MODE = 'RETURN_2_ROWS'" | $MYSQL_CLIENT_CONNECT | cut -f1
+
+# fails on the first error of a file with type errors
+echo "COPY INTO validation_csv FROM 'fs://${DATADIR}/wrong_sample.csv' ${CSV_FORMAT} VALIDATION_MODE = 'RETURN_5_ROWS'" | $MYSQL_CLIENT_CONNECT 2>&1 | grep -c "fail to decode column"
+
+# nothing is loaded, and the files can still be copied
+echo "select count(1) from validation_csv" | $MYSQL_CLIENT_CONNECT
+echo "COPY INTO validation_csv FROM 'fs://${DATADIR}/sample.csv' ${CSV_FORMAT}" | $MYSQL_CLIENT_CONNECT > /dev/null
+echo "select count(1) from validation_csv" | $MYSQL_CLIENT_CONNECT
+
+## Drop table
+echo "drop table if exists validation_csv;" | $MYSQL_CLIENT_CONNECT
@@ -0,0 +1,53 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "drop table if exists infer_csv;" | $MYSQL_CLIENT_CONNECT
+echo "drop table if exists infer_tsv;" | $MYSQL_CLIENT_CONNECT
+echo "drop table if exists ontime_infer;" | $MYSQL_CLIENT_CONNECT
+
+## Create table
+echo "create table infer_csv(Id int, City varchar, Score int);" | $MYSQL_CLIENT_CONNECT
+echo "create table infer_tsv(a int, b string);" | $MYSQL_CLIENT_CONNECT
+cat $CURDIR/../ddl/ontime.sql | sed 's/ontime/ontime_infer/g' | $MYSQL_CLIENT_CONNECT
+
+# Should be <root>/tests/data/
+DATADIR=$(realpath $CURDIR/../../../data/)
+
+echo "---csv"
+echo "copy into infer_csv from 'fs://${DATADIR}/sample.csv'" | $MYSQL_CLIENT_CONNECT > /dev/null
+echo "select count(1), sum(Score) from infer_csv" | $MYSQL_CLIENT_CONNECT
+
+echo "---tsv"
+echo "copy into infer_tsv from 'fs://${DATADIR}/field_white_space.tsv'" | $MYSQL_CLIENT_CONNECT > /dev/null
+echo "select count(1), sum(a) from infer_tsv" | $MYSQL_CLIENT_CONNECT
+
+echo "---ndjson"
+echo "copy into ontime_infer from 'fs://${DATADIR}/ontime_200.ndjson'" | $MYSQL_CLIENT_CONNECT > /dev/null
+echo "select count(1), avg(Year), sum(DayOfWeek) from ontime_infer" | $MYSQL_CLIENT_CONNECT
+echo "truncate table ontime_infer" | $MYSQL_CLIENT_CONNECT
+
+echo "---parquet"
+echo "copy into ontime_infer from 'fs://${DATADIR}/ontime_200.parquet'" | $MYSQL_CLIENT_CONNECT > /dev/null
+echo "select count(1), avg(Year), sum(DayOfWeek) from ontime_infer" | $MYSQL_CLIENT_CONNECT
+echo "truncate table ontime_infer" | $MYSQL_CLIENT_CONNECT
+
+echo "---mixed extensions"
+echo "copy into ontime_infer from 'fs://${DATADIR}/' PATTERN = 'ontime_200[.](ndjson|parquet)$'" | $MYSQL_CLIENT_CONNECT 2>&1 | grep -c "cannot infer the file format"
+echo "select count(1) from ontime_infer" | $MYSQL_CLIENT_CONNECT
+
+echo "---unknown extension"
+INFER_DIR=$(mktemp -d)
+echo "7,'Hangzhou',90" > $INFER_DIR/sample.txt
+echo "copy into infer_csv from 'fs://${INFER_DIR}/sample.txt'" | $MYSQL_CLIENT_CONNECT 2>&1 | grep -c "cannot infer the file format"
+
+echo "---explicit format"
+echo "copy into infer_csv from 'fs://${INFER_DIR}/sample.txt' FILE_FORMAT = (type = CSV)" | $MYSQL_CLIENT_CONNECT > /dev/null
+echo "select count(1), sum(Score) from infer_csv" | $MYSQL_CLIENT_CONNECT
+rm -rf $INFER_DIR
+
+## Drop table
+echo "drop table if exists infer_csv;" | $MYSQL_CLIENT_CONNECT
+echo "drop table if exists infer_tsv;" | $MYSQL_CLIENT_CONNECT
+echo "drop table if exists ontime_infer;" | $MYSQL_CLIENT_CONNECT
@@ -0,0 +1,32 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "drop table if exists summary_csv;" | $MYSQL_CLIENT_CONNECT
+echo "drop stage if exists summary_stage;" | $MYSQL_CLIENT_CONNECT
+
+## Create table
+echo "create table summary_csv(Id int, City varchar, Score int);" | $MYSQL_CLIENT_CONNECT
+echo "create stage summary_stage;" | $MYSQL_CLIENT_CONNECT
+
+# Should be <root>/tests/data/
+DATADIR=$(realpath $CURDIR/../../../data/)
+
+CSV_FORMAT="FILE_FORMAT = (type = CSV field_delimiter = ','  record_delimiter = '\n' skip_header = 0)"
+
+# files_processed, rows_loaded, rows_failed, bytes
+echo "COPY INTO summary_csv FROM 'fs://${DATADIR}/sample.csv' ${CSV_FORMAT}" | $MYSQL_CLIENT_CONNECT
+
+# the file is already copied
+echo "COPY INTO summary_csv FROM 'fs://${DATADIR}/sample.csv' ${CSV_FORMAT}" | $MYSQL_CLIENT_CONNECT
+
+# the rows with errors are skipped, file, rows_loaded, errors_seen, error of each file
+echo "COPY INTO summary_csv FROM 'fs://${DATADIR}/wrong_sample.csv' ${CSV_FORMAT} ON_ERROR = continue" | $MYSQL_CLIENT_CONNECT | cut -f2-3
+
+# the size of the unloaded data depends on the format, only the files and rows are checked
+echo "COPY INTO @summary_stage FROM summary_csv FILE_FORMAT = (type = CSV)" | $MYSQL_CLIENT_CONNECT | cut -f1-3
+
+## Drop table
+echo "drop table if exists summary_csv;" | $MYSQL_CLIENT_CONNECT
+echo "drop stage if exists summary_stage;" | $MYSQL_CLIENT_CONNECT
@@ -0,0 +1,44 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+export TEST_USER_PASSWORD="password"
+export TEST_USER_CONNECT="mysql --defaults-extra-file=password.out --port ${QUERY_MYSQL_HANDLER_PORT} -s"
+echo -e "[mysql]\nhost=${QUERY_MYSQL_HANDLER_HOST}\nuser=test-user\npassword=${TEST_USER_PASSWORD}" >> password.out
+
+echo "drop table if exists t_00_0007;" | $MYSQL_CLIENT_CONNECT
+echo "drop stage if exists s_00_0007;" | $MYSQL_CLIENT_CONNECT
+echo "drop function if exists f_00_0007;" | $MYSQL_CLIENT_CONNECT
+echo "drop user if exists 'test-user'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+
+echo "create user 'test-user'@'$QUERY_MYSQL_HANDLER_HOST' IDENTIFIED BY '$TEST_USER_PASSWORD'" | $MYSQL_CLIENT_CONNECT
+echo "create stage s_00_0007;" | $MYSQL_CLIENT_CONNECT
+echo "copy into @s_00_0007 from (select number from numbers(3)) FILE_FORMAT = (type = CSV);" | $MYSQL_CLIENT_CONNECT > /dev/null
+echo "create table t_00_0007(c int);" | $MYSQL_CLIENT_CONNECT
+echo "GRANT INSERT ON default.t_00_0007 TO 'test-user'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+
+## copy from the stage without the READ privilege
+echo "copy into t_00_0007 from @s_00_0007 FILE_FORMAT = (type = CSV);" | $TEST_USER_CONNECT > /dev/null
+echo "GRANT READ ON STAGE s_00_0007 TO 'test-user'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+echo "copy into t_00_0007 from @s_00_0007 FILE_FORMAT = (type = CSV);" | $TEST_USER_CONNECT > /dev/null
+echo "select count(*) from t_00_0007;" | $MYSQL_CLIENT_CONNECT
+
+## copy into the stage without the WRITE privilege
+echo "GRANT SELECT ON default.t_00_0007 TO 'test-user'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+echo "copy into @s_00_0007 from t_00_0007 FILE_FORMAT = (type = CSV);" | $TEST_USER_CONNECT > /dev/null
+echo "GRANT WRITE ON STAGE s_00_0007 TO 'test-user'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+echo "copy into @s_00_0007 from t_00_0007 FILE_FORMAT = (type = CSV);" | $TEST_USER_CONNECT > /dev/null
+echo "list @s_00_0007;" | $TEST_USER_CONNECT | wc -l | sed 's/ //g'
+
+## call the udf without the USAGE privilege
+echo "create function f_00_0007 as (a) -> a + 1;" | $MYSQL_CLIENT_CONNECT
+echo "select f_00_0007(1);" | $TEST_USER_CONNECT
+echo "GRANT USAGE ON UDF f_00_0007 TO 'test-user'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+echo "select f_00_0007(1);" | $TEST_USER_CONNECT
+
+echo "drop table t_00_0007 all;" | $MYSQL_CLIENT_CONNECT
+echo "drop stage s_00_0007;" | $MYSQL_CLIENT_CONNECT
+echo "drop function f_00_0007;" | $MYSQL_CLIENT_CONNECT
+echo "drop user 'test-user'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+rm -rf password.out
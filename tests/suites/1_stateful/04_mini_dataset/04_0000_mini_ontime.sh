@@ -7,9 +7,10 @@ echo "drop table if exists ontime_mini;" | $MYSQL_CLIENT_CONNECT
 ## Create table
 cat $CURDIR/../ddl/ontime.sql | sed 's/ontime/ontime_mini/g' | $MYSQL_CLIENT_CONNECT
 
+## Load data
+echo "COPY INTO ontime_mini FROM 'https://repo.databend.rs/dataset/stateful/ontime_2006_100000.csv' FILE_FORMAT = ( type = CSV field_delimiter = ',' record_delimiter = '\n' skip_header = 1 );" | $MYSQL_CLIENT_CONNECT > /dev/null
+
 ontime_statements=(
-  ## Load data
-  "COPY INTO ontime_mini FROM 'https://repo.databend.rs/dataset/stateful/ontime_2006_100000.csv' FILE_FORMAT = ( type = CSV field_delimiter = ',' record_delimiter = '\n' skip_header = 1 );"
   ## run test
   "SELECT DayOfWeek, count(*) AS c FROM ontime_mini WHERE (Year >= 2000) AND (Year <= 2008) GROUP BY DayOfWeek ORDER BY c DESC;"
   "SELECT DayOfWeek, count(*) AS c FROM ontime_mini WHERE (DepDelay > 10) AND (Year >= 2000) AND (Year <= 2008) GROUP BY DayOfWeek ORDER BY c DESC;"
@@ -7,9 +7,10 @@ echo "drop table if exists hits;" | $MYSQL_CLIENT_CONNECT
 ## Create table
 cat $CURDIR/../ddl/hits.sql | $MYSQL_CLIENT_CONNECT
 
+## Load data
+echo "COPY INTO hits FROM 'https://repo.databend.rs/dataset/stateful/hits_100k.tsv' FILE_FORMAT = ( type = 'tsv' record_delimiter = '\n' skip_header = 1 );" | $MYSQL_CLIENT_CONNECT > /dev/null
+
 hits_statements=(
-  ## load data
-  "COPY INTO hits FROM 'https://repo.databend.rs/dataset/stateful/hits_100k.tsv' FILE_FORMAT = ( type = 'tsv' record_delimiter = '\n' skip_header = 1 );"
   ## run test
   "SELECT '====== SQL1 ======';"
   "SELECT COUNT(*) FROM hits;"
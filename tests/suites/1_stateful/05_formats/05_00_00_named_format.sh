@@ -31,23 +31,23 @@ echo "create stage stage_05_00_00 url = '${DATADIR}' FILE_FORMAT = (FORMAT_NAME
 
 # test copy into table
 echo "---copy into table, use format csv from stage"
-echo "copy into table_csv from @stage_05_00_00  pattern = '.*csv' ;" | $MYSQL_CLIENT_CONNECT
+echo "copy into table_csv from @stage_05_00_00  pattern = '.*csv' ;" | $MYSQL_CLIENT_CONNECT > /dev/null
 echo "select count(*) from table_csv" | $MYSQL_CLIENT_CONNECT
 
 echo "---copy into table, use format csv from copy stmt"
-echo "copy into table_csv from @stage_05_00_00 FILE_FORMAT = ( FORMAT_NAME = 'my_csv') pattern = '.*csv' ;" | $MYSQL_CLIENT_CONNECT
+echo "copy into table_csv from @stage_05_00_00 FILE_FORMAT = ( FORMAT_NAME = 'my_csv') pattern = '.*csv' ;" | $MYSQL_CLIENT_CONNECT > /dev/null
 echo "select count(*) from table_csv" | $MYSQL_CLIENT_CONNECT
 
 echo "---copy into table, use format my_parquet from copy stmt"
-echo "copy into table_parquet from @stage_05_00_00 FILE_FORMAT = ( FORMAT_NAME = 'my_parquet') pattern = '.*parquet' ;" | $MYSQL_CLIENT_CONNECT
+echo "copy into table_parquet from @stage_05_00_00 FILE_FORMAT = ( FORMAT_NAME = 'my_parquet') pattern = '.*parquet' ;" | $MYSQL_CLIENT_CONNECT > /dev/null
 echo "select count(*) from table_parquet" | $MYSQL_CLIENT_CONNECT
 echo "---copy into table, use format parquet from copy stmt"
-echo "copy into table_parquet from @stage_05_00_00 FILE_FORMAT = ( FORMAT_NAME = 'parquet') pattern = '.*parquet' ;" | $MYSQL_CLIENT_CONNECT
+echo "copy into table_parquet from @stage_05_00_00 FILE_FORMAT = ( FORMAT_NAME = 'parquet') pattern = '.*parquet' ;" | $MYSQL_CLIENT_CONNECT > /dev/null
 echo "select count(*) from table_parquet" | $MYSQL_CLIENT_CONNECT
 
 # test copy from table
 echo "---copy from table"
-echo "copy into @stage_05_00_00 from table_csv FILE_FORMAT = ( FORMAT_NAME = 'my_csv')  pattern = '.*csv' ;" | $MYSQL_CLIENT_CONNECT
+echo "copy into @stage_05_00_00 from table_csv FILE_FORMAT = ( FORMAT_NAME = 'my_csv')  pattern = '.*csv' ;" | $MYSQL_CLIENT_CONNECT > /dev/null
 cat $DATADIR_PATH/*.csv | wc -l | sed 's/ //g'
 
 # test select stage
@@ -22,7 +22,7 @@ aws --endpoint-url http://127.0.0.1:9900/ s3 cp $DATA s3://testbucket/$DATA > /d
 echo "---copy into"
 # let input data dispatch to multi threads
 echo "set global input_read_buffer_size = 100" | $MYSQL_CLIENT_CONNECT
-echo "copy into t1 from 's3://testbucket/${DATA}' connection=(aws_key_id='minioadmin' aws_secret_key='minioadmin' endpoint_url='http://127.0.0.1:9900/') FILE_FORMAT = (type = CSV) force=true" | $MYSQL_CLIENT_CONNECT
+echo "copy into t1 from 's3://testbucket/${DATA}' connection=(aws_key_id='minioadmin' aws_secret_key='minioadmin' endpoint_url='http://127.0.0.1:9900/') FILE_FORMAT = (type = CSV) force=true" | $MYSQL_CLIENT_CONNECT > /dev/null
 echo "set global input_read_buffer_size = 1048576" | $MYSQL_CLIENT_CONNECT
 
 echo "---row_count"
@@ -26,7 +26,7 @@ echo "---copy into"
 # let input data dispatch to multi threads
 # echo "set global max_threads = 1" | $MYSQL_CLIENT_CONNECT # for debug
 echo "set global input_read_buffer_size = 100" | $MYSQL_CLIENT_CONNECT
-echo "copy into t1 from 's3://testbucket/${DATA}' connection=(aws_key_id='minioadmin' aws_secret_key='minioadmin' endpoint_url='http://127.0.0.1:9900/') FILE_FORMAT = (type = CSV) force=true" | $MYSQL_CLIENT_CONNECT
+echo "copy into t1 from 's3://testbucket/${DATA}' connection=(aws_key_id='minioadmin' aws_secret_key='minioadmin' endpoint_url='http://127.0.0.1:9900/') FILE_FORMAT = (type = CSV) force=true" | $MYSQL_CLIENT_CONNECT > /dev/null
 echo "set global input_read_buffer_size = 1048576" | $MYSQL_CLIENT_CONNECT
 
 echo "---row_count"
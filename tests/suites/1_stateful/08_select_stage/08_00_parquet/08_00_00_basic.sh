@@ -10,13 +10,13 @@ echo "insert into t1 (id,name,age) values(1,'2',3), (4, '5', 6);" | $MYSQL_CLIEN
 echo '--- named internal stage'
 echo "drop stage if exists s1;" | $MYSQL_CLIENT_CONNECT
 echo "create stage s1 FILE_FORMAT = (type = PARQUET);" | $MYSQL_CLIENT_CONNECT
-echo "copy into @s1 from t1;" | $MYSQL_CLIENT_CONNECT
+echo "copy into @s1 from t1;" | $MYSQL_CLIENT_CONNECT > /dev/null
 echo "select * from @s1;" | $MYSQL_CLIENT_CONNECT
 
 DATADIR_PATH="/tmp/08_00_00"
 rm -rf ${DATADIR_PATH}
 DATADIR="fs://$DATADIR_PATH/"
-echo "copy into '${DATADIR}' from t1 FILE_FORMAT = (type = PARQUET);" | $MYSQL_CLIENT_CONNECT
+echo "copy into '${DATADIR}' from t1 FILE_FORMAT = (type = PARQUET);" | $MYSQL_CLIENT_CONNECT > /dev/null
 
 #echo '--- uri'
 #echo "select * from '${DATADIR}';" | $MYSQL_CLIENT_CONNECT
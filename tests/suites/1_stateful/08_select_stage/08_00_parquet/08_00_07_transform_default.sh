@@ -0,0 +1,39 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../../shell_env.sh
+
+echo "drop table if exists t1;" | $MYSQL_CLIENT_CONNECT
+echo "drop table if exists t2;" | $MYSQL_CLIENT_CONNECT
+echo "drop table if exists t3;" | $MYSQL_CLIENT_CONNECT
+echo "CREATE TABLE t1 (id INT, age INT);" | $MYSQL_CLIENT_CONNECT
+echo "insert into t1 (id, age) values(1,3), (4, 6);" | $MYSQL_CLIENT_CONNECT
+echo "CREATE TABLE t2 (id INT NOT NULL, city VARCHAR NOT NULL DEFAULT 'unknown', age INT NOT NULL, score INT NULL);" | $MYSQL_CLIENT_CONNECT
+echo "CREATE TABLE t3 (id INT NOT NULL, age INT NOT NULL, city VARCHAR NOT NULL);" | $MYSQL_CLIENT_CONNECT
+
+DATADIR_PATH="/tmp/08_00_07"
+rm -rf ${DATADIR_PATH}
+DATADIR="fs://$DATADIR_PATH/"
+echo "copy into '${DATADIR}' from t1 FILE_FORMAT = (type = PARQUET);" | $MYSQL_CLIENT_CONNECT > /dev/null
+
+echo "drop stage if exists s1;" | $MYSQL_CLIENT_CONNECT
+echo "create stage s1 url = '${DATADIR}' FILE_FORMAT = (type = PARQUET);"  | $MYSQL_CLIENT_CONNECT
+
+echo '--- columns mapped by name'
+echo "copy into t2 from (select age, id from @s1 t);" | $MYSQL_CLIENT_CONNECT > /dev/null
+echo "select * from t2 order by id;" | $MYSQL_CLIENT_CONNECT
+echo "truncate table t2;" | $MYSQL_CLIENT_CONNECT
+
+echo '--- columns mapped by position'
+echo "copy into t2 from (select (t.id+10), 'beijing', age from @s1 t) force=true;" | $MYSQL_CLIENT_CONNECT > /dev/null
+echo "select * from t2 order by id;" | $MYSQL_CLIENT_CONNECT
+
+echo '--- missing column without default'
+echo "copy into t3 from (select id, age from @s1 t);" | $MYSQL_CLIENT_CONNECT 2>&1 | grep -c "column \`city\` is not nullable and has no default value"
+echo "select count(*) from t3;" | $MYSQL_CLIENT_CONNECT
+
+echo "drop table if exists t1;" | $MYSQL_CLIENT_CONNECT
+echo "drop table if exists t2;" | $MYSQL_CLIENT_CONNECT
+echo "drop table if exists t3;" | $MYSQL_CLIENT_CONNECT
+echo "drop stage if exists s1;" | $MYSQL_CLIENT_CONNECT
+rm -rf ${DATADIR_PATH}
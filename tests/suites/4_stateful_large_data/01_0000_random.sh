@@ -0,0 +1,11 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../shell_env.sh
+
+echo "create table oracle (a int, b timestamp, c String) Engine = Random;" | $MYSQL_CLIENT_CONNECT
+echo "create stage oracle;" | $MYSQL_CLIENT_CONNECT
+echo "copy into @oracle from (select * from oracle limit 10000) file_format=(type=parquet) max_file_size=8;" | $MYSQL_CLIENT_CONNECT > /dev/null
+echo "create table target (a int, b timestamp, c String);" | $MYSQL_CLIENT_CONNECT
+echo "copy into target from @oracle file_format=(type=parquet);" | $MYSQL_CLIENT_CONNECT > /dev/null
+echo "select count(*) from target;" | $MYSQL_CLIENT_CONNECT